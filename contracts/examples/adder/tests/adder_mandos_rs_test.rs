@@ -1,4 +1,4 @@
-use elrond_wasm_debug::*;
+use elrond_wasm_debug::{elrond_wasm::contract_base::ContractAbiProvider, *};
 
 fn world() -> BlockchainMock {
     let mut blockchain = BlockchainMock::new();
@@ -12,3 +12,32 @@ fn world() -> BlockchainMock {
 fn adder_rs() {
     elrond_wasm_debug::mandos_rs("mandos/adder.scen.json", world());
 }
+
+#[test]
+fn adder_coverage_rs() {
+    let report_dir = std::env::temp_dir().join("adder-coverage-rs");
+    let _ = std::fs::remove_dir_all(&report_dir);
+
+    let mut world = world();
+    world.register_contract_abi("file:output/adder.wasm", adder::AbiProvider::abi());
+    world.enable_coverage();
+    elrond_wasm_debug::mandos_rs_mut("mandos/adder.scen.json", &mut world);
+    world.write_coverage_reports(&report_dir);
+
+    let report = std::fs::read_to_string(report_dir.join("Adder.coverage.txt")).unwrap();
+    assert!(report.starts_with("Contract Adder: 3 out of 3 endpoints called"));
+    assert!(report.contains("\tadd: 1\n"));
+}
+
+#[test]
+#[should_panic(expected = "cannot write coverage reports")]
+fn adder_coverage_unwritable_dir_rs() {
+    // a file is in the way, the report directory cannot be created
+    let blocking_file = std::env::temp_dir().join("adder-coverage-rs-blocked");
+    std::fs::write(&blocking_file, b"").unwrap();
+
+    let mut world = world();
+    world.enable_coverage();
+    elrond_wasm_debug::mandos_rs_mut("mandos/adder.scen.json", &mut world);
+    world.write_coverage_reports(blocking_file.join("reports"));
+}
@@ -65,3 +65,8 @@ fn withdraw_ok_rs() {
 fn withdraw_toomuch_rs() {
     elrond_wasm_debug::mandos_rs("mandos/withdraw_TooMuch.scen.json", world());
 }
+
+#[test]
+fn all_scenarios_parallel_rs() {
+    elrond_wasm_debug::mandos_rs_dir("mandos", world);
+}
@@ -9,3 +9,25 @@ pub enum AbiEnum {
     SomethingMore(u8, OnlyShowsUpAsNested08),
     SomeStruct { a: u16, b: OnlyShowsUpAsNested09 },
 }
+
+/// Tests that explicit discriminants show up in the ABI as encoded.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum ExplicitDiscriminant {
+    Zero,
+    Thirty = 30,
+    ThirtyOne,
+}
+
+/// Tests that `#[codec(discriminant = ...)]` and `#[codec(skip)]` show up in the ABI as encoded.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum CodecAttributesEnum {
+    #[codec(discriminant = 5)]
+    Five,
+    Six(u8, #[codec(skip)] u64),
+    #[codec(discriminant = 10)]
+    Ten {
+        a: u16,
+        #[codec(default)]
+        b: u32,
+    },
+}
@@ -33,3 +33,22 @@ pub struct AbiManagedVecItem {
     pub value1: u32,
     pub value2: u32,
 }
+
+/// Tests that `#[codec(version = ...)]` shows up in the ABI.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+#[codec(version = 2)]
+pub struct VersionedStruct {
+    pub int: u16,
+    #[codec(since = 2)]
+    pub added_in_v2: u32,
+}
+
+/// Only used in storage, shows up in the ABI types so that its layout can be checked.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyInStorage {
+    pub amount: u64,
+    pub round: u32,
+}
+
+/// Storage types are named via `TypeAbi`, so renaming an alias does not change the ABI.
+pub type StorageEntryId = u32;
@@ -8,6 +8,7 @@ mod only_nested;
 
 use abi_enum::*;
 use abi_test_type::*;
+use elrond_wasm::elrond_codec::{Compact, CompactVec};
 use only_nested::*;
 
 /// Contract whose sole purpose is to verify that
@@ -36,6 +37,20 @@ pub trait AbiTester {
         e
     }
 
+    #[endpoint]
+    fn echo_explicit_discriminant(&self, e: ExplicitDiscriminant) -> CodecAttributesEnum {
+        match e {
+            ExplicitDiscriminant::Zero => CodecAttributesEnum::Five,
+            ExplicitDiscriminant::Thirty => CodecAttributesEnum::Six(30, 0),
+            ExplicitDiscriminant::ThirtyOne => CodecAttributesEnum::Ten { a: 31, b: 0 },
+        }
+    }
+
+    #[endpoint]
+    fn echo_versioned_struct(&self, s: VersionedStruct) -> VersionedStruct {
+        s
+    }
+
     #[endpoint]
     #[only_owner]
     fn take_managed_type(&self, _arg: AbiManagedType<Self::Api>) {}
@@ -111,6 +126,13 @@ pub trait AbiTester {
     #[storage_mapper("sample_storage_mapper")]
     fn sample_storage_mapper(&self) -> SingleValueMapper<OnlyShowsUpAsNestedInSingleValueMapper>;
 
+    /// Storage mappers with arguments show up in the ABI storage section, with their key arguments.
+    #[storage_mapper("sample_map_mapper")]
+    fn sample_map_mapper(&self, owner: &ManagedAddress) -> MapMapper<u64, BigUint>;
+
+    #[storage_mapper("only_in_storage")]
+    fn only_in_storage(&self, id: StorageEntryId) -> SingleValueMapper<OnlyInStorage>;
+
     #[view]
     fn item_for_vec(&self) -> Vec<OnlyShowsUpAsNestedInVec> {
         Vec::new()
@@ -150,6 +172,25 @@ pub trait AbiTester {
         None
     }
 
+    /// Compact numbers and lists are named explicitly, since their nested encoding differs.
+    #[view]
+    fn item_for_compact_vec(
+        &self,
+        _counter: Compact<u64>,
+    ) -> CompactVec<OnlyShowsUpAsNestedInCompactVec> {
+        CompactVec::new()
+    }
+
+    /// Pages show up as 2 results, the next cursor and the variadic items.
+    #[view]
+    fn sample_page(
+        &self,
+        _cursor: usize,
+        _limit: usize,
+    ) -> MultiValuePage<MultiValue2<u64, BigUint>> {
+        MultiValuePage::new(0, MultiValueEncoded::new())
+    }
+
     #[endpoint]
     #[payable("EGLD")]
     fn payable_egld(&self) {}
@@ -0,0 +1,233 @@
+use std::fs;
+
+use elrond_wasm::contract_base::ContractAbiProvider;
+use elrond_wasm_debug::{
+    abi_json::{deserialize_abi_from_json, ContractAbiJson, StructFieldDescriptionJson},
+    meta::abi_breaking_changes,
+};
+
+/// The committed ABI plays the role of the previous contract version.
+fn old_abi() -> ContractAbiJson {
+    let abi_string = fs::read_to_string("./abi_tester_expected_main.abi.json").unwrap();
+    deserialize_abi_from_json(abi_string.as_str()).unwrap()
+}
+
+fn new_abi() -> ContractAbiJson {
+    ContractAbiJson::from(&<abi_tester::AbiProvider>::abi().main_contract())
+}
+
+fn field(name: &str, since: Option<u8>, default: bool) -> StructFieldDescriptionJson {
+    StructFieldDescriptionJson {
+        docs: Vec::new(),
+        name: name.to_string(),
+        field_type: "u64".to_string(),
+        since,
+        default,
+    }
+}
+
+#[test]
+fn abi_compat_same_version_test() {
+    assert!(abi_breaking_changes(&old_abi(), &new_abi()).is_empty());
+}
+
+#[test]
+fn abi_compat_endpoints_test() {
+    let mut new_abi = new_abi();
+    new_abi
+        .endpoints
+        .retain(|endpoint| endpoint.name != "multi_result_vec");
+    for endpoint in new_abi.endpoints.iter_mut() {
+        match endpoint.name.as_str() {
+            "payable_egld" => endpoint.name = "payEgld".to_string(),
+            "take_managed_type" => endpoint.inputs[0].type_name = "BigInt".to_string(),
+            "payable_some_token" => endpoint.payable_in_tokens = vec!["OTHER-TOKEN".to_string()],
+            "esdt_token_payment" => endpoint.only_owner = Some(true),
+            _ => {},
+        }
+    }
+
+    assert_eq!(
+        abi_breaking_changes(&old_abi(), &new_abi),
+        vec![
+            "endpoint `take_managed_type`: argument `_arg` changed type from `AbiManagedType` to `BigInt`",
+            "endpoint `multi_result_vec` removed",
+            "endpoint `esdt_token_payment`: became only owner",
+            "endpoint `payable_egld` removed, possibly renamed to `payEgld`",
+            "endpoint `payable_some_token`: no longer payable in `TOKEN-FOR-ABI`",
+        ]
+    );
+}
+
+#[test]
+fn abi_compat_optional_argument_test() {
+    let mut extra_args = old_abi()
+        .endpoints
+        .into_iter()
+        .find(|endpoint| endpoint.name == "optional_arg")
+        .unwrap()
+        .inputs;
+    let mut new_abi = new_abi();
+    let endpoint_index = new_abi
+        .endpoints
+        .iter()
+        .position(|endpoint| endpoint.name == "take_managed_type")
+        .unwrap();
+
+    // `_opt_args`, optional
+    new_abi.endpoints[endpoint_index]
+        .inputs
+        .push(extra_args.pop().unwrap());
+    assert!(abi_breaking_changes(&old_abi(), &new_abi).is_empty());
+
+    // `_simple_arg`, required
+    new_abi.endpoints[endpoint_index]
+        .inputs
+        .push(extra_args.pop().unwrap());
+    assert_eq!(
+        abi_breaking_changes(&old_abi(), &new_abi),
+        vec!["endpoint `take_managed_type`: required argument `_simple_arg` added"]
+    );
+}
+
+#[test]
+fn abi_compat_events_and_types_test() {
+    let mut new_abi = new_abi();
+    new_abi.events[0].inputs[1].indexed = Some(true);
+    new_abi.events.remove(1);
+    new_abi.types.get_mut("AbiEnum").unwrap().variants.remove(0);
+    new_abi
+        .types
+        .get_mut("AbiTestType")
+        .unwrap()
+        .fields
+        .swap(0, 1);
+
+    let breaking_changes = abi_breaking_changes(&old_abi(), &new_abi);
+    assert_eq!(breaking_changes.len(), 4);
+    assert_eq!(
+        breaking_changes[0],
+        "event `payable-event`: signature changed from `token: TokenIdentifier (indexed), amount: BigUint` to `token: TokenIdentifier (indexed), amount: BigUint (indexed)`"
+    );
+    assert_eq!(breaking_changes[1], "event `address-h256-event` removed");
+    assert_eq!(
+        breaking_changes[2],
+        "enum `AbiEnum`: variant `Nothing` removed"
+    );
+    assert!(breaking_changes[3].starts_with("struct `AbiTestType`: fields changed"));
+}
+
+#[test]
+fn abi_compat_constructor_test() {
+    let mut new_abi = new_abi();
+    let constructor = new_abi.constructor.as_mut().unwrap();
+    constructor.inputs[0].type_name = "u64".to_string();
+    constructor.payable_in_tokens.clear();
+    assert_eq!(
+        abi_breaking_changes(&old_abi(), &new_abi),
+        vec![
+            "constructor: argument `_constructor_arg_1` changed type from `i32` to `u64`",
+            "constructor: no longer payable in `EGLD`",
+        ]
+    );
+
+    new_abi.constructor = None;
+    assert_eq!(
+        abi_breaking_changes(&old_abi(), &new_abi),
+        vec!["constructor removed"]
+    );
+}
+
+#[test]
+fn abi_compat_version_test() {
+    let mut new_abi = new_abi();
+    new_abi.types.get_mut("VersionedStruct").unwrap().version = Some(3);
+    assert!(abi_breaking_changes(&old_abi(), &new_abi).is_empty());
+
+    new_abi.types.get_mut("VersionedStruct").unwrap().version = None;
+    assert_eq!(
+        abi_breaking_changes(&old_abi(), &new_abi),
+        vec!["struct `VersionedStruct`: version changed from 2 to none"]
+    );
+}
+
+#[test]
+fn abi_compat_appended_fields_test() {
+    let mut new_abi = new_abi();
+    let versioned_struct = new_abi.types.get_mut("VersionedStruct").unwrap();
+    versioned_struct.version = Some(3);
+    versioned_struct
+        .fields
+        .push(field("added_in_v3", Some(3), false));
+    assert!(abi_breaking_changes(&old_abi(), &new_abi).is_empty());
+
+    let abi_test_type = new_abi.types.get_mut("AbiTestType").unwrap();
+    abi_test_type.fields.push(field("with_default", None, true));
+    assert!(abi_breaking_changes(&old_abi(), &new_abi).is_empty());
+
+    // older data was already encoded with version 2
+    new_abi
+        .types
+        .get_mut("VersionedStruct")
+        .unwrap()
+        .fields
+        .push(field("added_in_v2_too", Some(2), false));
+    new_abi
+        .types
+        .get_mut("AbiTestType")
+        .unwrap()
+        .fields
+        .push(field("plain", None, false));
+    let breaking_changes = abi_breaking_changes(&old_abi(), &new_abi);
+    assert_eq!(breaking_changes.len(), 2);
+    assert!(breaking_changes[0].starts_with("struct `AbiTestType`: fields changed"));
+    assert_eq!(
+        breaking_changes[1],
+        "struct `VersionedStruct`: fields changed from `int: u16, added_in_v2: u32` to `int: u16, added_in_v2: u32, added_in_v3: u64, added_in_v2_too: u64`"
+    );
+}
+
+#[test]
+fn abi_compat_storage_test() {
+    let mut new_abi = new_abi();
+    for storage in new_abi.storage.iter_mut() {
+        if storage.identifier == "sample_map_mapper" {
+            storage.value_types[1] = "BigInt".to_string();
+            storage.key_args[0].type_name = "u32".to_string();
+        }
+    }
+    new_abi
+        .storage
+        .retain(|storage| storage.identifier != "sample_storage_mapper");
+
+    assert_eq!(
+        abi_breaking_changes(&old_abi(), &new_abi),
+        vec![
+            "storage `sample_map_mapper`: key arguments changed from `Address` to `u32`",
+            "storage `sample_map_mapper`: stored type changed from `u64, BigUint` to `u64, BigInt`",
+        ]
+    );
+
+    for storage in new_abi.storage.iter_mut() {
+        storage.mapper = "UnorderedSetMapper".to_string();
+    }
+    assert_eq!(
+        abi_breaking_changes(&old_abi(), &new_abi)[0],
+        "storage `sample_map_mapper`: mapper changed from `MapMapper` to `UnorderedSetMapper`"
+    );
+}
+
+#[test]
+fn abi_compat_storage_only_type_test() {
+    let mut new_abi = new_abi();
+    new_abi
+        .types
+        .get_mut("OnlyInStorage")
+        .unwrap()
+        .fields
+        .swap(0, 1);
+    assert_eq!(
+        abi_breaking_changes(&old_abi(), &new_abi),
+        vec!["struct `OnlyInStorage`: fields changed from `amount: u64, round: u32` to `round: u32, amount: u64`"]
+    );
+}
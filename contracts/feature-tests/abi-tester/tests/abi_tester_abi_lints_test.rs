@@ -0,0 +1,51 @@
+use elrond_wasm::{
+    abi::{ContractAbi, TypeDescriptionContainer},
+    contract_base::ContractAbiProvider,
+};
+use elrond_wasm_debug::meta::abi_lints;
+
+fn abi() -> ContractAbi {
+    <abi_tester::AbiProvider>::abi()
+}
+
+#[test]
+fn abi_lints_none_test() {
+    assert!(abi_lints(&abi()).is_empty());
+}
+
+#[test]
+fn abi_lints_endpoints_test() {
+    let mut abi = abi();
+    let mut duplicate_endpoint = abi.endpoints[0].clone();
+    duplicate_endpoint.module = "OtherModule";
+    abi.endpoints.push(duplicate_endpoint);
+    abi.endpoints[1].name = "ESDTTransfer";
+    abi.endpoints[2].name = "finish";
+
+    assert_eq!(
+        abi_lints(&abi),
+        vec![
+            "endpoint `echo_abi_test_type`: declared in both `AbiTester` and `OtherModule`",
+            "endpoint `ESDTTransfer` in `AbiTester`: same name as a builtin function",
+            "endpoint `finish` in `AbiTester`: same name as a VM API function",
+        ]
+    );
+}
+
+#[test]
+fn abi_lints_events_and_types_test() {
+    let mut abi = abi();
+    let mut duplicate_event = abi.events[0].clone();
+    duplicate_event.module = "OtherModule";
+    abi.events.push(duplicate_event);
+    abi.type_descriptions
+        .register_rust_type("AbiTestType", "other_crate::other_module::AbiTestType");
+
+    assert_eq!(
+        abi_lints(&abi),
+        vec![
+            "event `payable-event`: declared in both `AbiTester` and `OtherModule`",
+            "type `AbiTestType`: name used by `abi_tester::abi_test_type::AbiTestType`, `other_crate::other_module::AbiTestType`",
+        ]
+    );
+}
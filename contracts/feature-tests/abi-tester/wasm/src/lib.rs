@@ -10,6 +10,8 @@ elrond_wasm_node::wasm_endpoints! {
         address_vs_h256
         echo_abi_test_type
         echo_enum
+        echo_explicit_discriminant
+        echo_versioned_struct
         esdt_local_role
         esdt_token_data
         esdt_token_payment
@@ -17,6 +19,7 @@ elrond_wasm_node::wasm_endpoints! {
         item_for_array_vec
         item_for_box
         item_for_boxed_slice
+        item_for_compact_vec
         item_for_managed_vec
         item_for_option
         item_for_ref
@@ -31,6 +34,7 @@ elrond_wasm_node::wasm_endpoints! {
         payable_any_token
         payable_egld
         payable_some_token
+        sample_page
         sample_storage_mapper
         take_managed_type
         var_args
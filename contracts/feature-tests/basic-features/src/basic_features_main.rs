@@ -21,13 +21,17 @@ pub mod non_zero_features;
 pub mod storage_direct_load;
 pub mod storage_direct_store;
 pub mod storage_mapper_fungible_token;
+pub mod storage_mapper_get_at_address;
 pub mod storage_mapper_linked_list;
 pub mod storage_mapper_map;
 pub mod storage_mapper_map_storage;
+pub mod storage_mapper_merkle_tree;
 pub mod storage_mapper_non_fungible_token;
+pub mod storage_mapper_priority_queue;
 pub mod storage_mapper_queue;
 pub mod storage_mapper_set;
 pub mod storage_mapper_single;
+pub mod storage_mapper_sorted;
 pub mod storage_mapper_token_attributes;
 pub mod storage_mapper_unique_id_mapper;
 pub mod storage_mapper_vec;
@@ -58,10 +62,14 @@ pub trait BasicFeatures:
     + storage_direct_store::StorageStoreFeatures
     + storage_mapper_linked_list::LinkedListMapperFeatures
     + storage_mapper_queue::QueueMapperFeatures
+    + storage_mapper_priority_queue::PriorityQueueMapperFeatures
     + storage_mapper_map::MapMapperFeatures
     + storage_mapper_map_storage::MapStorageMapperFeatures
+    + storage_mapper_merkle_tree::MerkleTreeMapperFeatures
     + storage_mapper_set::SetMapperFeatures
+    + storage_mapper_get_at_address::StorageMapperGetAtAddress
     + storage_mapper_single::SingleValueMapperFeatures
+    + storage_mapper_sorted::SortedMapperFeatures
     + storage_mapper_vec::VecMapperFeatures
     + storage_mapper_token_attributes::TokenAttributesMapperFeatures
     + storage_mapper_whitelist::StorageMapperWhitelistFeatures
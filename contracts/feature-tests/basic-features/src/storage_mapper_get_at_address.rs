@@ -0,0 +1,111 @@
+elrond_wasm::imports!();
+
+/// Storage mappers reading the storage of another contract, from the same shard.
+#[elrond_wasm::module]
+pub trait StorageMapperGetAtAddress {
+    #[storage_mapper_from_address("my_single_value_mapper")]
+    fn single_value_from_address(
+        &self,
+        address: ManagedAddress,
+    ) -> SingleValueMapper<BigInt, ManagedAddress>;
+
+    #[storage_mapper_from_address("vec_mapper")]
+    fn vec_mapper_from_address(&self, address: ManagedAddress) -> VecMapper<u32, ManagedAddress>;
+
+    #[storage_mapper_from_address("set_mapper")]
+    fn set_mapper_from_address(&self, address: ManagedAddress) -> SetMapper<u32, ManagedAddress>;
+
+    #[storage_mapper_from_address("map_mapper")]
+    fn map_mapper_from_address(
+        &self,
+        address: ManagedAddress,
+    ) -> MapMapper<u32, u32, ManagedAddress>;
+
+    #[storage_mapper("unordered_set_mapper")]
+    fn unordered_set_mapper(&self) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper_from_address("unordered_set_mapper")]
+    fn unordered_set_mapper_from_address(
+        &self,
+        address: ManagedAddress,
+    ) -> UnorderedSetMapper<u32, ManagedAddress>;
+
+    #[storage_mapper("user_mapper")]
+    fn user_mapper(&self) -> UserMapper;
+
+    #[storage_mapper_from_address("user_mapper")]
+    fn user_mapper_from_address(&self, address: ManagedAddress) -> UserMapper<ManagedAddress>;
+
+    #[endpoint]
+    fn unordered_set_mapper_insert(&self, item: u32) -> bool {
+        self.unordered_set_mapper().insert(item)
+    }
+
+    #[endpoint]
+    fn user_mapper_get_or_create_user(&self, address: ManagedAddress) -> usize {
+        self.user_mapper().get_or_create_user(&address)
+    }
+
+    #[view]
+    fn single_value_mapper_get_at_address(&self, address: ManagedAddress) -> BigInt {
+        self.single_value_from_address(address).get()
+    }
+
+    #[view]
+    fn single_value_mapper_is_empty_at_address(&self, address: ManagedAddress) -> bool {
+        self.single_value_from_address(address).is_empty()
+    }
+
+    #[view]
+    fn vec_mapper_get_at_address(&self, address: ManagedAddress, index: usize) -> u32 {
+        self.vec_mapper_from_address(address).get(index)
+    }
+
+    #[view]
+    fn vec_mapper_at_address(&self, address: ManagedAddress) -> VecMapper<u32, ManagedAddress> {
+        self.vec_mapper_from_address(address)
+    }
+
+    #[view]
+    fn set_mapper_contains_at_address(&self, address: ManagedAddress, item: u32) -> bool {
+        self.set_mapper_from_address(address).contains(&item)
+    }
+
+    #[view]
+    fn set_mapper_at_address(&self, address: ManagedAddress) -> SetMapper<u32, ManagedAddress> {
+        self.set_mapper_from_address(address)
+    }
+
+    #[view]
+    fn map_mapper_get_at_address(&self, address: ManagedAddress, key: u32) -> Option<u32> {
+        self.map_mapper_from_address(address).get(&key)
+    }
+
+    #[view]
+    fn map_mapper_at_address(
+        &self,
+        address: ManagedAddress,
+    ) -> MapMapper<u32, u32, ManagedAddress> {
+        self.map_mapper_from_address(address)
+    }
+
+    #[view]
+    fn unordered_set_mapper_contains_at_address(&self, address: ManagedAddress, item: u32) -> bool {
+        self.unordered_set_mapper_from_address(address)
+            .contains(&item)
+    }
+
+    #[view]
+    fn user_mapper_get_user_id_at_address(
+        &self,
+        address: ManagedAddress,
+        user: ManagedAddress,
+    ) -> usize {
+        self.user_mapper_from_address(address).get_user_id(&user)
+    }
+
+    #[view]
+    fn user_mapper_at_address(&self, address: ManagedAddress) -> UserMapper<ManagedAddress> {
+        self.user_mapper_from_address(address)
+    }
+}
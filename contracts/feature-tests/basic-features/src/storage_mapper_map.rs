@@ -82,4 +82,13 @@ pub trait MapMapperFeatures {
             .or_insert_with_key(|key| key + key_increment)
             .get()
     }
+
+    #[view]
+    fn map_mapper_page(
+        &self,
+        cursor: usize,
+        limit: usize,
+    ) -> MultiValuePage<MultiValue2<u32, u32>> {
+        self.map_mapper().page(cursor, limit)
+    }
 }
@@ -0,0 +1,47 @@
+elrond_wasm::imports!();
+
+/// Storage mapper test.
+#[elrond_wasm::module]
+pub trait MerkleTreeMapperFeatures {
+    #[storage_mapper("merkle_tree_mapper")]
+    fn merkle_tree_mapper(&self) -> MerkleTreeMapper;
+
+    #[endpoint]
+    fn merkle_tree_mapper_append(&self, leaf: ManagedByteArray<32>) -> usize {
+        self.merkle_tree_mapper().append(leaf)
+    }
+
+    #[view]
+    fn merkle_tree_mapper_root(&self) -> ManagedByteArray<32> {
+        self.merkle_tree_mapper().root()
+    }
+
+    #[view]
+    fn merkle_tree_mapper_leaf_count(&self) -> usize {
+        self.merkle_tree_mapper().leaf_count()
+    }
+
+    #[view]
+    fn merkle_tree_mapper_verify_proof(
+        &self,
+        leaf: ManagedByteArray<32>,
+        leaf_index: usize,
+        proof: MultiValueEncoded<ManagedByteArray<32>>,
+    ) -> bool {
+        self.merkle_tree_mapper()
+            .verify_proof(&leaf, leaf_index, proof)
+    }
+
+    #[view]
+    fn verify_merkle_proof(
+        &self,
+        root: ManagedByteArray<32>,
+        leaf: ManagedByteArray<32>,
+        leaf_index: usize,
+        depth: usize,
+        proof: MultiValueEncoded<ManagedByteArray<32>>,
+    ) -> bool {
+        self.crypto()
+            .verify_merkle_proof(&root, &leaf, leaf_index, depth, proof)
+    }
+}
@@ -0,0 +1,46 @@
+elrond_wasm::imports!();
+
+/// Storage mapper test.
+#[elrond_wasm::module]
+pub trait PriorityQueueMapperFeatures {
+    #[view]
+    #[storage_mapper("priority_queue_mapper")]
+    fn priority_queue_mapper(&self) -> PriorityQueueMapper<u64, ManagedBuffer>;
+
+    #[endpoint]
+    fn priority_queue_mapper_push(&self, priority: u64, item: ManagedBuffer) {
+        self.priority_queue_mapper().push(priority, item);
+    }
+
+    #[endpoint]
+    fn priority_queue_mapper_pop(&self) -> OptionalValue<MultiValue2<u64, ManagedBuffer>> {
+        self.priority_queue_mapper()
+            .pop()
+            .map(MultiValue2::from)
+            .into()
+    }
+
+    #[endpoint]
+    fn priority_queue_mapper_pop_due(
+        &self,
+        deadline: u64,
+    ) -> OptionalValue<MultiValue2<u64, ManagedBuffer>> {
+        self.priority_queue_mapper()
+            .pop_if_priority_at_most(&deadline)
+            .map(MultiValue2::from)
+            .into()
+    }
+
+    #[view]
+    fn priority_queue_mapper_peek(&self) -> OptionalValue<MultiValue2<u64, ManagedBuffer>> {
+        self.priority_queue_mapper()
+            .peek()
+            .map(MultiValue2::from)
+            .into()
+    }
+
+    #[view]
+    fn priority_queue_mapper_len(&self) -> usize {
+        self.priority_queue_mapper().len()
+    }
+}
@@ -26,4 +26,9 @@ pub trait QueueMapperFeatures {
         }
         sc_panic!("Queue empty!")
     }
+
+    #[view]
+    fn queue_mapper_page(&self, cursor: usize, limit: usize) -> MultiValuePage<u32> {
+        self.queue_mapper().page(cursor, limit)
+    }
 }
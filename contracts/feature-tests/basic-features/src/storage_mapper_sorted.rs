@@ -0,0 +1,80 @@
+elrond_wasm::imports!();
+
+/// Storage mapper test.
+#[elrond_wasm::module]
+pub trait SortedMapperFeatures {
+    #[view]
+    #[storage_mapper("sorted_set_mapper")]
+    fn sorted_set_mapper(&self) -> SortedSetMapper<u32>;
+
+    #[view]
+    #[storage_mapper("sorted_map_mapper")]
+    fn sorted_map_mapper(&self) -> SortedMapMapper<u32, u32>;
+
+    #[endpoint]
+    fn sorted_set_mapper_insert(&self, item: u32) -> bool {
+        self.sorted_set_mapper().insert(item)
+    }
+
+    #[endpoint]
+    fn sorted_set_mapper_remove(&self, item: u32) -> bool {
+        self.sorted_set_mapper().remove(&item)
+    }
+
+    #[endpoint]
+    fn sorted_set_mapper_contains(&self, item: u32) -> bool {
+        self.sorted_set_mapper().contains(&item)
+    }
+
+    #[endpoint]
+    fn sorted_set_mapper_first(&self) -> Option<u32> {
+        self.sorted_set_mapper().first()
+    }
+
+    #[endpoint]
+    fn sorted_set_mapper_last(&self) -> Option<u32> {
+        self.sorted_set_mapper().last()
+    }
+
+    #[endpoint]
+    fn sorted_set_mapper_range(&self, start: u32, end: u32) -> MultiValueEncoded<u32> {
+        self.sorted_set_mapper().range(start..end).collect()
+    }
+
+    #[endpoint]
+    fn sorted_set_mapper_range_rev(&self, start: u32, end: u32) -> MultiValueEncoded<u32> {
+        self.sorted_set_mapper().range(start..end).rev().collect()
+    }
+
+    #[view]
+    fn sorted_set_mapper_page(&self, cursor: usize, limit: usize) -> MultiValuePage<u32> {
+        self.sorted_set_mapper().page(cursor, limit)
+    }
+
+    #[endpoint]
+    fn sorted_map_mapper_insert(&self, key: u32, value: u32) -> Option<u32> {
+        self.sorted_map_mapper().insert(key, value)
+    }
+
+    #[endpoint]
+    fn sorted_map_mapper_remove(&self, key: u32) -> Option<u32> {
+        self.sorted_map_mapper().remove(&key)
+    }
+
+    #[endpoint]
+    fn sorted_map_mapper_get(&self, key: u32) -> Option<u32> {
+        self.sorted_map_mapper().get(&key)
+    }
+
+    #[endpoint]
+    fn sorted_map_mapper_range(
+        &self,
+        start: u32,
+        end: u32,
+    ) -> MultiValueEncoded<MultiValue2<u32, u32>> {
+        self.sorted_map_mapper()
+            .range(start..end)
+            .map(MultiValue2::from)
+            .collect()
+    }
+}
@@ -22,4 +22,9 @@ pub trait VecMapperFeatures {
     fn vec_mapper_len(&self) -> usize {
         self.vec_mapper().len()
     }
+
+    #[view]
+    fn vec_mapper_page(&self, cursor: usize, limit: usize) -> MultiValuePage<u32> {
+        self.vec_mapper().page(cursor, limit)
+    }
 }
@@ -338,6 +338,11 @@ fn storage_mapper_fungible_token_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_fungible_token.scen.json");
 }
 
+#[test]
+fn storage_mapper_get_at_address_go() {
+    elrond_wasm_debug::mandos_go("mandos/storage_mapper_get_at_address.scen.json");
+}
+
 #[test]
 fn storage_mapper_linked_list_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_linked_list.scen.json");
@@ -353,11 +358,26 @@ fn storage_mapper_map_storage_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_map_storage.scen.json");
 }
 
+#[test]
+fn storage_mapper_merkle_tree_go() {
+    elrond_wasm_debug::mandos_go("mandos/storage_mapper_merkle_tree.scen.json");
+}
+
 #[test]
 fn storage_mapper_non_fungible_token_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_non_fungible_token.scen.json");
 }
 
+#[test]
+fn storage_mapper_pagination_go() {
+    elrond_wasm_debug::mandos_go("mandos/storage_mapper_pagination.scen.json");
+}
+
+#[test]
+fn storage_mapper_priority_queue_go() {
+    elrond_wasm_debug::mandos_go("mandos/storage_mapper_priority_queue.scen.json");
+}
+
 #[test]
 fn storage_mapper_queue_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_queue.scen.json");
@@ -373,6 +393,11 @@ fn storage_mapper_single_value_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_single_value.scen.json");
 }
 
+#[test]
+fn storage_mapper_sorted_go() {
+    elrond_wasm_debug::mandos_go("mandos/storage_mapper_sorted.scen.json");
+}
+
 #[test]
 fn storage_mapper_token_attributes_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_token_attributes.scen.json");
@@ -66,10 +66,10 @@ fn count_ones_rs() {
     elrond_wasm_debug::mandos_rs("mandos/count_ones.scen.json", world());
 }
 
-// #[test]
-// fn crypto_elliptic_curves_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_elliptic_curves.scen.json", world());
-// }
+#[test]
+fn crypto_elliptic_curves_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_elliptic_curves.scen.json", world());
+}
 
 #[test]
 fn crypto_keccak256_rs() {
@@ -81,10 +81,10 @@ fn crypto_keccak256_legacy_managed_rs() {
     elrond_wasm_debug::mandos_rs("mandos/crypto_keccak256_legacy_managed.scen.json", world());
 }
 
-// #[test]
-// fn crypto_ripemd160_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_ripemd160.scen.json", world());
-// }
+#[test]
+fn crypto_ripemd160_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_ripemd160.scen.json", world());
+}
 
 #[test]
 fn crypto_sha256_rs() {
@@ -106,10 +106,10 @@ fn crypto_verify_ed25519_rs() {
     elrond_wasm_debug::mandos_rs("mandos/crypto_verify_ed25519.scen.json", world());
 }
 
-// #[test]
-// fn crypto_verify_secp256k1_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_verify_secp256k1.scen.json", world());
-// }
+#[test]
+fn crypto_verify_secp256k1_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_verify_secp256k1.scen.json", world());
+}
 
 #[test]
 fn echo_array_u8_rs() {
@@ -276,11 +276,12 @@ fn only_user_account_rs() {
     elrond_wasm_debug::mandos_rs("mandos/only_user_account.scen.json", world());
 }
 
-// Will never run in mandos-rs.
-// #[test]
-// fn out_of_gas_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/out_of_gas.scen.json", world());
-// }
+#[test]
+fn out_of_gas_rs() {
+    let mut world = world();
+    world.set_gas_schedule(GasSchedule::default());
+    elrond_wasm_debug::mandos_rs("mandos/out_of_gas.scen.json", world);
+}
 
 #[test]
 fn panic_rs() {
@@ -362,6 +363,11 @@ fn storage_map3_rs() {
 //     elrond_wasm_debug::mandos_rs("mandos/storage_mapper_fungible_token.scen.json", world());
 // }
 
+#[test]
+fn storage_mapper_get_at_address_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/storage_mapper_get_at_address.scen.json", world());
+}
+
 #[test]
 fn storage_mapper_linked_list_rs() {
     elrond_wasm_debug::mandos_rs("mandos/storage_mapper_linked_list.scen.json", world());
@@ -377,6 +383,11 @@ fn storage_mapper_map_storage_rs() {
     elrond_wasm_debug::mandos_rs("mandos/storage_mapper_map_storage.scen.json", world());
 }
 
+#[test]
+fn storage_mapper_merkle_tree_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/storage_mapper_merkle_tree.scen.json", world());
+}
+
 // #[test]
 // fn storage_mapper_non_fungible_token_rs() {
 //     elrond_wasm_debug::mandos_rs(
@@ -385,6 +396,16 @@ fn storage_mapper_map_storage_rs() {
 //     );
 // }
 
+#[test]
+fn storage_mapper_pagination_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/storage_mapper_pagination.scen.json", world());
+}
+
+#[test]
+fn storage_mapper_priority_queue_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/storage_mapper_priority_queue.scen.json", world());
+}
+
 #[test]
 fn storage_mapper_queue_rs() {
     elrond_wasm_debug::mandos_rs("mandos/storage_mapper_queue.scen.json", world());
@@ -400,6 +421,11 @@ fn storage_mapper_single_value_rs() {
     elrond_wasm_debug::mandos_rs("mandos/storage_mapper_single_value.scen.json", world());
 }
 
+#[test]
+fn storage_mapper_sorted_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/storage_mapper_sorted.scen.json", world());
+}
+
 #[test]
 fn storage_mapper_token_attributes_rs() {
     elrond_wasm_debug::mandos_rs("mandos/storage_mapper_token_attributes.scen.json", world());
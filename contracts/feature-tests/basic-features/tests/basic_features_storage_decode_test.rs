@@ -0,0 +1,73 @@
+use basic_features::{
+    storage_mapper_linked_list::LinkedListMapperFeatures, storage_mapper_map::MapMapperFeatures,
+    storage_mapper_merkle_tree::MerkleTreeMapperFeatures,
+    storage_mapper_priority_queue::PriorityQueueMapperFeatures,
+    storage_mapper_set::SetMapperFeatures, storage_mapper_sorted::SortedMapperFeatures,
+    storage_mapper_vec::VecMapperFeatures,
+};
+use elrond_wasm::{
+    contract_base::ContractAbiProvider,
+    types::{ManagedBuffer, ManagedByteArray},
+};
+use elrond_wasm_debug::{rust_biguint, testing_framework::*};
+
+const WASM_PATH: &str = "output/basic-features.wasm";
+
+#[test]
+fn storage_decode_mappers_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let owner = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        basic_features::contract_obj,
+        WASM_PATH,
+    );
+    wrapper.register_contract_abi(&sc_wrapper, basic_features::AbiProvider::abi());
+
+    wrapper
+        .execute_tx(&owner, &sc_wrapper, &rust_zero, |sc| {
+            sc.vec_mapper_push(7);
+            sc.set_mapper_insert(8);
+            sc.map_mapper_insert(9, 10);
+            sc.list_mapper_push_back(11);
+            sc.sorted_set_mapper_insert(12);
+            sc.sorted_map_mapper_insert(13, 14);
+            sc.priority_queue_mapper_push(15, ManagedBuffer::from(&b"due"[..]));
+            sc.merkle_tree_mapper_append(ManagedByteArray::new_from_bytes(&[16u8; 32]));
+        })
+        .assert_ok();
+
+    let decoded_storage = wrapper
+        .get_state()
+        .decoded_storage(sc_wrapper.address_ref())
+        .unwrap();
+    let expected = [
+        "vec_mapper.len = 1",
+        "vec_mapper.item(1) = 7",
+        "set_mapper.info = { len: 1, front: 1, back: 1, new: 1 }",
+        "set_mapper.node_id(8) = 1",
+        "set_mapper.value(1) = 8",
+        "map_mapper.mapped(9) = 10",
+        "map_mapper.value(1) = 9",
+        "list_mapper.info = { len: 1, front: 1, back: 1, new: 1 }",
+        "list_mapper.node(1) = { value: 11, node_id: 1, next_id: 0, prev_id: 0 }",
+        "sorted_set_mapper.info = { len: 1, root: 1, new: 1 }",
+        "sorted_set_mapper.node_links(1) = { parent: 0, left: 0, right: 0, height: 1 }",
+        "sorted_set_mapper.value(1) = 12",
+        "sorted_map_mapper.node_id(13) = 1",
+        "sorted_map_mapper.mapped(13) = 14",
+        "priority_queue_mapper.len = 1",
+        "priority_queue_mapper.item(1) = { priority: 15, item: \"due\" }",
+        "merkle_tree_mapper.leaf_count = 1",
+    ];
+    for entry in expected {
+        assert!(
+            decoded_storage.iter().any(|decoded| decoded == entry),
+            "missing decoded storage entry: {}\ndecoded storage: {:#?}",
+            entry,
+            decoded_storage
+        );
+    }
+}
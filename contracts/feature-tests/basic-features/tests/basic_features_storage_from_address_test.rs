@@ -0,0 +1,73 @@
+use basic_features::{
+    storage_mapper_get_at_address::StorageMapperGetAtAddress,
+    storage_mapper_map::MapMapperFeatures, storage_mapper_vec::VecMapperFeatures,
+};
+use elrond_wasm::types::ManagedAddress;
+use elrond_wasm_debug::{rust_biguint, testing_framework::*};
+
+const WASM_PATH: &str = "output/basic-features.wasm";
+
+#[test]
+fn storage_mapper_from_address_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut wrapper = BlockchainStateWrapper::new();
+    wrapper.enable_sharding(2);
+    let owner = wrapper.create_user_account(&rust_zero);
+    let reader_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        basic_features::contract_obj,
+        WASM_PATH,
+    );
+    let same_shard_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        basic_features::contract_obj,
+        WASM_PATH,
+    );
+    let other_shard_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        basic_features::contract_obj,
+        WASM_PATH,
+    );
+    wrapper.set_account_shard(reader_wrapper.address_ref(), 0);
+    wrapper.set_account_shard(same_shard_wrapper.address_ref(), 0);
+    wrapper.set_account_shard(other_shard_wrapper.address_ref(), 1);
+
+    for sc_wrapper in [&same_shard_wrapper, &other_shard_wrapper] {
+        wrapper
+            .execute_tx(&owner, sc_wrapper, &rust_zero, |sc| {
+                sc.vec_mapper_push(7);
+                sc.map_mapper_insert(8, 9);
+                sc.user_mapper_get_or_create_user(ManagedAddress::from(&owner));
+            })
+            .assert_ok();
+    }
+
+    let same_shard_address = same_shard_wrapper.address_ref().clone();
+    wrapper
+        .execute_query(&reader_wrapper, |sc| {
+            let address = ManagedAddress::from(&same_shard_address);
+            assert_eq!(sc.vec_mapper_get_at_address(address.clone(), 1), 7);
+            assert_eq!(sc.map_mapper_get_at_address(address.clone(), 8), Some(9));
+            assert_eq!(
+                sc.user_mapper_get_user_id_at_address(address, ManagedAddress::from(&owner)),
+                1
+            );
+
+            // the reader's own storage is untouched
+            assert_eq!(sc.vec_mapper_len(), 0);
+        })
+        .assert_ok();
+
+    let other_shard_address = other_shard_wrapper.address_ref().clone();
+    wrapper
+        .execute_query(&reader_wrapper, |sc| {
+            let _ = sc.vec_mapper_get_at_address(ManagedAddress::from(&other_shard_address), 1);
+        })
+        .assert_error(
+            10,
+            "storage load from address: account not in the same shard",
+        );
+}
@@ -201,14 +201,17 @@ elrond_wasm_node::wasm_endpoints! {
         managed_vec_remove
         managed_vec_set
         map_mapper
+        map_mapper_at_address
         map_mapper_contains_key
         map_mapper_entry_and_modify
         map_mapper_entry_or_default_update_increment
         map_mapper_entry_or_insert_default
         map_mapper_entry_or_insert_with_key
         map_mapper_get
+        map_mapper_get_at_address
         map_mapper_insert
         map_mapper_keys
+        map_mapper_page
         map_mapper_remove
         map_mapper_values
         map_my_single_value_mapper
@@ -236,6 +239,10 @@ elrond_wasm_node::wasm_endpoints! {
         mbuffer_eq
         mbuffer_new
         mbuffer_set_random
+        merkle_tree_mapper_append
+        merkle_tree_mapper_leaf_count
+        merkle_tree_mapper_root
+        merkle_tree_mapper_verify_proof
         mint_and_send_fungible
         mint_fungible
         mul_assign_big_int
@@ -259,8 +266,15 @@ elrond_wasm_node::wasm_endpoints! {
         pow_big_int_ref
         pow_big_uint
         pow_big_uint_ref
+        priority_queue_mapper
+        priority_queue_mapper_len
+        priority_queue_mapper_peek
+        priority_queue_mapper_pop
+        priority_queue_mapper_pop_due
+        priority_queue_mapper_push
         queue_mapper
         queue_mapper_front
+        queue_mapper_page
         queue_mapper_pop_front
         queue_mapper_push_back
         raw_byte_length_single_value_mapper
@@ -280,7 +294,9 @@ elrond_wasm_node::wasm_endpoints! {
         sc_panic
         set_local_roles_fungible
         set_mapper
+        set_mapper_at_address
         set_mapper_contains
+        set_mapper_contains_at_address
         set_mapper_insert
         set_mapper_remove
         shl_assign_big_uint
@@ -291,6 +307,22 @@ elrond_wasm_node::wasm_endpoints! {
         shr_assign_big_uint_ref
         shr_big_uint
         shr_big_uint_ref
+        single_value_mapper_get_at_address
+        single_value_mapper_is_empty_at_address
+        sorted_map_mapper
+        sorted_map_mapper_get
+        sorted_map_mapper_insert
+        sorted_map_mapper_range
+        sorted_map_mapper_remove
+        sorted_set_mapper
+        sorted_set_mapper_contains
+        sorted_set_mapper_first
+        sorted_set_mapper_insert
+        sorted_set_mapper_last
+        sorted_set_mapper_page
+        sorted_set_mapper_range
+        sorted_set_mapper_range_rev
+        sorted_set_mapper_remove
         sqrt_big_uint
         sqrt_big_uint_ref
         storage_read_from_address
@@ -333,13 +365,22 @@ elrond_wasm_node::wasm_endpoints! {
         unique_id_mapper_get
         unique_id_mapper_set
         unique_id_mapper_swap_remove
+        unordered_set_mapper_contains_at_address
+        unordered_set_mapper_insert
+        user_mapper_at_address
+        user_mapper_get_or_create_user
+        user_mapper_get_user_id_at_address
         vec_mapper
+        vec_mapper_at_address
         vec_mapper_get
+        vec_mapper_get_at_address
         vec_mapper_len
+        vec_mapper_page
         vec_mapper_push
         verify_bls_signature
         verify_custom_secp256k1_signature
         verify_ed25519_signature
+        verify_merkle_proof
         verify_secp256k1_signature
     )
 }
@@ -62,6 +62,42 @@ pub trait PromisesFeatures {
             .register_promise();
     }
 
+    /// Registers one promise for each endpoint name, all to the same contract.
+    /// Each of them passes its index to the callback, via the callback closure.
+    #[endpoint]
+    fn promise_multiple_calls(
+        &self,
+        to: ManagedAddress,
+        gas_limit: u64,
+        extra_gas_for_callback: u64,
+        endpoint_names: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        for (index, endpoint_name) in endpoint_names.into_iter().enumerate() {
+            self.send()
+                .contract_call::<()>(to.clone(), endpoint_name)
+                .with_gas_limit(gas_limit)
+                .with_extra_gas_for_callback(extra_gas_for_callback)
+                .with_callback_closure_arg(&index)
+                .with_success_callback(b"multi_success_callback")
+                .with_error_callback(b"multi_error_callback")
+                .register_promise();
+        }
+    }
+
+    #[endpoint]
+    fn multi_success_callback(&self, _results: MultiValueEncoded<ManagedBuffer>) {
+        let index: usize = self.blockchain().get_promise_callback_closure_args();
+        self.promise_outcome(index)
+            .set(ManagedBuffer::from(b"success"));
+    }
+
+    #[endpoint]
+    fn multi_error_callback(&self, _error: MultiValueEncoded<ManagedBuffer>) {
+        let index: usize = self.blockchain().get_promise_callback_closure_args();
+        self.promise_outcome(index)
+            .set(ManagedBuffer::from(b"error"));
+    }
+
     #[endpoint]
     fn success_callback(&self, args: MultiValueEncoded<ManagedBuffer>) {
         self.async_call_callback_data().set(true);
@@ -80,6 +116,10 @@ pub trait PromisesFeatures {
     #[storage_mapper("async_call_callback_data")]
     fn async_call_callback_data(&self) -> SingleValueMapper<bool>;
 
+    #[view]
+    #[storage_mapper("promise_outcome")]
+    fn promise_outcome(&self, index: usize) -> SingleValueMapper<ManagedBuffer>;
+
     #[event("async_call_event_callback")]
     fn async_call_event_callback(&self, arguments: &ManagedVec<Self::Api, ManagedBuffer>);
 }
@@ -9,7 +9,11 @@ elrond_wasm_node::wasm_endpoints! {
     (
         async_call_callback_data
         error_callback
+        multi_error_callback
+        multi_success_callback
         promise_multi_transfer
+        promise_multiple_calls
+        promise_outcome
         promise_single_token
         success_callback
     )
@@ -381,6 +381,11 @@ fn forwarder_validate_token_identifier_go() {
 //     elrond_wasm_debug::mandos_go("mandos-promises/promises_multi_transfer.scen.json");
 // }
 
+// #[test]
+// fn promises_multiple_calls_go() {
+//     elrond_wasm_debug::mandos_go("mandos-promises/promises_multiple_calls.scen.json");
+// }
+
 // #[test]
 // fn promises_single_transfer_go() {
 //     elrond_wasm_debug::mandos_go("mandos-promises/promises_single_transfer.scen.json");
@@ -494,6 +494,11 @@ fn promises_multi_transfer_rs() {
     elrond_wasm_debug::mandos_rs("mandos-promises/promises_multi_transfer.scen.json", world());
 }
 
+#[test]
+fn promises_multiple_calls_rs() {
+    elrond_wasm_debug::mandos_rs("mandos-promises/promises_multiple_calls.scen.json", world());
+}
+
 // #[test]
 // fn promises_single_transfer_rs() {
 //     elrond_wasm_debug::mandos_rs("mandos-promises/promises_single_transfer.scen.json", world());
@@ -15,9 +15,18 @@ pub trait MultiContractFeatures {
     }
 
     #[view]
+    #[label("sample-value-reader")]
     #[storage_mapper("sample-value")]
     fn sample_value(&self) -> SingleValueMapper<BigUint>;
 
+    /// Only ends up in the main contract and the `admin` contract, see `multicontract.toml`.
+    #[only_owner]
+    #[endpoint]
+    #[label("admin")]
+    fn set_sample_value(&self, sample_value: BigUint) {
+        self.sample_value().set(sample_value);
+    }
+
     #[external_view]
     fn sample_value_external_get(&self) -> BigUint {
         self.sample_value().get()
@@ -0,0 +1,88 @@
+use elrond_wasm::{abi::EndpointLocationAbi, contract_base::ContractAbiProvider};
+use elrond_wasm_debug::meta::{MultiContractConfigSerde, OutputContractSerde};
+
+fn endpoint_names(contract_id: &str) -> Vec<&'static str> {
+    let original_contract_abi = <multi_contract_features::AbiProvider>::abi();
+    let config = MultiContractConfigSerde::load_from_file("multicontract.toml").unwrap();
+    let contract_abi =
+        config.contracts[contract_id].contract_abi(contract_id, &original_contract_abi);
+    assert_eq!(contract_abi.constructors.len(), 1);
+    contract_abi
+        .endpoints
+        .iter()
+        .map(|endpoint| endpoint.name)
+        .collect()
+}
+
+#[test]
+fn multi_contract_config_test() {
+    assert_eq!(endpoint_names("admin"), vec!["set_sample_value"]);
+    assert_eq!(endpoint_names("reader"), vec!["sample_value"]);
+}
+
+#[test]
+fn multi_contract_output_name_test() {
+    let config = MultiContractConfigSerde::load_from_file("multicontract.toml").unwrap();
+    assert_eq!(
+        config.contracts["admin"].output_base_name("admin", "multi-contract-features"),
+        "multi-contract-features-admin"
+    );
+    assert_eq!(
+        config.contracts["reader"].output_base_name("reader", "multi-contract-features"),
+        "multi-contract-features-reader"
+    );
+}
+
+#[test]
+#[should_panic(expected = "no endpoint labeled `missing`")]
+fn multi_contract_missing_label_test() {
+    let original_contract_abi = <multi_contract_features::AbiProvider>::abi();
+    let output_contract = OutputContractSerde {
+        add_labels: vec!["admin".to_string(), "missing".to_string()],
+        ..Default::default()
+    };
+    let _ = output_contract.contract_abi("admin", &original_contract_abi);
+}
+
+#[test]
+fn multi_contract_external_view_test() {
+    let original_contract_abi = <multi_contract_features::AbiProvider>::abi();
+    let output_contract = OutputContractSerde {
+        add_endpoints: vec!["sample_value_external_get".to_string()],
+        ..Default::default()
+    };
+    let contract_abi = output_contract.contract_abi("external", &original_contract_abi);
+    assert!(contract_abi.constructors.is_empty());
+    assert!(contract_abi.location_exists(EndpointLocationAbi::ViewContract));
+    assert_eq!(contract_abi.endpoints.len(), 1);
+    assert_eq!(contract_abi.endpoints[0].name, "sample_value_external_get");
+}
+
+#[test]
+#[should_panic(expected = "cannot mix external views with regular endpoints")]
+fn multi_contract_mixed_external_view_test() {
+    let original_contract_abi = <multi_contract_features::AbiProvider>::abi();
+    let output_contract = OutputContractSerde {
+        add_endpoints: vec![
+            "sample_value".to_string(),
+            "sample_value_external_get".to_string(),
+        ],
+        ..Default::default()
+    };
+    let _ = output_contract.contract_abi("mixed", &original_contract_abi);
+}
+
+#[test]
+fn multi_contract_invalid_id_test() {
+    for contract_id in ["../admin", "a/b", "admin panel", "", "view"] {
+        let result = std::panic::catch_unwind(|| {
+            let original_contract_abi = <multi_contract_features::AbiProvider>::abi();
+            let output_contract = OutputContractSerde {
+                add_labels: vec!["admin".to_string()],
+                ..Default::default()
+            };
+            let _ = output_contract.contract_abi(contract_id, &original_contract_abi);
+        });
+        assert!(result.is_err(), "contract id `{}` accepted", contract_id);
+    }
+}
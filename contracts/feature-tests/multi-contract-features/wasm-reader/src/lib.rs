@@ -0,0 +1,14 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+
+elrond_wasm_node::wasm_endpoints! {
+    multi_contract_features
+    (
+        sample_value
+    )
+}
+
+elrond_wasm_node::wasm_empty_callback! {}
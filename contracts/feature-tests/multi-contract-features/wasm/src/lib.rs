@@ -8,6 +8,7 @@ elrond_wasm_node::wasm_endpoints! {
     multi_contract_features
     (
         sample_value
+        set_sample_value
     )
 }
 
@@ -222,6 +222,25 @@ pub trait RustTestingFrameworkTester: dummy_module::DummyModule {
         );
     }
 
+    #[endpoint]
+    fn execute_on_dest_add_value_with_gas(
+        &self,
+        other_sc_address: ManagedAddress,
+        value: BigUint,
+        gas_limit: u64,
+    ) {
+        let mut args = ManagedArgBuffer::new();
+        args.push_arg(value);
+
+        let _ = self.send_raw().execute_on_dest_context_raw(
+            gas_limit,
+            &other_sc_address,
+            &BigUint::zero(),
+            &ManagedBuffer::new_from_bytes(b"addValue"),
+            &args,
+        );
+    }
+
     #[endpoint(addValue)]
     fn add(&self, value: BigUint) {
         let caller = self.blockchain().get_caller();
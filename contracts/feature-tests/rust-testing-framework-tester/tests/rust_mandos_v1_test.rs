@@ -4,18 +4,23 @@ use num_traits::ToPrimitive;
 
 use basic_features::BasicFeatures;
 use elrond_wasm::{
+    contract_base::ContractAbiProvider,
     elrond_codec::Empty,
-    types::{Address, BigUint, EsdtLocalRole, EsdtTokenPayment, ManagedVec, TokenIdentifier},
+    types::{
+        Address, BigUint, EsdtLocalRole, EsdtTokenPayment, ManagedBuffer, ManagedVec,
+        TokenIdentifier,
+    },
 };
 use elrond_wasm_debug::{
     assert_values_eq, managed_address, managed_biguint, managed_buffer, managed_token_id,
-    rust_biguint, testing_framework::*, tx_mock::TxInputESDT, DebugApi,
+    rust_biguint, testing_framework::*, tx_mock::TxInputESDT, DebugApi, GasSchedule,
 };
 use rust_testing_framework_tester::{dummy_module::DummyModule, *};
 
 const TEST_OUTPUT_PATH: &'static str = "test.scen.json";
 const TEST_MULTIPLE_SC_OUTPUT_PATH: &'static str = "test_multiple_sc.scen.json";
 const TEST_ESDT_OUTPUT_PATH: &'static str = "test_esdt_generation.scen.json";
+const TEST_EXPORT_STATE_PATH: &'static str = "test_export_state.scen.json";
 
 const SC_WASM_PATH: &'static str = "output/rust-testing-framework-tester.wasm";
 const ADDER_WASM_PATH: &'static str = "../../examples/adder/output/adder.wasm";
@@ -1112,6 +1117,323 @@ fn test_async_call() {
         .assert_ok();
 }
 
+#[test]
+fn test_coverage() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    wrapper.enable_coverage();
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let adder_wrapper =
+        wrapper.create_sc_account(&rust_zero, None, adder::contract_obj, ADDER_WASM_PATH);
+    wrapper.register_contract_abi(&adder_wrapper, adder::AbiProvider::abi());
+
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            let adder_address = managed_address!(adder_wrapper.address_ref());
+            sc.call_other_contract_add_async_call(adder_address, managed_biguint!(10));
+        })
+        .assert_ok();
+
+    // endpoints called directly count too, once per transaction
+    wrapper
+        .execute_tx(&user_addr, &adder_wrapper, &rust_zero, |sc| {
+            sc.add(managed_biguint!(5));
+            sc.add(managed_biguint!(5));
+        })
+        .assert_ok();
+
+    let reports = wrapper.get_mut_state().coverage_reports();
+    let adder_report = reports
+        .iter()
+        .find(|report| report.contract_name == "Adder")
+        .unwrap();
+    assert_eq!(adder_report.endpoints.get("add"), Some(&2));
+    assert_eq!(
+        adder_report.untested_endpoints,
+        vec!["getSum".to_string(), "init".to_string()]
+    );
+    assert!(adder_report.storage_keys_read.contains("str:sum"));
+    assert!(adder_report.storage_keys_written.contains("str:sum"));
+
+    let tester_report = reports
+        .iter()
+        .find(|report| report.contract_name == "rust-testing-framework-tester")
+        .unwrap();
+    assert_eq!(tester_report.endpoints.get("callBack"), Some(&1));
+    assert_eq!(
+        tester_report
+            .endpoints
+            .get("call_other_contract_add_async_call"),
+        Some(&1)
+    );
+    assert!(tester_report
+        .storage_keys_written
+        .contains("str:callbackExecuted"));
+}
+
+#[test]
+fn test_cross_shard_async_call() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    wrapper.enable_sharding(2);
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let adder_wrapper =
+        wrapper.create_sc_account(&rust_zero, None, adder::contract_obj, ADDER_WASM_PATH);
+    wrapper.set_account_shard(sc_wrapper.address_ref(), 0);
+    wrapper.set_account_shard(adder_wrapper.address_ref(), 1);
+
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            let adder_address = managed_address!(adder_wrapper.address_ref());
+            let value_to_add = managed_biguint!(10);
+            sc.call_other_contract_add_async_call(adder_address, value_to_add);
+        })
+        .assert_ok();
+
+    // nothing happened on the other shard yet
+    wrapper
+        .execute_query(&adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), managed_biguint!(0));
+        })
+        .assert_ok();
+
+    // first block: the async call reaches the adder
+    let results = wrapper.deliver_cross_shard_calls();
+    assert_eq!(results.len(), 1);
+    results[0].assert_ok();
+
+    wrapper
+        .execute_query(&adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), managed_biguint!(10));
+        })
+        .assert_ok();
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            assert!(!sc.callback_executed().get());
+        })
+        .assert_ok();
+
+    // second block: the callback gets back to the caller
+    let results = wrapper.deliver_cross_shard_calls();
+    assert_eq!(results.len(), 1);
+    results[0].assert_ok();
+
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            assert!(sc.callback_executed().get());
+        })
+        .assert_ok();
+
+    assert!(wrapper.deliver_cross_shard_calls().is_empty());
+}
+
+#[test]
+fn test_gas_metering() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    wrapper.set_gas_schedule(GasSchedule::default());
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+        sc.add(managed_biguint!(5));
+    });
+    tx_result.assert_ok();
+    assert!(tx_result.result_gas_used > 0);
+
+    // the second call is more expensive, since it also loads the previously stored values
+    wrapper.set_tx_gas_limit(tx_result.result_gas_used);
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.add(managed_biguint!(5));
+        })
+        .assert_error(5, "not enough gas");
+
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            assert_eq!(sc.total_value().get(), managed_biguint!(5));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_snapshot_restore() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.add(managed_biguint!(5));
+        })
+        .assert_ok();
+    let snapshot_id = wrapper.snapshot();
+
+    for value in [10u64, 20u64] {
+        wrapper
+            .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+                sc.add(managed_biguint!(value));
+            })
+            .assert_ok();
+        wrapper
+            .execute_query(&sc_wrapper, |sc| {
+                assert_eq!(sc.total_value().get(), managed_biguint!(5 + value));
+            })
+            .assert_ok();
+
+        wrapper.restore(snapshot_id);
+        wrapper
+            .execute_query(&sc_wrapper, |sc| {
+                assert_eq!(sc.total_value().get(), managed_biguint!(5));
+            })
+            .assert_ok();
+    }
+}
+
+#[test]
+fn test_export_import_state() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    wrapper.set_esdt_balance(&user_addr, b"COOL-123456", &rust_biguint!(500));
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.add(managed_biguint!(5));
+        })
+        .assert_ok();
+    wrapper.export_state(TEST_EXPORT_STATE_PATH);
+
+    // same accounts, so the contract gets registered and the addresses match
+    let mut imported = BlockchainStateWrapper::new();
+    let imported_user_addr = imported.create_user_account(&rust_zero);
+    let imported_sc_wrapper = imported.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    assert_eq!(imported_user_addr, user_addr);
+    imported.import_state(TEST_EXPORT_STATE_PATH);
+
+    imported.check_egld_balance(&user_addr, &rust_biguint!(1_000));
+    imported.check_esdt_balance(&user_addr, b"COOL-123456", &rust_biguint!(500));
+    imported
+        .execute_query(&imported_sc_wrapper, |sc| {
+            assert_eq!(sc.total_value().get(), managed_biguint!(5));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_gas_metering_managed_buffer() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    wrapper.set_gas_schedule(GasSchedule {
+        managed_buffer_op: 1_000,
+        ..GasSchedule::zero()
+    });
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |_| {
+        let buffer = ManagedBuffer::<DebugApi>::new_from_bytes(b"abc");
+        assert_eq!(buffer.len(), 3);
+    });
+    tx_result.assert_ok();
+    assert_eq!(tx_result.result_gas_used, 2_000);
+
+    wrapper.set_tx_gas_limit(1_500);
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |_| {
+            let buffer = ManagedBuffer::<DebugApi>::new_from_bytes(b"abc");
+            assert_eq!(buffer.len(), 3);
+        })
+        .assert_error(5, "not enough gas");
+}
+
+#[test]
+fn test_gas_metering_sync_call_gas_limit() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    wrapper.set_gas_schedule(GasSchedule::default());
+    wrapper.set_tx_gas_limit(10_000_000);
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let other_sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    // the child call only gets the gas passed explicitly, not enough for a storage write
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.execute_on_dest_add_value_with_gas(
+                managed_address!(other_sc_wrapper.address_ref()),
+                managed_biguint!(5),
+                1_000,
+            );
+        })
+        .assert_error(5, "not enough gas");
+
+    // the parent is only charged with the gas the child actually used
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+        sc.execute_on_dest_add_value_with_gas(
+            managed_address!(other_sc_wrapper.address_ref()),
+            managed_biguint!(5),
+            5_000_000,
+        );
+    });
+    tx_result.assert_ok();
+    assert!(tx_result.result_gas_used < 5_000_000);
+
+    wrapper
+        .execute_query(&other_sc_wrapper, |sc| {
+            assert_eq!(sc.get_val(), managed_biguint!(5));
+        })
+        .assert_ok();
+}
+
 #[test]
 fn test_wrapper_getters() {
     let mut wrapper = BlockchainStateWrapper::new();
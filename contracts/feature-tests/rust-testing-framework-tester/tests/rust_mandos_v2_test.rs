@@ -44,3 +44,54 @@ fn tester_deploy_test() {
 
     world.write_mandos_trace("mandos/trace-deploy.scen.json");
 }
+
+#[test]
+fn tester_gas_refund_test() {
+    let _ = DebugApi::dummy();
+    let mut world = world();
+    world.set_gas_schedule(GasSchedule::default());
+    let ic = world.interpreter_context();
+
+    let owner_address = "address:owner";
+    let mut tester_contract =
+        ContractInfo::<rust_testing_framework_tester::Proxy<DebugApi>>::new("sc:contract");
+
+    world.mandos_set_state(
+        SetStateStep::new()
+            .put_account(owner_address, Account::new().balance("10,000,000"))
+            .new_address(owner_address, 0, &tester_contract),
+    );
+
+    // the constructor only stores a 1-byte value: 75,000 + 10,000 gas,
+    // plus 2 * 2,000 gas for the managed buffers holding the key and the value,
+    // the rest of the gas paid upfront is refunded
+    let mut deploy_step: ScDeployStep = tester_contract
+        .init()
+        .into_blockchain_call()
+        .from(owner_address)
+        .contract_code(WASM_PATH_EXPR, &ic)
+        .gas_limit("5,000,000")
+        .into();
+    deploy_step.tx.gas_price = U64Value::from(1u64);
+    world.mandos_sc_deploy(deploy_step);
+    world.mandos_check_state(
+        CheckStateStep::new().put_account(owner_address, CheckAccount::new().balance("9,911,000")),
+    );
+
+    // only the gas actually used is paid for
+    let mut call_step: ScCallStep = tester_contract
+        .add(5u32)
+        .into_blockchain_call()
+        .from(owner_address)
+        .gas_limit("5,000,000")
+        .into();
+    call_step.tx.gas_price = U64Value::from(1u64);
+    world.mandos_sc_call(call_step);
+
+    let owner_balance = &world
+        .account(&AddressValue::from(owner_address).to_address())
+        .unwrap()
+        .egld_balance;
+    assert!(*owner_balance < num_bigint::BigUint::from(9_911_000u64));
+    assert!(*owner_balance > num_bigint::BigUint::from(5_000_000u64));
+}
@@ -0,0 +1,194 @@
+const CODEC_ATTRIBUTE_NAME: &str = "codec";
+const SKIP_NAME: &str = "skip";
+const DEFAULT_NAME: &str = "default";
+const SINCE_NAME: &str = "since";
+const DISCRIMINANT_NAME: &str = "discriminant";
+const VERSION_NAME: &str = "version";
+
+/// Options of a field, from its `#[codec(...)]` attributes.
+#[derive(Default, Clone, Copy)]
+pub struct FieldAttributes {
+    /// `#[codec(skip)]`: the field is not encoded, it is decoded as `Default::default()`.
+    pub skip: bool,
+
+    /// `#[codec(default)]`: the field is decoded as `Default::default()` if the input ends before it.
+    /// Only allowed on trailing fields, and only applies to top-decoding.
+    pub default: bool,
+
+    /// `#[codec(since = N)]`: the field only appears in data with version tag `N` or higher,
+    /// it is decoded as `Default::default()` from older data.
+    /// Only allowed in structs with a `#[codec(version = ...)]`.
+    pub since: Option<u8>,
+}
+
+/// All the items of all the `#[codec(...)]` attributes.
+fn codec_attribute_items(attrs: &[syn::Attribute]) -> Vec<syn::Meta> {
+    let mut result = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident(CODEC_ATTRIBUTE_NAME) {
+            continue;
+        }
+        match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => {
+                for nested in list.nested {
+                    match nested {
+                        syn::NestedMeta::Meta(meta) => result.push(meta),
+                        syn::NestedMeta::Lit(_) => {
+                            panic!("unexpected literal in codec attribute")
+                        },
+                    }
+                }
+            },
+            _ => panic!("codec attribute must be of the form #[codec(...)]"),
+        }
+    }
+    result
+}
+
+fn item_name(meta: &syn::Meta) -> String {
+    meta.path()
+        .get_ident()
+        .map(|ident| ident.to_string())
+        .unwrap_or_default()
+}
+
+fn u8_value(meta: &syn::Meta) -> u8 {
+    let name = item_name(meta);
+    if let syn::Meta::NameValue(name_value) = meta {
+        if let syn::Lit::Int(lit_int) = &name_value.lit {
+            return lit_int
+                .base10_parse::<u8>()
+                .unwrap_or_else(|_| panic!("codec {} must fit in a u8", name));
+        }
+    }
+    panic!("codec {} must be of the form `{} = <number>`", name, name)
+}
+
+fn assert_flag(meta: &syn::Meta) {
+    if !matches!(meta, syn::Meta::Path(_)) {
+        let name = item_name(meta);
+        panic!("codec {} takes no value", name);
+    }
+}
+
+pub fn field_attributes(field: &syn::Field) -> FieldAttributes {
+    let mut result = FieldAttributes::default();
+    for meta in codec_attribute_items(field.attrs.as_slice()) {
+        match item_name(&meta).as_str() {
+            SKIP_NAME => {
+                assert_flag(&meta);
+                result.skip = true;
+            },
+            DEFAULT_NAME => {
+                assert_flag(&meta);
+                result.default = true;
+            },
+            SINCE_NAME => {
+                result.since = Some(u8_value(&meta));
+            },
+            other => panic!("unknown codec field attribute `{}`", other),
+        }
+    }
+    assert!(
+        !result.skip || (!result.default && result.since.is_none()),
+        "codec skip cannot be combined with other codec field attributes"
+    );
+    result
+}
+
+/// The version from `#[codec(version = N)]`, if the type is versioned.
+pub fn container_version(ast: &syn::DeriveInput) -> Option<u8> {
+    let mut result = None;
+    for meta in codec_attribute_items(ast.attrs.as_slice()) {
+        match item_name(&meta).as_str() {
+            VERSION_NAME => {
+                let version = u8_value(&meta);
+                assert!(version > 0, "codec version must be at least 1");
+                result = Some(version);
+            },
+            other => panic!("unknown codec type attribute `{}`", other),
+        }
+    }
+    if result.is_some() {
+        assert!(
+            matches!(ast.data, syn::Data::Struct(_)),
+            "codec version only supported for structs"
+        );
+    }
+    result
+}
+
+/// Checks the field attributes of a struct or of an enum variant.
+pub fn check_fields_attributes(fields: &syn::Fields, version: Option<u8>) {
+    let mut default_found = false;
+    for field in fields.iter() {
+        let attributes = field_attributes(field);
+        if attributes.skip {
+            continue;
+        }
+        if attributes.default {
+            default_found = true;
+        } else {
+            assert!(
+                !default_found,
+                "codec default only allowed on trailing fields, all fields after it must also be codec default or skip"
+            );
+        }
+        if let Some(since) = attributes.since {
+            let version = version.expect("codec since requires a codec version on the struct");
+            assert!(
+                (1..=version).contains(&since),
+                "codec since must be between 1 and the struct version"
+            );
+        }
+    }
+}
+
+/// The discriminant of each variant, as encoded.
+///
+/// By default the variants get consecutive discriminants, starting from 0.
+/// They can be set explicitly, either as Rust discriminants (`A = 3`, fieldless enums only),
+/// or with `#[codec(discriminant = 3)]`. The following variants continue from there.
+pub fn variant_discriminants(data_enum: &syn::DataEnum) -> Vec<u8> {
+    let mut result: Vec<u8> = Vec::with_capacity(data_enum.variants.len());
+    let mut next_discriminant: u16 = 0;
+    for variant in data_enum.variants.iter() {
+        let mut discriminant = next_discriminant;
+        if let Some((_, expr)) = &variant.discriminant {
+            discriminant = discriminant_expr_value(expr) as u16;
+        }
+        for meta in codec_attribute_items(variant.attrs.as_slice()) {
+            match item_name(&meta).as_str() {
+                DISCRIMINANT_NAME => discriminant = u8_value(&meta) as u16,
+                other => panic!("unknown codec variant attribute `{}`", other),
+            }
+        }
+        assert!(
+            discriminant <= u8::MAX as u16,
+            "enum discriminants must fit in a u8"
+        );
+        let discriminant = discriminant as u8;
+        assert!(
+            !result.contains(&discriminant),
+            "duplicate enum discriminant {}",
+            discriminant
+        );
+        result.push(discriminant);
+        next_discriminant = discriminant as u16 + 1;
+    }
+    result
+}
+
+fn discriminant_expr_value(expr: &syn::Expr) -> u8 {
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit_int),
+        ..
+    }) = expr
+    {
+        lit_int
+            .base10_parse::<u8>()
+            .expect("enum discriminants must fit in a u8")
+    } else {
+        panic!("only integer literal enum discriminants are supported")
+    }
+}
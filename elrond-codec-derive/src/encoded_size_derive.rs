@@ -0,0 +1,112 @@
+use crate::{codec_attr::*, util::*};
+use proc_macro::TokenStream;
+use quote::quote;
+
+/// Adds up the sizes of the encoded fields, in a const expression.
+/// `size_const` is either `ENCODED_SIZE` or `MAX_ENCODED_LEN`.
+fn fields_size_snippet(
+    fields: &syn::Fields,
+    initial_size: usize,
+    size_const: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_size_snippets = fields_snippets(fields, |_, field| {
+        if field_attributes(field).skip {
+            return quote! {};
+        }
+        let ty = &field.ty;
+        quote! {
+            let size = elrond_codec::encoded_size_add(size, <#ty as elrond_codec::EncodedSize>::#size_const);
+        }
+    });
+    quote! {
+        {
+            let size: core::option::Option<usize> = core::option::Option::Some(#initial_size);
+            #(#field_size_snippets)*
+            size
+        }
+    }
+}
+
+/// The version tag of versioned structs takes 1 byte.
+fn struct_size_snippet(
+    ast: &syn::DeriveInput,
+    data_struct: &syn::DataStruct,
+    size_const: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let version_size = if container_version(ast).is_some() {
+        1
+    } else {
+        0
+    };
+    fields_size_snippet(&data_struct.fields, version_size, size_const)
+}
+
+/// Each variant is 1 discriminant byte followed by its fields.
+/// `combine_fn` merges the sizes of the variants.
+fn enum_size_snippet(
+    data_enum: &syn::DataEnum,
+    size_const: &proc_macro2::TokenStream,
+    combine_fn: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut variant_sizes = data_enum
+        .variants
+        .iter()
+        .map(|variant| fields_size_snippet(&variant.fields, 1, size_const));
+    let first_variant_size = variant_sizes
+        .next()
+        .expect("enum must have at least 1 variant");
+    let other_variant_size_snippets = variant_sizes.map(|variant_size| {
+        quote! {
+            let size = elrond_codec::#combine_fn(size, #variant_size);
+        }
+    });
+    quote! {
+        {
+            let size: core::option::Option<usize> = #first_variant_size;
+            #(#other_variant_size_snippets)*
+            size
+        }
+    }
+}
+
+pub fn encoded_size_impl(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let encoded_size_const = quote! { ENCODED_SIZE };
+    let max_encoded_len_const = quote! { MAX_ENCODED_LEN };
+    let (encoded_size_snippet, max_encoded_len_snippet) = match &ast.data {
+        syn::Data::Struct(data_struct) => {
+            check_fields_attributes(&data_struct.fields, container_version(ast));
+            (
+                struct_size_snippet(ast, data_struct, &encoded_size_const),
+                struct_size_snippet(ast, data_struct, &max_encoded_len_const),
+            )
+        },
+        syn::Data::Enum(data_enum) => {
+            for variant in data_enum.variants.iter() {
+                check_fields_attributes(&variant.fields, None);
+            }
+            (
+                enum_size_snippet(
+                    data_enum,
+                    &encoded_size_const,
+                    &quote! { encoded_size_either },
+                ),
+                enum_size_snippet(
+                    data_enum,
+                    &max_encoded_len_const,
+                    &quote! { max_encoded_len_either },
+                ),
+            )
+        },
+        syn::Data::Union(_) => panic!("Union not supported"),
+    };
+
+    let gen = quote! {
+        impl #impl_generics elrond_codec::EncodedSize for #name #ty_generics #where_clause {
+            const ENCODED_SIZE: core::option::Option<usize> = #encoded_size_snippet;
+            const MAX_ENCODED_LEN: core::option::Option<usize> = #max_encoded_len_snippet;
+        }
+    };
+    gen.into()
+}
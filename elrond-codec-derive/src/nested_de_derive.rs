@@ -1,22 +1,70 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::util::*;
+use crate::{codec_attr::*, util::*};
 
+/// `#[codec(default)]` only applies at top level.
+/// A nested value is followed by the rest of the outer value, so its input never ends after it,
+/// and its trailing fields are always decoded.
 pub fn dep_decode_snippet(
     _index: usize,
     field: &syn::Field,
     input_value: &proc_macro2::TokenStream,
+    top_level: bool,
 ) -> proc_macro2::TokenStream {
     let ty = &field.ty;
+    let attributes = field_attributes(field);
+    let default_value = quote! {
+        <#ty as core::default::Default>::default()
+    };
+    let mut value = quote! {
+        <#ty as elrond_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+    };
+    if attributes.skip {
+        value = default_value.clone();
+    }
+    if attributes.default && top_level {
+        value = quote! {
+            if elrond_codec::NestedDecodeInput::is_depleted(&*#input_value) {
+                #default_value
+            } else {
+                #value
+            }
+        };
+    }
+    if let Some(since) = attributes.since {
+        value = quote! {
+            if codec_version >= #since {
+                #value
+            } else {
+                #default_value
+            }
+        };
+    }
     if let Some(ident) = &field.ident {
         quote! {
-            #ident: <#ty as elrond_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+            #ident: #value
         }
     } else {
+        value
+    }
+}
+
+/// Decodes and checks the version tag, for structs with `#[codec(version = N)]`.
+/// Data with any version from 1 up to N is accepted.
+pub fn version_dep_decode_snippet(
+    ast: &syn::DeriveInput,
+    input_value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if let Some(version) = container_version(ast) {
         quote! {
-            <#ty as elrond_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+            let codec_version = <u8 as elrond_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?;
+            if codec_version == 0 || codec_version > #version {
+                return core::result::Result::Err(h.handle_error(elrond_codec::DecodeError::UNSUPPORTED_VERSION));
+            }
         }
+    } else {
+        quote! {}
     }
 }
 
@@ -24,19 +72,20 @@ pub fn variant_dep_decode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
     input_value: &proc_macro2::TokenStream,
+    top_level: bool,
 ) -> Vec<proc_macro2::TokenStream> {
     data_enum
 		.variants
 		.iter()
-		.enumerate()
-		.map(|(variant_index, variant)| {
-			let variant_index_u8 = variant_index as u8;
+		.zip(variant_discriminants(data_enum))
+		.map(|(variant, discriminant)| {
+			check_fields_attributes(&variant.fields, None);
 			let variant_ident = &variant.ident;
 			let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
-				dep_decode_snippet(index, field, input_value)
+				dep_decode_snippet(index, field, input_value, top_level)
 			});
 			quote! {
-				#variant_index_u8 => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
+				#discriminant => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
 			}
 		})
 		.collect()
@@ -47,9 +96,11 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            check_fields_attributes(&data_struct.fields, container_version(ast));
+            let version_dep_decode_snippet = version_dep_decode_snippet(ast, &quote! {input});
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {input})
+                    dep_decode_snippet(index, field, &quote! {input}, false)
                 });
             quote! {
                 impl #impl_generics elrond_codec::NestedDecode for #name #ty_generics #where_clause {
@@ -58,6 +109,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         I: elrond_codec::NestedDecodeInput,
                         H: elrond_codec::DecodeErrorHandler,
                     {
+                        #version_dep_decode_snippet
                         core::result::Result::Ok(
                             #name #field_dep_decode_snippets
                         )
@@ -71,7 +123,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                 "enums with more than 256 variants not supported"
             );
             let variant_dep_decode_snippets =
-                variant_dep_decode_snippets(name, data_enum, &quote! {input});
+                variant_dep_decode_snippets(name, data_enum, &quote! {input}, false);
 
             quote! {
                 impl #impl_generics elrond_codec::NestedDecode for #name #ty_generics #where_clause {
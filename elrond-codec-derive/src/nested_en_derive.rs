@@ -1,4 +1,4 @@
-use crate::util::*;
+use crate::{codec_attr::*, util::*};
 use proc_macro::TokenStream;
 use quote::quote;
 
@@ -8,6 +8,28 @@ pub fn dep_encode_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::Toke
     }
 }
 
+/// Encodes a field, unless it is marked `#[codec(skip)]`.
+pub fn field_dep_encode_snippet(
+    value: &proc_macro2::TokenStream,
+    field: &syn::Field,
+) -> proc_macro2::TokenStream {
+    if field_attributes(field).skip {
+        return quote! {};
+    }
+    dep_encode_snippet(value)
+}
+
+/// Encodes the version tag first, for structs with `#[codec(version = N)]`.
+pub fn version_dep_encode_snippet(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    if let Some(version) = container_version(ast) {
+        quote! {
+            elrond_codec::NestedEncode::dep_encode_or_handle_err(&#version, dest, h)?;
+        }
+    } else {
+        quote! {}
+    }
+}
+
 fn variant_dep_encode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
@@ -15,18 +37,18 @@ fn variant_dep_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
+            check_fields_attributes(&variant.fields, None);
             let variant_ident = &variant.ident;
             let local_var_declarations =
-                fields_decl_syntax(&variant.fields, local_variable_for_field);
+                fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
             let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                dep_encode_snippet(&local_variable_for_field(index, field))
+                field_dep_encode_snippet(&local_variable_for_field(index, field), field)
             });
             quote! {
                 #name::#variant_ident #local_var_declarations => {
-                    elrond_codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, dest, h)?;
+                    elrond_codec::NestedEncode::dep_encode_or_handle_err(&#discriminant, dest, h)?;
                     #(#variant_field_snippets)*
                 },
             }
@@ -39,8 +61,10 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            check_fields_attributes(&data_struct.fields, container_version(ast));
+            let version_dep_encode_snippet = version_dep_encode_snippet(ast);
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                field_dep_encode_snippet(&self_field_expr(index, field), field)
             });
             quote! {
                 impl #impl_generics elrond_codec::NestedEncode for #name #ty_generics #where_clause {
@@ -49,6 +73,7 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         O: elrond_codec::NestedEncodeOutput,
                         H: elrond_codec::EncodeErrorHandler,
                     {
+                        #version_dep_encode_snippet
                         #(#field_dep_encode_snippets)*
                         core::result::Result::Ok(())
                     }
@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_de_derive::*, util::*};
+use crate::{codec_attr::*, nested_de_derive::*, util::*};
 
 fn fieldless_enum_match_arm_result_ok(
     name: &syn::Ident,
@@ -10,19 +10,19 @@ fn fieldless_enum_match_arm_result_ok(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             quote! {
-                #variant_index_u8 => core::result::Result::Ok( #name::#variant_ident ),
+                #discriminant => core::result::Result::Ok( #name::#variant_ident ),
             }
         })
         .collect()
 }
 
 /// Generates a default-value deserializer snippet automatically.
-/// Currently only does so for enums whose first variant is fieldless.
+/// Currently only does so for enums with a fieldless variant with discriminant 0,
+/// which is what empty input decodes to.
 /// Not called for TopDecodeOrDefault, since that one already provides an explicit default.
 fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
@@ -31,15 +31,22 @@ fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
             !data_enum.variants.is_empty(),
             "cannot deserialize enums without variants"
         );
-        let first_variant = &data_enum.variants[0];
-        if first_variant.fields.is_empty() {
-            let first_variant_ident = &first_variant.ident;
-            let auto_default = quote! {
-                if top_input.byte_len() == 0 {
-                    return core::result::Result::Ok(#name::#first_variant_ident);
-                }
-            };
-            return auto_default;
+        let zero_variant = data_enum
+            .variants
+            .iter()
+            .zip(variant_discriminants(data_enum))
+            .find(|(_, discriminant)| *discriminant == 0)
+            .map(|(variant, _)| variant);
+        if let Some(zero_variant) = zero_variant {
+            if zero_variant.fields.is_empty() {
+                let zero_variant_ident = &zero_variant.ident;
+                let auto_default = quote! {
+                    if top_input.byte_len() == 0 {
+                        return core::result::Result::Ok(#name::#zero_variant_ident);
+                    }
+                };
+                return auto_default;
+            }
         }
     }
 
@@ -52,13 +59,17 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
+            check_fields_attributes(&data_struct.fields, container_version(ast));
+            let version_dep_decode_snippet =
+                version_dep_decode_snippet(ast, &quote! {&mut nested_buffer});
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {&mut nested_buffer})
+                    dep_decode_snippet(index, field, &quote! {&mut nested_buffer}, true)
                 });
 
             quote! {
                 let mut nested_buffer = top_input.into_nested_buffer();
+                #version_dep_decode_snippet
                 let result = #name #field_dep_decode_snippets ;
                 if !elrond_codec::NestedDecodeInput::is_depleted(&nested_buffer) {
                     return core::result::Result::Err(h.handle_error(elrond_codec::DecodeError::INPUT_TOO_LONG));
@@ -81,8 +92,12 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
                     }
                 }
             } else {
-                let variant_dep_decode_snippets =
-                    variant_dep_decode_snippets(name, data_enum, &quote! {&mut nested_buffer});
+                let variant_dep_decode_snippets = variant_dep_decode_snippets(
+                    name,
+                    data_enum,
+                    &quote! {&mut nested_buffer},
+                    true,
+                );
 
                 quote! {
                     let mut nested_buffer = top_input.into_nested_buffer();
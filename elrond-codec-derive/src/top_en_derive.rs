@@ -1,7 +1,11 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_en_derive::dep_encode_snippet, util::*};
+use crate::{
+    codec_attr::*,
+    nested_en_derive::{field_dep_encode_snippet, version_dep_encode_snippet},
+    util::*,
+};
 
 pub fn variant_top_encode_snippets(
     name: &syn::Ident,
@@ -10,28 +14,28 @@ pub fn variant_top_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
+            check_fields_attributes(&variant.fields, None);
             let variant_ident = &variant.ident;
             if variant.fields.is_empty() {
                 // top-encode discriminant directly
                 quote! {
                     #name::#variant_ident =>
-                        elrond_codec::TopEncode::top_encode_or_handle_err(&#variant_index_u8, output, h),
+                        elrond_codec::TopEncode::top_encode_or_handle_err(&#discriminant, output, h),
                 }
             } else {
                 // dep-encode to buffer first
                 let local_var_declarations =
-                    fields_decl_syntax(&variant.fields, local_variable_for_field);
+                    fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
                 let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                    dep_encode_snippet(&local_variable_for_field(index, field))
+                    field_dep_encode_snippet(&local_variable_for_field(index, field), field)
                 });
                 quote! {
                     #name::#variant_ident #local_var_declarations => {
                         let mut buffer = output.start_nested_encode();
                         let dest = &mut buffer;
-                        elrond_codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, dest, h)?;
+                        elrond_codec::NestedEncode::dep_encode_or_handle_err(&#discriminant, dest, h)?;
                         #(#variant_field_snippets)*
                         output.finalize_nested_encode(buffer);
                         core::result::Result::Ok(())
@@ -47,12 +51,15 @@ fn top_encode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
+            check_fields_attributes(&data_struct.fields, container_version(ast));
+            let version_dep_encode_snippet = version_dep_encode_snippet(ast);
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                field_dep_encode_snippet(&self_field_expr(index, field), field)
             });
             quote! {
                 let mut buffer = output.start_nested_encode();
                 let dest = &mut buffer;
+                #version_dep_encode_snippet
                 #(#field_dep_encode_snippets)*
                 output.finalize_nested_encode(buffer);
                 core::result::Result::Ok(())
@@ -66,6 +66,7 @@ impl DecodeError {
     pub const INPUT_OUT_OF_RANGE: DecodeError = DecodeError("input out of range");
     pub const INVALID_VALUE: DecodeError = DecodeError("invalid value");
     pub const UNSUPPORTED_OPERATION: DecodeError = DecodeError("unsupported operation");
+    pub const UNSUPPORTED_VERSION: DecodeError = DecodeError("unsupported version");
     pub const ARRAY_DECODE_ERROR: DecodeError = DecodeError("array decode error");
     pub const UTF8_DECODE_ERROR: DecodeError = DecodeError("utf-8 decode error");
     pub const CAPACITY_EXCEEDED_ERROR: DecodeError = DecodeError("capacity exceeded");
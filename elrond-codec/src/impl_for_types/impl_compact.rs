@@ -0,0 +1,382 @@
+use crate::{
+    DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
+    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+};
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+use super::impl_slice::dep_encode_slice_contents;
+
+/// Maximum length of a compact-encoded `u64`: 64 bits, 7 per byte.
+const COMPACT_U64_MAX_LEN: usize = 10;
+
+/// Wraps an unsigned integer so that it gets nested-encoded as unsigned LEB128,
+/// instead of fixed width big endian.
+///
+/// Every byte holds 7 bits of the number, least significant group first.
+/// The highest bit of each byte is set if more bytes follow.
+/// Small numbers are cheaper: values below 128 only take 1 byte, values below 16384 take 2.
+///
+/// Only the shortest encoding is accepted when decoding, so each value has exactly one representation.
+///
+/// Top-encoding is the same as for the wrapped number, since it is already minimal.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Compact<T>(pub T);
+
+impl<T> Compact<T> {
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Compact<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Compact(value)
+    }
+}
+
+impl<T> Deref for Compact<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Compact-encodes a number (nested-encoding).
+///
+/// Smaller types need to be converted to u64 before using this function.
+///
+/// No generics here, we avoid monomorphization to make the SC binary as small as possible.
+pub fn compact_encode_number(mut x: u64, buffer: &mut [u8; COMPACT_U64_MAX_LEN]) -> &[u8] {
+    let mut len = 0usize;
+    loop {
+        let byte = (x & 0x7f) as u8;
+        x >>= 7;
+        if x == 0 {
+            buffer[len] = byte;
+            len += 1;
+            return &buffer[..len];
+        }
+        buffer[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+/// Decodes a compact-encoded number, checking that it is encoded in the shortest form and that it does not exceed `max`.
+///
+/// The result needs to be cast to the desired type.
+pub fn compact_decode_number<I, H>(input: &mut I, max: u64, h: H) -> Result<u64, H::HandledErr>
+where
+    I: NestedDecodeInput,
+    H: DecodeErrorHandler,
+{
+    let mut result = 0u64;
+    let mut shift = 0u32;
+    loop {
+        let byte = input.read_byte(h)?;
+        if shift > 0 && byte == 0 {
+            // a trailing zero group is redundant
+            return Err(h.handle_error(DecodeError::INVALID_VALUE));
+        }
+        let bits = (byte & 0x7f) as u64;
+        if shift >= 64 || (bits << shift) >> shift != bits {
+            return Err(h.handle_error(DecodeError::INPUT_OUT_OF_RANGE));
+        }
+        result |= bits << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    if result > max {
+        return Err(h.handle_error(DecodeError::INPUT_OUT_OF_RANGE));
+    }
+    Ok(result)
+}
+
+fn dep_encode_compact<O, H>(x: u64, dest: &mut O) -> Result<(), H::HandledErr>
+where
+    O: NestedEncodeOutput,
+    H: EncodeErrorHandler,
+{
+    let mut buffer = [0u8; COMPACT_U64_MAX_LEN];
+    dest.write(compact_encode_number(x, &mut buffer));
+    Ok(())
+}
+
+macro_rules! compact_num_unsigned {
+    ($ty:ty, $bounds_ty:ty) => {
+        impl TopEncode for Compact<$ty> {
+            #[inline]
+            fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+            where
+                O: TopEncodeOutput,
+                H: EncodeErrorHandler,
+            {
+                self.0.top_encode_or_handle_err(output, h)
+            }
+        }
+
+        impl TopDecode for Compact<$ty> {
+            #[inline]
+            fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+            where
+                I: TopDecodeInput,
+                H: DecodeErrorHandler,
+            {
+                Ok(Compact(<$ty>::top_decode_or_handle_err(input, h)?))
+            }
+        }
+
+        impl NestedEncode for Compact<$ty> {
+            #[inline]
+            fn dep_encode_or_handle_err<O, H>(
+                &self,
+                dest: &mut O,
+                _h: H,
+            ) -> Result<(), H::HandledErr>
+            where
+                O: NestedEncodeOutput,
+                H: EncodeErrorHandler,
+            {
+                dep_encode_compact::<O, H>(self.0 as u64, dest)
+            }
+        }
+
+        impl NestedDecode for Compact<$ty> {
+            #[inline]
+            fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+            where
+                I: NestedDecodeInput,
+                H: DecodeErrorHandler,
+            {
+                let num = compact_decode_number(input, <$bounds_ty>::MAX as u64, h)?;
+                Ok(Compact(num as $ty))
+            }
+        }
+    };
+}
+
+compact_num_unsigned!(u16, u16);
+compact_num_unsigned!(u32, u32);
+compact_num_unsigned!(usize, u32); // same bounds as the regular usize decoding
+compact_num_unsigned!(u64, u64);
+
+/// A `Vec` whose length is nested-encoded as a [`Compact`] number, instead of 4 bytes.
+///
+/// The items are encoded as usual. Top-encoding is the same as for `Vec`, which has no length prefix.
+///
+/// Only for heap `Vec`s. `ManagedVec` and the other managed collections keep their 4-byte length prefix.
+#[derive(Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompactVec<T>(pub Vec<T>);
+
+impl<T> CompactVec<T> {
+    #[inline]
+    pub fn new() -> Self {
+        CompactVec(Vec::new())
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for CompactVec<T> {
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        CompactVec(vec)
+    }
+}
+
+impl<T> Deref for CompactVec<T> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for CompactVec<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.0
+    }
+}
+
+impl<T: NestedEncode> TopEncode for CompactVec<T> {
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.0.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<T: NestedDecode> TopDecode for CompactVec<T> {
+    #[inline]
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(CompactVec(Vec::<T>::top_decode_or_handle_err(input, h)?))
+    }
+}
+
+impl<T: NestedEncode> NestedEncode for CompactVec<T> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        Compact(self.0.len()).dep_encode_or_handle_err(dest, h)?;
+        dep_encode_slice_contents(self.0.as_slice(), dest, h)
+    }
+}
+
+impl<T: NestedDecode> NestedDecode for CompactVec<T> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let size = Compact::<usize>::dep_decode_or_handle_err(input, h)?.0;
+        T::if_u8(
+            input,
+            |input| {
+                if size > input.remaining_len() {
+                    return Err(h.handle_error(DecodeError::INPUT_TOO_SHORT));
+                }
+                let mut vec_u8: Vec<u8> = alloc::vec![0; size];
+                input.read_into(vec_u8.as_mut_slice(), h)?;
+                let cast_vec: Vec<T> = unsafe { core::mem::transmute(vec_u8) };
+                Ok(CompactVec(cast_vec))
+            },
+            |input| {
+                let mut result: Vec<T> = Vec::new();
+                for _ in 0..size {
+                    result.push(T::dep_decode_or_handle_err(input, h)?);
+                }
+                Ok(CompactVec(result))
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{Compact, CompactVec};
+    use crate::{
+        test_util::{check_dep_encode_decode, check_top_encode_decode},
+        DecodeError, NestedDecode,
+    };
+    use alloc::vec::Vec;
+
+    fn dep_decode<T: NestedDecode>(mut bytes: &[u8]) -> Result<T, DecodeError> {
+        let result = T::dep_decode(&mut bytes);
+        if result.is_ok() {
+            assert!(bytes.is_empty(), "input not depleted");
+        }
+        result
+    }
+
+    #[test]
+    fn test_compact_dep() {
+        check_dep_encode_decode(Compact(0u32), &[0]);
+        check_dep_encode_decode(Compact(1u32), &[1]);
+        check_dep_encode_decode(Compact(127u32), &[0x7f]);
+        check_dep_encode_decode(Compact(128u32), &[0x80, 0x01]);
+        check_dep_encode_decode(Compact(300u16), &[0xac, 0x02]);
+        check_dep_encode_decode(Compact(16383usize), &[0xff, 0x7f]);
+        check_dep_encode_decode(Compact(16384u64), &[0x80, 0x80, 0x01]);
+        check_dep_encode_decode(Compact(u16::MAX), &[0xff, 0xff, 0x03]);
+        check_dep_encode_decode(Compact(u32::MAX), &[0xff, 0xff, 0xff, 0xff, 0x0f]);
+        check_dep_encode_decode(
+            Compact(u64::MAX),
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+        );
+    }
+
+    #[test]
+    fn test_compact_top() {
+        check_top_encode_decode(Compact(0u32), &[]);
+        check_top_encode_decode(Compact(300u64), &[0x01, 0x2c]);
+        check_top_encode_decode(Compact(5usize), &[5]);
+    }
+
+    #[test]
+    fn test_compact_decode_errors() {
+        // not the shortest encoding
+        assert_eq!(
+            dep_decode::<Compact<u32>>(&[0x80, 0x00]),
+            Err(DecodeError::INVALID_VALUE)
+        );
+        assert_eq!(
+            dep_decode::<Compact<u32>>(&[0x81, 0x80, 0x00]),
+            Err(DecodeError::INVALID_VALUE)
+        );
+        // missing continuation
+        assert_eq!(
+            dep_decode::<Compact<u32>>(&[0x80]),
+            Err(DecodeError::INPUT_TOO_SHORT)
+        );
+        // too large for the type
+        assert_eq!(
+            dep_decode::<Compact<u16>>(&[0x80, 0x80, 0x04]),
+            Err(DecodeError::INPUT_OUT_OF_RANGE)
+        );
+        assert_eq!(
+            dep_decode::<Compact<usize>>(&[0x80, 0x80, 0x80, 0x80, 0x10]),
+            Err(DecodeError::INPUT_OUT_OF_RANGE)
+        );
+        // too large for a u64
+        assert_eq!(
+            dep_decode::<Compact<u64>>(&[
+                0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02
+            ]),
+            Err(DecodeError::INPUT_OUT_OF_RANGE)
+        );
+        assert_eq!(
+            dep_decode::<Compact<u64>>(&[
+                0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x81, 0x01
+            ]),
+            Err(DecodeError::INPUT_OUT_OF_RANGE)
+        );
+    }
+
+    #[test]
+    fn test_compact_vec() {
+        check_dep_encode_decode(CompactVec::<u32>::new(), &[0]);
+        check_dep_encode_decode(CompactVec(alloc::vec![1u16, 2u16]), &[2, 0, 1, 0, 2]);
+        check_dep_encode_decode(CompactVec(b"abc".to_vec()), &[3, b'a', b'b', b'c']);
+        check_top_encode_decode(CompactVec(alloc::vec![1u16, 2u16]), &[0, 1, 0, 2]);
+
+        let long = CompactVec((0..200u8).collect::<Vec<u8>>());
+        let mut expected = alloc::vec![0xc8, 0x01];
+        expected.extend(0..200u8);
+        check_dep_encode_decode(long, expected.as_slice());
+
+        // nested compact vectors
+        check_dep_encode_decode(
+            CompactVec(alloc::vec![CompactVec(alloc::vec![Compact(5u64)])]),
+            &[1, 1, 5],
+        );
+
+        assert_eq!(
+            dep_decode::<CompactVec<u8>>(&[3, b'a']),
+            Err(DecodeError::INPUT_TOO_SHORT)
+        );
+        assert_eq!(
+            dep_decode::<CompactVec<u16>>(&[2, 0, 1]),
+            Err(DecodeError::INPUT_TOO_SHORT)
+        );
+    }
+}
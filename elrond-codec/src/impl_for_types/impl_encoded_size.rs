@@ -0,0 +1,224 @@
+use crate::{
+    encoded_size_add, encoded_size_either, encoded_size_mul, max_encoded_len_either, Compact,
+    CompactVec, Empty, EncodedSize,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use arrayvec::ArrayVec;
+use core::{marker::PhantomData, num::NonZeroUsize};
+
+macro_rules! encoded_size_fixed {
+    ($ty:ty, $size:expr) => {
+        impl EncodedSize for $ty {
+            const ENCODED_SIZE: Option<usize> = Some($size);
+        }
+    };
+}
+
+encoded_size_fixed!((), 0);
+encoded_size_fixed!(Empty, 0);
+encoded_size_fixed!(bool, 1);
+encoded_size_fixed!(u8, 1);
+encoded_size_fixed!(u16, 2);
+encoded_size_fixed!(u32, 4);
+encoded_size_fixed!(usize, 4);
+encoded_size_fixed!(u64, 8);
+encoded_size_fixed!(i8, 1);
+encoded_size_fixed!(i16, 2);
+encoded_size_fixed!(i32, 4);
+encoded_size_fixed!(isize, 4);
+encoded_size_fixed!(i64, 8);
+encoded_size_fixed!(NonZeroUsize, 4);
+
+impl<T> EncodedSize for PhantomData<T> {
+    const ENCODED_SIZE: Option<usize> = Some(0);
+}
+
+macro_rules! encoded_size_unbounded {
+    ($ty:ty) => {
+        impl EncodedSize for $ty {
+            const ENCODED_SIZE: Option<usize> = None;
+        }
+    };
+}
+
+encoded_size_unbounded!(String);
+encoded_size_unbounded!(&str);
+encoded_size_unbounded!(Box<str>);
+
+#[cfg(feature = "num-bigint")]
+encoded_size_unbounded!(num_bigint::BigUint);
+
+#[cfg(feature = "num-bigint")]
+encoded_size_unbounded!(num_bigint::BigInt);
+
+impl<T> EncodedSize for Vec<T> {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
+impl<T> EncodedSize for &[T] {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
+impl<T> EncodedSize for Box<[T]> {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
+impl<T> EncodedSize for CompactVec<T> {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
+impl<T: EncodedSize> EncodedSize for &T {
+    const ENCODED_SIZE: Option<usize> = T::ENCODED_SIZE;
+    const MAX_ENCODED_LEN: Option<usize> = T::MAX_ENCODED_LEN;
+}
+
+impl<T: EncodedSize> EncodedSize for Box<T> {
+    const ENCODED_SIZE: Option<usize> = T::ENCODED_SIZE;
+    const MAX_ENCODED_LEN: Option<usize> = T::MAX_ENCODED_LEN;
+}
+
+impl<T: EncodedSize, const N: usize> EncodedSize for [T; N] {
+    const ENCODED_SIZE: Option<usize> = encoded_size_mul(T::ENCODED_SIZE, N);
+    const MAX_ENCODED_LEN: Option<usize> = encoded_size_mul(T::MAX_ENCODED_LEN, N);
+}
+
+/// Encoded like a slice: 4 bytes of length, followed by at most `CAP` items.
+impl<T: EncodedSize, const CAP: usize> EncodedSize for ArrayVec<T, CAP> {
+    const ENCODED_SIZE: Option<usize> = None;
+    const MAX_ENCODED_LEN: Option<usize> =
+        encoded_size_add(Some(4), encoded_size_mul(T::MAX_ENCODED_LEN, CAP));
+}
+
+/// A 0 byte for `None`, a 1 byte followed by the contents for `Some`.
+impl<T: EncodedSize> EncodedSize for Option<T> {
+    const ENCODED_SIZE: Option<usize> =
+        encoded_size_either(Some(1), encoded_size_add(Some(1), T::ENCODED_SIZE));
+    const MAX_ENCODED_LEN: Option<usize> =
+        max_encoded_len_either(Some(1), encoded_size_add(Some(1), T::MAX_ENCODED_LEN));
+}
+
+macro_rules! encoded_size_compact {
+    ($ty:ty, $max_len:expr) => {
+        impl EncodedSize for Compact<$ty> {
+            const ENCODED_SIZE: Option<usize> = None;
+            const MAX_ENCODED_LEN: Option<usize> = Some($max_len);
+        }
+    };
+}
+
+// 7 bits per byte
+encoded_size_compact!(u16, 3);
+encoded_size_compact!(u32, 5);
+encoded_size_compact!(usize, 5);
+encoded_size_compact!(u64, 10);
+
+macro_rules! tuple_impls {
+    ($(($($name:ident)+))+) => {
+        $(
+            impl<$($name),+> EncodedSize for ($($name,)+)
+            where
+                $($name: EncodedSize,)+
+            {
+                const ENCODED_SIZE: Option<usize> = {
+                    let size = Some(0);
+                    $(
+                        let size = encoded_size_add(size, $name::ENCODED_SIZE);
+                    )+
+                    size
+                };
+
+                const MAX_ENCODED_LEN: Option<usize> = {
+                    let size = Some(0);
+                    $(
+                        let size = encoded_size_add(size, $name::MAX_ENCODED_LEN);
+                    )+
+                    size
+                };
+            }
+        )+
+    }
+}
+
+tuple_impls! {
+    (T0)
+    (T0 T1)
+    (T0 T1 T2)
+    (T0 T1 T2 T3)
+    (T0 T1 T2 T3 T4)
+    (T0 T1 T2 T3 T4 T5)
+    (T0 T1 T2 T3 T4 T5 T6)
+    (T0 T1 T2 T3 T4 T5 T6 T7)
+    (T0 T1 T2 T3 T4 T5 T6 T7 T8)
+    (T0 T1 T2 T3 T4 T5 T6 T7 T8 T9)
+    (T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10)
+    (T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11)
+    (T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12)
+    (T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13)
+    (T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14)
+    (T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{test_util::check_dep_encode, Compact, CompactVec, EncodedSize, NestedEncode};
+    use alloc::vec::Vec;
+    use arrayvec::ArrayVec;
+    use core::fmt::Debug;
+
+    /// Checks that the actual encoding fits the sizes.
+    fn check_encoded_size<T: NestedEncode + EncodedSize + Debug>(value: T) {
+        let len = check_dep_encode(&value).len();
+        if let Some(size) = T::ENCODED_SIZE {
+            assert_eq!(len, size, "wrong ENCODED_SIZE for {:?}", value);
+        }
+        if let Some(max_len) = T::MAX_ENCODED_LEN {
+            assert!(len <= max_len, "MAX_ENCODED_LEN exceeded by {:?}", value);
+        }
+    }
+
+    #[test]
+    fn test_fixed_sizes() {
+        assert_eq!(u8::ENCODED_SIZE, Some(1));
+        assert_eq!(bool::ENCODED_SIZE, Some(1));
+        assert_eq!(i16::ENCODED_SIZE, Some(2));
+        assert_eq!(usize::ENCODED_SIZE, Some(4));
+        assert_eq!(u64::MAX_ENCODED_LEN, Some(8));
+        assert_eq!(<[u32; 3]>::ENCODED_SIZE, Some(12));
+        assert_eq!(<(u8, u16, [i64; 2])>::ENCODED_SIZE, Some(19));
+        assert_eq!(<(u8, u16, [i64; 2])>::MAX_ENCODED_LEN, Some(19));
+
+        check_encoded_size(5u16);
+        check_encoded_size([1u32, 2, 3]);
+        check_encoded_size((1u8, 2u16, [3i64, 4]));
+    }
+
+    #[test]
+    fn test_variable_sizes() {
+        assert_eq!(Option::<u32>::ENCODED_SIZE, None);
+        assert_eq!(Option::<u32>::MAX_ENCODED_LEN, Some(5));
+        assert_eq!(Option::<()>::ENCODED_SIZE, Some(1));
+        assert_eq!(ArrayVec::<u16, 3>::ENCODED_SIZE, None);
+        assert_eq!(ArrayVec::<u16, 3>::MAX_ENCODED_LEN, Some(10));
+        assert_eq!(Compact::<u32>::MAX_ENCODED_LEN, Some(5));
+        assert_eq!(Compact::<u64>::MAX_ENCODED_LEN, Some(10));
+        assert_eq!(<(u8, Option<u8>)>::ENCODED_SIZE, None);
+        assert_eq!(<(u8, Option<u8>)>::MAX_ENCODED_LEN, Some(3));
+
+        check_encoded_size(Option::<u32>::None);
+        check_encoded_size(Some(5u32));
+        check_encoded_size(ArrayVec::<u16, 3>::from([1, 2, 3]));
+        check_encoded_size(Compact(u32::MAX));
+        check_encoded_size(Compact(u64::MAX));
+        check_encoded_size(Compact(u16::MAX));
+    }
+
+    #[test]
+    fn test_unbounded_sizes() {
+        assert_eq!(Vec::<u8>::ENCODED_SIZE, None);
+        assert_eq!(Vec::<u8>::MAX_ENCODED_LEN, None);
+        assert_eq!(CompactVec::<u8>::MAX_ENCODED_LEN, None);
+        assert_eq!(<(u8, Vec<u8>)>::MAX_ENCODED_LEN, None);
+        assert_eq!(Option::<Vec<u8>>::MAX_ENCODED_LEN, None);
+        assert_eq!(<[Vec<u8>; 2]>::MAX_ENCODED_LEN, None);
+    }
+}
@@ -2,7 +2,9 @@ mod impl_array;
 mod impl_array_vec;
 mod impl_bool;
 mod impl_bytes;
+pub mod impl_compact;
 pub mod impl_empty;
+mod impl_encoded_size;
 mod impl_non_zero_usize;
 mod impl_num_signed;
 mod impl_num_unsigned;
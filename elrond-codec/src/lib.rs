@@ -45,7 +45,10 @@ pub use codec_err::{DecodeError, EncodeError};
 pub use codec_err_handler::*;
 pub use default_traits::{DecodeDefault, EncodeDefault};
 pub use equivalent::*;
-pub use impl_for_types::impl_empty::Empty;
+pub use impl_for_types::{
+    impl_compact::{compact_decode_number, compact_encode_number, Compact, CompactVec},
+    impl_empty::Empty,
+};
 pub use multi::*;
 pub use single::*;
 
@@ -0,0 +1,51 @@
+/// Size of the nested encoding of a type, known at compile time.
+///
+/// Can be used to preallocate buffers, to estimate storage costs in the worst case,
+/// or to check the input length of fixed-size types only once, before decoding them.
+///
+/// Can be derived, the derived implementation takes the `#[codec(...)]` attributes into account.
+pub trait EncodedSize {
+    /// The length of the nested encoding, if it is the same for all values of the type.
+    const ENCODED_SIZE: Option<usize>;
+
+    /// An upper bound for the length of the nested encoding, `None` if the encoding is unbounded.
+    const MAX_ENCODED_LEN: Option<usize> = Self::ENCODED_SIZE;
+}
+
+/// The size of 2 consecutive encoded items. Used for both exact and maximum sizes.
+pub const fn encoded_size_add(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+    match (a, b) {
+        (Some(a), Some(b)) => a.checked_add(b),
+        _ => None,
+    }
+}
+
+/// The size of `n` consecutive encoded items. Used for both exact and maximum sizes.
+pub const fn encoded_size_mul(a: Option<usize>, n: usize) -> Option<usize> {
+    match a {
+        Some(a) => a.checked_mul(n),
+        None => None,
+    }
+}
+
+/// The exact size of an item that can have one of 2 layouts: only known if it is the same for both.
+pub const fn encoded_size_either(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+    match (a, b) {
+        (Some(a), Some(b)) if a == b => Some(a),
+        _ => None,
+    }
+}
+
+/// The maximum size of an item that can have one of 2 layouts.
+pub const fn max_encoded_len_either(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            if a > b {
+                Some(a)
+            } else {
+                Some(b)
+            }
+        },
+        _ => None,
+    }
+}
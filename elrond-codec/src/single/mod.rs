@@ -1,3 +1,4 @@
+mod encoded_size;
 mod nested_de;
 mod nested_de_input;
 mod nested_de_input_owned;
@@ -9,6 +10,9 @@ mod top_de_input;
 mod top_en;
 mod top_en_output;
 
+pub use encoded_size::{
+    encoded_size_add, encoded_size_either, encoded_size_mul, max_encoded_len_either, EncodedSize,
+};
 pub use nested_de::NestedDecode;
 pub use nested_de_input::NestedDecodeInput;
 pub use nested_de_input_owned::OwnedBytesNestedDecodeInput;
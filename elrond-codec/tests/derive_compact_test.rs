@@ -0,0 +1,47 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    test_util::{check_dep_encode_decode, check_top_encode, check_top_encode_decode},
+    Compact, CompactVec,
+};
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct Counters {
+    pub fixed: u32,
+    pub compact: Compact<u32>,
+    pub timestamps: CompactVec<Compact<u64>>,
+    pub name: CompactVec<u8>,
+}
+
+#[test]
+fn struct_compact_fields_test() {
+    let counters = Counters {
+        fixed: 3,
+        compact: Compact(3),
+        timestamps: CompactVec(vec![Compact(1), Compact(200)]),
+        name: CompactVec(b"abc".to_vec()),
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* fixed */ 0, 0, 0, 3,
+        /* compact */ 3,
+        /* timestamps */ 2, 1, 0xc8, 0x01,
+        /* name */ 3, b'a', b'b', b'c',
+    ];
+
+    check_top_encode_decode(counters.clone(), bytes);
+    check_dep_encode_decode(counters, bytes);
+}
+
+#[test]
+fn compact_smaller_than_fixed_test() {
+    let small = vec![1u32, 2, 3];
+    let fixed = check_top_encode(&Some(small.clone()));
+    let compact = check_top_encode(&Some(CompactVec(
+        small.into_iter().map(Compact).collect::<Vec<_>>(),
+    )));
+    assert_eq!(fixed.len(), 1 + 4 + 3 * 4);
+    assert_eq!(compact, vec![1, 3, 1, 2, 3]);
+}
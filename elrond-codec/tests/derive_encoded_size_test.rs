@@ -0,0 +1,140 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{test_util::check_dep_encode, Compact, EncodedSize, NestedEncode};
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+pub struct FixedStruct {
+    pub byte: u8,
+    pub int: u32,
+    pub array: [u16; 3],
+    pub pair: (i64, bool),
+}
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+pub struct BoundedStruct {
+    pub byte: u8,
+    pub opt: Option<u32>,
+    pub compact: Compact<u64>,
+}
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+pub struct UnboundedStruct {
+    pub byte: u8,
+    pub seq: Vec<u8>,
+}
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+pub struct TupleStruct(u8, u16, FixedStruct);
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+pub struct UnitStruct;
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+#[codec(version = 2)]
+pub struct StructWithAttributes {
+    pub int: u16,
+    #[codec(skip)]
+    pub cached: Vec<u8>,
+    #[codec(since = 2)]
+    pub flag: bool,
+}
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+pub enum FieldlessEnum {
+    A,
+    B,
+    C = 10,
+}
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+pub enum SameSizeEnum {
+    A(u32),
+    B { x: u16, y: u16 },
+}
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+pub enum DifferentSizeEnum {
+    Empty,
+    Small(u8),
+    Large([u64; 2]),
+}
+
+#[derive(NestedEncode, EncodedSize, Debug)]
+pub struct Generic<T: NestedEncode + EncodedSize> {
+    pub value: T,
+    pub byte: u8,
+}
+
+fn check_encoded_size<T: NestedEncode + EncodedSize>(value: T) {
+    let len = check_dep_encode(&value).len();
+    if let Some(size) = T::ENCODED_SIZE {
+        assert_eq!(len, size);
+    }
+    if let Some(max_len) = T::MAX_ENCODED_LEN {
+        assert!(len <= max_len);
+    }
+}
+
+fn fixed_struct() -> FixedStruct {
+    FixedStruct {
+        byte: 1,
+        int: 2,
+        array: [3, 4, 5],
+        pair: (-6, true),
+    }
+}
+
+#[test]
+fn struct_encoded_size_test() {
+    assert_eq!(FixedStruct::ENCODED_SIZE, Some(1 + 4 + 6 + 9));
+    assert_eq!(FixedStruct::MAX_ENCODED_LEN, Some(20));
+    check_encoded_size(fixed_struct());
+
+    assert_eq!(BoundedStruct::ENCODED_SIZE, None);
+    assert_eq!(BoundedStruct::MAX_ENCODED_LEN, Some(1 + 5 + 10));
+    check_encoded_size(BoundedStruct {
+        byte: 1,
+        opt: Some(u32::MAX),
+        compact: Compact(u64::MAX),
+    });
+
+    assert_eq!(UnboundedStruct::ENCODED_SIZE, None);
+    assert_eq!(UnboundedStruct::MAX_ENCODED_LEN, None);
+
+    assert_eq!(TupleStruct::ENCODED_SIZE, Some(23));
+    check_encoded_size(TupleStruct(1, 2, fixed_struct()));
+
+    assert_eq!(UnitStruct::ENCODED_SIZE, Some(0));
+    check_encoded_size(UnitStruct);
+
+    assert_eq!(Generic::<u64>::ENCODED_SIZE, Some(9));
+    assert_eq!(Generic::<Vec<u8>>::ENCODED_SIZE, None);
+}
+
+#[test]
+fn struct_with_attributes_encoded_size_test() {
+    // version byte, no skipped field
+    assert_eq!(StructWithAttributes::ENCODED_SIZE, Some(1 + 2 + 1));
+    check_encoded_size(StructWithAttributes {
+        int: 1,
+        cached: vec![1, 2, 3],
+        flag: true,
+    });
+}
+
+#[test]
+fn enum_encoded_size_test() {
+    assert_eq!(FieldlessEnum::ENCODED_SIZE, Some(1));
+    check_encoded_size(FieldlessEnum::C);
+
+    assert_eq!(SameSizeEnum::ENCODED_SIZE, Some(5));
+    check_encoded_size(SameSizeEnum::A(1));
+    check_encoded_size(SameSizeEnum::B { x: 1, y: 2 });
+
+    assert_eq!(DifferentSizeEnum::ENCODED_SIZE, None);
+    assert_eq!(DifferentSizeEnum::MAX_ENCODED_LEN, Some(17));
+    check_encoded_size(DifferentSizeEnum::Empty);
+    check_encoded_size(DifferentSizeEnum::Small(1));
+    check_encoded_size(DifferentSizeEnum::Large([1, 2]));
+}
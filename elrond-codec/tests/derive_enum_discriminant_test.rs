@@ -0,0 +1,81 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    test_util::{check_dep_encode_decode, check_top_decode, check_top_encode_decode},
+    DecodeError, TopDecode,
+};
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+enum Status {
+    Inactive = 1,
+    Active,
+    Paused = 10,
+    Closed = 255,
+}
+
+#[test]
+fn fieldless_enum_explicit_discriminants() {
+    check_top_encode_decode(Status::Inactive, &[1]);
+    check_top_encode_decode(Status::Active, &[2]);
+    check_top_encode_decode(Status::Paused, &[10]);
+    check_top_encode_decode(Status::Closed, &[255]);
+
+    check_dep_encode_decode(Status::Inactive, &[1]);
+    check_dep_encode_decode(Status::Closed, &[255]);
+
+    // no variant has discriminant 0, so empty input is not a valid value
+    assert_eq!(Status::top_decode(&[][..]), Err(DecodeError::INVALID_VALUE));
+    assert_eq!(
+        Status::top_decode(&[3][..]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+enum Reordered {
+    Second = 1,
+    First = 0,
+}
+
+#[test]
+fn fieldless_enum_zero_variant_not_first() {
+    check_top_encode_decode(Reordered::First, &[]);
+    check_top_encode_decode(Reordered::Second, &[1]);
+    check_dep_encode_decode(Reordered::First, &[0]);
+    assert_eq!(Reordered::First, check_top_decode(&[0]));
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+enum Command {
+    #[codec(discriminant = 5)]
+    Stop,
+    Move(u8, u8),
+    #[codec(discriminant = 20)]
+    Say {
+        message: Vec<u8>,
+    },
+}
+
+#[test]
+fn field_enum_explicit_discriminants() {
+    check_top_encode_decode(Command::Stop, &[5]);
+    check_top_encode_decode(Command::Move(1, 2), &[6, 1, 2]);
+    check_top_encode_decode(
+        Command::Say {
+            message: b"hi".to_vec(),
+        },
+        &[20, 0, 0, 0, 2, b'h', b'i'],
+    );
+    check_dep_encode_decode(Command::Stop, &[5]);
+    check_dep_encode_decode(Command::Move(1, 2), &[6, 1, 2]);
+
+    assert_eq!(
+        Command::top_decode(&[][..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+    assert_eq!(
+        Command::top_decode(&[0][..]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+}
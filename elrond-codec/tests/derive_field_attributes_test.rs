@@ -0,0 +1,163 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    test_util::{
+        check_dep_decode, check_dep_encode, check_dep_encode_decode, check_top_decode,
+        check_top_encode, check_top_encode_decode,
+    },
+    DecodeError, NestedDecode, TopDecode,
+};
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructV1 {
+    pub int: u16,
+    pub seq: Vec<u8>,
+}
+
+/// `StructV1`, with fields added at the end.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructV2 {
+    pub int: u16,
+    pub seq: Vec<u8>,
+    #[codec(default)]
+    pub new_int: u32,
+    #[codec(default)]
+    pub new_seq: Vec<u8>,
+}
+
+#[test]
+fn struct_default_fields_test() {
+    let v2 = StructV2 {
+        int: 0x42,
+        seq: vec![1, 2],
+        new_int: 3,
+        new_seq: vec![4],
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* seq */ 0, 0, 0, 2, 1, 2,
+        /* new_int */ 0, 0, 0, 3,
+        /* new_seq */ 0, 0, 0, 1, 4,
+    ];
+    check_top_encode_decode(v2.clone(), bytes);
+    check_dep_encode_decode(v2, bytes);
+
+    // old data, the new fields get their default values
+    let v1 = StructV1 {
+        int: 0x42,
+        seq: vec![1, 2],
+    };
+    let v1_bytes = check_top_encode(&v1);
+    let expected = StructV2 {
+        int: 0x42,
+        seq: vec![1, 2],
+        new_int: 0,
+        new_seq: Vec::new(),
+    };
+    assert_eq!(check_top_decode::<StructV2>(&v1_bytes[..]), expected);
+
+    // nested, the fields are always decoded, the input that follows belongs to the outer value
+    assert_eq!(
+        StructV2::dep_decode(&mut &v1_bytes[..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+    // otherwise the second item would be decoded as the new fields of the first one
+    let v1_vec_bytes = check_top_encode(&vec![v1.clone(), v1]);
+    assert!(Vec::<StructV2>::top_decode(&v1_vec_bytes[..]).is_err());
+
+    // data that ends between the new fields
+    #[rustfmt::skip]
+    let partial_bytes = &[
+        /* int */ 0, 0x42,
+        /* seq */ 0, 0, 0, 2, 1, 2,
+        /* new_int */ 0, 0, 0, 3,
+    ];
+    let expected = StructV2 {
+        int: 0x42,
+        seq: vec![1, 2],
+        new_int: 3,
+        new_seq: Vec::new(),
+    };
+    assert_eq!(check_top_decode::<StructV2>(partial_bytes), expected);
+
+    // data cut in the middle of a field is still an error
+    assert_eq!(
+        StructV2::top_decode(&[0, 0x42, 0, 0, 0, 2, 1, 2, 0, 0][..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructWithSkip {
+    pub int: u16,
+    #[codec(skip)]
+    pub cached: u64,
+    pub byte: u8,
+}
+
+#[test]
+fn struct_skip_field_test() {
+    let s = StructWithSkip {
+        int: 0x42,
+        cached: 0x123456,
+        byte: 5,
+    };
+    let bytes = &[0, 0x42, 5];
+    assert_eq!(check_top_encode(&s), bytes.to_vec());
+    assert_eq!(check_dep_encode(&s), bytes.to_vec());
+
+    let decoded = StructWithSkip {
+        int: 0x42,
+        cached: 0,
+        byte: 5,
+    };
+    assert_eq!(check_top_decode::<StructWithSkip>(bytes), decoded);
+    assert_eq!(check_dep_decode::<StructWithSkip>(bytes), decoded);
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct TupleStructWithAttributes(u8, #[codec(skip)] u32, #[codec(default)] u16);
+
+#[test]
+fn tuple_struct_attributes_test() {
+    check_top_encode_decode(TupleStructWithAttributes(1, 0, 2), &[1, 0, 2]);
+    assert_eq!(
+        check_top_decode::<TupleStructWithAttributes>(&[1]),
+        TupleStructWithAttributes(1, 0, 0)
+    );
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub enum EnumWithAttributes {
+    Empty,
+    Fields {
+        int: u16,
+        #[codec(skip)]
+        cached: u32,
+        #[codec(default)]
+        extra: u8,
+    },
+}
+
+#[test]
+fn enum_variant_field_attributes_test() {
+    check_top_encode_decode(
+        EnumWithAttributes::Fields {
+            int: 3,
+            cached: 0,
+            extra: 4,
+        },
+        &[1, 0, 3, 4],
+    );
+    assert_eq!(
+        check_top_decode::<EnumWithAttributes>(&[1, 0, 3]),
+        EnumWithAttributes::Fields {
+            int: 3,
+            cached: 0,
+            extra: 0,
+        }
+    );
+}
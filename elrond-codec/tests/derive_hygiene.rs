@@ -19,6 +19,10 @@ struct TopDecodeInput;
 struct TopEncodeOutput;
 struct NestedDecodeInput;
 struct NestedEncodeOutput;
+struct EncodedSize;
+
+// Used for the fields with codec attributes.
+struct Default;
 
 // Making sure derive explicitly only works with core::result::Result
 // and doesn't get tricked by other enums with the same name.
@@ -27,6 +31,12 @@ enum Result {
     Err,
 }
 
+// Same for the size options of `EncodedSize`.
+enum Option {
+    Some,
+    None,
+}
+
 // This one will interfere with any improperly generated `Ok` and `Err` expressions.
 #[allow(unused_imports)]
 use crate::Result::{Err, Ok};
@@ -44,7 +54,9 @@ fn top_encode_to_vec_u8() {}
 fn boxed_slice_into_vec() {}
 fn vec_into_boxed_slice() {}
 
-#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[derive(
+    NestedEncode, NestedDecode, TopEncode, TopDecode, EncodedSize, PartialEq, Eq, Clone, Debug,
+)]
 pub struct Struct {
     pub int: u16,
     pub seq: Vec<u8>,
@@ -53,10 +65,14 @@ pub struct Struct {
     pub uint_64: u64,
 }
 
-#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[derive(
+    NestedEncode, NestedDecode, TopEncode, TopDecode, EncodedSize, PartialEq, Eq, Clone, Debug,
+)]
 struct TupleStruct(u8, u16, u32);
 
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, EncodedSize, PartialEq, Eq, Clone, Debug,
+)]
 enum DayOfWeek {
     Monday,
     Tuesday,
@@ -67,7 +83,9 @@ enum DayOfWeek {
     Sunday,
 }
 
-#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[derive(
+    NestedEncode, NestedDecode, TopEncode, TopDecode, EncodedSize, PartialEq, Eq, Clone, Debug,
+)]
 enum EnumWithEverything {
     Quit,
     Today(DayOfWeek),
@@ -81,6 +99,30 @@ enum EnumWithEverything {
     },
 }
 
+#[derive(
+    NestedEncode, NestedDecode, TopEncode, TopDecode, EncodedSize, PartialEq, Eq, Clone, Debug,
+)]
+#[codec(version = 2)]
+struct StructWithCodecAttributes {
+    int: u16,
+    #[codec(skip)]
+    skipped: u32,
+    #[codec(since = 2)]
+    added: u64,
+    #[codec(default)]
+    trailing: Vec<u8>,
+}
+
+#[derive(
+    NestedEncode, NestedDecode, TopEncode, TopDecode, EncodedSize, PartialEq, Eq, Clone, Debug,
+)]
+enum EnumWithDiscriminants {
+    #[codec(discriminant = 1)]
+    First(#[codec(skip)] u8, u16),
+    #[codec(discriminant = 5)]
+    Second,
+}
+
 trait SimpleTrait {
     fn simple_function(&self);
 }
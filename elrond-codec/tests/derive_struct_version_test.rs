@@ -0,0 +1,100 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    test_util::{
+        check_dep_decode, check_dep_encode_decode, check_top_decode, check_top_encode_decode,
+    },
+    DecodeError, TopDecode,
+};
+
+/// The first layout of the data.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[codec(version = 1)]
+pub struct ConfigV1 {
+    pub owner_share: u16,
+    pub fee: u32,
+}
+
+/// The current layout: fields can be added anywhere, not only at the end.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[codec(version = 3)]
+pub struct Config {
+    pub owner_share: u16,
+    #[codec(since = 3)]
+    pub min_amount: u64,
+    pub fee: u32,
+    #[codec(since = 2)]
+    pub paused: bool,
+}
+
+#[test]
+fn struct_version_current_test() {
+    let config = Config {
+        owner_share: 5,
+        min_amount: 7,
+        fee: 1000,
+        paused: true,
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* version */ 3,
+        /* owner_share */ 0, 5,
+        /* min_amount */ 0, 0, 0, 0, 0, 0, 0, 7,
+        /* fee */ 0, 0, 0x03, 0xe8,
+        /* paused */ 1,
+    ];
+    check_top_encode_decode(config.clone(), bytes);
+    check_dep_encode_decode(config, bytes);
+}
+
+#[test]
+fn struct_version_old_layouts_test() {
+    // produced by the first version of the struct
+    let v1_bytes = elrond_codec::test_util::check_top_encode(&ConfigV1 {
+        owner_share: 5,
+        fee: 1000,
+    });
+    assert_eq!(v1_bytes, vec![1, 0, 5, 0, 0, 0x03, 0xe8]);
+
+    let expected = Config {
+        owner_share: 5,
+        min_amount: 0,
+        fee: 1000,
+        paused: false,
+    };
+    assert_eq!(check_top_decode::<Config>(&v1_bytes[..]), expected);
+    assert_eq!(check_dep_decode::<Config>(&v1_bytes[..]), expected);
+
+    #[rustfmt::skip]
+    let v2_bytes = &[
+        /* version */ 2,
+        /* owner_share */ 0, 5,
+        /* fee */ 0, 0, 0x03, 0xe8,
+        /* paused */ 1,
+    ];
+    let expected = Config {
+        owner_share: 5,
+        min_amount: 0,
+        fee: 1000,
+        paused: true,
+    };
+    assert_eq!(check_top_decode::<Config>(v2_bytes), expected);
+}
+
+#[test]
+fn struct_version_unsupported_test() {
+    assert_eq!(
+        Config::top_decode(&[4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0x03, 0xe8, 1][..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+    assert_eq!(
+        Config::top_decode(&[0, 0, 5, 0, 0, 0x03, 0xe8][..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+    assert_eq!(
+        Config::top_decode(&[][..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
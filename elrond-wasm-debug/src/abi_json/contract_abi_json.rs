@@ -8,6 +8,7 @@ use std::collections::BTreeMap;
 #[serde(rename_all = "camelCase")]
 pub struct ContractAbiJson {
     pub build_info: BuildInfoAbiJson,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -15,6 +16,9 @@ pub struct ContractAbiJson {
     pub constructor: Option<ConstructorAbiJson>,
     pub endpoints: Vec<EndpointAbiJson>,
     pub events: Vec<EventAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageAbiJson>,
     pub has_callback: bool,
     pub types: BTreeMap<String, TypeDescriptionJson>,
 }
@@ -28,10 +32,11 @@ impl From<&ContractAbi> for ContractAbiJson {
             constructor: abi.constructors.get(0).map(ConstructorAbiJson::from),
             endpoints: abi.endpoints.iter().map(EndpointAbiJson::from).collect(),
             events: abi.events.iter().map(EventAbiJson::from).collect(),
+            storage: abi.storage.iter().map(StorageAbiJson::from).collect(),
             has_callback: abi.has_callback,
             types: BTreeMap::new(),
         };
-        for (type_name, type_description) in abi.type_descriptions.0.iter() {
+        for (type_name, type_description) in abi.type_descriptions.descriptions.iter() {
             if type_description.contents.is_specified() {
                 contract_json.types.insert(
                     type_name.clone(),
@@ -52,3 +57,7 @@ pub fn serialize_abi_to_json(abi_json: &ContractAbiJson) -> String {
     serialized.push('\n');
     serialized
 }
+
+pub fn deserialize_abi_from_json(input: &str) -> Result<ContractAbiJson, String> {
+    serde_json::from_str(input).map_err(|err| err.to_string())
+}
@@ -26,6 +26,7 @@ impl From<&InputAbi> for InputAbiJson {
 #[derive(Serialize, Deserialize)]
 pub struct OutputAbiJson {
     #[serde(rename = "name")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "String::is_empty")]
     pub output_name: String,
     #[serde(rename = "type")]
@@ -56,9 +57,11 @@ pub enum EndpointMutabilityAbiJson {
 /// Same as EndpointAbiJson but ignores the name
 #[derive(Serialize, Deserialize)]
 pub struct ConstructorAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
@@ -82,6 +85,7 @@ impl From<&EndpointAbi> for ConstructorAbiJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EndpointAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -93,6 +97,7 @@ pub struct EndpointAbiJson {
     pub only_admin: Option<bool>,
     pub mutability: EndpointMutabilityAbiJson,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
@@ -25,6 +25,7 @@ impl From<&EventInputAbi> for EventInputAbiJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EventAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub identifier: String,
@@ -2,6 +2,7 @@ mod build_info_abi_json;
 mod contract_abi_json;
 mod endpoint_abi_json;
 mod event_abi_json;
+mod storage_abi_json;
 mod type_abi_json;
 
 pub use build_info_abi_json::{BuildInfoAbiJson, RustcAbiJson};
@@ -9,6 +10,7 @@ pub use contract_abi_json::*;
 use elrond_wasm::{abi::ContractAbi, contract_base::ContractAbiProvider};
 pub use endpoint_abi_json::*;
 pub use event_abi_json::*;
+pub use storage_abi_json::*;
 pub use type_abi_json::*;
 
 /// Function provided for convenience.
@@ -0,0 +1,50 @@
+use alloc::vec::Vec;
+use elrond_wasm::abi::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct StorageKeyArgAbiJson {
+    #[serde(rename = "name")]
+    pub arg_name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+}
+
+impl From<&StorageKeyArgAbi> for StorageKeyArgAbiJson {
+    fn from(abi: &StorageKeyArgAbi) -> Self {
+        StorageKeyArgAbiJson {
+            arg_name: abi.arg_name.to_string(),
+            type_name: abi.type_name.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageAbiJson {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    pub identifier: String,
+    pub mapper: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub key_args: Vec<StorageKeyArgAbiJson>,
+    pub value_types: Vec<String>,
+}
+
+impl From<&StorageAbi> for StorageAbiJson {
+    fn from(abi: &StorageAbi) -> Self {
+        StorageAbiJson {
+            docs: abi.docs.iter().map(|d| d.to_string()).collect(),
+            identifier: abi.identifier.to_string(),
+            mapper: abi.mapper.to_string(),
+            key_args: abi
+                .key_args
+                .iter()
+                .map(StorageKeyArgAbiJson::from)
+                .collect(),
+            value_types: abi.value_types.clone(),
+        }
+    }
+}
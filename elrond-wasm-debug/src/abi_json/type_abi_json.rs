@@ -7,12 +7,19 @@ pub struct TypeDescriptionJson {
     #[serde(rename = "type")]
     pub content_type: String,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u8>,
+
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub variants: Vec<EnumVariantDescriptionJson>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
@@ -27,6 +34,7 @@ impl From<&TypeDescription> for TypeDescriptionJson {
         let mut type_desc_json = TypeDescriptionJson {
             content_type: content_type.to_string(),
             docs: abi.docs.iter().map(|line| line.to_string()).collect(),
+            version: abi.version,
             variants: Vec::new(),
             fields: Vec::new(),
         };
@@ -54,11 +62,24 @@ impl From<&TypeDescription> for TypeDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct StructFieldDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(rename = "type")]
     pub field_type: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<u8>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub default: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
 }
 
 impl From<&StructFieldDescription> for StructFieldDescriptionJson {
@@ -67,16 +88,20 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             field_type: abi.field_type.clone(),
+            since: abi.since,
+            default: abi.default,
         }
     }
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct EnumVariantDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     pub discriminant: usize,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
@@ -4,7 +4,7 @@ use crate::{
     DebugApi,
 };
 use elrond_wasm::{
-    api::{BlockchainApi, BlockchainApiImpl, HandleConstraints, ManagedBufferApi, ManagedTypeApi},
+    api::{BlockchainApi, BlockchainApiImpl, HandleConstraints, ManagedTypeApi},
     types::{
         heap::{Address, H256},
         BigUint, EsdtLocalRole, EsdtLocalRoleFlags, EsdtTokenData, EsdtTokenType, ManagedAddress,
@@ -38,8 +38,8 @@ impl BlockchainApiImpl for DebugApi {
         })
     }
 
-    fn get_shard_of_address_legacy(&self, _address: &Address) -> u32 {
-        panic!("get_shard_of_address not implemented")
+    fn get_shard_of_address_legacy(&self, address: &Address) -> u32 {
+        self.blockchain_ref().get_shard_of_address(address)
     }
 
     fn is_smart_contract_legacy(&self, address: &Address) -> bool {
@@ -64,7 +64,12 @@ impl BlockchainApiImpl for DebugApi {
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.gas_left()
+    }
+
+    fn load_callback_closure(&self, dest: Self::ManagedBufferHandle) {
+        let callback_closure = self.input_ref().promise_callback_closure_data.clone();
+        self.mb_set(dest, callback_closure.to_vec());
     }
 
     fn get_block_timestamp(&self) -> u64 {
@@ -209,7 +214,7 @@ impl BlockchainApiImpl for DebugApi {
     ) -> bool {
         let mut frozen = false;
         let address = ManagedAddress::<Self>::from_handle(address_handle).to_address();
-        let token_identifier_value = self.mb_to_boxed_bytes(token_id_handle);
+        let token_identifier_value = self.mb_get(token_id_handle);
         self.blockchain_cache().with_account(&address, |account| {
             if let Some(esdt_data) = account
                 .esdt
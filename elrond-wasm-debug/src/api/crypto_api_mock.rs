@@ -1,15 +1,125 @@
+use super::managed_types::signal_ec_error;
 use crate::DebugApi;
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    pairing, G1Affine, G1Projective, G2Affine,
+};
+use core::convert::TryFrom;
 use ed25519_dalek::*;
 use elrond_wasm::{
-    api::{
-        CryptoApi, CryptoApiImpl, ManagedBufferApi, KECCAK256_RESULT_LEN, RIPEMD_RESULT_LEN,
-        SHA256_RESULT_LEN,
-    },
+    api::{CryptoApi, CryptoApiImpl, KECCAK256_RESULT_LEN, RIPEMD_RESULT_LEN, SHA256_RESULT_LEN},
     types::{heap::BoxedBytes, MessageHashType},
 };
+use k256::ecdsa::{
+    signature::hazmat::PrehashVerifier, Signature as Secp256k1Signature,
+    VerifyingKey as Secp256k1VerifyingKey,
+};
+use ripemd::Ripemd160;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
+/// Signatures are in G1, keys in G2, messages are hashed to G1 as in the IETF BLS signature draft.
+const BLS_HASH_TO_G1_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+const DER_LENGTH_TOO_LARGE: &str = "DER length too large";
+
+/// Mirrors the VM, which verifies secp256k1 signatures over the double SHA256 hash of the message,
+/// unless a different hash type is explicitly requested.
+const SECP256K1_DEFAULT_HASH_TYPE: MessageHashType = MessageHashType::ECDSADoubleSha256;
+
+fn hash_secp256k1_message(message: &[u8], hash_type: MessageHashType) -> Vec<u8> {
+    match hash_type {
+        MessageHashType::ECDSAPlainMsg => message.to_vec(),
+        MessageHashType::ECDSASha256 => Sha256::digest(message).to_vec(),
+        MessageHashType::ECDSADoubleSha256 => Sha256::digest(&Sha256::digest(message)).to_vec(),
+        MessageHashType::ECDSAKeccak256 => Keccak256::digest(message).to_vec(),
+        MessageHashType::ECDSARipemd160 => <Ripemd160 as ripemd::Digest>::digest(message).to_vec(),
+    }
+}
+
+/// Herumi serializes points with the x coordinate in little endian and the parity of y in the top bit,
+/// so we go through the big endian compressed format, then pick the y with the right parity.
+fn bls_g1_from_herumi(bytes: &[u8]) -> Option<G1Affine> {
+    let mut compressed = <[u8; 48]>::try_from(bytes).ok()?;
+    let y_is_odd = compressed[47] & 0x80 != 0;
+    compressed.reverse();
+    compressed[0] |= 0x80;
+    let point = Option::<G1Affine>::from(G1Affine::from_compressed(&compressed))?;
+    if (point.to_uncompressed()[95] & 1 != 0) == y_is_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+/// Herumi writes c0 before c1, the compressed format expects c1 first.
+/// The parity bit refers to c0 of y.
+fn bls_g2_from_herumi(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != 96 {
+        return None;
+    }
+    let y_is_odd = bytes[95] & 0x80 != 0;
+    let mut compressed = [0u8; 96];
+    compressed[..48].copy_from_slice(&bytes[48..]);
+    compressed[48..].copy_from_slice(&bytes[..48]);
+    compressed[..48].reverse();
+    compressed[48..].reverse();
+    compressed[0] |= 0x80;
+    let point = Option::<G2Affine>::from(G2Affine::from_compressed(&compressed))?;
+    if (point.to_uncompressed()[191] & 1 != 0) == y_is_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+fn bls_verify(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let (key, signature) = match (bls_g2_from_herumi(key), bls_g1_from_herumi(signature)) {
+        (Some(key), Some(signature)) => (key, signature),
+        _ => return false,
+    };
+    let hash = G1Affine::from(
+        <G1Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(
+            message,
+            BLS_HASH_TO_G1_DST,
+        ),
+    );
+    pairing(&signature, &G2Affine::generator()) == pairing(&hash, &key)
+}
+
+/// Only the short form, which covers all secp256k1 signatures.
+fn der_length(len: usize) -> Result<u8, &'static str> {
+    match u8::try_from(len) {
+        Ok(len) if len < 0x80 => Ok(len),
+        _ => Err(DER_LENGTH_TOO_LARGE),
+    }
+}
+
+/// DER integers are big endian, without leading zeroes,
+/// but with an extra zero byte if the first bit is set, to keep them positive.
+fn der_encode_integer(bytes: &[u8]) -> Result<Vec<u8>, &'static str> {
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    let trimmed = &bytes[first_non_zero..];
+    let mut result = vec![0x02];
+    if trimmed.is_empty() || trimmed[0] & 0x80 != 0 {
+        result.push(der_length(trimmed.len() + 1)?);
+        result.push(0);
+    } else {
+        result.push(der_length(trimmed.len())?);
+    }
+    result.extend_from_slice(trimmed);
+    Ok(result)
+}
+
+fn der_encode_signature(r: &[u8], s: &[u8]) -> Result<Vec<u8>, &'static str> {
+    let mut sequence = der_encode_integer(r)?;
+    sequence.extend_from_slice(&der_encode_integer(s)?);
+
+    let mut result = vec![0x30, der_length(sequence.len())?];
+    result.extend_from_slice(&sequence);
+    Ok(result)
+}
+
 impl CryptoApi for DebugApi {
     type CryptoApiImpl = DebugApi;
 
@@ -20,6 +130,7 @@ impl CryptoApi for DebugApi {
 
 impl CryptoApiImpl for DebugApi {
     fn sha256_legacy(&self, data: &[u8]) -> [u8; SHA256_RESULT_LEN] {
+        self.use_gas(|gas_schedule| gas_schedule.crypto_hash);
         let mut hasher = Sha256::new();
         hasher.update(data);
         hasher.finalize().into()
@@ -32,11 +143,12 @@ impl CryptoApiImpl for DebugApi {
     ) {
         // default implementation used in debugger
         // the VM has a dedicated hook
-        let result_bytes = self.sha256_legacy(self.mb_to_boxed_bytes(data_handle).as_slice());
-        self.mb_overwrite(dest, &result_bytes[..]);
+        let result_bytes = self.sha256_legacy(self.mb_get(data_handle).as_slice());
+        self.mb_set(dest, result_bytes.to_vec());
     }
 
     fn keccak256_legacy(&self, data: &[u8]) -> [u8; KECCAK256_RESULT_LEN] {
+        self.use_gas(|gas_schedule| gas_schedule.crypto_hash);
         let mut hasher = Keccak256::new();
         hasher.update(data);
         hasher.finalize().into()
@@ -49,36 +161,44 @@ impl CryptoApiImpl for DebugApi {
     ) {
         // default implementation used in debugger
         // the VM has a dedicated hook
-        let result_bytes = self.keccak256_legacy(self.mb_to_boxed_bytes(data_handle).as_slice());
-        self.mb_overwrite(dest, &result_bytes[..]);
+        let result_bytes = self.keccak256_legacy(self.mb_get(data_handle).as_slice());
+        self.mb_set(dest, result_bytes.to_vec());
     }
 
-    fn ripemd160_legacy(&self, _data: &[u8]) -> [u8; RIPEMD_RESULT_LEN] {
-        panic!("ripemd160 not implemented yet!")
+    fn ripemd160_legacy(&self, data: &[u8]) -> [u8; RIPEMD_RESULT_LEN] {
+        self.use_gas(|gas_schedule| gas_schedule.crypto_hash);
+        <Ripemd160 as ripemd::Digest>::digest(data).into()
     }
 
     fn ripemd160_managed(
         &self,
-        _dest: Self::ManagedBufferHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        dest: Self::ManagedBufferHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ripemd160 not implemented yet!")
+        let result_bytes = self.ripemd160_legacy(self.mb_get(data_handle).as_slice());
+        self.mb_set(dest, result_bytes.to_vec());
     }
 
-    fn verify_bls_legacy(&self, _key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        panic!("verify_bls not implemented yet!")
+    fn verify_bls_legacy(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        self.use_gas(|gas_schedule| gas_schedule.crypto_verify_signature);
+        bls_verify(key, message, signature)
     }
 
     fn verify_bls_managed(
         &self,
-        _key: Self::ManagedBufferHandle,
-        _message: Self::ManagedBufferHandle,
-        _signature: Self::ManagedBufferHandle,
+        key: Self::ManagedBufferHandle,
+        message: Self::ManagedBufferHandle,
+        signature: Self::ManagedBufferHandle,
     ) -> bool {
-        panic!("verify_bls not implemented yet!")
+        self.verify_bls_legacy(
+            self.mb_get(key).as_slice(),
+            self.mb_get(message).as_slice(),
+            self.mb_get(signature).as_slice(),
+        )
     }
 
     fn verify_ed25519_legacy(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        self.use_gas(|gas_schedule| gas_schedule.crypto_verify_signature);
         let public = PublicKey::from_bytes(key);
         if public.is_err() {
             return false;
@@ -99,55 +219,84 @@ impl CryptoApiImpl for DebugApi {
         signature: Self::ManagedBufferHandle,
     ) -> bool {
         self.verify_ed25519_legacy(
-            self.mb_to_boxed_bytes(key).as_slice(),
-            self.mb_to_boxed_bytes(message).as_slice(),
-            self.mb_to_boxed_bytes(signature).as_slice(),
+            self.mb_get(key).as_slice(),
+            self.mb_get(message).as_slice(),
+            self.mb_get(signature).as_slice(),
         )
     }
 
-    fn verify_secp256k1_legacy(&self, _key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        panic!("verify_secp256k1 not implemented yet!")
+    fn verify_secp256k1_legacy(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        self.verify_custom_secp256k1_legacy(key, message, signature, SECP256K1_DEFAULT_HASH_TYPE)
     }
 
     fn verify_secp256k1_managed(
         &self,
-        _key: Self::ManagedBufferHandle,
-        _message: Self::ManagedBufferHandle,
-        _signature: Self::ManagedBufferHandle,
+        key: Self::ManagedBufferHandle,
+        message: Self::ManagedBufferHandle,
+        signature: Self::ManagedBufferHandle,
     ) -> bool {
-        panic!("verify_secp256k1 not implemented yet!")
+        self.verify_secp256k1_legacy(
+            self.mb_get(key).as_slice(),
+            self.mb_get(message).as_slice(),
+            self.mb_get(signature).as_slice(),
+        )
     }
 
     fn verify_custom_secp256k1_legacy(
         &self,
-        _key: &[u8],
-        _message: &[u8],
-        _signature: &[u8],
-        _hash_type: MessageHashType,
+        key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        hash_type: MessageHashType,
     ) -> bool {
-        panic!("verify_custom_secp256k1 not implemented yet!")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_verify_signature);
+        let public = match Secp256k1VerifyingKey::from_sec1_bytes(key) {
+            Ok(public) => public,
+            Err(_) => return false,
+        };
+        let sig = match Secp256k1Signature::from_der(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+
+        // the VM also accepts non-normalized ("high S") signatures
+        let sig = sig.normalize_s().unwrap_or(sig);
+
+        let hash = hash_secp256k1_message(message, hash_type);
+        public.verify_prehash(&hash, &sig).is_ok()
     }
 
     fn verify_custom_secp256k1_managed(
         &self,
-        _key: Self::ManagedBufferHandle,
-        _message: Self::ManagedBufferHandle,
-        _signature: Self::ManagedBufferHandle,
-        _hash_type: MessageHashType,
+        key: Self::ManagedBufferHandle,
+        message: Self::ManagedBufferHandle,
+        signature: Self::ManagedBufferHandle,
+        hash_type: MessageHashType,
     ) -> bool {
-        panic!("verify_custom_secp256k1 not implemented yet!")
+        self.verify_custom_secp256k1_legacy(
+            self.mb_get(key).as_slice(),
+            self.mb_get(message).as_slice(),
+            self.mb_get(signature).as_slice(),
+            hash_type,
+        )
     }
 
-    fn encode_secp256k1_der_signature_legacy(&self, _r: &[u8], _s: &[u8]) -> BoxedBytes {
-        panic!("encode_secp256k1_signature not implemented yet!")
+    fn encode_secp256k1_der_signature_legacy(&self, r: &[u8], s: &[u8]) -> BoxedBytes {
+        der_encode_signature(r, s)
+            .unwrap_or_else(|err| signal_ec_error(err))
+            .into()
     }
 
     fn encode_secp256k1_der_signature_managed(
         &self,
-        _r: Self::ManagedBufferHandle,
-        _s: Self::ManagedBufferHandle,
-        _dest: Self::ManagedBufferHandle,
+        r: Self::ManagedBufferHandle,
+        s: Self::ManagedBufferHandle,
+        dest: Self::ManagedBufferHandle,
     ) {
-        panic!("encode_secp256k1_signature not implemented yet!")
+        let encoded = self.encode_secp256k1_der_signature_legacy(
+            self.mb_get(r).as_slice(),
+            self.mb_get(s).as_slice(),
+        );
+        self.mb_set(dest, encoded.into_vec());
     }
 }
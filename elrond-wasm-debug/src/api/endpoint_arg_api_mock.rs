@@ -4,7 +4,7 @@ use crate::{
     DebugApi,
 };
 use alloc::vec::Vec;
-use elrond_wasm::api::{EndpointArgumentApi, EndpointArgumentApiImpl, ManagedBufferApi};
+use elrond_wasm::api::{EndpointArgumentApi, EndpointArgumentApiImpl};
 use num_traits::cast::ToPrimitive;
 
 impl EndpointArgumentApi for DebugApi {
@@ -40,7 +40,7 @@ impl EndpointArgumentApiImpl for DebugApi {
 
     fn load_argument_managed_buffer(&self, arg_index: i32, dest: Self::ManagedBufferHandle) {
         let arg_bytes = self.get_argument_vec_u8(arg_index);
-        self.mb_overwrite(dest, arg_bytes.as_slice());
+        self.mb_set(dest, arg_bytes.to_vec());
     }
 
     fn get_argument_i64(&self, arg_index: i32) -> i64 {
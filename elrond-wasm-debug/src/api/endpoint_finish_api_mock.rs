@@ -2,7 +2,7 @@ use crate::{
     num_bigint::{BigInt, BigUint},
     DebugApi,
 };
-use elrond_wasm::api::{BigIntApi, EndpointFinishApi, EndpointFinishApiImpl, ManagedBufferApi};
+use elrond_wasm::api::{BigIntApi, EndpointFinishApi, EndpointFinishApiImpl};
 
 impl EndpointFinishApi for DebugApi {
     type EndpointFinishApiImpl = DebugApi;
@@ -35,7 +35,7 @@ impl EndpointFinishApiImpl for DebugApi {
     }
 
     fn finish_managed_buffer_raw(&self, handle: Self::ManagedBufferHandle) {
-        let bytes = self.mb_to_boxed_bytes(handle);
+        let bytes = self.mb_get(handle);
         self.finish_slice_u8(bytes.as_slice());
     }
 
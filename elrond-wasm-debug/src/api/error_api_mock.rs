@@ -1,5 +1,5 @@
 use crate::{tx_mock::TxPanic, DebugApi};
-use elrond_wasm::api::{ErrorApi, ErrorApiImpl, ManagedBufferApi};
+use elrond_wasm::api::{ErrorApi, ErrorApiImpl};
 
 impl ErrorApi for DebugApi {
     type ErrorApiImpl = DebugApi;
@@ -22,7 +22,7 @@ impl ErrorApiImpl for DebugApi {
     }
 
     fn signal_error_from_buffer(&self, message_handle: Self::ManagedBufferHandle) -> ! {
-        let message = self.mb_to_boxed_bytes(message_handle);
+        let message = self.mb_get(message_handle);
         self.signal_error(message.as_slice())
     }
 }
@@ -4,7 +4,7 @@ use core::{
     ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Rem, Shl, Shr, Sub},
 };
 use elrond_wasm::{
-    api::{BigIntApi, ErrorApiImpl, HandleTypeInfo, ManagedBufferApi},
+    api::{BigIntApi, ErrorApiImpl, HandleTypeInfo},
     err_msg,
     types::heap::BoxedBytes,
 };
@@ -22,13 +22,14 @@ fn assert_positive(bi: &num_bigint::BigInt) {
 }
 
 macro_rules! binary_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost:ident) => {
         fn $method_name(
             &self,
             dest: Self::BigIntHandle,
             x: Self::BigIntHandle,
             y: Self::BigIntHandle,
         ) {
+            self.use_gas(|gas_schedule| gas_schedule.$gas_cost);
             let bi_x = self.bi_get(x);
             let bi_y = self.bi_get(y);
             let result = bi_x.$rust_op_name(bi_y);
@@ -45,6 +46,7 @@ macro_rules! binary_bitwise_op_method {
             x: Self::BigIntHandle,
             y: Self::BigIntHandle,
         ) {
+            self.use_gas(|gas_schedule| gas_schedule.big_int_op);
             let bi_x = self.bi_get(x);
             assert_positive(&bi_x);
             let bi_y = self.bi_get(y);
@@ -56,8 +58,9 @@ macro_rules! binary_bitwise_op_method {
 }
 
 macro_rules! unary_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost:ident) => {
         fn $method_name(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle) {
+            self.use_gas(|gas_schedule| gas_schedule.$gas_cost);
             let bi_x = self.bi_get(x);
             let result = bi_x.$rust_op_name();
             self.bi_overwrite(dest, result);
@@ -141,8 +144,8 @@ impl BigIntApi for DebugApi {
         big_int_to_i64(&bi)
     }
 
-    binary_op_method! {bi_add, add}
-    binary_op_method! {bi_sub, sub}
+    binary_op_method! {bi_add, add, big_int_op}
+    binary_op_method! {bi_sub, sub, big_int_op}
 
     fn bi_sub_unsigned(
         &self,
@@ -150,6 +153,7 @@ impl BigIntApi for DebugApi {
         x: Self::BigIntHandle,
         y: Self::BigIntHandle,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_op);
         let bi_x = self.bi_get(x);
         let bi_y = self.bi_get(y);
         let result = bi_x.sub(bi_y);
@@ -159,12 +163,12 @@ impl BigIntApi for DebugApi {
         self.bi_overwrite(dest, result);
     }
 
-    binary_op_method! {bi_mul, mul}
-    binary_op_method! {bi_t_div, div}
-    binary_op_method! {bi_t_mod, rem}
+    binary_op_method! {bi_mul, mul, big_int_mul_div}
+    binary_op_method! {bi_t_div, div, big_int_mul_div}
+    binary_op_method! {bi_t_mod, rem, big_int_mul_div}
 
-    unary_op_method! {bi_abs, abs}
-    unary_op_method! {bi_neg, neg}
+    unary_op_method! {bi_abs, abs, big_int_op}
+    unary_op_method! {bi_neg, neg, big_int_op}
 
     fn bi_sign(&self, x: Self::BigIntHandle) -> elrond_wasm::api::Sign {
         let bi = self.bi_get(x);
@@ -176,14 +180,16 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_cmp(&self, x: Self::BigIntHandle, y: Self::BigIntHandle) -> Ordering {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_op);
         let bi_x = self.bi_get(x);
         let bi_y = self.bi_get(y);
         bi_x.cmp(&bi_y)
     }
 
-    unary_op_method! {bi_sqrt, sqrt}
+    unary_op_method! {bi_sqrt, sqrt, big_int_mul_div}
 
     fn bi_pow(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, y: Self::BigIntHandle) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_mul_div);
         let bi_x = self.bi_get(x);
         let bi_y = self.bi_get(y);
         let exp = big_int_to_i64(&bi_y).unwrap().try_into().unwrap();
@@ -192,6 +198,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_log2(&self, x: Self::BigIntHandle) -> u32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_op);
         let bi_x = self.bi_get(x);
         bi_x.bits() as u32 - 1
     }
@@ -201,6 +208,7 @@ impl BigIntApi for DebugApi {
     binary_bitwise_op_method! {bi_xor, bitxor}
 
     fn bi_shr(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, bits: usize) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_op);
         let bi_x = self.bi_get(x);
         assert_positive(&bi_x);
         let result = bi_x.shr(bits);
@@ -208,6 +216,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_shl(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, bits: usize) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_op);
         let bi_x = self.bi_get(x);
         assert_positive(&bi_x);
         let result = bi_x.shl(bits);
@@ -219,6 +228,6 @@ impl BigIntApi for DebugApi {
             let bi_x = self.bi_get(x);
             bi_x.to_string()
         };
-        self.mb_overwrite(str_handle, s.as_bytes());
+        self.mb_set(str_handle, s.as_bytes().to_vec());
     }
 }
@@ -1,210 +1,375 @@
-use elrond_wasm::{api::EllipticCurveApi, types::heap::BoxedBytes};
+use crate::{num_bigint::BigUint, tx_mock::TxPanic, DebugApi};
+use elrond_wasm::{
+    api::{EllipticCurveApi, HandleTypeInfo},
+    types::heap::BoxedBytes,
+};
 
-use crate::DebugApi;
+use super::elliptic_curve_util::EllipticCurveParams;
+
+const EC_UNKNOWN_CURVE: &str = "unknown elliptic curve";
+const EC_POINT_NOT_ON_CURVE: &str = "point is not on curve";
+const EC_BAD_BUFFER_LENGTH: &str = "length of buffer is not correct";
+
+pub(crate) fn signal_ec_error(message: &str) -> ! {
+    std::panic::panic_any(TxPanic {
+        status: 10,
+        message: message.to_string(),
+    })
+}
+
+impl DebugApi {
+    fn ec_insert_new(&self, name: &[u8]) -> <Self as HandleTypeInfo>::EllipticCurveHandle {
+        let curve = EllipticCurveParams::from_name(name)
+            .unwrap_or_else(|| signal_ec_error(EC_UNKNOWN_CURVE));
+        let mut managed_types = self.m_types_borrow_mut();
+        managed_types.elliptic_curve_map.insert_new_handle(curve)
+    }
+
+    fn ec_get(&self, handle: <Self as HandleTypeInfo>::EllipticCurveHandle) -> EllipticCurveParams {
+        let managed_types = handle.context.m_types_borrow();
+        managed_types
+            .elliptic_curve_map
+            .get(handle.get_raw_handle_unchecked())
+            .clone()
+    }
+
+    /// Loads a point and checks that it lies on the curve, as the VM does before any operation.
+    fn ec_get_point_on_curve(
+        &self,
+        curve: &EllipticCurveParams,
+        x_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_handle: <Self as HandleTypeInfo>::BigIntHandle,
+    ) -> (BigUint, BigUint) {
+        let x = self.big_uint_handle_to_value(x_handle);
+        let y = self.big_uint_handle_to_value(y_handle);
+        if !curve.is_on_curve(&x, &y) {
+            signal_ec_error(EC_POINT_NOT_ON_CURVE);
+        }
+        (x, y)
+    }
+
+    fn ec_set_point(
+        &self,
+        x_result_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_result_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        point: (BigUint, BigUint),
+    ) {
+        self.set_big_uint(x_result_handle, point.0);
+        self.set_big_uint(y_result_handle, point.1);
+    }
+
+    fn ec_marshal_bytes(
+        &self,
+        ec_handle: <Self as HandleTypeInfo>::EllipticCurveHandle,
+        x_pair_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_pair_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        compressed: bool,
+    ) -> Vec<u8> {
+        let curve = self.ec_get(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, x_pair_handle, y_pair_handle);
+        if compressed {
+            curve.marshal_compressed(&x, &y)
+        } else {
+            curve.marshal(&x, &y)
+        }
+    }
+
+    fn ec_unmarshal_bytes(
+        &self,
+        x_result_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_result_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        ec_handle: <Self as HandleTypeInfo>::EllipticCurveHandle,
+        data: &[u8],
+        compressed: bool,
+    ) {
+        let curve = self.ec_get(ec_handle);
+        let expected_len = if compressed {
+            1 + curve.byte_length()
+        } else {
+            1 + 2 * curve.byte_length()
+        };
+        if data.len() != expected_len {
+            signal_ec_error(EC_BAD_BUFFER_LENGTH);
+        }
+        let point = if compressed {
+            curve.unmarshal_compressed(data)
+        } else {
+            curve.unmarshal(data)
+        };
+        let point = point.unwrap_or_else(|| signal_ec_error(EC_POINT_NOT_ON_CURVE));
+        self.ec_set_point(x_result_handle, y_result_handle, point);
+    }
+
+    fn ec_generate_key_bytes(
+        &self,
+        x_pub_key_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_pub_key_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        ec_handle: <Self as HandleTypeInfo>::EllipticCurveHandle,
+    ) -> Vec<u8> {
+        let curve = self.ec_get(ec_handle);
+        let private_key = {
+            let mut rng = self.rng_borrow_mut();
+            curve.generate_private_key(|dest| rng.fill(dest))
+        };
+        let public_key = curve.scalar_base_mult(private_key.as_slice());
+        self.ec_set_point(x_pub_key_handle, y_pub_key_handle, public_key);
+        private_key
+    }
+}
 
 impl EllipticCurveApi for DebugApi {
-    fn ec_create_from_name_bytes(&self, _name: &[u8]) -> Self::ManagedBufferHandle {
-        panic!("ec_create not implemented")
+    fn ec_create_from_name_bytes(&self, name: &[u8]) -> Self::EllipticCurveHandle {
+        self.ec_insert_new(name)
     }
 
     fn ec_create_from_name_mb(
         &self,
-        _name_handle: Self::ManagedBufferHandle,
-    ) -> Self::ManagedBufferHandle {
-        panic!("ec_create not implemented")
+        name_handle: Self::ManagedBufferHandle,
+    ) -> Self::EllipticCurveHandle {
+        self.ec_insert_new(self.mb_get(name_handle).as_slice())
     }
 
     fn ec_get_values(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _field_order_handle: Self::BigIntHandle,
-        _base_point_order_handle: Self::BigIntHandle,
-        _eq_constant_handle: Self::BigIntHandle,
-        _x_base_point_handle: Self::BigIntHandle,
-        _y_base_point_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        field_order_handle: Self::BigIntHandle,
+        base_point_order_handle: Self::BigIntHandle,
+        eq_constant_handle: Self::BigIntHandle,
+        x_base_point_handle: Self::BigIntHandle,
+        y_base_point_handle: Self::BigIntHandle,
     ) {
-        panic!("ec_get_values not implemented")
+        let curve = self.ec_get(ec_handle);
+        self.set_big_uint(field_order_handle, curve.field_order);
+        self.set_big_uint(base_point_order_handle, curve.base_point_order);
+        self.set_big_uint(eq_constant_handle, curve.eq_constant);
+        self.set_big_uint(x_base_point_handle, curve.x_base_point);
+        self.set_big_uint(y_base_point_handle, curve.y_base_point);
     }
 
-    fn ec_curve_length(&self, _ec_handle: Self::EllipticCurveHandle) -> u32 {
-        panic!("ec_curve_length not implemented")
+    fn ec_curve_length(&self, ec_handle: Self::EllipticCurveHandle) -> u32 {
+        self.ec_get(ec_handle).size_of_field
     }
 
-    fn ec_private_key_byte_length(&self, _ec_handle: Self::EllipticCurveHandle) -> u32 {
-        panic!("ec_private_key_byte_length not implemented")
+    fn ec_private_key_byte_length(&self, ec_handle: Self::EllipticCurveHandle) -> u32 {
+        self.ec_get(ec_handle).byte_length() as u32
     }
 
     fn ec_add(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_first_point: Self::BigIntHandle,
-        _y_first_point: Self::BigIntHandle,
-        _x_second_point: Self::BigIntHandle,
-        _y_second_point: Self::BigIntHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_first_point: Self::BigIntHandle,
+        y_first_point: Self::BigIntHandle,
+        x_second_point: Self::BigIntHandle,
+        y_second_point: Self::BigIntHandle,
     ) {
-        panic!("ec_add not implemented")
+        let curve = self.ec_get(ec_handle);
+        let (x1, y1) = self.ec_get_point_on_curve(&curve, x_first_point, y_first_point);
+        let (x2, y2) = self.ec_get_point_on_curve(&curve, x_second_point, y_second_point);
+        let result = curve.add(&x1, &y1, &x2, &y2);
+        self.ec_set_point(x_result_handle, y_result_handle, result);
     }
 
     fn ec_double(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
     ) {
-        panic!("ec_double not implemented")
+        let curve = self.ec_get(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, x_point_handle, y_point_handle);
+        let result = curve.double(&x, &y);
+        self.ec_set_point(x_result_handle, y_result_handle, result);
     }
 
     fn ec_is_on_curve(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
     ) -> bool {
-        panic!("ec_is_on_curve not implemented")
+        let curve = self.ec_get(ec_handle);
+        let x = self.big_uint_handle_to_value(x_point_handle);
+        let y = self.big_uint_handle_to_value(y_point_handle);
+        curve.is_on_curve(&x, &y)
     }
 
     fn ec_scalar_mult_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_mult not implemented")
+        let curve = self.ec_get(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, x_point_handle, y_point_handle);
+        let result = curve.scalar_mult(&x, &y, data);
+        self.ec_set_point(x_result_handle, y_result_handle, result);
     }
 
     fn ec_scalar_mult(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_scalar_mult not implemented")
+        self.ec_scalar_mult_legacy(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            x_point_handle,
+            y_point_handle,
+            self.mb_get(data_handle).as_slice(),
+        )
     }
 
     fn ec_scalar_base_mult_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_base_mult not implemented")
+        let curve = self.ec_get(ec_handle);
+        let result = curve.scalar_base_mult(data);
+        self.ec_set_point(x_result_handle, y_result_handle, result);
     }
 
     fn ec_scalar_base_mult(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_scalar_base_mult not implemented")
+        self.ec_scalar_base_mult_legacy(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            self.mb_get(data_handle).as_slice(),
+        )
     }
 
     fn ec_marshal_legacy(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
     ) -> BoxedBytes {
-        panic!("ec_marshal not implemented")
+        self.ec_marshal_bytes(ec_handle, x_pair_handle, y_pair_handle, false)
+            .into()
     }
 
     fn ec_marshal(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
-        _result_handle: Self::ManagedBufferHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
+        result_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_marshal not implemented")
+        let bytes = self.ec_marshal_bytes(ec_handle, x_pair_handle, y_pair_handle, false);
+        self.mb_set(result_handle, bytes.to_vec());
     }
 
     fn ec_marshal_compressed_legacy(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
     ) -> BoxedBytes {
-        panic!("ec_marshal_compressed not implemented")
+        self.ec_marshal_bytes(ec_handle, x_pair_handle, y_pair_handle, true)
+            .into()
     }
 
     fn ec_marshal_compressed(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
-        _result_handle: Self::ManagedBufferHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
+        result_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_marshal_compressed not implemented")
+        let bytes = self.ec_marshal_bytes(ec_handle, x_pair_handle, y_pair_handle, true);
+        self.mb_set(result_handle, bytes.to_vec());
     }
 
     fn ec_unmarshal_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal not implemented")
+        self.ec_unmarshal_bytes(x_result_handle, y_result_handle, ec_handle, data, false)
     }
 
     fn ec_unmarshal(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_unmarshal not implemented")
+        self.ec_unmarshal_bytes(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            self.mb_get(data_handle).as_slice(),
+            false,
+        )
     }
 
     fn ec_unmarshal_compressed_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal_compressed not implemented")
+        self.ec_unmarshal_bytes(x_result_handle, y_result_handle, ec_handle, data, true)
     }
 
     fn ec_unmarshal_compressed(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_unmarshal_compressed not implemented")
+        self.ec_unmarshal_bytes(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            self.mb_get(data_handle).as_slice(),
+            true,
+        )
     }
 
     fn ec_generate_key_legacy(
         &self,
-        _x_pub_key_handle: Self::BigIntHandle,
-        _y_pub_key_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
+        x_pub_key_handle: Self::BigIntHandle,
+        y_pub_key_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
     ) -> BoxedBytes {
-        panic!("ec_generate_key not implemented")
+        self.ec_generate_key_bytes(x_pub_key_handle, y_pub_key_handle, ec_handle)
+            .into()
     }
 
     fn ec_generate_key(
         &self,
-        _x_pub_key_handle: Self::BigIntHandle,
-        _y_pub_key_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _result_handle: Self::ManagedBufferHandle,
+        x_pub_key_handle: Self::BigIntHandle,
+        y_pub_key_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        result_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_generate_key not implemented")
+        let private_key = self.ec_generate_key_bytes(x_pub_key_handle, y_pub_key_handle, ec_handle);
+        self.mb_set(result_handle, private_key.to_vec());
     }
 }
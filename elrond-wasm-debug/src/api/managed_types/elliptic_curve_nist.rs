@@ -0,0 +1,89 @@
+use crate::num_bigint::BigUint;
+use num_traits::Zero;
+use p256::elliptic_curve::{
+    group::{Curve, Group},
+    sec1::{EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    CurveArithmetic, FieldBytes, FieldBytesSize, PrimeField,
+};
+
+/// Point arithmetic for the curves that have a RustCrypto implementation,
+/// on the `(x, y)` representation of `EllipticCurveParams`, with `(0, 0)` as the point at infinity.
+pub(super) trait NistCurve: CurveArithmetic
+where
+    Self::AffinePoint: FromEncodedPoint<Self> + ToEncodedPoint<Self>,
+    FieldBytesSize<Self>: ModulusSize,
+{
+    fn field_bytes(value: &BigUint) -> Option<FieldBytes<Self>> {
+        let mut bytes = FieldBytes::<Self>::default();
+        let value_bytes = value.to_bytes_be();
+        if value_bytes.len() > bytes.len() {
+            return None;
+        }
+        let offset = bytes.len() - value_bytes.len();
+        bytes[offset..].copy_from_slice(&value_bytes);
+        Some(bytes)
+    }
+
+    /// `None` if the point is not on the curve, which includes the point at infinity.
+    fn to_point(x: &BigUint, y: &BigUint) -> Option<Self::ProjectivePoint> {
+        let encoded = EncodedPoint::<Self>::from_affine_coordinates(
+            &Self::field_bytes(x)?,
+            &Self::field_bytes(y)?,
+            false,
+        );
+        Option::<Self::AffinePoint>::from(Self::AffinePoint::from_encoded_point(&encoded))
+            .map(Into::into)
+    }
+
+    /// The callers check that the points are on the curve,
+    /// so anything else can only be the point at infinity.
+    fn to_point_or_identity(x: &BigUint, y: &BigUint) -> Self::ProjectivePoint {
+        Self::to_point(x, y).unwrap_or_else(Self::ProjectivePoint::identity)
+    }
+
+    fn from_point(point: Self::ProjectivePoint) -> (BigUint, BigUint) {
+        let encoded = point.to_affine().to_encoded_point(false);
+        match (encoded.x(), encoded.y()) {
+            (Some(x), Some(y)) => (BigUint::from_bytes_be(x), BigUint::from_bytes_be(y)),
+            _ => (BigUint::zero(), BigUint::zero()),
+        }
+    }
+
+    fn is_on_curve(x: &BigUint, y: &BigUint) -> bool {
+        Self::to_point(x, y).is_some()
+    }
+
+    fn add_points(x1: &BigUint, y1: &BigUint, x2: &BigUint, y2: &BigUint) -> (BigUint, BigUint) {
+        Self::from_point(Self::to_point_or_identity(x1, y1) + Self::to_point_or_identity(x2, y2))
+    }
+
+    fn double_point(x: &BigUint, y: &BigUint) -> (BigUint, BigUint) {
+        Self::from_point(Self::to_point_or_identity(x, y).double())
+    }
+
+    /// The scalar is big endian, of any length, so it is first reduced modulo the base point order.
+    fn scalar_mult(
+        x: &BigUint,
+        y: &BigUint,
+        scalar: &[u8],
+        base_point_order: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let reduced = BigUint::from_bytes_be(scalar) % base_point_order;
+        let scalar = Self::field_bytes(&reduced)
+            .and_then(|bytes| Option::<Self::Scalar>::from(Self::Scalar::from_repr(bytes)))
+            .expect("the reduced scalar is always in range");
+        Self::from_point(Self::to_point_or_identity(x, y) * scalar)
+    }
+
+    /// Expects the SEC 1 compressed form, with the tag and length already checked.
+    fn decompress(data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let encoded = EncodedPoint::<Self>::from_bytes(data).ok()?;
+        let point =
+            Option::<Self::AffinePoint>::from(Self::AffinePoint::from_encoded_point(&encoded))?;
+        Some(Self::from_point(point.into()))
+    }
+}
+
+impl NistCurve for p256::NistP256 {}
+impl NistCurve for p384::NistP384 {}
+impl NistCurve for p521::NistP521 {}
@@ -0,0 +1,376 @@
+use crate::num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use super::elliptic_curve_nist::NistCurve;
+
+/// Parameters of a short Weierstrass curve of the form `y² = x³ - 3x + b`,
+/// the same representation as Go's `elliptic.CurveParams`, used by the VM.
+///
+/// The point at infinity is represented as `(0, 0)`, also following the Go implementation.
+///
+/// The arithmetic on p256, p384 and p521 is done by the RustCrypto curve crates.
+/// The p224 arithmetic works on the curve parameters, like Go's generic `CurveParams` code.
+/// Both are checked against known answers generated with OpenSSL, for all 4 curves.
+#[derive(Clone, Debug)]
+pub struct EllipticCurveParams {
+    /// Order of the underlying field.
+    pub field_order: BigUint,
+    /// Order of the base point.
+    pub base_point_order: BigUint,
+    /// The `b` constant of the curve equation.
+    pub eq_constant: BigUint,
+    pub x_base_point: BigUint,
+    pub y_base_point: BigUint,
+    /// Size of the underlying field, in bits.
+    pub size_of_field: u32,
+    backend: CurveBackend,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum CurveBackend {
+    Generic,
+    P256,
+    P384,
+    P521,
+}
+
+/// Evaluates `$nist` with `$curve` set to the RustCrypto curve type, if there is one, `$generic` otherwise.
+macro_rules! with_nist_curve {
+    ($self:ident, $curve:ident => $nist:expr, $generic:expr) => {
+        match $self.backend {
+            CurveBackend::P256 => {
+                type $curve = p256::NistP256;
+                $nist
+            },
+            CurveBackend::P384 => {
+                type $curve = p384::NistP384;
+                $nist
+            },
+            CurveBackend::P521 => {
+                type $curve = p521::NistP521;
+                $nist
+            },
+            CurveBackend::Generic => $generic,
+        }
+    };
+}
+
+fn big_uint_from_hex(hex_str: &str) -> BigUint {
+    BigUint::parse_bytes(hex_str.as_bytes(), 16).unwrap()
+}
+
+impl EllipticCurveParams {
+    /// Only the NIST curves supported by the VM are recognized: p224, p256, p384 and p521.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"p224" => Some(Self::from_hex(
+                "ffffffffffffffffffffffffffffffff000000000000000000000001",
+                "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+                "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+                "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+                "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+                224,
+                CurveBackend::Generic,
+            )),
+            b"p256" => Some(Self::from_hex(
+                "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+                "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+                "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+                "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+                256,
+                CurveBackend::P256,
+            )),
+            b"p384" => Some(Self::from_hex(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+                "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+                "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+                "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+                384,
+                CurveBackend::P384,
+            )),
+            b"p521" => Some(Self::from_hex(
+                "1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "1fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+                "51953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+                "c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+                "11839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+                521,
+                CurveBackend::P521,
+            )),
+            _ => None,
+        }
+    }
+
+    fn from_hex(
+        p: &str,
+        n: &str,
+        b: &str,
+        gx: &str,
+        gy: &str,
+        size_of_field: u32,
+        backend: CurveBackend,
+    ) -> Self {
+        EllipticCurveParams {
+            field_order: big_uint_from_hex(p),
+            base_point_order: big_uint_from_hex(n),
+            eq_constant: big_uint_from_hex(b),
+            x_base_point: big_uint_from_hex(gx),
+            y_base_point: big_uint_from_hex(gy),
+            size_of_field,
+            backend,
+        }
+    }
+
+    /// Length in bytes of one serialized coordinate, also the length of a private key.
+    pub fn byte_length(&self) -> usize {
+        (self.size_of_field as usize + 7) / 8
+    }
+
+    fn is_infinity(x: &BigUint, y: &BigUint) -> bool {
+        x.is_zero() && y.is_zero()
+    }
+
+    fn mod_add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.field_order
+    }
+
+    fn mod_sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        ((a % p) + p - (b % p)) % p
+    }
+
+    fn mod_mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a * b) % &self.field_order
+    }
+
+    /// The field order is prime, so Fermat's little theorem gives the inverse.
+    fn mod_inv(&self, a: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        a.modpow(&(p - 2u32), p)
+    }
+
+    /// Evaluates `x³ - 3x + b`.
+    fn polynomial(&self, x: &BigUint) -> BigUint {
+        let x3 = self.mod_mul(&self.mod_mul(x, x), x);
+        let three_x = self.mod_mul(x, &BigUint::from(3u32));
+        self.mod_add(&self.mod_sub(&x3, &three_x), &self.eq_constant)
+    }
+
+    pub fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        if x >= &self.field_order || y >= &self.field_order {
+            return false;
+        }
+        with_nist_curve!(self, C => C::is_on_curve(x, y), self.mod_mul(y, y) == self.polynomial(x))
+    }
+
+    pub fn add(
+        &self,
+        x1: &BigUint,
+        y1: &BigUint,
+        x2: &BigUint,
+        y2: &BigUint,
+    ) -> (BigUint, BigUint) {
+        with_nist_curve!(self, C => C::add_points(x1, y1, x2, y2), self.generic_add(x1, y1, x2, y2))
+    }
+
+    fn generic_add(
+        &self,
+        x1: &BigUint,
+        y1: &BigUint,
+        x2: &BigUint,
+        y2: &BigUint,
+    ) -> (BigUint, BigUint) {
+        if Self::is_infinity(x1, y1) {
+            return (x2.clone(), y2.clone());
+        }
+        if Self::is_infinity(x2, y2) {
+            return (x1.clone(), y1.clone());
+        }
+        if x1 == x2 {
+            if y1 == y2 {
+                return self.generic_double(x1, y1);
+            }
+            return (BigUint::zero(), BigUint::zero());
+        }
+
+        let lambda = self.mod_mul(&self.mod_sub(y2, y1), &self.mod_inv(&self.mod_sub(x2, x1)));
+        let x3 = self.mod_sub(&self.mod_sub(&self.mod_mul(&lambda, &lambda), x1), x2);
+        let y3 = self.mod_sub(&self.mod_mul(&lambda, &self.mod_sub(x1, &x3)), y1);
+        (x3, y3)
+    }
+
+    pub fn double(&self, x: &BigUint, y: &BigUint) -> (BigUint, BigUint) {
+        with_nist_curve!(self, C => C::double_point(x, y), self.generic_double(x, y))
+    }
+
+    fn generic_double(&self, x: &BigUint, y: &BigUint) -> (BigUint, BigUint) {
+        if y.is_zero() {
+            return (BigUint::zero(), BigUint::zero());
+        }
+
+        // a = -3, so the numerator is 3x² - 3
+        let three = BigUint::from(3u32);
+        let numerator = self.mod_sub(&self.mod_mul(&three, &self.mod_mul(x, x)), &three);
+        let denominator = self.mod_inv(&self.mod_mul(&BigUint::from(2u32), y));
+        let lambda = self.mod_mul(&numerator, &denominator);
+        let x3 = self.mod_sub(
+            &self.mod_mul(&lambda, &lambda),
+            &self.mod_mul(&BigUint::from(2u32), x),
+        );
+        let y3 = self.mod_sub(&self.mod_mul(&lambda, &self.mod_sub(x, &x3)), y);
+        (x3, y3)
+    }
+
+    /// The scalar is given as big endian bytes.
+    pub fn scalar_mult(&self, x: &BigUint, y: &BigUint, scalar: &[u8]) -> (BigUint, BigUint) {
+        with_nist_curve!(
+            self,
+            C => C::scalar_mult(x, y, scalar, &self.base_point_order),
+            self.generic_scalar_mult(x, y, scalar)
+        )
+    }
+
+    /// Double-and-add.
+    fn generic_scalar_mult(&self, x: &BigUint, y: &BigUint, scalar: &[u8]) -> (BigUint, BigUint) {
+        let mut result = (BigUint::zero(), BigUint::zero());
+        for byte in scalar {
+            for bit_index in (0..8).rev() {
+                result = self.generic_double(&result.0, &result.1);
+                if (byte >> bit_index) & 1 == 1 {
+                    result = self.generic_add(&result.0, &result.1, x, y);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn scalar_base_mult(&self, scalar: &[u8]) -> (BigUint, BigUint) {
+        self.scalar_mult(&self.x_base_point, &self.y_base_point, scalar)
+    }
+
+    /// Uncompressed form: `0x04 | x | y`, as in the SEC 1 standard.
+    pub fn marshal(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let byte_len = self.byte_length();
+        let mut result = vec![4u8];
+        result.extend_from_slice(&pad_left(x, byte_len));
+        result.extend_from_slice(&pad_left(y, byte_len));
+        result
+    }
+
+    /// Compressed form: `0x02 | x` or `0x03 | x`, depending on the parity of `y`.
+    pub fn marshal_compressed(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![2u8 + y.bit(0) as u8];
+        result.extend_from_slice(&pad_left(x, self.byte_length()));
+        result
+    }
+
+    pub fn unmarshal(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let byte_len = self.byte_length();
+        if data.len() != 1 + 2 * byte_len || data[0] != 4 {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..1 + byte_len]);
+        let y = BigUint::from_bytes_be(&data[1 + byte_len..]);
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    pub fn unmarshal_compressed(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let byte_len = self.byte_length();
+        if data.len() != 1 + byte_len || (data[0] != 2 && data[0] != 3) {
+            return None;
+        }
+        with_nist_curve!(self, C => C::decompress(data), self.generic_decompress(data))
+    }
+
+    fn generic_decompress(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let x = BigUint::from_bytes_be(&data[1..]);
+        if x >= self.field_order {
+            return None;
+        }
+        let mut y = self.mod_sqrt(&self.polynomial(&x))?;
+        if y.bit(0) != (data[0] & 1 == 1) {
+            y = self.mod_sub(&BigUint::zero(), &y);
+        }
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Tonelli-Shanks, since the p224 field order is not congruent to 3 mod 4.
+    fn mod_sqrt(&self, a: &BigUint) -> Option<BigUint> {
+        let p = &self.field_order;
+        let one = BigUint::one();
+        if a.is_zero() {
+            return Some(BigUint::zero());
+        }
+        let p_minus_one = p - &one;
+        let legendre_exp = &p_minus_one >> 1;
+        if a.modpow(&legendre_exp, p) != one {
+            return None;
+        }
+
+        let mut q = p_minus_one.clone();
+        let mut s = 0u32;
+        while !q.bit(0) {
+            q >>= 1;
+            s += 1;
+        }
+
+        let mut z = BigUint::from(2u32);
+        while z.modpow(&legendre_exp, p) != p_minus_one {
+            z += 1u32;
+        }
+
+        let mut m = s;
+        let mut c = z.modpow(&q, p);
+        let mut t = a.modpow(&q, p);
+        let mut r = a.modpow(&((&q + &one) >> 1), p);
+        while t != one {
+            let mut i = 0u32;
+            let mut t_pow = t.clone();
+            while t_pow != one {
+                t_pow = self.mod_mul(&t_pow, &t_pow);
+                i += 1;
+            }
+            let b = c.modpow(&(BigUint::one() << (m - i - 1)), p);
+            m = i;
+            c = self.mod_mul(&b, &b);
+            t = self.mod_mul(&t, &c);
+            r = self.mod_mul(&r, &b);
+        }
+        Some(r)
+    }
+
+    /// Mirrors the private key generation in Go's `elliptic.GenerateKey`:
+    /// random bytes, masked to the bit size of the base point order, rejected if out of range.
+    pub fn generate_private_key<F: FnMut(&mut [u8])>(&self, mut fill_random: F) -> Vec<u8> {
+        const MASK: [u8; 8] = [0xff, 0x1, 0x3, 0x7, 0xf, 0x1f, 0x3f, 0x7f];
+        let bit_size = self.base_point_order.bits();
+        let mut private_key = vec![0u8; self.byte_length()];
+        loop {
+            fill_random(&mut private_key[..]);
+            private_key[0] &= MASK[(bit_size % 8) as usize];
+            let k = BigUint::from_bytes_be(&private_key);
+            if !k.is_zero() && k < self.base_point_order {
+                return private_key;
+            }
+        }
+    }
+}
+
+fn pad_left(value: &BigUint, byte_len: usize) -> Vec<u8> {
+    let bytes = if value.is_zero() {
+        Vec::new()
+    } else {
+        value.to_bytes_be()
+    };
+    let mut result = vec![0u8; byte_len - bytes.len()];
+    result.extend_from_slice(&bytes);
+    result
+}
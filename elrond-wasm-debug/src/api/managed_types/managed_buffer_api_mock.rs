@@ -39,7 +39,11 @@ impl DebugApi {
         f(value)
     }
 
-    fn mb_set(&self, handle: <Self as HandleTypeInfo>::ManagedBufferHandle, value: Vec<u8>) {
+    pub(crate) fn mb_set(
+        &self,
+        handle: <Self as HandleTypeInfo>::ManagedBufferHandle,
+        value: Vec<u8>,
+    ) {
         let mut managed_types = handle.context.m_types_borrow_mut();
         managed_types
             .managed_buffer_map
@@ -54,19 +58,23 @@ impl DebugApi {
 
 impl ManagedBufferApi for DebugApi {
     fn mb_new_empty(&self) -> Self::ManagedBufferHandle {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         self.mb_new(Vec::new())
     }
 
     fn mb_new_from_bytes(&self, bytes: &[u8]) -> Self::ManagedBufferHandle {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         self.mb_new(Vec::from(bytes))
     }
 
     fn mb_len(&self, handle: Self::ManagedBufferHandle) -> usize {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         let data = self.mb_get(handle);
         data.len()
     }
 
     fn mb_to_boxed_bytes(&self, handle: Self::ManagedBufferHandle) -> BoxedBytes {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         let data = self.mb_get(handle);
         data.into()
     }
@@ -77,6 +85,7 @@ impl ManagedBufferApi for DebugApi {
         starting_position: usize,
         dest_slice: &mut [u8],
     ) -> Result<(), InvalidSliceError> {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         let opt_slice = self.mb_get_slice(source_handle, starting_position, dest_slice.len());
         if let Some(slice) = opt_slice {
             dest_slice.copy_from_slice(slice.as_slice());
@@ -93,6 +102,7 @@ impl ManagedBufferApi for DebugApi {
         slice_len: usize,
         dest_handle: Self::ManagedBufferHandle,
     ) -> Result<(), InvalidSliceError> {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         let opt_slice = self.mb_get_slice(source_handle, starting_position, slice_len);
         if let Some(slice) = opt_slice {
             self.mb_set(dest_handle, slice);
@@ -107,12 +117,14 @@ impl ManagedBufferApi for DebugApi {
         handle: Self::ManagedBufferHandle,
         destination: &mut [u8],
     ) {
-        let bytes = self.mb_to_boxed_bytes(handle);
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
+        let bytes = self.mb_get(handle);
         let offset = 32 - bytes.len();
         destination[offset..].copy_from_slice(bytes.as_slice());
     }
 
     fn mb_overwrite(&self, handle: Self::ManagedBufferHandle, value: &[u8]) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         self.mb_set(handle, value.into());
     }
 
@@ -122,6 +134,7 @@ impl ManagedBufferApi for DebugApi {
         starting_position: usize,
         source_slice: &[u8],
     ) -> Result<(), InvalidSliceError> {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         self.mb_update(dest_handle, |bytes| {
             let end_position = starting_position + source_slice.len();
             if end_position <= bytes.len() {
@@ -134,6 +147,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_set_random(&self, dest_handle: Self::ManagedBufferHandle, length: usize) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         let mut bytes = Vec::<u8>::new();
         bytes.resize(length, 0);
         let mut rng = self.rng_borrow_mut();
@@ -146,6 +160,7 @@ impl ManagedBufferApi for DebugApi {
         accumulator_handle: Self::ManagedBufferHandle,
         data_handle: Self::ManagedBufferHandle,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         let mut data = self.mb_get(data_handle);
         self.mb_update(accumulator_handle, |accumulator| {
             accumulator.append(&mut data);
@@ -153,6 +168,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_append_bytes(&self, accumulator_handle: Self::ManagedBufferHandle, bytes: &[u8]) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         self.mb_update(accumulator_handle, |accumulator| {
             accumulator.extend_from_slice(bytes);
         });
@@ -163,6 +179,7 @@ impl ManagedBufferApi for DebugApi {
         handle1: Self::ManagedBufferHandle,
         handle2: Self::ManagedBufferHandle,
     ) -> bool {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         let bytes1 = self.mb_get(handle1);
         let bytes2 = self.mb_get(handle2);
         bytes1 == bytes2
@@ -173,6 +190,7 @@ impl ManagedBufferApi for DebugApi {
         source_handle: Self::ManagedBufferHandle,
         dest_handle: Self::ManagedBufferHandle,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_op);
         let data = self.mb_get(source_handle);
         let encoded = hex::encode(data);
         self.mb_set(dest_handle, encoded.into_bytes());
@@ -1,7 +1,7 @@
 use std::convert::TryInto;
 
 use crate::num_bigint::{BigInt, Sign};
-use elrond_wasm::api::{BigIntApi, ManagedBufferApi, ManagedTypeApi, ManagedTypeApiImpl};
+use elrond_wasm::api::{BigIntApi, ManagedTypeApi, ManagedTypeApiImpl};
 
 use crate::DebugApi;
 
@@ -19,7 +19,7 @@ impl ManagedTypeApiImpl for DebugApi {
         buffer_handle: Self::ManagedBufferHandle,
         dest: Self::BigIntHandle,
     ) {
-        let bytes = self.mb_to_boxed_bytes(buffer_handle);
+        let bytes = self.mb_get(buffer_handle);
         let bi = BigInt::from_bytes_be(Sign::Plus, bytes.as_slice());
         self.bi_overwrite(dest, bi);
     }
@@ -29,7 +29,7 @@ impl ManagedTypeApiImpl for DebugApi {
         buffer_handle: Self::ManagedBufferHandle,
         dest: Self::BigIntHandle,
     ) {
-        let bytes = self.mb_to_boxed_bytes(buffer_handle);
+        let bytes = self.mb_get(buffer_handle);
         let bi = BigInt::from_signed_bytes_be(bytes.as_slice());
         self.bi_overwrite(dest, bi);
     }
@@ -40,7 +40,7 @@ impl ManagedTypeApiImpl for DebugApi {
         dest: Self::ManagedBufferHandle,
     ) {
         let bi_bytes = self.bi_get_unsigned_bytes(big_int_handle);
-        self.mb_overwrite(dest, bi_bytes.as_slice());
+        self.mb_set(dest, bi_bytes.into_vec());
     }
 
     fn mb_from_big_int_signed(
@@ -49,7 +49,7 @@ impl ManagedTypeApiImpl for DebugApi {
         dest: Self::ManagedBufferHandle,
     ) {
         let bi_bytes = self.bi_get_signed_bytes(big_int_handle);
-        self.mb_overwrite(dest, bi_bytes.as_slice());
+        self.mb_set(dest, bi_bytes.into_vec());
     }
 
     fn mb_to_big_float(
@@ -73,6 +73,6 @@ impl ManagedTypeApiImpl for DebugApi {
     ) {
         let bf = self.bf_get_f64(big_float_handle);
         let bf_bytes = bf.to_be_bytes();
-        self.mb_overwrite(dest, &bf_bytes[..]);
+        self.mb_set(dest, bf_bytes.to_vec());
     }
 }
@@ -2,6 +2,11 @@ mod big_float_api_mock;
 mod big_int_api_mock;
 mod big_int_util;
 mod elliptic_curve_api_mock;
+mod elliptic_curve_nist;
+mod elliptic_curve_util;
 mod managed_buffer_api_mock;
 mod managed_type_api_mock;
 mod static_var_api_mock;
+
+pub(crate) use elliptic_curve_api_mock::signal_ec_error;
+pub use elliptic_curve_util::EllipticCurveParams;
@@ -13,3 +13,4 @@ mod storage_api_mock;
 mod vm_api_mock;
 
 pub use debug_handle_mock::DebugHandle;
+pub use managed_types::EllipticCurveParams;
@@ -1,4 +1,4 @@
-use crate::DebugApi;
+use crate::{tx_mock::TxContextStack, DebugApi};
 use elrond_wasm::{
     api::{PrintApi, PrintApiImpl},
     types::ManagedBufferCachedBuilder,
@@ -10,6 +10,12 @@ impl PrintApi for DebugApi {
     fn print_api_impl() -> Self::PrintApiImpl {
         DebugApi::new_from_static()
     }
+
+    fn trace_endpoint_call(endpoint_name: &'static str) {
+        if let Some(tx_context) = TxContextStack::static_try_peek() {
+            tx_context.record_direct_endpoint_call(endpoint_name);
+        }
+    }
 }
 
 impl PrintApiImpl for DebugApi {
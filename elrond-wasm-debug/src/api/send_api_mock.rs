@@ -40,6 +40,8 @@ impl DebugApi {
         tx_result: TxResult,
         blockchain_updates: BlockchainUpdate,
     ) -> Vec<Vec<u8>> {
+        self.use_gas_raw(tx_result.result_gas_used);
+
         self.blockchain_cache().commit_updates(blockchain_updates);
 
         self.result_borrow_mut().merge_after_sync_call(&tx_result);
@@ -47,10 +49,17 @@ impl DebugApi {
         tx_result.result_values
     }
 
+    /// The child call gets the gas limit passed by the contract, but no more than what is left.
+    /// The parent is only charged with the gas the child actually used, after the call.
+    fn child_gas_limit(&self, gas_limit: u64) -> u64 {
+        gas_limit.min(self.gas_left())
+    }
+
     fn prepare_execute_on_dest_context_input(
         &self,
         to: Address,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: Vec<u8>,
         args: Vec<Vec<u8>>,
     ) -> TxInput {
@@ -63,9 +72,10 @@ impl DebugApi {
             esdt_values: Vec::new(),
             func_name,
             args,
-            gas_limit: 1000,
+            gas_limit: self.child_gas_limit(gas_limit),
             gas_price: 0,
             tx_hash,
+            promise_callback_closure_data: Vec::new(),
         }
     }
 
@@ -73,10 +83,14 @@ impl DebugApi {
         &self,
         to: Address,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: Vec<u8>,
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
-        let tx_input = self.prepare_execute_on_dest_context_input(to, egld_value, func_name, args);
+        self.use_gas(|gas_schedule| gas_schedule.execute_on_dest_context);
+
+        let tx_input =
+            self.prepare_execute_on_dest_context_input(to, egld_value, gas_limit, func_name, args);
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
         let (tx_result, blockchain_updates) =
             execute_builtin_function_or_default(tx_input, tx_cache);
@@ -96,10 +110,14 @@ impl DebugApi {
         &self,
         to: Address,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: Vec<u8>,
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
-        let tx_input = self.prepare_execute_on_dest_context_input(to, egld_value, func_name, args);
+        self.use_gas(|gas_schedule| gas_schedule.transfer_value);
+
+        let tx_input =
+            self.prepare_execute_on_dest_context_input(to, egld_value, gas_limit, func_name, args);
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
         let (tx_result, blockchain_updates) =
             execute_builtin_function_or_default(tx_input, tx_cache);
@@ -119,8 +137,11 @@ impl DebugApi {
         &self,
         contract_code: Vec<u8>,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         args: Vec<Vec<u8>>,
     ) -> (Address, Vec<Vec<u8>>) {
+        self.use_gas(|gas_schedule| gas_schedule.execute_on_dest_context);
+
         let contract_address = &self.input_ref().to;
         let tx_hash = self.get_tx_hash_legacy();
         let tx_input = TxInput {
@@ -130,9 +151,10 @@ impl DebugApi {
             esdt_values: Vec::new(),
             func_name: Vec::new(),
             args,
-            gas_limit: 1000,
+            gas_limit: self.child_gas_limit(gas_limit),
             gas_price: 0,
             tx_hash,
+            promise_callback_closure_data: Vec::new(),
         };
 
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
@@ -154,6 +176,20 @@ impl DebugApi {
         }
     }
 
+    /// Legacy async calls receive the requested gas, capped to the remaining gas,
+    /// except for the part reserved for the callback.
+    ///
+    /// Returns the gas limit of the async call and the gas reserved for the callback.
+    fn use_async_call_gas(&self, gas_limit: u64) -> (u64, u64) {
+        self.use_gas(|gas_schedule| gas_schedule.async_call + gas_schedule.async_callback_gas_lock);
+        let extra_gas_for_callback = self
+            .gas_schedule()
+            .map_or(0, |gas_schedule| gas_schedule.async_callback_gas_lock);
+        let gas_limit = self.child_gas_limit(gas_limit);
+        self.use_gas_raw(gas_limit);
+        (gas_limit, extra_gas_for_callback)
+    }
+
     fn perform_async_call(&self, call: AsyncCallTxData) -> ! {
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.extract_result();
@@ -164,6 +200,7 @@ impl DebugApi {
     fn perform_upgrade_contract<M: ManagedTypeApi>(
         &self,
         sc_address: &ManagedAddress<M>,
+        gas: u64,
         amount: &BigUint<M>,
         contract_code: Vec<u8>,
         code_metadata: CodeMetadata,
@@ -174,6 +211,7 @@ impl DebugApi {
             self.big_uint_handle_to_value(amount.get_handle().cast_or_signal_error::<M, _>());
         let contract_address = self.input_ref().to.clone();
         let tx_hash = self.get_tx_hash_legacy();
+        let (gas_limit, extra_gas_for_callback) = self.use_async_call_gas(gas);
 
         let mut arguments = vec![contract_code, top_encode_to_vec_u8(&code_metadata).unwrap()];
         arguments.extend(
@@ -188,6 +226,8 @@ impl DebugApi {
             endpoint_name: UPGRADE_CONTRACT_FUNC_NAME.to_vec(),
             arguments,
             tx_hash,
+            gas_limit,
+            extra_gas_for_callback,
         };
         self.perform_async_call(call)
     }
@@ -212,7 +252,7 @@ impl SendApiImpl for DebugApi {
         &self,
         to: &ManagedAddress<M>,
         amount: &BigUint<M>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<M>,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> Result<(), &'static [u8]> {
@@ -223,6 +263,7 @@ impl SendApiImpl for DebugApi {
         let _ = self.perform_transfer_execute(
             recipient,
             egld_value,
+            gas_limit,
             endpoint_name.to_boxed_bytes().into_vec(),
             arg_buffer.to_raw_args_vec(),
         );
@@ -235,7 +276,7 @@ impl SendApiImpl for DebugApi {
         to: &ManagedAddress<M>,
         token: &TokenIdentifier<M>,
         amount: &BigUint<M>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<M>,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> Result<(), &'static [u8]> {
@@ -249,6 +290,7 @@ impl SendApiImpl for DebugApi {
         let _ = self.perform_transfer_execute(
             recipient,
             num_bigint::BigUint::zero(),
+            gas_limit,
             ESDT_TRANSFER_FUNC_NAME.to_vec(),
             args,
         );
@@ -262,7 +304,7 @@ impl SendApiImpl for DebugApi {
         token: &TokenIdentifier<M>,
         nonce: u64,
         amount: &BigUint<M>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<M>,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> Result<(), &'static [u8]> {
@@ -285,6 +327,7 @@ impl SendApiImpl for DebugApi {
         let _ = self.perform_transfer_execute(
             contract_address,
             num_bigint::BigUint::zero(),
+            gas_limit,
             ESDT_NFT_TRANSFER_FUNC_NAME.to_vec(),
             args,
         );
@@ -296,7 +339,7 @@ impl SendApiImpl for DebugApi {
         &self,
         to: &ManagedAddress<M>,
         payments: &ManagedVec<M, EsdtTokenPayment<M>>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<M>,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> Result<(), &'static [u8]> {
@@ -329,6 +372,7 @@ impl SendApiImpl for DebugApi {
         let _ = self.perform_transfer_execute(
             contract_address,
             num_bigint::BigUint::zero(),
+            gas_limit,
             ESDT_MULTI_TRANSFER_FUNC_NAME.to_vec(),
             args,
         );
@@ -348,6 +392,8 @@ impl SendApiImpl for DebugApi {
         let contract_address = self.input_ref().to.clone();
         let recipient = to.to_address();
         let tx_hash = self.get_tx_hash_legacy();
+        // legacy async calls get all the remaining gas
+        let (gas_limit, extra_gas_for_callback) = self.use_async_call_gas(u64::MAX);
         let call = AsyncCallTxData {
             from: contract_address,
             to: recipient,
@@ -355,6 +401,8 @@ impl SendApiImpl for DebugApi {
             endpoint_name: endpoint_name.to_boxed_bytes().into_vec(),
             arguments: arg_buffer.to_raw_args_vec(),
             tx_hash,
+            gas_limit,
+            extra_gas_for_callback,
         };
         self.perform_async_call(call)
     }
@@ -366,10 +414,15 @@ impl SendApiImpl for DebugApi {
         endpoint_name: &ManagedBuffer<M>,
         success_callback: &'static [u8],
         error_callback: &'static [u8],
-        _gas: u64,
-        _extra_gas_for_callback: u64,
+        gas: u64,
+        extra_gas_for_callback: u64,
         arg_buffer: &ManagedArgBuffer<M>,
+        callback_closure: &ManagedBuffer<M>,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.async_call);
+        // the gas for the promise and its callback is reserved upfront
+        self.use_gas_raw(gas.saturating_add(extra_gas_for_callback));
+
         let amount_value =
             self.big_uint_handle_to_value(amount.get_handle().cast_or_signal_error::<M, _>());
         let contract_address = self.input_ref().to.clone();
@@ -383,12 +436,15 @@ impl SendApiImpl for DebugApi {
             endpoint_name: endpoint_name.to_boxed_bytes().into_vec(),
             arguments: arg_buffer.to_raw_args_vec(),
             tx_hash,
+            gas_limit: gas,
+            extra_gas_for_callback,
         };
 
         let promise = Promise {
             endpoint: call,
             success_callback,
             error_callback,
+            callback_closure: callback_closure.to_boxed_bytes().into_vec(),
         };
 
         let mut tx_result = self.result_borrow_mut();
@@ -397,7 +453,7 @@ impl SendApiImpl for DebugApi {
 
     fn deploy_contract<M: ManagedTypeApi>(
         &self,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<M>,
         code: &ManagedBuffer<M>,
         _code_metadata: CodeMetadata,
@@ -407,14 +463,14 @@ impl SendApiImpl for DebugApi {
             self.big_uint_handle_to_value(amount.get_handle().cast_or_signal_error::<M, _>());
         let contract_code = code.to_boxed_bytes().into_vec();
         let (new_address, result) =
-            self.perform_deploy(contract_code, egld_value, arg_buffer.to_raw_args_vec());
+            self.perform_deploy(contract_code, egld_value, gas, arg_buffer.to_raw_args_vec());
 
         (ManagedAddress::from(new_address), ManagedVec::from(result))
     }
 
     fn deploy_from_source_contract<M: ManagedTypeApi>(
         &self,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<M>,
         source_contract_address: &ManagedAddress<M>,
         _code_metadata: CodeMetadata,
@@ -426,6 +482,7 @@ impl SendApiImpl for DebugApi {
         let (new_address, result) = self.perform_deploy(
             source_contract_code,
             egld_value,
+            gas,
             arg_buffer.to_raw_args_vec(),
         );
 
@@ -435,32 +492,46 @@ impl SendApiImpl for DebugApi {
     fn upgrade_contract<M: ManagedTypeApi>(
         &self,
         sc_address: &ManagedAddress<M>,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<M>,
         code: &ManagedBuffer<M>,
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<M>,
     ) {
         let contract_code = code.to_boxed_bytes().into_vec();
-        self.perform_upgrade_contract(sc_address, amount, contract_code, code_metadata, arg_buffer)
+        self.perform_upgrade_contract(
+            sc_address,
+            gas,
+            amount,
+            contract_code,
+            code_metadata,
+            arg_buffer,
+        )
     }
 
     fn upgrade_from_source_contract<M: ManagedTypeApi>(
         &self,
         sc_address: &ManagedAddress<M>,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<M>,
         source_contract_address: &ManagedAddress<M>,
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<M>,
     ) {
         let contract_code = self.get_contract_code(&source_contract_address.to_address());
-        self.perform_upgrade_contract(sc_address, amount, contract_code, code_metadata, arg_buffer)
+        self.perform_upgrade_contract(
+            sc_address,
+            gas,
+            amount,
+            contract_code,
+            code_metadata,
+            arg_buffer,
+        )
     }
 
     fn execute_on_dest_context_raw<M: ManagedTypeApi>(
         &self,
-        _gas: u64,
+        gas: u64,
         to: &ManagedAddress<M>,
         value: &BigUint<M>,
         endpoint_name: &ManagedBuffer<M>,
@@ -473,6 +544,7 @@ impl SendApiImpl for DebugApi {
         let result = self.perform_execute_on_dest_context(
             recipient,
             egld_value,
+            gas,
             endpoint_name.to_boxed_bytes().into_vec(),
             arg_buffer.to_raw_args_vec(),
         );
@@ -5,8 +5,7 @@ use crate::{
 };
 use alloc::vec::Vec;
 use elrond_wasm::api::{
-    BigIntApi, ManagedBufferApi, StorageReadApi, StorageReadApiImpl, StorageWriteApi,
-    StorageWriteApiImpl,
+    BigIntApi, StorageReadApi, StorageReadApiImpl, StorageWriteApi, StorageWriteApiImpl,
 };
 
 impl StorageReadApi for DebugApi {
@@ -19,10 +18,22 @@ impl StorageReadApi for DebugApi {
 
 impl DebugApi {
     fn storage_load_vec_u8(&self, key: &[u8]) -> Vec<u8> {
-        self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
-            None => Vec::with_capacity(0),
-            Some(value) => value.clone(),
-        })
+        self.record_coverage(&self.input_ref().to, |coverage, contract_code| {
+            coverage.record_storage_read(contract_code, key)
+        });
+        let value =
+            self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
+                None => Vec::with_capacity(0),
+                Some(value) => value.clone(),
+            });
+        self.use_storage_load_gas(value.len());
+        value
+    }
+
+    fn use_storage_load_gas(&self, value_len: usize) {
+        self.use_gas(|gas_schedule| {
+            gas_schedule.storage_load + gas_schedule.storage_load_per_byte * value_len as u64
+        });
     }
 }
 
@@ -46,9 +57,9 @@ impl StorageReadApiImpl for DebugApi {
         key_handle: Self::ManagedBufferHandle,
         dest: Self::ManagedBufferHandle,
     ) {
-        let key_bytes = self.mb_to_boxed_bytes(key_handle);
+        let key_bytes = self.mb_get(key_handle);
         let bytes = self.storage_load_vec_u8(key_bytes.as_slice());
-        self.mb_overwrite(dest, bytes.as_slice());
+        self.mb_set(dest, bytes.to_vec());
     }
 
     fn storage_load_from_address(
@@ -57,16 +68,31 @@ impl StorageReadApiImpl for DebugApi {
         key_handle: Self::ManagedBufferHandle,
         dest: Self::ManagedBufferHandle,
     ) {
-        let address = elrond_wasm::types::heap::Address::from_slice(
-            self.mb_to_boxed_bytes(address_handle).as_slice(),
-        );
-        let key_bytes = self.mb_to_boxed_bytes(key_handle);
-        self.with_account(&address, |account| {
-            match account.storage.get(key_bytes.as_slice()) {
-                None => self.mb_overwrite(dest, &[]),
-                Some(value) => self.mb_overwrite(dest, value.as_slice()),
-            }
-        })
+        let address =
+            elrond_wasm::types::heap::Address::from_slice(self.mb_get(address_handle).as_slice());
+        if self
+            .blockchain_ref()
+            .is_cross_shard_call(&self.input_ref().to, &address)
+        {
+            std::panic::panic_any(TxPanic {
+                status: 10,
+                message: "storage load from address: account not in the same shard".to_string(),
+            });
+        }
+
+        let key_bytes = self.mb_get(key_handle);
+        self.record_coverage(&address, |coverage, contract_code| {
+            coverage.record_storage_read(contract_code, key_bytes.as_slice())
+        });
+        let value = self.with_account(&address, |account| {
+            account
+                .storage
+                .get(key_bytes.as_slice())
+                .cloned()
+                .unwrap_or_default()
+        });
+        self.use_storage_load_gas(value.len());
+        self.mb_set(dest, value.to_vec());
     }
 }
 
@@ -88,6 +114,13 @@ impl StorageWriteApiImpl for DebugApi {
             });
         }
 
+        self.use_gas(|gas_schedule| {
+            gas_schedule.storage_store + gas_schedule.storage_store_per_byte * value.len() as u64
+        });
+
+        self.record_coverage(&self.input_ref().to, |coverage, contract_code| {
+            coverage.record_storage_write(contract_code, key)
+        });
         self.with_contract_account_mut(|account| {
             account.storage.insert(key.to_vec(), value.to_vec());
         });
@@ -102,13 +135,13 @@ impl StorageWriteApiImpl for DebugApi {
         key_handle: Self::ManagedBufferHandle,
         value_handle: Self::ManagedBufferHandle,
     ) {
-        let key_bytes = self.mb_to_boxed_bytes(key_handle);
-        let value_bytes = self.mb_to_boxed_bytes(value_handle);
+        let key_bytes = self.mb_get(key_handle);
+        let value_bytes = self.mb_get(value_handle);
         self.storage_store_slice_u8(key_bytes.as_slice(), value_bytes.as_slice());
     }
 
     fn storage_store_managed_buffer_clear(&self, key_handle: Self::ManagedBufferHandle) {
-        let key_bytes = self.mb_to_boxed_bytes(key_handle);
+        let key_bytes = self.mb_get(key_handle);
         self.storage_store_slice_u8(key_bytes.as_slice(), &[]);
     }
 }
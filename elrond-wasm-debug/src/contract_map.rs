@@ -3,13 +3,19 @@ use crate::DebugApi;
 use super::*;
 
 use alloc::{boxed::Box, vec::Vec};
-use elrond_wasm::contract_base::CallableContract;
-use std::{collections::HashMap, fmt};
+use elrond_wasm::{abi::ContractAbi, contract_base::CallableContract};
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+};
 
 pub type ContractCallFactory = Box<dyn Fn(DebugApi) -> Box<dyn CallableContract>>;
 
 pub struct ContractMap {
     contract_objs: HashMap<Vec<u8>, Box<dyn CallableContract>>,
+    contract_paths: HashMap<Vec<u8>, PathBuf>,
+    contract_abis: HashMap<Vec<u8>, ContractAbi>,
 }
 
 impl fmt::Debug for ContractMap {
@@ -22,6 +28,8 @@ impl ContractMap {
     pub fn new() -> Self {
         ContractMap {
             contract_objs: HashMap::new(),
+            contract_paths: HashMap::new(),
+            contract_abis: HashMap::new(),
         }
     }
 
@@ -49,6 +57,29 @@ impl ContractMap {
     pub fn contains_contract(&self, contract_bytes: &[u8]) -> bool {
         self.contract_objs.contains_key(contract_bytes)
     }
+
+    /// Remembers where the contract code was loaded from, so the state can later be exported with the same code.
+    pub fn register_contract_path(&mut self, contract_bytes: Vec<u8>, path: PathBuf) {
+        self.contract_paths.insert(contract_bytes, path);
+    }
+
+    pub fn contract_path(&self, contract_bytes: &[u8]) -> Option<&Path> {
+        self.contract_paths
+            .get(contract_bytes)
+            .map(PathBuf::as_path)
+    }
+
+    pub fn register_contract_abi(&mut self, contract_bytes: Vec<u8>, abi: ContractAbi) {
+        self.contract_abis.insert(contract_bytes, abi);
+    }
+
+    pub fn contract_abi(&self, contract_bytes: &[u8]) -> Option<&ContractAbi> {
+        self.contract_abis.get(contract_bytes)
+    }
+
+    pub fn contract_abis(&self) -> &HashMap<Vec<u8>, ContractAbi> {
+        &self.contract_abis
+    }
 }
 
 fn unknown_contract_panic(contract_identifier: &[u8]) -> ! {
@@ -62,6 +93,22 @@ fn unknown_contract_panic(contract_identifier: &[u8]) -> ! {
     }
 }
 
+impl Clone for ContractMap {
+    fn clone(&self) -> Self {
+        ContractMap {
+            contract_objs: self
+                .contract_objs
+                .iter()
+                .map(|(contract_bytes, contract_obj)| {
+                    (contract_bytes.clone(), contract_obj.clone_obj())
+                })
+                .collect(),
+            contract_paths: self.contract_paths.clone(),
+            contract_abis: self.contract_abis.clone(),
+        }
+    }
+}
+
 impl Default for ContractMap {
     fn default() -> Self {
         Self::new()
@@ -8,6 +8,7 @@ mod contract_map;
 mod display_util;
 mod managed_test_util;
 pub mod mandos_system;
+pub mod merkle_tree;
 pub mod meta;
 pub mod testing_framework;
 pub mod tx_execution;
@@ -17,10 +18,13 @@ pub mod world_mock;
 pub use contract_map::*;
 pub use display_util::*;
 pub use managed_test_util::*;
-pub use mandos_system::{executor::*, mandos_go, mandos_rs};
+pub use mandos_system::{
+    executor::*, mandos_go, mandos_rs, mandos_rs_dir, mandos_rs_dir_filtered, mandos_rs_filtered,
+    mandos_rs_mut, MandosStepFilter,
+};
 
 pub use tx_mock::DebugApi;
-pub use world_mock::BlockchainMock;
+pub use world_mock::{BlockchainMock, GasSchedule};
 
 // Re-exporting the whole mandos crate for easier use in tests.
 pub use mandos;
@@ -7,7 +7,7 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_gas, check_tx_output};
 
 impl BlockchainMock {
     /// Adds a mandos SC call step, as specified in the `sc_call_step` argument, then executes it.
@@ -71,6 +71,7 @@ pub(crate) fn execute(
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
         tx_hash: generate_tx_hash_dummy(&sc_call_step.id),
+        promise_callback_closure_data: Vec::new(),
     };
 
     // nonce gets increased irrespective of whether the tx fails or not
@@ -86,6 +87,14 @@ fn execute_and_check(
     let (tx_result, state) = execute(state, sc_call_step);
     if let Some(tx_expect) = &sc_call_step.expect {
         check_tx_output(&sc_call_step.id, tx_expect, &tx_result);
+        if state.is_gas_metering_enabled() {
+            check_tx_gas(
+                &sc_call_step.id,
+                tx_expect,
+                sc_call_step.tx.gas_limit.value,
+                &tx_result,
+            );
+        }
     }
     (tx_result, state)
 }
@@ -10,7 +10,7 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_gas, check_tx_output};
 
 impl BlockchainMock {
     /// Adds a mandos SC deploy step, as specified in the `sc_deploy_step` argument, then executes it.
@@ -85,6 +85,7 @@ pub(crate) fn execute(
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
         tx_hash: generate_tx_hash_dummy(&sc_deploy_step.id),
+        promise_callback_closure_data: Vec::new(),
     };
     sc_create(tx_input, &tx.contract_code.value, state)
 }
@@ -96,6 +97,14 @@ fn execute_and_check(
     let (tx_result, address, state) = execute(state, sc_deploy_step);
     if let Some(tx_expect) = &sc_deploy_step.expect {
         check_tx_output(&sc_deploy_step.id, tx_expect, &tx_result);
+        if state.is_gas_metering_enabled() {
+            check_tx_gas(
+                &sc_deploy_step.id,
+                tx_expect,
+                sc_deploy_step.tx.gas_limit.value,
+                &tx_result,
+            );
+        }
     }
     (tx_result, address, state)
 }
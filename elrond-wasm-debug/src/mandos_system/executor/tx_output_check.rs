@@ -72,3 +72,15 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         },
     }
 }
+
+/// Gas is only checked when gas metering is enabled, otherwise the gas used is not known.
+pub fn check_tx_gas(tx_id: &str, tx_expect: &TxExpect, gas_limit: u64, tx_result: &TxResult) {
+    let gas_remaining = gas_limit.saturating_sub(tx_result.result_gas_used);
+    assert!(
+        tx_expect.gas.check(gas_remaining),
+        "gas remaining mismatch. Tx id: '{}'. Want: {}. Have: {}",
+        tx_id,
+        tx_expect.gas,
+        gas_remaining,
+    );
+}
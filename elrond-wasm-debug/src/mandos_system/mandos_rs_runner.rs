@@ -3,27 +3,199 @@
 use crate::world_mock::BlockchainMock;
 
 use crate::mandos_system::model::Step;
-use std::path::Path;
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+const SCENARIO_FILE_SUFFIX: &str = ".scen.json";
+
+/// Selects which steps of a scenario get executed by `mandos_rs_filtered`.
+///
+/// The default filter runs everything.
+#[derive(Clone, Debug, Default)]
+pub struct MandosStepFilter {
+    tags: Vec<String>,
+    stop_after_step_id: Option<String>,
+}
+
+impl MandosStepFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only runs the steps having at least one of the selected tags.
+    ///
+    /// `setState` steps always run, whatever their tags, since the selected steps depend on them.
+    /// `checkState` steps, like all the others, only run if they have one of the selected tags.
+    ///
+    /// Tagged `externalSteps` steps run all the steps in the referenced file,
+    /// untagged ones are still loaded, and their steps filtered.
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Stops the scenario after the step with the given id, whether it was selected or not.
+    pub fn stop_after(mut self, step_id: &str) -> Self {
+        self.stop_after_step_id = Some(step_id.to_string());
+        self
+    }
+
+    fn selects_all(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    fn selects(&self, step: &Step) -> bool {
+        if self.selects_all() || matches!(step, Step::SetState(_)) {
+            return true;
+        }
+        step.tags().iter().any(|tag| self.tags.contains(tag))
+    }
+
+    fn without_tags(&self) -> Self {
+        MandosStepFilter {
+            tags: Vec::new(),
+            stop_after_step_id: self.stop_after_step_id.clone(),
+        }
+    }
+
+    fn is_last_step(&self, step: &Step) -> bool {
+        self.stop_after_step_id.is_some() && step.id() == self.stop_after_step_id.as_deref()
+    }
+}
 
 /// Runs mandos test using the Rust infrastructure and the debug mode.
 /// Uses a contract map to replace the references to the wasm bytecode
 /// with the contracts running in debug mode.
-pub fn mandos_rs<P: AsRef<Path>>(relative_path: P, mut world: BlockchainMock) {
+pub fn mandos_rs<P: AsRef<Path>>(relative_path: P, world: BlockchainMock) {
+    mandos_rs_filtered(relative_path, world, &MandosStepFilter::default());
+}
+
+/// Same as `mandos_rs`, but only borrows the world, so it can still be used afterwards,
+/// for instance to write the coverage reports.
+pub fn mandos_rs_mut<P: AsRef<Path>>(relative_path: P, world: &mut BlockchainMock) {
+    let absolute_path = world.current_dir.join(relative_path);
+    parse_execute_mandos_steps(absolute_path.as_ref(), world, &MandosStepFilter::default());
+}
+
+/// Same as `mandos_rs`, but only runs the steps selected by the filter.
+pub fn mandos_rs_filtered<P: AsRef<Path>>(
+    relative_path: P,
+    mut world: BlockchainMock,
+    filter: &MandosStepFilter,
+) {
     let mut absolute_path = world.current_dir.clone();
     absolute_path.push(relative_path);
-    parse_execute_mandos_steps(absolute_path.as_ref(), &mut world);
+    parse_execute_mandos_steps(absolute_path.as_ref(), &mut world, filter);
+}
+
+/// Runs all the `*.scen.json` files in a directory and its subdirectories, in parallel.
+///
+/// The scenarios are independent, each of them gets a fresh world from the builder.
+/// All scenarios are run, the function panics at the end if any of them failed.
+/// It also panics if there are no scenarios to run, which usually means a wrong path.
+pub fn mandos_rs_dir<P: AsRef<Path>>(relative_dir: P, world_builder: fn() -> BlockchainMock) {
+    mandos_rs_dir_filtered(relative_dir, world_builder, &MandosStepFilter::default());
 }
 
-fn parse_execute_mandos_steps(steps_path: &Path, state: &mut BlockchainMock) {
+/// Same as `mandos_rs_dir`, but only runs the steps selected by the filter.
+pub fn mandos_rs_dir_filtered<P: AsRef<Path>>(
+    relative_dir: P,
+    world_builder: fn() -> BlockchainMock,
+    filter: &MandosStepFilter,
+) {
+    let dir_path = world_builder().current_dir.join(relative_dir);
+    let mut scenario_paths = Vec::new();
+    collect_scenario_paths(&dir_path, &mut scenario_paths);
+    assert!(
+        !scenario_paths.is_empty(),
+        "no {} files found in {:?}",
+        SCENARIO_FILE_SUFFIX,
+        dir_path
+    );
+    scenario_paths.sort();
+
+    let num_threads = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(scenario_paths.len());
+    let next_index = AtomicUsize::new(0);
+    let failed_paths = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..num_threads {
+            scope.spawn(|| loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                let scenario_path = match scenario_paths.get(index) {
+                    Some(scenario_path) => scenario_path,
+                    None => break,
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    let mut world = world_builder();
+                    parse_execute_mandos_steps(scenario_path, &mut world, filter);
+                }));
+                if result.is_err() {
+                    failed_paths.lock().unwrap().push(scenario_path.clone());
+                }
+            });
+        }
+    });
+
+    let mut failed_paths = failed_paths.into_inner().unwrap();
+    if !failed_paths.is_empty() {
+        failed_paths.sort();
+        panic!(
+            "{} out of {} scenarios failed: {:#?}",
+            failed_paths.len(),
+            scenario_paths.len(),
+            failed_paths
+        );
+    }
+}
+
+fn collect_scenario_paths(dir_path: &Path, scenario_paths: &mut Vec<PathBuf>) {
+    let read_dir = std::fs::read_dir(dir_path)
+        .unwrap_or_else(|err| panic!("cannot read directory {:?}: {}", dir_path, err));
+    for entry in read_dir {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_scenario_paths(&path, scenario_paths);
+        } else if path.to_string_lossy().ends_with(SCENARIO_FILE_SUFFIX) {
+            scenario_paths.push(path);
+        }
+    }
+}
+
+/// Returns true if the filter signalled that execution should stop.
+fn parse_execute_mandos_steps(
+    steps_path: &Path,
+    state: &mut BlockchainMock,
+    filter: &MandosStepFilter,
+) -> bool {
     let scenario = crate::mandos_system::parse_scenario(steps_path);
 
     for step in scenario.steps.into_iter() {
+        let is_selected = filter.selects(&step);
+        let is_last_step = filter.is_last_step(&step);
         match step {
             Step::ExternalSteps(external_steps_step) => {
                 let parent_path = steps_path.parent().unwrap();
                 let new_path = parent_path.join(&external_steps_step.path);
-                parse_execute_mandos_steps(new_path.as_path(), state);
+                let external_filter = if is_selected {
+                    filter.without_tags()
+                } else {
+                    filter.clone()
+                };
+                if parse_execute_mandos_steps(new_path.as_path(), state, &external_filter) {
+                    return true;
+                }
             },
+            _ if !is_selected => {},
             Step::SetState(set_state_step) => {
                 state.mandos_set_state(set_state_step);
             },
@@ -49,5 +221,11 @@ fn parse_execute_mandos_steps(steps_path: &Path, state: &mut BlockchainMock) {
                 state.mandos_dump_state();
             },
         }
+
+        if is_last_step {
+            return true;
+        }
     }
+
+    false
 }
@@ -5,5 +5,8 @@ pub mod model;
 mod parse_util;
 
 pub use mandos_go_runner::mandos_go;
-pub use mandos_rs_runner::mandos_rs;
+pub use mandos_rs_runner::{
+    mandos_rs, mandos_rs_dir, mandos_rs_dir_filtered, mandos_rs_filtered, mandos_rs_mut,
+    MandosStepFilter,
+};
 pub use parse_util::{parse_scenario, parse_scenario_raw};
@@ -2,6 +2,7 @@ use crate::mandos_system::model::{AddressKey, CheckAccount, CheckAccounts};
 
 #[derive(Debug, Default)]
 pub struct CheckStateStep {
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub accounts: CheckAccounts,
 }
@@ -1,4 +1,5 @@
 #[derive(Debug, Default)]
 pub struct DumpStateStep {
+    pub tags: Vec<String>,
     pub comment: Option<String>,
 }
@@ -15,6 +15,7 @@ use super::{
 
 #[derive(Debug)]
 pub struct ExternalStepsStep {
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub path: String,
 }
@@ -32,13 +33,48 @@ pub enum Step {
     DumpState(DumpStateStep),
 }
 
+impl Step {
+    /// Only transaction steps have ids.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Step::ScCall(s) => Some(s.id.as_str()),
+            Step::ScQuery(s) => Some(s.id.as_str()),
+            Step::ScDeploy(s) => Some(s.id.as_str()),
+            Step::Transfer(s) => Some(s.id.as_str()),
+            Step::ValidatorReward(s) => Some(s.id.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn tags(&self) -> &[String] {
+        match self {
+            Step::ExternalSteps(s) => &s.tags,
+            Step::SetState(s) => &s.tags,
+            Step::ScCall(s) => &s.tags,
+            Step::ScQuery(s) => &s.tags,
+            Step::ScDeploy(s) => &s.tags,
+            Step::Transfer(s) => &s.tags,
+            Step::ValidatorReward(s) => &s.tags,
+            Step::CheckState(s) => &s.tags,
+            Step::DumpState(s) => &s.tags,
+        }
+    }
+}
+
 impl InterpretableFrom<StepRaw> for Step {
     fn interpret_from(from: StepRaw, context: &InterpreterContext) -> Self {
         match from {
-            StepRaw::ExternalSteps { comment, path } => {
-                Step::ExternalSteps(ExternalStepsStep { comment, path })
-            },
+            StepRaw::ExternalSteps {
+                tags,
+                comment,
+                path,
+            } => Step::ExternalSteps(ExternalStepsStep {
+                tags,
+                comment,
+                path,
+            }),
             StepRaw::SetState {
+                tags,
                 comment,
                 accounts,
                 new_addresses,
@@ -46,6 +82,7 @@ impl InterpretableFrom<StepRaw> for Step {
                 previous_block_info,
                 current_block_info,
             } => Step::SetState(SetStateStep {
+                tags,
                 comment,
                 accounts: accounts
                     .into_iter()
@@ -74,6 +111,7 @@ impl InterpretableFrom<StepRaw> for Step {
             StepRaw::ScCall {
                 id,
                 tx_id,
+                tags,
                 comment,
                 display_logs: _,
                 tx,
@@ -81,6 +119,7 @@ impl InterpretableFrom<StepRaw> for Step {
             } => Step::ScCall(ScCallStep {
                 id,
                 tx_id,
+                tags,
                 comment,
                 tx: Box::new(TxCall::interpret_from(tx, context)),
                 expect: expect.map(|v| TxExpect::interpret_from(v, context)),
@@ -88,6 +127,7 @@ impl InterpretableFrom<StepRaw> for Step {
             StepRaw::ScQuery {
                 id,
                 tx_id,
+                tags,
                 comment,
                 display_logs: _,
                 tx,
@@ -95,6 +135,7 @@ impl InterpretableFrom<StepRaw> for Step {
             } => Step::ScQuery(ScQueryStep {
                 id,
                 tx_id,
+                tags,
                 comment,
                 tx: Box::new(TxQuery::interpret_from(tx, context)),
                 expect: expect.map(|v| TxExpect::interpret_from(v, context)),
@@ -102,6 +143,7 @@ impl InterpretableFrom<StepRaw> for Step {
             StepRaw::ScDeploy {
                 id,
                 tx_id,
+                tags,
                 comment,
                 display_logs: _,
                 tx,
@@ -109,6 +151,7 @@ impl InterpretableFrom<StepRaw> for Step {
             } => Step::ScDeploy(ScDeployStep {
                 id,
                 tx_id,
+                tags,
                 comment,
                 tx: Box::new(TxDeploy::interpret_from(tx, context)),
                 expect: expect.map(|v| TxExpect::interpret_from(v, context)),
@@ -116,30 +159,41 @@ impl InterpretableFrom<StepRaw> for Step {
             StepRaw::Transfer {
                 id,
                 tx_id,
+                tags,
                 comment,
                 tx,
             } => Step::Transfer(TransferStep {
                 id,
                 tx_id,
+                tags,
                 comment,
                 tx: Box::new(TxTransfer::interpret_from(tx, context)),
             }),
             StepRaw::ValidatorReward {
                 id,
                 tx_id,
+                tags,
                 comment,
                 tx,
             } => Step::ValidatorReward(ValidatorRewardStep {
                 id,
                 tx_id,
+                tags,
                 comment,
                 tx: Box::new(TxValidatorReward::interpret_from(tx, context)),
             }),
-            StepRaw::CheckState { comment, accounts } => Step::CheckState(CheckStateStep {
+            StepRaw::CheckState {
+                tags,
+                comment,
+                accounts,
+            } => Step::CheckState(CheckStateStep {
+                tags,
                 comment,
                 accounts: CheckAccounts::interpret_from(accounts, context),
             }),
-            StepRaw::DumpState { comment } => Step::DumpState(DumpStateStep { comment }),
+            StepRaw::DumpState { tags, comment } => {
+                Step::DumpState(DumpStateStep { tags, comment })
+            },
         }
     }
 }
@@ -148,10 +202,12 @@ impl IntoRaw<StepRaw> for Step {
     fn into_raw(self) -> StepRaw {
         match self {
             Step::ExternalSteps(s) => StepRaw::ExternalSteps {
+                tags: s.tags,
                 comment: s.comment,
                 path: s.path,
             },
             Step::SetState(s) => StepRaw::SetState {
+                tags: s.tags,
                 comment: s.comment,
                 accounts: s
                     .accounts
@@ -170,6 +226,7 @@ impl IntoRaw<StepRaw> for Step {
             Step::ScCall(s) => StepRaw::ScCall {
                 id: s.id,
                 tx_id: s.tx_id,
+                tags: s.tags,
                 comment: s.comment,
                 display_logs: None,
                 tx: s.tx.into_raw(),
@@ -178,6 +235,7 @@ impl IntoRaw<StepRaw> for Step {
             Step::ScQuery(s) => StepRaw::ScQuery {
                 id: s.id,
                 tx_id: s.tx_id,
+                tags: s.tags,
                 comment: s.comment,
                 display_logs: None,
                 tx: s.tx.into_raw(),
@@ -186,6 +244,7 @@ impl IntoRaw<StepRaw> for Step {
             Step::ScDeploy(s) => StepRaw::ScDeploy {
                 id: s.id,
                 tx_id: s.tx_id,
+                tags: s.tags,
                 comment: s.comment,
                 display_logs: None,
                 tx: s.tx.into_raw(),
@@ -194,20 +253,26 @@ impl IntoRaw<StepRaw> for Step {
             Step::Transfer(s) => StepRaw::Transfer {
                 id: s.id,
                 tx_id: s.tx_id,
+                tags: s.tags,
                 comment: s.comment,
                 tx: s.tx.into_raw(),
             },
             Step::ValidatorReward(s) => StepRaw::ValidatorReward {
                 id: s.id,
                 tx_id: s.tx_id,
+                tags: s.tags,
                 comment: s.comment,
                 tx: s.tx.into_raw(),
             },
             Step::CheckState(s) => StepRaw::CheckState {
+                tags: s.tags,
                 comment: s.comment,
                 accounts: s.accounts.into_raw(),
             },
-            Step::DumpState(s) => StepRaw::DumpState { comment: s.comment },
+            Step::DumpState(s) => StepRaw::DumpState {
+                tags: s.tags,
+                comment: s.comment,
+            },
         }
     }
 }
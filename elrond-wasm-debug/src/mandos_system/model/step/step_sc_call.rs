@@ -14,6 +14,7 @@ use elrond_wasm::{
 pub struct ScCallStep {
     pub id: String,
     pub tx_id: Option<String>,
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub tx: Box<TxCall>,
     pub expect: Option<TxExpect>,
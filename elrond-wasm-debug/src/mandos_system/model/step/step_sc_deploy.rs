@@ -13,6 +13,7 @@ use super::convert_call_args;
 pub struct ScDeployStep {
     pub id: String,
     pub tx_id: Option<String>,
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub tx: Box<TxDeploy>,
     pub expect: Option<TxExpect>,
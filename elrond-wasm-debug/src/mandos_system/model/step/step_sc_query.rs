@@ -14,6 +14,7 @@ use super::{format_expect, process_contract_call};
 pub struct ScQueryStep {
     pub id: String,
     pub tx_id: Option<String>,
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub tx: Box<TxQuery>,
     pub expect: Option<TxExpect>,
@@ -6,6 +6,7 @@ use crate::mandos_system::model::{
 
 #[derive(Debug, Default)]
 pub struct SetStateStep {
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub accounts: BTreeMap<AddressKey, Account>,
     pub new_addresses: Vec<NewAddress>,
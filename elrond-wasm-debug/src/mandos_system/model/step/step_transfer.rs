@@ -6,6 +6,7 @@ use crate::mandos_system::model::{
 pub struct TransferStep {
     pub id: String,
     pub tx_id: Option<String>,
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub tx: Box<TxTransfer>,
 }
@@ -14,6 +15,7 @@ pub struct TransferStep {
 pub struct ValidatorRewardStep {
     pub id: String,
     pub tx_id: Option<String>,
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub tx: Box<TxValidatorReward>,
 }
@@ -13,6 +13,7 @@ use super::ScCallStep;
 pub struct TypedScCall<OriginalResult> {
     pub id: String,
     pub tx_id: Option<String>,
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub tx: Box<TxCall>,
     pub expect: Option<TxExpect>,
@@ -24,6 +25,7 @@ impl<OriginalResult> Default for TypedScCall<OriginalResult> {
         Self {
             id: Default::default(),
             tx_id: Default::default(),
+            tags: Default::default(),
             comment: Default::default(),
             tx: Default::default(),
             expect: Default::default(),
@@ -37,6 +39,7 @@ impl<OriginalResult> From<TypedScCall<OriginalResult>> for ScCallStep {
         ScCallStep {
             id: typed.id,
             tx_id: typed.tx_id,
+            tags: typed.tags,
             comment: typed.comment,
             tx: typed.tx,
             expect: typed.expect,
@@ -49,6 +52,7 @@ impl<OriginalResult> From<ScCallStep> for TypedScCall<OriginalResult> {
         Self {
             id: untyped.id,
             tx_id: untyped.tx_id,
+            tags: untyped.tags,
             comment: untyped.comment,
             tx: untyped.tx,
             expect: untyped.expect,
@@ -17,6 +17,7 @@ use super::ScDeployStep;
 pub struct TypedScDeploy<OriginalResult> {
     pub id: String,
     pub tx_id: Option<String>,
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub tx: Box<TxDeploy>,
     pub expect: Option<TxExpect>,
@@ -28,6 +29,7 @@ impl<OriginalResult> Default for TypedScDeploy<OriginalResult> {
         Self {
             id: Default::default(),
             tx_id: Default::default(),
+            tags: Default::default(),
             comment: Default::default(),
             tx: Default::default(),
             expect: Default::default(),
@@ -41,6 +43,7 @@ impl<OriginalResult> From<TypedScDeploy<OriginalResult>> for ScDeployStep {
         Self {
             id: typed.id,
             tx_id: typed.tx_id,
+            tags: typed.tags,
             comment: typed.comment,
             tx: typed.tx,
             expect: typed.expect,
@@ -53,6 +56,7 @@ impl<OriginalResult> From<ScDeployStep> for TypedScDeploy<OriginalResult> {
         Self {
             id: untyped.id,
             tx_id: untyped.tx_id,
+            tags: untyped.tags,
             comment: untyped.comment,
             tx: untyped.tx,
             expect: untyped.expect,
@@ -10,6 +10,7 @@ use super::ScQueryStep;
 pub struct TypedScQuery<OriginalResult> {
     pub id: String,
     pub tx_id: Option<String>,
+    pub tags: Vec<String>,
     pub comment: Option<String>,
     pub tx: Box<TxQuery>,
     pub expect: Option<TxExpect>,
@@ -21,6 +22,7 @@ impl<OriginalResult> Default for TypedScQuery<OriginalResult> {
         Self {
             id: Default::default(),
             tx_id: Default::default(),
+            tags: Default::default(),
             comment: Default::default(),
             tx: Default::default(),
             expect: Default::default(),
@@ -34,6 +36,7 @@ impl<OriginalResult> From<TypedScQuery<OriginalResult>> for ScQueryStep {
         Self {
             id: typed.id,
             tx_id: typed.tx_id,
+            tags: typed.tags,
             comment: typed.comment,
             tx: typed.tx,
             expect: typed.expect,
@@ -46,6 +49,7 @@ impl<OriginalResult> From<ScQueryStep> for TypedScQuery<OriginalResult> {
         Self {
             id: untyped.id,
             tx_id: untyped.tx_id,
+            tags: untyped.tags,
             comment: untyped.comment,
             tx: untyped.tx,
             expect: untyped.expect,
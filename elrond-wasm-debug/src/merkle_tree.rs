@@ -0,0 +1,121 @@
+use sha2::{Digest, Sha256};
+
+pub const MERKLE_HASH_LEN: usize = 32;
+
+pub type MerkleHash = [u8; MERKLE_HASH_LEN];
+
+/// Off-chain Merkle tree, with the same layout and hashing as the `MerkleTreeMapper`:
+/// nodes are the SHA-256 of their concatenated children,
+/// the depth is the smallest one that fits all the leaves,
+/// and the missing leaves on the right are 32 zero bytes.
+///
+/// Useful for building roots and proofs in tests.
+#[derive(Clone, Debug, Default)]
+pub struct MerkleTree {
+    leaves: Vec<MerkleHash>,
+}
+
+impl MerkleTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_leaves(leaves: Vec<MerkleHash>) -> Self {
+        MerkleTree { leaves }
+    }
+
+    /// Adds a leaf, returns its index.
+    pub fn append(&mut self, leaf: MerkleHash) -> usize {
+        self.leaves.push(leaf);
+        self.leaves.len() - 1
+    }
+
+    pub fn leaves(&self) -> &[MerkleHash] {
+        self.leaves.as_slice()
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// The number of levels below the root, which is also the length of the proofs.
+    pub fn depth(&self) -> usize {
+        self.leaves.len().next_power_of_two().trailing_zeros() as usize
+    }
+
+    /// All the levels of the tree, from the leaves up to the root.
+    /// Levels with an odd number of nodes get completed with the root of an empty subtree.
+    fn levels(&self) -> Vec<Vec<MerkleHash>> {
+        let mut zero_subtree_root = [0u8; MERKLE_HASH_LEN];
+        let mut level = self.leaves.clone();
+        let mut levels = Vec::new();
+        for _ in 0..self.depth() {
+            if level.len() % 2 == 1 {
+                level.push(zero_subtree_root);
+            }
+            let parent_level = level
+                .chunks(2)
+                .map(|pair| merkle_node_hash(&pair[0], &pair[1]))
+                .collect();
+            levels.push(level);
+            level = parent_level;
+            zero_subtree_root = merkle_node_hash(&zero_subtree_root, &zero_subtree_root);
+        }
+        levels.push(level);
+        levels
+    }
+
+    pub fn root(&self) -> MerkleHash {
+        self.levels()
+            .last()
+            .and_then(|root_level| root_level.first().cloned())
+            .unwrap_or([0u8; MERKLE_HASH_LEN])
+    }
+
+    /// The sibling hashes of the leaf, from the leaf level up to just below the root.
+    pub fn proof(&self, leaf_index: usize) -> Vec<MerkleHash> {
+        assert!(
+            leaf_index < self.leaves.len(),
+            "Merkle tree leaf index out of range"
+        );
+        let levels = self.levels();
+        let mut index = leaf_index;
+        let mut proof = Vec::new();
+        for level in &levels[..levels.len() - 1] {
+            proof.push(level[index ^ 1]);
+            index >>= 1;
+        }
+        proof
+    }
+}
+
+pub fn merkle_node_hash(left: &MerkleHash, right: &MerkleHash) -> MerkleHash {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Same check as `verify_merkle_proof` in `elrond-wasm`.
+pub fn verify_merkle_proof(
+    root: &MerkleHash,
+    leaf: &MerkleHash,
+    leaf_index: usize,
+    depth: usize,
+    proof: &[MerkleHash],
+) -> bool {
+    if proof.len() != depth {
+        return false;
+    }
+    let mut node = *leaf;
+    let mut index = leaf_index;
+    for sibling in proof {
+        node = if index & 1 == 0 {
+            merkle_node_hash(&node, sibling)
+        } else {
+            merkle_node_hash(sibling, &node)
+        };
+        index >>= 1;
+    }
+    index == 0 && node == *root
+}
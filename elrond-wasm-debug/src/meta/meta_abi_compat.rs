@@ -0,0 +1,459 @@
+use std::{fs, process};
+
+use crate::abi_json::{
+    deserialize_abi_from_json, ContractAbiJson, EndpointAbiJson, EndpointMutabilityAbiJson,
+    EventAbiJson, InputAbiJson, OutputAbiJson, StorageAbiJson, StructFieldDescriptionJson,
+    TypeDescriptionJson,
+};
+
+use super::meta_config::MetaConfig;
+
+/// Wildcard in `payableInTokens`, for endpoints that accept any token.
+const PAYABLE_ANY_TOKEN: &str = "*";
+
+fn is_optional_input(input: &InputAbiJson) -> bool {
+    input.multi_arg.unwrap_or(false)
+        || input.type_name.starts_with("optional<")
+        || input.type_name.starts_with("variadic<")
+}
+
+fn input_signature(inputs: &[InputAbiJson]) -> Vec<&str> {
+    inputs
+        .iter()
+        .map(|input| input.type_name.as_str())
+        .collect()
+}
+
+fn output_signature(outputs: &[OutputAbiJson]) -> Vec<&str> {
+    outputs
+        .iter()
+        .map(|output| output.type_name.as_str())
+        .collect()
+}
+
+fn mutability_name(mutability: &EndpointMutabilityAbiJson) -> &'static str {
+    match mutability {
+        EndpointMutabilityAbiJson::Mutable => "mutable",
+        EndpointMutabilityAbiJson::Readonly => "readonly",
+        EndpointMutabilityAbiJson::Pure => "pure",
+    }
+}
+
+fn accepts_token(payable_in_tokens: &[String], token: &str) -> bool {
+    payable_in_tokens
+        .iter()
+        .any(|accepted| accepted == PAYABLE_ANY_TOKEN || accepted == token)
+}
+
+/// `subject` is the endpoint or the constructor, as shown in the issues.
+fn check_inputs(
+    subject: &str,
+    old_inputs: &[InputAbiJson],
+    new_inputs: &[InputAbiJson],
+    issues: &mut Vec<String>,
+) {
+    for (index, old_input) in old_inputs.iter().enumerate() {
+        match new_inputs.get(index) {
+            Some(new_input) => {
+                if new_input.type_name != old_input.type_name
+                    || new_input.multi_arg != old_input.multi_arg
+                {
+                    issues.push(format!(
+                        "{}: argument `{}` changed type from `{}` to `{}`",
+                        subject, old_input.arg_name, old_input.type_name, new_input.type_name
+                    ));
+                }
+            },
+            None => issues.push(format!(
+                "{}: argument `{}` removed",
+                subject, old_input.arg_name
+            )),
+        }
+    }
+    for new_input in new_inputs.iter().skip(old_inputs.len()) {
+        if !is_optional_input(new_input) {
+            issues.push(format!(
+                "{}: required argument `{}` added",
+                subject, new_input.arg_name
+            ));
+        }
+    }
+}
+
+fn check_payable_tokens(
+    subject: &str,
+    old_tokens: &[String],
+    new_tokens: &[String],
+    issues: &mut Vec<String>,
+) {
+    for old_token in old_tokens {
+        if !accepts_token(new_tokens, old_token) {
+            issues.push(format!("{}: no longer payable in `{}`", subject, old_token));
+        }
+    }
+}
+
+/// Upgrades call the constructor again, so its arguments must stay compatible too.
+fn check_constructor(
+    old_abi: &ContractAbiJson,
+    new_abi: &ContractAbiJson,
+    issues: &mut Vec<String>,
+) {
+    let (old, new) = match (&old_abi.constructor, &new_abi.constructor) {
+        (Some(old), Some(new)) => (old, new),
+        (Some(_), None) => {
+            issues.push("constructor removed".to_string());
+            return;
+        },
+        (None, _) => return,
+    };
+    check_inputs(
+        "constructor",
+        old.inputs.as_slice(),
+        new.inputs.as_slice(),
+        issues,
+    );
+    check_payable_tokens(
+        "constructor",
+        old.payable_in_tokens.as_slice(),
+        new.payable_in_tokens.as_slice(),
+        issues,
+    );
+}
+
+fn check_endpoint(old: &EndpointAbiJson, new: &EndpointAbiJson, issues: &mut Vec<String>) {
+    let subject = format!("endpoint `{}`", old.name);
+    check_inputs(
+        &subject,
+        old.inputs.as_slice(),
+        new.inputs.as_slice(),
+        issues,
+    );
+
+    let old_outputs = output_signature(old.outputs.as_slice());
+    let new_outputs = output_signature(new.outputs.as_slice());
+    if old_outputs != new_outputs {
+        issues.push(format!(
+            "endpoint `{}`: results changed from `{}` to `{}`",
+            old.name,
+            old_outputs.join(", "),
+            new_outputs.join(", ")
+        ));
+    }
+
+    let became_mutable = matches!(new.mutability, EndpointMutabilityAbiJson::Mutable)
+        && !matches!(old.mutability, EndpointMutabilityAbiJson::Mutable);
+    if became_mutable {
+        issues.push(format!(
+            "endpoint `{}`: changed from {} to mutable",
+            old.name,
+            mutability_name(&old.mutability)
+        ));
+    }
+
+    check_payable_tokens(
+        &subject,
+        old.payable_in_tokens.as_slice(),
+        new.payable_in_tokens.as_slice(),
+        issues,
+    );
+
+    if new.only_owner.unwrap_or(false) && !old.only_owner.unwrap_or(false) {
+        issues.push(format!("endpoint `{}`: became only owner", old.name));
+    }
+    if new.only_admin.unwrap_or(false) && !old.only_admin.unwrap_or(false) {
+        issues.push(format!("endpoint `{}`: became only admin", old.name));
+    }
+}
+
+/// Endpoints that are gone from the new ABI are reported as removed.
+/// If a new endpoint has the exact same signature, it is likely the same one, renamed.
+fn check_endpoints(old_abi: &ContractAbiJson, new_abi: &ContractAbiJson, issues: &mut Vec<String>) {
+    for old_endpoint in &old_abi.endpoints {
+        if let Some(new_endpoint) = new_abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == old_endpoint.name)
+        {
+            check_endpoint(old_endpoint, new_endpoint, issues);
+            continue;
+        }
+
+        let rename_candidate = new_abi.endpoints.iter().find(|new_endpoint| {
+            !old_abi
+                .endpoints
+                .iter()
+                .any(|endpoint| endpoint.name == new_endpoint.name)
+                && input_signature(new_endpoint.inputs.as_slice())
+                    == input_signature(old_endpoint.inputs.as_slice())
+                && output_signature(new_endpoint.outputs.as_slice())
+                    == output_signature(old_endpoint.outputs.as_slice())
+        });
+        if let Some(new_endpoint) = rename_candidate {
+            issues.push(format!(
+                "endpoint `{}` removed, possibly renamed to `{}`",
+                old_endpoint.name, new_endpoint.name
+            ));
+        } else {
+            issues.push(format!("endpoint `{}` removed", old_endpoint.name));
+        }
+    }
+}
+
+fn event_signature(event: &EventAbiJson) -> Vec<String> {
+    event
+        .inputs
+        .iter()
+        .map(|input| {
+            let indexed = if input.indexed.unwrap_or(false) {
+                " (indexed)"
+            } else {
+                ""
+            };
+            format!("{}: {}{}", input.arg_name, input.type_name, indexed)
+        })
+        .collect()
+}
+
+fn check_events(old_abi: &ContractAbiJson, new_abi: &ContractAbiJson, issues: &mut Vec<String>) {
+    for old_event in &old_abi.events {
+        match new_abi
+            .events
+            .iter()
+            .find(|event| event.identifier == old_event.identifier)
+        {
+            Some(new_event) => {
+                let old_signature = event_signature(old_event);
+                let new_signature = event_signature(new_event);
+                if old_signature != new_signature {
+                    issues.push(format!(
+                        "event `{}`: signature changed from `{}` to `{}`",
+                        old_event.identifier,
+                        old_signature.join(", "),
+                        new_signature.join(", ")
+                    ));
+                }
+            },
+            None => issues.push(format!("event `{}` removed", old_event.identifier)),
+        }
+    }
+}
+
+fn fields_signature(fields: &[StructFieldDescriptionJson]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| format!("{}: {}", field.name, field.field_type))
+        .collect()
+}
+
+fn version_str(version: Option<u8>) -> String {
+    version.map_or_else(|| "none".to_string(), |version| version.to_string())
+}
+
+/// Appending fields is fine if older data can still be decoded without them:
+/// either they are `#[codec(default)]`, or they came with a version raise (`#[codec(since = N)]`).
+fn only_compatible_fields_appended(
+    old_fields: &[StructFieldDescriptionJson],
+    new_fields: &[StructFieldDescriptionJson],
+    old_version: Option<u8>,
+) -> bool {
+    if new_fields.len() <= old_fields.len()
+        || fields_signature(old_fields) != fields_signature(&new_fields[..old_fields.len()])
+    {
+        return false;
+    }
+    new_fields[old_fields.len()..].iter().all(|field| {
+        field.default
+            || matches!((field.since, old_version), (Some(since), Some(old_version)) if since > old_version)
+    })
+}
+
+/// New enum variants are fine, as long as the existing ones keep their discriminants and fields.
+fn check_type(
+    type_name: &str,
+    old: &TypeDescriptionJson,
+    new: &TypeDescriptionJson,
+    issues: &mut Vec<String>,
+) {
+    if old.content_type != new.content_type {
+        issues.push(format!(
+            "type `{}`: changed from {} to {}",
+            type_name, old.content_type, new.content_type
+        ));
+        return;
+    }
+
+    // raising the version is fine, older data still decodes
+    let version_ok = match (old.version, new.version) {
+        (Some(old_version), Some(new_version)) => new_version >= old_version,
+        (old_version, new_version) => old_version == new_version,
+    };
+    if !version_ok {
+        issues.push(format!(
+            "struct `{}`: version changed from {} to {}",
+            type_name,
+            version_str(old.version),
+            version_str(new.version)
+        ));
+    }
+
+    let old_fields = fields_signature(old.fields.as_slice());
+    let new_fields = fields_signature(new.fields.as_slice());
+    if old_fields != new_fields
+        && !only_compatible_fields_appended(&old.fields, &new.fields, old.version)
+    {
+        issues.push(format!(
+            "struct `{}`: fields changed from `{}` to `{}`",
+            type_name,
+            old_fields.join(", "),
+            new_fields.join(", ")
+        ));
+    }
+
+    for old_variant in &old.variants {
+        match new
+            .variants
+            .iter()
+            .find(|variant| variant.name == old_variant.name)
+        {
+            Some(new_variant) => {
+                if new_variant.discriminant != old_variant.discriminant {
+                    issues.push(format!(
+                        "enum `{}`: variant `{}` changed discriminant from {} to {}",
+                        type_name,
+                        old_variant.name,
+                        old_variant.discriminant,
+                        new_variant.discriminant
+                    ));
+                }
+                if fields_signature(new_variant.fields.as_slice())
+                    != fields_signature(old_variant.fields.as_slice())
+                    && !only_compatible_fields_appended(
+                        &old_variant.fields,
+                        &new_variant.fields,
+                        old.version,
+                    )
+                {
+                    issues.push(format!(
+                        "enum `{}`: fields of variant `{}` changed",
+                        type_name, old_variant.name
+                    ));
+                }
+            },
+            None => issues.push(format!(
+                "enum `{}`: variant `{}` removed",
+                type_name, old_variant.name
+            )),
+        }
+    }
+}
+
+/// Types that are no longer used at all are not reported.
+fn check_types(old_abi: &ContractAbiJson, new_abi: &ContractAbiJson, issues: &mut Vec<String>) {
+    for (type_name, old_type) in &old_abi.types {
+        if let Some(new_type) = new_abi.types.get(type_name) {
+            check_type(type_name, old_type, new_type, issues);
+        }
+    }
+}
+
+fn storage_key_signature(storage: &StorageAbiJson) -> Vec<&str> {
+    storage
+        .key_args
+        .iter()
+        .map(|key_arg| key_arg.type_name.as_str())
+        .collect()
+}
+
+/// The data already in storage has to stay readable after the upgrade,
+/// so the mapper, the key argument types and the stored types of existing entries cannot change.
+/// Entries that are no longer declared are not reported.
+fn check_storage(old_abi: &ContractAbiJson, new_abi: &ContractAbiJson, issues: &mut Vec<String>) {
+    for old_storage in &old_abi.storage {
+        let new_storage = if let Some(new_storage) = new_abi
+            .storage
+            .iter()
+            .find(|storage| storage.identifier == old_storage.identifier)
+        {
+            new_storage
+        } else {
+            continue;
+        };
+
+        if new_storage.mapper != old_storage.mapper {
+            issues.push(format!(
+                "storage `{}`: mapper changed from `{}` to `{}`",
+                old_storage.identifier, old_storage.mapper, new_storage.mapper
+            ));
+        }
+
+        let old_key_signature = storage_key_signature(old_storage);
+        let new_key_signature = storage_key_signature(new_storage);
+        if old_key_signature != new_key_signature {
+            issues.push(format!(
+                "storage `{}`: key arguments changed from `{}` to `{}`",
+                old_storage.identifier,
+                old_key_signature.join(", "),
+                new_key_signature.join(", ")
+            ));
+        }
+
+        if new_storage.value_types != old_storage.value_types {
+            issues.push(format!(
+                "storage `{}`: stored type changed from `{}` to `{}`",
+                old_storage.identifier,
+                old_storage.value_types.join(", "),
+                new_storage.value_types.join(", ")
+            ));
+        }
+    }
+}
+
+/// Lists the changes from the old ABI that would break existing callers, indexers
+/// or encoded data: removed endpoints and events, changed constructor and endpoint arguments,
+/// changed results and event signatures,
+/// changed struct and enum layouts, new payment or access restrictions,
+/// and changed types of the existing storage entries.
+///
+/// An empty list means the new version can safely replace the old one.
+pub fn abi_breaking_changes(old_abi: &ContractAbiJson, new_abi: &ContractAbiJson) -> Vec<String> {
+    let mut issues = Vec::new();
+    check_constructor(old_abi, new_abi, &mut issues);
+    check_endpoints(old_abi, new_abi, &mut issues);
+    check_events(old_abi, new_abi, &mut issues);
+    check_types(old_abi, new_abi, &mut issues);
+    check_storage(old_abi, new_abi, &mut issues);
+    issues
+}
+
+/// Reads a previously generated ABI file.
+pub fn load_abi_file(abi_path: &str) -> ContractAbiJson {
+    let abi_string = fs::read_to_string(abi_path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", abi_path, err));
+    deserialize_abi_from_json(abi_string.as_str())
+        .unwrap_or_else(|err| panic!("failed to parse {}: {}", abi_path, err))
+}
+
+impl MetaConfig {
+    /// Compares the main contract ABI against a previously generated ABI, loaded from `old_abi_path`.
+    /// Exits with a non-zero code if there are breaking changes.
+    pub fn check_abi_compatibility(&self, old_abi: &ContractAbiJson, old_abi_path: &str) {
+        let main_contract = self
+            .main_contract
+            .as_ref()
+            .expect("no main contract to check");
+        let new_abi = ContractAbiJson::from(&main_contract.abi);
+
+        let issues = abi_breaking_changes(old_abi, &new_abi);
+        if issues.is_empty() {
+            println!("No breaking changes from {}", old_abi_path);
+            return;
+        }
+
+        println!("Breaking changes from {}:", old_abi_path);
+        for issue in &issues {
+            println!("  - {}", issue);
+        }
+        process::exit(1);
+    }
+}
@@ -2,6 +2,11 @@ use std::{fs::create_dir_all, path::PathBuf};
 
 use elrond_wasm::abi::{ContractAbi, EndpointLocationAbi};
 
+use super::{
+    meta_elrond_json::{ElrondJsonSerde, ELROND_JSON_RELATIVE_PATH},
+    meta_multi_contract::{MultiContractConfigSerde, MULTI_CONTRACT_CONFIG_RELATIVE_PATH},
+};
+
 #[derive(Debug)]
 pub struct BuildArgs {
     pub debug_symbols: bool,
@@ -45,6 +50,9 @@ pub struct ContractMetadata {
     pub wasm_crate_path: String,
     pub output_base_name: String,
     pub abi: ContractAbi,
+    /// Fails the build if the contract ends up containing an allocator.
+    /// Configured in `elrond.json` for the main and view contracts, in `multicontract.toml` for the others.
+    pub no_allocator: bool,
 }
 
 impl ContractMetadata {
@@ -79,6 +87,8 @@ pub struct MetaConfig {
     pub snippets_dir: String,
     pub main_contract: Option<ContractMetadata>,
     pub view_contract: Option<ContractMetadata>,
+    /// Configured in `multicontract.toml`.
+    pub secondary_contracts: Vec<ContractMetadata>,
 }
 
 pub fn process_args(args: &[String]) -> BuildArgs {
@@ -123,6 +133,8 @@ impl MetaConfig {
 
         let main_contract_abi = original_contract_abi.main_contract();
         let main_contract_crate_name = main_contract_abi.get_crate_name();
+        let elrond_json =
+            ElrondJsonSerde::load_from_file(ELROND_JSON_RELATIVE_PATH).unwrap_or_default();
 
         let main_contract = ContractMetadata {
             location: EndpointLocationAbi::MainContract,
@@ -130,6 +142,7 @@ impl MetaConfig {
             wasm_crate_path: "../wasm".to_string(),
             output_base_name: main_contract_crate_name.to_string(),
             abi: main_contract_abi.clone(),
+            no_allocator: elrond_json.no_allocator,
         };
 
         let view_contract_opt =
@@ -142,19 +155,55 @@ impl MetaConfig {
                     wasm_crate_path: "../wasm-view".to_string(),
                     output_base_name: format!("{}-view", main_contract_crate_name),
                     abi: view_contract_abi,
+                    no_allocator: elrond_json.no_allocator,
                 })
             } else {
                 None
             };
 
+        let secondary_contracts =
+            MultiContractConfigSerde::load_from_file(MULTI_CONTRACT_CONFIG_RELATIVE_PATH)
+                .unwrap_or_default()
+                .contracts
+                .iter()
+                .map(|(contract_id, output_contract)| {
+                    let contract_abi =
+                        output_contract.contract_abi(contract_id, original_contract_abi);
+                    let location =
+                        if contract_abi.location_exists(EndpointLocationAbi::ViewContract) {
+                            EndpointLocationAbi::ViewContract
+                        } else {
+                            EndpointLocationAbi::MainContract
+                        };
+                    ContractMetadata {
+                        location,
+                        wasm_crate_name: format!("{}-wasm", &main_contract_crate_name),
+                        wasm_crate_path: format!("../wasm-{}", contract_id),
+                        output_base_name: output_contract
+                            .output_base_name(contract_id, main_contract_crate_name),
+                        abi: contract_abi,
+                        no_allocator: output_contract.no_allocator,
+                    }
+                })
+                .collect();
+
         MetaConfig {
             build_args,
             output_dir: "../output".to_string(),
             snippets_dir: "../interact-rs".to_string(),
             main_contract: Some(main_contract),
             view_contract: view_contract_opt,
+            secondary_contracts,
         }
     }
+
+    /// The main contract, the external view contract, and the ones from `multicontract.toml`.
+    pub fn output_contracts(&self) -> impl Iterator<Item = &ContractMetadata> {
+        self.main_contract
+            .iter()
+            .chain(self.view_contract.iter())
+            .chain(self.secondary_contracts.iter())
+    }
 }
 
 impl ContractMetadata {
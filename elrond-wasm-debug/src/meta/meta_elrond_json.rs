@@ -0,0 +1,31 @@
+use std::fs;
+
+use serde::Deserialize;
+
+/// Sits in the contract root, next to the meta crate. Relative to the meta crate.
+pub const ELROND_JSON_RELATIVE_PATH: &str = "../elrond.json";
+
+/// The fields of `elrond.json` that the meta crate cares about, other fields are ignored.
+///
+/// ```json
+/// {
+///     "language": "rust",
+///     "noAllocator": true
+/// }
+/// ```
+#[derive(Deserialize, Debug, Default)]
+pub struct ElrondJsonSerde {
+    /// The main contract and the external view contract must not contain an allocator.
+    /// Output contracts from `multicontract.toml` have their own `no-allocator` setting.
+    #[serde(default, rename = "noAllocator")]
+    pub no_allocator: bool,
+}
+
+impl ElrondJsonSerde {
+    pub fn load_from_file(path: &str) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        let config = serde_json::from_str(content.as_str())
+            .unwrap_or_else(|err| panic!("failed to parse {}: {}", path, err));
+        Some(config)
+    }
+}
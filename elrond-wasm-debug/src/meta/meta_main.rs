@@ -1,20 +1,38 @@
 use elrond_wasm::contract_base::ContractAbiProvider;
 use std::env;
 
-use super::meta_config::MetaConfig;
+use super::{
+    meta_abi_compat::load_abi_file,
+    meta_config::MetaConfig,
+    meta_validate_abi::{abi_lints, print_abi_lints},
+};
 
 static SNIPPETS_OVERWRITE_FLAG_NAME: &str = "--overwrite";
+const CHECK_ABI_COMMAND: &str = "check-abi";
 
 pub fn perform<AbiObj: ContractAbiProvider>() {
     let original_contract_abi = <AbiObj as ContractAbiProvider>::abi();
-    super::meta_validate_abi::validate_abi(&original_contract_abi).unwrap();
+    super::meta_validate_abi::validate_abi(&original_contract_abi)
+        .unwrap_or_else(|err| panic!("{}", err));
+    let lints = abi_lints(&original_contract_abi);
+    print_abi_lints(lints.as_slice());
 
     let args: Vec<String> = env::args().collect();
     let mut meta_config = MetaConfig::create(&original_contract_abi, args.as_slice());
 
+    // loaded before `write_abi`, since it is often the ABI file that is about to be overwritten
+    let old_abi_to_check = if args.get(1).map(String::as_str) == Some(CHECK_ABI_COMMAND) {
+        let old_abi_path = args
+            .get(2)
+            .expect("argument `check-abi` must be followed by the path of the previous ABI file");
+        Some((old_abi_path, load_abi_file(old_abi_path)))
+    } else {
+        None
+    };
+
     meta_config.write_abi();
 
-    meta_config.create_wasm_view_cargo_toml();
+    meta_config.create_wasm_secondary_cargo_toml();
 
     meta_config.write_wasm_src_lib();
 
@@ -22,8 +40,19 @@ pub fn perform<AbiObj: ContractAbiProvider>() {
 
     if args.len() > 1 {
         match args[1].as_str() {
-            "build" => meta_config.build_wasm(),
+            "build" => {
+                assert!(
+                    lints.is_empty(),
+                    "Invalid contract ABI, see the warnings above."
+                );
+                meta_config.build_wasm()
+            },
             "clean" => meta_config.clean_wasm(),
+            CHECK_ABI_COMMAND => {
+                if let Some((old_abi_path, old_abi)) = &old_abi_to_check {
+                    meta_config.check_abi_compatibility(old_abi, old_abi_path);
+                }
+            },
             "snippets" => {
                 let overwrite = match args.get(2) {
                     Some(arg) => arg.as_str() == SNIPPETS_OVERWRITE_FLAG_NAME,
@@ -0,0 +1,157 @@
+use std::{collections::BTreeMap, fs};
+
+use elrond_wasm::abi::{ContractAbi, EndpointAbi, EndpointLocationAbi};
+use serde::Deserialize;
+
+/// Optional, sits next to `elrond.json`. Relative to the meta crate.
+pub const MULTI_CONTRACT_CONFIG_RELATIVE_PATH: &str = "../multicontract.toml";
+
+/// The `multicontract.toml` file, which defines extra output contracts,
+/// each with a subset of the endpoints of the main contract.
+///
+/// ```toml
+/// [contracts.admin]
+/// name = "my-contract-admin"
+/// add-labels = ["admin"]
+/// add-modules = ["PauseModule"]
+/// add-endpoints = ["upgradeSettings"]
+/// no-allocator = true
+/// ```
+///
+/// Each output contract gets its own wasm crate, `wasm-<id>`, and its own ABI.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct MultiContractConfigSerde {
+    #[serde(default)]
+    pub contracts: BTreeMap<String, OutputContractSerde>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct OutputContractSerde {
+    /// Output file base name. Defaults to `<crate name>-<id>`.
+    pub name: Option<String>,
+
+    /// Adds all endpoints annotated with any of these `#[label(...)]`s.
+    #[serde(default, rename = "add-labels")]
+    pub add_labels: Vec<String>,
+
+    /// Adds all endpoints declared in any of these contract or module traits.
+    #[serde(default, rename = "add-modules")]
+    pub add_modules: Vec<String>,
+
+    /// Adds individual endpoints, by their public name.
+    #[serde(default, rename = "add-endpoints")]
+    pub add_endpoints: Vec<String>,
+
+    /// The build fails if the output contract contains an allocator.
+    #[serde(default, rename = "no-allocator")]
+    pub no_allocator: bool,
+}
+
+/// The contract id ends up in the wasm crate directory name, `wasm-<id>`.
+fn check_contract_id(contract_id: &str) {
+    assert!(
+        contract_id != "view",
+        "the `view` contract id is reserved for the external view contract"
+    );
+    assert!(
+        !contract_id.is_empty()
+            && !contract_id.contains('/')
+            && !contract_id.contains('\\')
+            && !contract_id.contains("..")
+            && !contract_id.chars().any(char::is_whitespace),
+        "invalid contract id `{}`: it cannot be empty or contain slashes, `..` or whitespace",
+        contract_id
+    );
+}
+
+impl MultiContractConfigSerde {
+    pub fn load_from_file(path: &str) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        let config = toml::from_str(content.as_str())
+            .unwrap_or_else(|err| panic!("failed to parse {}: {}", path, err));
+        Some(config)
+    }
+}
+
+impl OutputContractSerde {
+    pub fn output_base_name(&self, contract_id: &str, crate_name: &str) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("{}-{}", crate_name, contract_id))
+    }
+
+    pub fn selects_endpoint(&self, endpoint: &EndpointAbi) -> bool {
+        self.add_labels
+            .iter()
+            .any(|label| endpoint.labels.contains(&label.as_str()))
+            || self
+                .add_modules
+                .iter()
+                .any(|module| module == endpoint.module)
+            || self.add_endpoints.iter().any(|name| name == endpoint.name)
+    }
+
+    /// The constructor, the callback and the selected endpoints.
+    /// External views can also be selected, but only without any regular endpoints,
+    /// which produces an external view contract.
+    ///
+    /// Panics if one of the labels, modules or endpoint names does not select anything,
+    /// since that is most likely a typo.
+    pub fn contract_abi(
+        &self,
+        contract_id: &str,
+        original_contract_abi: &ContractAbi,
+    ) -> ContractAbi {
+        check_contract_id(contract_id);
+        let contract_abi =
+            original_contract_abi.filtered_contract(|endpoint| self.selects_endpoint(endpoint));
+        let has_location = |location| {
+            contract_abi
+                .endpoints
+                .iter()
+                .any(|endpoint| endpoint.location == location)
+        };
+        assert!(
+            !(has_location(EndpointLocationAbi::MainContract)
+                && has_location(EndpointLocationAbi::ViewContract)),
+            "contract `{}`: cannot mix external views with regular endpoints",
+            contract_id
+        );
+        for label in &self.add_labels {
+            assert!(
+                contract_abi
+                    .endpoints
+                    .iter()
+                    .any(|endpoint| endpoint.labels.contains(&label.as_str())),
+                "contract `{}`: no endpoint labeled `{}`",
+                contract_id,
+                label
+            );
+        }
+        for module in &self.add_modules {
+            assert!(
+                contract_abi
+                    .endpoints
+                    .iter()
+                    .any(|endpoint| endpoint.module == module),
+                "contract `{}`: no endpoints in module `{}`",
+                contract_id,
+                module
+            );
+        }
+        for name in &self.add_endpoints {
+            assert!(
+                contract_abi
+                    .endpoints
+                    .iter()
+                    .any(|endpoint| endpoint.name == name),
+                "contract `{}`: endpoint `{}` not found",
+                contract_id,
+                name
+            );
+        }
+        contract_abi
+    }
+}
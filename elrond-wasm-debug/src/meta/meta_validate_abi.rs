@@ -1,4 +1,26 @@
-use elrond_wasm::abi::ContractAbi;
+use std::collections::BTreeMap;
+
+use elrond_wasm::{abi::ContractAbi, api::*};
+
+use super::meta_vm_api_names::VM_API_FUNCTION_NAMES;
+
+/// Calls to these functions are handled by the protocol, they never reach the contract.
+const BUILTIN_FUNCTION_NAMES: &[&[u8]] = &[
+    ESDT_TRANSFER_FUNC_NAME,
+    ESDT_LOCAL_MINT_FUNC_NAME,
+    ESDT_LOCAL_BURN_FUNC_NAME,
+    ESDT_MULTI_TRANSFER_FUNC_NAME,
+    ESDT_NFT_TRANSFER_FUNC_NAME,
+    ESDT_NFT_CREATE_FUNC_NAME,
+    ESDT_NFT_ADD_QUANTITY_FUNC_NAME,
+    ESDT_NFT_ADD_URI_FUNC_NAME,
+    ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME,
+    ESDT_NFT_BURN_FUNC_NAME,
+    CHANGE_OWNER_BUILTIN_FUNC_NAME,
+    CLAIM_DEVELOPER_REWARDS_FUNC_NAME,
+    SET_USERNAME_FUNC_NAME,
+    UPGRADE_CONTRACT_FUNC_NAME,
+];
 
 fn validate_abi_constructor(abi: &ContractAbi) -> Result<(), &'static str> {
     match abi.constructors.len() {
@@ -8,7 +30,95 @@ fn validate_abi_constructor(abi: &ContractAbi) -> Result<(), &'static str> {
     }
 }
 
+/// Endpoints only clash if they end up in the same output contract.
+fn lint_duplicate_endpoints(abi: &ContractAbi, lints: &mut Vec<String>) {
+    for (index, endpoint) in abi.endpoints.iter().enumerate() {
+        if let Some(previous) = abi.endpoints[..index].iter().find(|previous| {
+            previous.name == endpoint.name && previous.location == endpoint.location
+        }) {
+            lints.push(format!(
+                "endpoint `{}`: declared in both `{}` and `{}`",
+                endpoint.name, previous.module, endpoint.module
+            ));
+        }
+    }
+}
+
+fn lint_reserved_endpoint_names(abi: &ContractAbi, lints: &mut Vec<String>) {
+    for endpoint in &abi.endpoints {
+        if BUILTIN_FUNCTION_NAMES.contains(&endpoint.name.as_bytes()) {
+            lints.push(format!(
+                "endpoint `{}` in `{}`: same name as a builtin function",
+                endpoint.name, endpoint.module
+            ));
+        }
+        if VM_API_FUNCTION_NAMES.contains(&endpoint.name) {
+            lints.push(format!(
+                "endpoint `{}` in `{}`: same name as a VM API function",
+                endpoint.name, endpoint.module
+            ));
+        }
+    }
+}
+
+fn lint_duplicate_events(abi: &ContractAbi, lints: &mut Vec<String>) {
+    for (index, event) in abi.events.iter().enumerate() {
+        if let Some(previous) = abi.events[..index]
+            .iter()
+            .find(|previous| previous.identifier == event.identifier)
+        {
+            lints.push(format!(
+                "event `{}`: declared in both `{}` and `{}`",
+                event.identifier, previous.module, event.module
+            ));
+        }
+    }
+}
+
+/// Only the first type to describe itself under a name makes it into the ABI.
+/// Instances of the same generic type are not considered different types.
+fn lint_type_name_collisions(abi: &ContractAbi, lints: &mut Vec<String>) {
+    let rust_type_paths: BTreeMap<_, _> = abi.type_descriptions.rust_type_paths().iter().collect();
+
+    for (type_name, paths) in rust_type_paths {
+        let mut definitions: Vec<&str> = Vec::new();
+        for path in paths {
+            let definition = path.split('<').next().unwrap_or(path);
+            if !definitions.contains(&definition) {
+                definitions.push(definition);
+            }
+        }
+        if definitions.len() > 1 {
+            lints.push(format!(
+                "type `{}`: name used by `{}`",
+                type_name,
+                definitions.join("`, `")
+            ));
+        }
+    }
+}
+
+/// Problems that the compiler does not catch, and that would otherwise only show up
+/// when building the wasm, when calling the contract, or when reading the ABI:
+/// endpoints and events declared twice, endpoints named like builtin or VM API functions,
+/// and different types sharing the same ABI type name.
+pub fn abi_lints(abi: &ContractAbi) -> Vec<String> {
+    let mut lints = Vec::new();
+    lint_duplicate_endpoints(abi, &mut lints);
+    lint_reserved_endpoint_names(abi, &mut lints);
+    lint_duplicate_events(abi, &mut lints);
+    lint_type_name_collisions(abi, &mut lints);
+    lints
+}
+
 pub fn validate_abi(abi: &ContractAbi) -> Result<(), &'static str> {
     validate_abi_constructor(abi)?;
     Ok(())
 }
+
+/// All meta commands print the lints as warnings, only `build` fails because of them.
+pub fn print_abi_lints(lints: &[String]) {
+    for lint in lints {
+        println!("Warning: {}", lint);
+    }
+}
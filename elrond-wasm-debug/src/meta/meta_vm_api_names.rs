@@ -0,0 +1,239 @@
+/// The functions provided by the VM, as imported by `elrond-wasm-node`.
+/// Endpoints cannot have these names, since they would clash with the imports,
+/// and a contract importing anything else would not deploy.
+///
+/// `elrond-wasm-derive/src/validate/reserved.rs` checks the endpoint names
+/// against the older part of this list at compile time.
+///
+/// Exactly the imports of `elrond-wasm-node`, plus the older VM functions reserved in the derive crate,
+/// which `tests/vm_api_names_test.rs` checks against the `extern "C"` blocks and the reserved list.
+pub const VM_API_FUNCTION_NAMES: &[&str] = &[
+    "addEC",
+    "asyncCall",
+    "bigFloatAbs",
+    "bigFloatAdd",
+    "bigFloatCeil",
+    "bigFloatClone",
+    "bigFloatCmp",
+    "bigFloatDiv",
+    "bigFloatFloor",
+    "bigFloatGetConstE",
+    "bigFloatGetConstPi",
+    "bigFloatIsInt",
+    "bigFloatMul",
+    "bigFloatNeg",
+    "bigFloatNewFromFrac",
+    "bigFloatNewFromParts",
+    "bigFloatNewFromSci",
+    "bigFloatPow",
+    "bigFloatSetBigInt",
+    "bigFloatSetInt64",
+    "bigFloatSign",
+    "bigFloatSqrt",
+    "bigFloatSub",
+    "bigFloatTruncate",
+    "bigIntAbs",
+    "bigIntAdd",
+    "bigIntAnd",
+    "bigIntCmp",
+    "bigIntEDiv",
+    "bigIntEMod",
+    "bigIntFinishSigned",
+    "bigIntFinishUnsigned",
+    "bigIntGetCallValue",
+    "bigIntGetESDTCallValue",
+    "bigIntGetESDTCallValueByIndex",
+    "bigIntGetESDTExternalBalance",
+    "bigIntGetExternalBalance",
+    "bigIntGetInt64",
+    "bigIntGetSignedArgument",
+    "bigIntGetSignedBytes",
+    "bigIntGetUnsignedArgument",
+    "bigIntGetUnsignedBytes",
+    "bigIntIsInt64",
+    "bigIntLog2",
+    "bigIntMul",
+    "bigIntNeg",
+    "bigIntNew",
+    "bigIntNot",
+    "bigIntOr",
+    "bigIntPow",
+    "bigIntSetInt64",
+    "bigIntSetSignedBytes",
+    "bigIntSetUnsignedBytes",
+    "bigIntShl",
+    "bigIntShr",
+    "bigIntSign",
+    "bigIntSignedByteLength",
+    "bigIntSqrt",
+    "bigIntStorageLoadUnsigned",
+    "bigIntStorageStoreUnsigned",
+    "bigIntSub",
+    "bigIntTDiv",
+    "bigIntTMod",
+    "bigIntToString",
+    "bigIntUnsignedByteLength",
+    "bigIntXor",
+    "blockHash",
+    "checkNoPayment",
+    "cleanReturnData",
+    "createContract",
+    "createEC",
+    "delegateExecution",
+    "deleteFromReturnData",
+    "deployFromSourceContract",
+    "doubleEC",
+    "ellipticCurveGetValues",
+    "encodeSecp256k1DerSignature",
+    "executeOnDestContext",
+    "executeOnSameContext",
+    "executeReadOnly",
+    "finish",
+    "generateKeyEC",
+    "getArgument",
+    "getArgumentLength",
+    "getBlockEpoch",
+    "getBlockHash",
+    "getBlockNonce",
+    "getBlockRandomSeed",
+    "getBlockRound",
+    "getBlockTimestamp",
+    "getCallValue",
+    "getCaller",
+    "getCurrentESDTNFTNonce",
+    "getCurveLengthEC",
+    "getESDTLocalRoles",
+    "getESDTNFTAttributeLength",
+    "getESDTNFTNameLength",
+    "getESDTNFTURILength",
+    "getESDTTokenData",
+    "getESDTTokenName",
+    "getESDTTokenNameByIndex",
+    "getESDTTokenNonce",
+    "getESDTTokenNonceByIndex",
+    "getESDTTokenType",
+    "getESDTTokenTypeByIndex",
+    "getExternalBalance",
+    "getFunction",
+    "getGasLeft",
+    "getNumArguments",
+    "getNumESDTTransfers",
+    "getNumReturnData",
+    "getOriginalTxHash",
+    "getOwnerAddress",
+    "getPrevBlockEpoch",
+    "getPrevBlockNonce",
+    "getPrevBlockRandomSeed",
+    "getPrevBlockRound",
+    "getPrevBlockTimestamp",
+    "getPrivKeyByteLengthEC",
+    "getReturnData",
+    "getReturnDataSize",
+    "getSCAddress",
+    "getShardOfAddress",
+    "getStateRootHash",
+    "int64finish",
+    "int64getArgument",
+    "int64storageLoad",
+    "int64storageStore",
+    "isOnCurveEC",
+    "isSmartContract",
+    "keccak256",
+    "mBufferAppend",
+    "mBufferAppendBytes",
+    "mBufferCopyByteSlice",
+    "mBufferEq",
+    "mBufferFinish",
+    "mBufferFromBigFloat",
+    "mBufferFromBigIntSigned",
+    "mBufferFromBigIntUnsigned",
+    "mBufferGetArgument",
+    "mBufferGetByteSlice",
+    "mBufferGetBytes",
+    "mBufferGetLength",
+    "mBufferNew",
+    "mBufferNewFromBytes",
+    "mBufferSetByteSlice",
+    "mBufferSetBytes",
+    "mBufferSetRandom",
+    "mBufferStorageLoad",
+    "mBufferStorageLoadFromAddress",
+    "mBufferStorageStore",
+    "mBufferToBigFloat",
+    "mBufferToBigIntSigned",
+    "mBufferToBigIntUnsigned",
+    "managedAsyncCall",
+    "managedBufferToHex",
+    "managedCaller",
+    "managedCreateAsyncCall",
+    "managedCreateContract",
+    "managedCreateEC",
+    "managedDeployFromSourceContract",
+    "managedEncodeSecp256k1DerSignature",
+    "managedExecuteOnDestContext",
+    "managedExecuteOnSameContext",
+    "managedExecuteReadOnly",
+    "managedGenerateKeyEC",
+    "managedGetBlockRandomSeed",
+    "managedGetCallbackClosure",
+    "managedGetESDTTokenData",
+    "managedGetMultiESDTCallValue",
+    "managedGetOriginalTxHash",
+    "managedGetPrevBlockRandomSeed",
+    "managedGetReturnData",
+    "managedGetStateRootHash",
+    "managedIsESDTFrozen",
+    "managedIsESDTLimitedTransfer",
+    "managedIsESDTPaused",
+    "managedKeccak256",
+    "managedMarshalCompressedEC",
+    "managedMarshalEC",
+    "managedMultiTransferESDTNFTExecute",
+    "managedOwnerAddress",
+    "managedRipemd160",
+    "managedSCAddress",
+    "managedScalarBaseMultEC",
+    "managedScalarMultEC",
+    "managedSha256",
+    "managedSignalError",
+    "managedTransferValueExecute",
+    "managedUnmarshalCompressedEC",
+    "managedUnmarshalEC",
+    "managedUpgradeContract",
+    "managedUpgradeFromSourceContract",
+    "managedVerifyBLS",
+    "managedVerifyCustomSecp256k1",
+    "managedVerifyEd25519",
+    "managedVerifySecp256k1",
+    "managedWriteLog",
+    "marshalCompressedEC",
+    "marshalEC",
+    "multiTransferESDTNFTExecute",
+    "ripemd160",
+    "scalarBaseMultEC",
+    "scalarMultEC",
+    "sha256",
+    "signalError",
+    "smallIntFinishSigned",
+    "smallIntFinishUnsigned",
+    "smallIntGetSignedArgument",
+    "smallIntGetUnsignedArgument",
+    "storageLoad",
+    "storageLoadLength",
+    "storageStore",
+    "transferESDTExecute",
+    "transferESDTNFTExecute",
+    "transferValue",
+    "transferValueExecute",
+    "unmarshalCompressedEC",
+    "unmarshalEC",
+    "upgradeContract",
+    "upgradeFromSourceContract",
+    "validateTokenIdentifier",
+    "verifyBLS",
+    "verifyCustomSecp256k1",
+    "verifyEd25519",
+    "verifySecp256k1",
+    "writeEventLog",
+    "writeLog",
+];
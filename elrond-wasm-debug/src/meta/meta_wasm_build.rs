@@ -1,6 +1,9 @@
 use std::{fs, process::Command};
 
-use super::meta_config::{BuildArgs, ContractMetadata, MetaConfig};
+use super::{
+    meta_config::{BuildArgs, ContractMetadata, MetaConfig},
+    meta_wasm_report::WasmReport,
+};
 
 const WASM_OPT_NAME: &str = "wasm-opt";
 
@@ -11,12 +14,12 @@ impl MetaConfig {
             self.build_args.wasm_opt = false;
         }
 
-        if let Some(main_contract) = &self.main_contract {
-            build_contract(main_contract, &self.build_args, self.output_dir.as_str());
-        }
-
-        if let Some(view_contract) = &self.view_contract {
-            build_contract(view_contract, &self.build_args, self.output_dir.as_str());
+        for contract_metadata in self.output_contracts() {
+            build_contract(
+                contract_metadata,
+                &self.build_args,
+                self.output_dir.as_str(),
+            );
         }
     }
 }
@@ -47,6 +50,7 @@ fn build_contract(contract_metadata: &ContractMetadata, build_args: &BuildArgs,
         .expect("failed to copy compiled contract to output directory");
 
     optimize_contract(build_args, dest_wasm_path.as_str());
+    check_contract(contract_metadata, dest_wasm_path.as_str());
 }
 
 fn is_wasm_opt_installed() -> bool {
@@ -61,10 +65,38 @@ fn optimize_contract(build_args: &BuildArgs, wasm_path: &str) {
         return;
     }
 
-    let _ = Command::new(WASM_OPT_NAME)
-        .args([wasm_path, "-Oz", "--output", wasm_path])
+    let mut command = Command::new(WASM_OPT_NAME);
+    command.args([wasm_path, "-Oz", "--output", wasm_path]);
+    if build_args.debug_symbols {
+        // keeps the name section
+        command.arg("-g");
+    }
+    let _ = command
         .spawn()
         .expect("failed to spawn wasm-out process")
         .wait()
         .expect("wasm-out was not running");
 }
+
+/// Prints the size and imports of the final contract, and the function sizes if it has debug symbols.
+/// Fails on imports that the VM does not provide, and on allocators, if forbidden.
+fn check_contract(contract_metadata: &ContractMetadata, wasm_path: &str) {
+    let wasm_bytes = fs::read(wasm_path).expect("failed to read compiled contract");
+    let report = WasmReport::parse(wasm_bytes.as_slice())
+        .unwrap_or_else(|err| panic!("failed to parse {}: {}", wasm_path, err));
+    report.print(wasm_path);
+
+    let unknown_imports = report.unknown_imports();
+    assert!(
+        unknown_imports.is_empty(),
+        "{} imports functions that the VM does not provide: {}",
+        wasm_path,
+        unknown_imports.join(", ")
+    );
+    assert!(
+        !contract_metadata.no_allocator || !report.has_allocator(),
+        "{} contains an allocator, used by: {}",
+        wasm_path,
+        report.allocator_functions().join(", ")
+    );
+}
@@ -0,0 +1,211 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use wasmparser::{ExternalKind, Name, NameSectionReader, Operator, Parser, Payload, TypeRef};
+
+use super::meta_vm_api_names::VM_API_FUNCTION_NAMES;
+
+const NAME_SECTION_NAME: &str = "name";
+
+/// The symbols behind Rust's global allocator, the default one (`__rdl_`) or a custom one (`__rg_`).
+const ALLOCATOR_FUNCTION_NAMES: &[&str] = &[
+    "__rust_alloc",
+    "__rust_alloc_zeroed",
+    "__rust_realloc",
+    "__rust_dealloc",
+    "__rdl_alloc",
+    "__rdl_alloc_zeroed",
+    "__rdl_realloc",
+    "__rdl_dealloc",
+    "__rg_alloc",
+    "__rg_alloc_zeroed",
+    "__rg_realloc",
+    "__rg_dealloc",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSize {
+    /// From the name section, if there is one, otherwise `func[<index>]`.
+    pub name: String,
+    /// Size of the function body, in bytes.
+    pub size: usize,
+}
+
+/// What the meta `build` command finds out about each produced `.wasm` file.
+#[derive(Debug, Default)]
+pub struct WasmReport {
+    /// Total size, in bytes.
+    pub size: usize,
+    /// Largest first.
+    pub functions: Vec<FunctionSize>,
+    /// The imported functions, in import order.
+    pub imports: Vec<String>,
+    /// Functions containing `memory.grow`, which only an allocator needs.
+    pub memory_grow_functions: Vec<String>,
+    /// Allocator functions, found by name in the name section or in the exports.
+    pub named_allocator_functions: Vec<String>,
+    /// Only contracts built with `--wasm-symbols` keep their name section.
+    pub has_function_names: bool,
+}
+
+struct FunctionBodyInfo {
+    index: u32,
+    size: usize,
+    grows_memory: bool,
+}
+
+impl WasmReport {
+    pub fn parse(wasm_bytes: &[u8]) -> Result<WasmReport, String> {
+        parse_wasm(wasm_bytes).map_err(|err| err.to_string())
+    }
+
+    /// Imports that are not VM functions. A contract with any of them cannot be deployed.
+    pub fn unknown_imports(&self) -> Vec<&str> {
+        self.imports
+            .iter()
+            .map(String::as_str)
+            .filter(|import| !VM_API_FUNCTION_NAMES.contains(import))
+            .collect()
+    }
+
+    pub fn has_allocator(&self) -> bool {
+        !self.memory_grow_functions.is_empty() || !self.named_allocator_functions.is_empty()
+    }
+
+    /// The functions that give away the allocator, without duplicates.
+    pub fn allocator_functions(&self) -> Vec<&str> {
+        let mut result: Vec<&str> = Vec::new();
+        for name in self
+            .memory_grow_functions
+            .iter()
+            .chain(self.named_allocator_functions.iter())
+        {
+            if !result.contains(&name.as_str()) {
+                result.push(name.as_str());
+            }
+        }
+        result
+    }
+
+    pub fn print(&self, wasm_path: &str) {
+        println!("{}: {} bytes", wasm_path, self.size);
+        println!(
+            "  imports ({}): {}",
+            self.imports.len(),
+            self.imports.join(", ")
+        );
+        if self.has_function_names {
+            println!("  function sizes:");
+            for function in &self.functions {
+                println!("    {:>8}  {}", function.size, function.name);
+            }
+        }
+    }
+}
+
+fn grows_memory(body: &wasmparser::FunctionBody) -> wasmparser::Result<bool> {
+    for operator in body.get_operators_reader()? {
+        if let Operator::MemoryGrow { .. } = operator? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn read_function_names(
+    data: &[u8],
+    offset: usize,
+    function_names: &mut HashMap<u32, String>,
+) -> wasmparser::Result<()> {
+    for name in NameSectionReader::new(data, offset)? {
+        if let Name::Function(name_map) = name? {
+            let mut naming_reader = name_map.get_map()?;
+            for _ in 0..naming_reader.get_count() {
+                let naming = naming_reader.read()?;
+                function_names.insert(naming.index, naming.name.to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_wasm(wasm_bytes: &[u8]) -> wasmparser::Result<WasmReport> {
+    let mut imports = Vec::new();
+    let mut function_bodies = Vec::new();
+    let mut function_names = HashMap::new();
+    let mut allocator_exports = Vec::new();
+
+    for payload in Parser::new(0).parse_all(wasm_bytes) {
+        match payload? {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    let import = import?;
+                    if let TypeRef::Func(_) = import.ty {
+                        imports.push(import.name.to_string());
+                    }
+                }
+            },
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    let export = export?;
+                    if export.kind == ExternalKind::Func
+                        && ALLOCATOR_FUNCTION_NAMES.contains(&export.name)
+                    {
+                        allocator_exports.push(export.name.to_string());
+                    }
+                }
+            },
+            Payload::CodeSectionEntry(body) => {
+                // imported functions come first in the function index space
+                function_bodies.push(FunctionBodyInfo {
+                    index: (imports.len() + function_bodies.len()) as u32,
+                    size: body.range().len(),
+                    grows_memory: grows_memory(&body)?,
+                });
+            },
+            Payload::CustomSection(reader) if reader.name() == NAME_SECTION_NAME => {
+                read_function_names(reader.data(), reader.data_offset(), &mut function_names)?;
+            },
+            _ => {},
+        }
+    }
+
+    let function_name = |index: u32| {
+        function_names
+            .get(&index)
+            .cloned()
+            .unwrap_or_else(|| format!("func[{}]", index))
+    };
+    let memory_grow_functions = function_bodies
+        .iter()
+        .filter(|body| body.grows_memory)
+        .map(|body| function_name(body.index))
+        .collect();
+    let mut named_allocator_functions: Vec<String> = function_bodies
+        .iter()
+        .filter_map(|body| function_names.get(&body.index))
+        .filter(|name| ALLOCATOR_FUNCTION_NAMES.contains(&name.as_str()))
+        .cloned()
+        .collect();
+    for export in allocator_exports {
+        if !named_allocator_functions.contains(&export) {
+            named_allocator_functions.push(export);
+        }
+    }
+    let mut functions: Vec<FunctionSize> = function_bodies
+        .iter()
+        .map(|body| FunctionSize {
+            name: function_name(body.index),
+            size: body.size,
+        })
+        .collect();
+    functions.sort_by_key(|function| Reverse(function.size));
+
+    Ok(WasmReport {
+        size: wasm_bytes.len(),
+        functions,
+        imports,
+        memory_grow_functions,
+        named_allocator_functions,
+        has_function_names: !function_names.is_empty(),
+    })
+}
@@ -1,11 +1,22 @@
 mod meta_abi;
+mod meta_abi_compat;
 mod meta_cargo_toml;
 mod meta_config;
+mod meta_elrond_json;
 mod meta_generate_snippets;
 mod meta_main;
+mod meta_multi_contract;
 mod meta_validate_abi;
+mod meta_vm_api_names;
 mod meta_wasm_build;
 mod meta_wasm_clean;
 mod meta_wasm_crates;
+mod meta_wasm_report;
 
+pub use meta_abi_compat::abi_breaking_changes;
+pub use meta_elrond_json::ElrondJsonSerde;
 pub use meta_main::perform;
+pub use meta_multi_contract::{MultiContractConfigSerde, OutputContractSerde};
+pub use meta_validate_abi::abi_lints;
+pub use meta_vm_api_names::VM_API_FUNCTION_NAMES;
+pub use meta_wasm_report::{FunctionSize, WasmReport};
@@ -1,6 +1,7 @@
 use std::{collections::HashMap, path::PathBuf, rc::Rc, str::FromStr};
 
 use elrond_wasm::{
+    abi::ContractAbi,
     contract_base::{CallableContract, ContractBase},
     elrond_codec::{TopDecode, TopEncode},
     types::{
@@ -13,9 +14,18 @@ use num_traits::Zero;
 use crate::{
     num_bigint,
     testing_framework::raw_converter::bytes_to_hex,
-    tx_execution::{execute_async_call_and_callback, interpret_panic_as_tx_result},
-    tx_mock::{TxCache, TxContext, TxContextStack, TxInput, TxInputESDT, TxResult},
-    world_mock::{is_smart_contract_address, AccountData, AccountEsdt, EsdtInstanceMetadata},
+    tx_execution::{
+        execute_async_call_and_callback, execute_promise_call_and_callback,
+        gas_used_after_execution, interpret_panic_as_tx_result,
+    },
+    tx_mock::{
+        CrossShardCall, CrossShardCallStep, TxCache, TxContext, TxContextStack, TxInput,
+        TxInputESDT, TxResult,
+    },
+    world_mock::{
+        is_smart_contract_address, AccountData, AccountEsdt, EsdtInstanceMetadata, GasSchedule,
+        SnapshotId,
+    },
     BlockchainMock, DebugApi,
 };
 
@@ -56,6 +66,7 @@ pub struct BlockchainStateWrapper {
     address_to_code_path: HashMap<Address, Vec<u8>>,
     mandos_generator: MandosGenerator,
     workspace_path: PathBuf,
+    tx_gas_limit: u64,
 }
 
 impl BlockchainStateWrapper {
@@ -70,9 +81,14 @@ impl BlockchainStateWrapper {
             address_to_code_path: HashMap::new(),
             mandos_generator: MandosGenerator::new(),
             workspace_path: current_dir,
+            tx_gas_limit: u64::MAX,
         }
     }
 
+    pub fn get_state(&self) -> &BlockchainMock {
+        &self.rc_b_mock
+    }
+
     pub fn get_mut_state(&mut self) -> &mut Rc<BlockchainMock> {
         &mut self.rc_b_mock
     }
@@ -85,6 +101,22 @@ impl BlockchainStateWrapper {
             .write_mandos_output(full_path.to_str().unwrap());
     }
 
+    /// Saves the entire world state as a single `setState` mandos step,
+    /// in a file relative to the `mandos` folder.
+    pub fn export_state(&self, file_name: &str) {
+        self.rc_b_mock
+            .export_state(self.workspace_path.join(file_name));
+    }
+
+    /// Loads a world state file saved with `export_state`, relative to the `mandos` folder.
+    ///
+    /// The contracts referenced in the file must have already been registered,
+    /// for instance by creating the same SC accounts beforehand.
+    pub fn import_state(&mut self, file_name: &str) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.import_state(self.workspace_path.join(file_name));
+    }
+
     pub fn check_egld_balance(&self, address: &Address, expected_balance: &num_bigint::BigUint) {
         let actual_balance = match &self.rc_b_mock.accounts.get(address) {
             Some(acc) => acc.egld_balance.clone(),
@@ -329,7 +361,7 @@ impl BlockchainStateWrapper {
 
     pub fn set_egld_balance(&mut self, address: &Address, balance: &num_bigint::BigUint) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.account_mut(address) {
             Some(acc) => {
                 acc.egld_balance = balance.clone();
 
@@ -350,7 +382,7 @@ impl BlockchainStateWrapper {
         balance: &num_bigint::BigUint,
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.account_mut(address) {
             Some(acc) => {
                 acc.esdt.set_esdt_balance(
                     token_id.to_vec(),
@@ -397,7 +429,7 @@ impl BlockchainStateWrapper {
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
 
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.account_mut(address) {
             Some(acc) => {
                 acc.developer_rewards = developer_rewards;
 
@@ -426,7 +458,7 @@ impl BlockchainStateWrapper {
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
 
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.account_mut(address) {
             Some(acc) => {
                 acc.esdt.set_esdt_balance(
                     token_id.to_vec(),
@@ -458,7 +490,7 @@ impl BlockchainStateWrapper {
         roles: &[EsdtLocalRole],
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.account_mut(address) {
             Some(acc) => {
                 let mut roles_raw = Vec::new();
                 for role in roles {
@@ -475,6 +507,86 @@ impl BlockchainStateWrapper {
         }
     }
 
+    /// Enables gas metering for all subsequent transactions.
+    pub fn set_gas_schedule(&mut self, gas_schedule: GasSchedule) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.set_gas_schedule(gas_schedule);
+    }
+
+    /// Saves the current state, for instance after an expensive setup,
+    /// so that it can be restored before each test case.
+    ///
+    /// Note: restoring a snapshot does not affect the generated mandos output.
+    pub fn snapshot(&mut self) -> SnapshotId {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.snapshot()
+    }
+
+    pub fn restore(&mut self, snapshot_id: SnapshotId) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.restore(snapshot_id);
+    }
+
+    /// Records the storage keys touched by each contract, as well as the endpoints called.
+    ///
+    /// For the closures passed to `execute_tx` and `execute_query`,
+    /// only the first endpoint they call is counted, not the endpoints it calls in turn as methods.
+    /// Storage getters declared as views are only counted when called by name.
+    pub fn enable_coverage(&mut self) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.enable_coverage();
+    }
+
+    /// Lets the coverage report list the endpoints of the contract that were never called.
+    ///
+    /// The ABI is also used to decode the contract storage in `dump_state`.
+    pub fn register_contract_abi<CB, ContractObjBuilder>(
+        &mut self,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        abi: ContractAbi,
+    ) where
+        CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        ContractObjBuilder: 'static + Copy + Fn() -> CB,
+    {
+        let contract_code = self
+            .rc_b_mock
+            .accounts
+            .get(sc_wrapper.address_ref())
+            .and_then(|account| account.contract_path.clone())
+            .expect("not a smart contract account");
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.register_contract_abi_raw(contract_code, abi);
+    }
+
+    /// Path is relative to the crate root. Existing reports in the folder get merged with the new ones.
+    pub fn write_coverage_reports(&self, dir_path: &str) {
+        self.rc_b_mock.write_coverage_reports(dir_path);
+    }
+
+    /// Splits the accounts into shards. Async calls between shards are then only executed
+    /// when calling `deliver_cross_shard_calls`.
+    pub fn enable_sharding(&mut self, num_shards: u32) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.enable_sharding(num_shards);
+    }
+
+    pub fn set_account_shard(&mut self, address: &Address, shard: u32) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.set_account_shard(address, shard);
+    }
+
+    /// Simulates a new block, executing all pending cross-shard calls.
+    /// Callbacks of the calls executed now are only executed on the next call.
+    pub fn deliver_cross_shard_calls(&mut self) -> Vec<TxResult> {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.deliver_cross_shard_calls()
+    }
+
+    /// Sets the gas limit of all subsequent transactions. By default it is unlimited.
+    pub fn set_tx_gas_limit(&mut self, gas_limit: u64) {
+        self.tx_gas_limit = gas_limit;
+    }
+
     pub fn set_block_epoch(&mut self, block_epoch: u64) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
         b_mock_ref.current_block_info.block_epoch = block_epoch;
@@ -729,7 +841,13 @@ impl BlockchainStateWrapper {
             }
         }
 
-        let tx_input = build_tx_input(caller, sc_address, egld_payment, esdt_payments);
+        let tx_input = build_tx_input(
+            caller,
+            sc_address,
+            egld_payment,
+            esdt_payments,
+            self.tx_gas_limit,
+        );
         let tx_context_rc = Rc::new(TxContext::new(tx_input, tx_cache));
         TxContextStack::static_push(tx_context_rc);
 
@@ -737,11 +855,12 @@ impl BlockchainStateWrapper {
         let exec_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| tx_fn(sc)));
 
         let api_after_exec = Rc::try_unwrap(TxContextStack::static_pop()).unwrap();
-        let updates = api_after_exec.into_blockchain_updates();
-        let tx_result = match exec_result {
+        let mut tx_result = match exec_result {
             Ok(()) => TxResult::empty(),
             Err(panic_any) => interpret_panic_as_tx_result(panic_any),
         };
+        tx_result.result_gas_used = gas_used_after_execution(&api_after_exec, &tx_result);
+        let updates = api_after_exec.into_blockchain_updates();
 
         // only commit for successful non-query calls (caller == SC for queries)
         let is_successful_tx = tx_result.result_status == 0 && caller != sc_wrapper.address_ref();
@@ -752,12 +871,37 @@ impl BlockchainStateWrapper {
             updates.apply(b_mock_ref);
         }
         if is_successful_tx {
+            let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
             if let Some(async_data) = &tx_result.result_calls.async_call {
-                let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-                b_mock_ref.with_borrowed(|state| {
-                    let (_, _, state) = execute_async_call_and_callback(async_data.clone(), state);
-                    ((), state)
-                });
+                if b_mock_ref.is_cross_shard_call(&async_data.from, &async_data.to) {
+                    b_mock_ref.queue_cross_shard_call(CrossShardCall::new(
+                        caller.clone(),
+                        0,
+                        CrossShardCallStep::AsyncCall(async_data.clone()),
+                    ));
+                } else {
+                    b_mock_ref.with_borrowed(|state| {
+                        let (_, _, state) =
+                            execute_async_call_and_callback(async_data.clone(), state);
+                        ((), state)
+                    });
+                }
+            }
+
+            for promise in &tx_result.result_calls.promises {
+                if b_mock_ref.is_cross_shard_call(sc_address, &promise.endpoint.to) {
+                    b_mock_ref.queue_cross_shard_call(CrossShardCall::new(
+                        caller.clone(),
+                        0,
+                        CrossShardCallStep::Promise(promise.clone()),
+                    ));
+                } else {
+                    b_mock_ref.with_borrowed(|state| {
+                        let (_, _, state) =
+                            execute_promise_call_and_callback(sc_address, promise, state);
+                        ((), state)
+                    });
+                }
             }
         }
 
@@ -882,6 +1026,12 @@ impl BlockchainStateWrapper {
             println!();
             println!("Storage: ");
         }
+        if let Some(decoded_storage) = self.rc_b_mock.decoded_storage(address) {
+            for entry in decoded_storage {
+                println!("  {}", entry);
+            }
+            return;
+        }
         for (key, value) in &account.storage {
             let key_str = match String::from_utf8(key.to_vec()) {
                 core::result::Result::Ok(s) => s,
@@ -899,6 +1049,7 @@ fn build_tx_input(
     dest: &Address,
     egld_value: &num_bigint::BigUint,
     esdt_values: Vec<TxInputESDT>,
+    gas_limit: u64,
 ) -> TxInput {
     TxInput {
         from: caller.clone(),
@@ -907,9 +1058,10 @@ fn build_tx_input(
         esdt_values,
         func_name: Vec::new(),
         args: Vec::new(),
-        gas_limit: u64::MAX,
+        gas_limit,
         gas_price: 0,
         tx_hash: H256::zero(),
+        promise_callback_closure_data: Vec::new(),
     }
 }
 
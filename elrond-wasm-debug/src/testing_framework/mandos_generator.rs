@@ -58,6 +58,7 @@ impl MandosGenerator {
             block_hashes: Vec::new(),
             new_addresses: Vec::new(),
             comment: None,
+            tags: Vec::new(),
             current_block_info: None,
             previous_block_info: None,
         };
@@ -73,6 +74,7 @@ impl MandosGenerator {
             block_hashes: Vec::new(),
             new_addresses: Vec::new(),
             comment: None,
+            tags: Vec::new(),
             current_block_info: Some(current_raw),
             previous_block_info: Some(prev_raw),
         };
@@ -92,6 +94,7 @@ impl MandosGenerator {
 
         let step = StepRaw::ScCall {
             comment: None,
+            tags: Vec::new(),
             display_logs: None,
             id: self.next_tx_id_string(),
             tx: tx_raw,
@@ -107,6 +110,7 @@ impl MandosGenerator {
 
         let step = StepRaw::ScQuery {
             comment: None,
+            tags: Vec::new(),
             display_logs: None,
             id: self.next_tx_id_string(),
             tx_id: None,
@@ -122,6 +126,7 @@ impl MandosGenerator {
         let step = StepRaw::CheckState {
             accounts: check_raw,
             comment: None,
+            tags: Vec::new(),
         };
         self.add_step(step);
     }
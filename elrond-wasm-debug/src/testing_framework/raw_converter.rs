@@ -50,7 +50,11 @@ pub(crate) fn account_as_raw(acc: &AccountData) -> AccountRaw {
         nonce: Some(u64_as_raw(acc.nonce)),
         owner: acc.contract_owner.as_ref().map(address_as_raw),
         storage: storage_raw,
-        username: None, // TODO: Add if needed
+        username: if acc.username.is_empty() {
+            None
+        } else {
+            Some(bytes_as_raw(&acc.username))
+        },
         developer_rewards: developer_rewards_raw,
     }
 }
@@ -84,7 +88,11 @@ pub(crate) fn esdt_data_as_raw(esdt: &EsdtData) -> EsdtRaw {
     }
 
     EsdtRaw::Full(EsdtFullRaw {
-        frozen: None,
+        frozen: if esdt.frozen {
+            Some(u64_as_raw(1))
+        } else {
+            None
+        },
         instances: instances_raw,
         last_nonce: last_nonce_raw,
         roles: roles_raw,
@@ -275,14 +283,22 @@ pub(crate) fn opt_raw_value_to_check_raw(raw_value: &Option<ValueSubTree>) -> Ch
     }
 }
 
+/// Strings containing `|` or control characters would not be interpreted back to the same bytes,
+/// so these are also written as hex.
 pub(crate) fn bytes_to_mandos_string_or_hex(bytes: &[u8]) -> String {
     let conversion_result = String::from_utf8(bytes.to_vec());
     match conversion_result {
-        core::result::Result::Ok(bytes_as_str) => format!("str:{}", bytes_as_str),
-        core::result::Result::Err(_) => bytes_to_hex(bytes),
+        core::result::Result::Ok(bytes_as_str) if is_mandos_safe_str(&bytes_as_str) => {
+            format!("str:{}", bytes_as_str)
+        },
+        _ => bytes_to_hex(bytes),
     }
 }
 
+fn is_mandos_safe_str(s: &str) -> bool {
+    s.chars().all(|c| c != '|' && !c.is_control())
+}
+
 pub(crate) fn rust_biguint_as_raw(big_uint: &num_bigint::BigUint) -> ValueSubTree {
     ValueSubTree::Str(big_uint.to_string())
 }
@@ -1,6 +1,6 @@
 use crate::{
     tx_execution::default_execution,
-    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
 };
 
 use super::{
@@ -34,6 +34,61 @@ const ESDT_ROLE_NFT_ADD_URI: &[u8] = b"ESDTRoleNFTAddURI";
 const ESDT_ROLE_NFT_UPDATE_ATTRIBUTES: &[u8] = b"ESDTRoleNFTUpdateAttributes";
 
 pub fn execute_builtin_function_or_default(
+    mut tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    // builtin functions have a flat cost,
+    // the rest of the gas goes to the endpoint called afterwards, if any
+    let gas_limit = tx_input.gas_limit;
+    let builtin_gas_cost = builtin_function_gas_cost(&tx_input, &tx_cache);
+    if builtin_gas_cost > gas_limit {
+        let mut tx_result = TxResult::from_panic_obj(&TxPanic::out_of_gas());
+        tx_result.result_gas_used = gas_limit;
+        return (tx_result, BlockchainUpdate::empty());
+    }
+    tx_input.gas_limit -= builtin_gas_cost;
+
+    let (mut tx_result, blockchain_updates) =
+        dispatch_builtin_function_or_default(tx_input, tx_cache);
+    tx_result.result_gas_used = if tx_result.result_status == 0 {
+        tx_result.result_gas_used + builtin_gas_cost
+    } else {
+        gas_limit
+    };
+
+    (tx_result, blockchain_updates)
+}
+
+fn builtin_function_gas_cost(tx_input: &TxInput, tx_cache: &TxCache) -> u64 {
+    match &tx_cache.blockchain_ref().gas_schedule {
+        Some(gas_schedule) if is_builtin_function(tx_input.func_name.as_slice()) => {
+            gas_schedule.builtin_function
+        },
+        _ => 0,
+    }
+}
+
+fn is_builtin_function(func_name: &[u8]) -> bool {
+    matches!(
+        func_name,
+        ESDT_LOCAL_MINT_FUNC_NAME
+            | ESDT_LOCAL_BURN_FUNC_NAME
+            | ESDT_MULTI_TRANSFER_FUNC_NAME
+            | ESDT_NFT_TRANSFER_FUNC_NAME
+            | ESDT_NFT_CREATE_FUNC_NAME
+            | ESDT_NFT_ADD_QUANTITY_FUNC_NAME
+            | ESDT_NFT_BURN_FUNC_NAME
+            | ESDT_NFT_ADD_URI_FUNC_NAME
+            | ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME
+            | ESDT_TRANSFER_FUNC_NAME
+            | CHANGE_OWNER_BUILTIN_FUNC_NAME
+            | CLAIM_DEVELOPER_REWARDS_FUNC_NAME
+            | SET_USERNAME_FUNC_NAME
+            | UPGRADE_CONTRACT_FUNC_NAME
+    )
+}
+
+fn dispatch_builtin_function_or_default(
     tx_input: TxInput,
     tx_cache: TxCache,
 ) -> (TxResult, BlockchainUpdate) {
@@ -89,6 +89,7 @@ pub fn execute_esdt_multi_transfer(
         gas_limit: tx_input.gas_limit,
         gas_price: tx_input.gas_price,
         tx_hash: tx_input.tx_hash,
+        promise_callback_closure_data: Vec::new(),
     };
 
     let (mut tx_result, blockchain_updates) = default_execution(exec_input, tx_cache);
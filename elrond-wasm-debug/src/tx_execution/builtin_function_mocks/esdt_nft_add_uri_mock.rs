@@ -41,6 +41,7 @@ pub fn execute_nft_add_uri(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, B
         result_values: Vec::new(),
         result_logs: vec![esdt_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        result_gas_used: 0,
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
@@ -70,6 +70,7 @@ pub fn execute_esdt_nft_create(
         result_values: vec![top_encode_to_vec_u8(&new_nonce).unwrap()],
         result_logs: vec![esdt_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        result_gas_used: 0,
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
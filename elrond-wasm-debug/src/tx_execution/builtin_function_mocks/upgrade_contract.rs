@@ -39,6 +39,7 @@ pub fn execute_upgrade_contract(
         gas_limit: tx_input.gas_limit,
         gas_price: tx_input.gas_price,
         tx_hash: tx_input.tx_hash,
+        promise_callback_closure_data: Vec::new(),
     };
 
     default_execution(exec_input, tx_cache)
@@ -6,8 +6,9 @@ use num_traits::Zero;
 
 use crate::{
     tx_mock::{
-        async_call_tx_input, async_callback_tx_input, async_promise_tx_input, merge_results,
-        AsyncCallTxData, Promise, TxCache, TxContext, TxInput, TxResult, TxResultCalls,
+        async_call_tx_input, async_callback_tx_input, async_gas_remaining, async_promise_tx_input,
+        merge_results, AsyncCallTxData, CrossShardCall, CrossShardCallStep, Promise, TxCache,
+        TxContext, TxInput, TxResult, TxResultCalls,
     },
     world_mock::{AccountData, AccountEsdt, BlockchainMock},
 };
@@ -24,6 +25,9 @@ pub fn execute_sc_query(tx_input: TxInput, state: BlockchainMock) -> (TxResult,
 
 pub fn execute_sc_call(tx_input: TxInput, mut state: BlockchainMock) -> (TxResult, BlockchainMock) {
     state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+    let from = tx_input.from.clone();
+    let gas_limit = tx_input.gas_limit;
+    let gas_price = tx_input.gas_price;
 
     let state_rc = Rc::new(state);
     let tx_cache = TxCache::new(state_rc.clone());
@@ -33,6 +37,11 @@ pub fn execute_sc_call(tx_input: TxInput, mut state: BlockchainMock) -> (TxResul
     if tx_result.result_status == 0 {
         blockchain_updates.apply(&mut state);
     }
+    state.refund_tx_gas(
+        &from,
+        gas_limit.saturating_sub(tx_result.result_gas_used),
+        gas_price,
+    );
 
     (tx_result, state)
 }
@@ -42,108 +51,159 @@ pub fn execute_async_call_and_callback(
     state: BlockchainMock,
 ) -> (TxResult, TxResult, BlockchainMock) {
     if state.accounts.contains_key(&async_data.to) {
-        let async_input = async_call_tx_input(&async_data);
-
-        let (async_result, state) = sc_call_with_async_and_callback(async_input, state);
-
-        let callback_input = async_callback_tx_input(&async_data, &async_result);
-        let (callback_result, state) = execute_sc_call(callback_input, state);
-        assert!(
-            callback_result.result_calls.async_call.is_none(),
-            "successive asyncs currently not supported"
-        );
+        let (async_result, state) = execute_async_call(&async_data, state);
+        let (callback_result, state) = execute_async_callback(&async_data, &async_result, state);
         (async_result, callback_result, state)
     } else {
-        let state_rc = Rc::new(state);
-        let tx_cache = TxCache::new(state_rc.clone());
-        tx_cache.subtract_egld_balance(&async_data.from, &async_data.call_value);
-        tx_cache.insert_account(AccountData {
-            address: async_data.to.clone(),
-            nonce: 0,
-            egld_balance: async_data.call_value,
-            esdt: AccountEsdt::default(),
-            username: Vec::new(),
-            storage: HashMap::new(),
-            contract_path: None,
-            contract_owner: None,
-            developer_rewards: BigUint::zero(),
-        });
-        let blockchain_updates = tx_cache.into_blockchain_updates();
-        let mut state = Rc::try_unwrap(state_rc).unwrap();
-        state.commit_updates(blockchain_updates);
-
+        let state = transfer_to_new_account(&async_data, state);
         (TxResult::empty(), TxResult::empty(), state)
     }
 }
 
+pub fn execute_async_call(
+    async_data: &AsyncCallTxData,
+    state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
+    let async_input = async_call_tx_input(async_data);
+    sc_call_with_async_and_callback(async_input, state)
+}
+
+pub fn execute_async_callback(
+    async_data: &AsyncCallTxData,
+    async_result: &TxResult,
+    state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
+    let callback_input = async_callback_tx_input(async_data, async_result);
+    let (callback_result, state) = execute_sc_call(callback_input, state);
+    assert!(
+        callback_result.result_calls.async_call.is_none(),
+        "successive asyncs currently not supported"
+    );
+    (callback_result, state)
+}
+
+/// Async calls to accounts that do not exist simply create them, with the call value as balance.
+pub(crate) fn transfer_to_new_account(
+    async_data: &AsyncCallTxData,
+    state: BlockchainMock,
+) -> BlockchainMock {
+    let state_rc = Rc::new(state);
+    let tx_cache = TxCache::new(state_rc.clone());
+    tx_cache.subtract_egld_balance(&async_data.from, &async_data.call_value);
+    tx_cache.insert_account(AccountData {
+        address: async_data.to.clone(),
+        nonce: 0,
+        egld_balance: async_data.call_value.clone(),
+        esdt: AccountEsdt::default(),
+        username: Vec::new(),
+        storage: HashMap::new(),
+        contract_path: None,
+        contract_owner: None,
+        developer_rewards: BigUint::zero(),
+    });
+    let blockchain_updates = tx_cache.into_blockchain_updates();
+    let mut state = Rc::try_unwrap(state_rc).unwrap();
+    state.commit_updates(blockchain_updates);
+    state
+}
+
 // TODO: refactor
 pub fn sc_call_with_async_and_callback(
     tx_input: TxInput,
     state: BlockchainMock,
 ) -> (TxResult, BlockchainMock) {
     let contract_address = tx_input.to.clone();
+    let from = tx_input.from.clone();
+    let gas_price = tx_input.gas_price;
     let (mut tx_result, mut state) = execute_sc_call(tx_input, state);
     let result_calls = std::mem::replace(&mut tx_result.result_calls, TxResultCalls::empty());
-    if tx_result.result_status == 0 {
-        if let Some(async_data) = result_calls.async_call {
-            let (async_result, callback_result, new_state) =
-                execute_async_call_and_callback(async_data, state);
-            state = new_state;
-
-            tx_result = merge_results(tx_result, async_result);
-            tx_result = merge_results(tx_result, callback_result);
+    if tx_result.result_status != 0 {
+        return (tx_result, state);
+    }
 
+    if let Some(async_data) = result_calls.async_call {
+        if state.is_cross_shard_call(&async_data.from, &async_data.to) {
+            state.queue_cross_shard_call(CrossShardCall::new(
+                from,
+                gas_price,
+                CrossShardCallStep::AsyncCall(async_data),
+            ));
             return (tx_result, state);
         }
+
+        let (async_result, callback_result, new_state) =
+            execute_async_call_and_callback(async_data.clone(), state);
+        state = new_state;
+
+        let gas_remaining = async_gas_remaining(&async_data, &async_result, &callback_result);
+        state.refund_tx_gas(&from, gas_remaining, gas_price);
+
+        tx_result = merge_results(tx_result, async_result);
+        tx_result = merge_results(tx_result, callback_result);
+        tx_result.result_gas_used = tx_result.result_gas_used.saturating_sub(gas_remaining);
+
+        return (tx_result, state);
     }
 
     for callback in result_calls.promises {
+        if state.is_cross_shard_call(&contract_address, &callback.endpoint.to) {
+            state.queue_cross_shard_call(CrossShardCall::new(
+                from.clone(),
+                gas_price,
+                CrossShardCallStep::Promise(callback),
+            ));
+            continue;
+        }
+
         let (async_result, callback_result, new_state) =
             execute_promise_call_and_callback(&contract_address, &callback, state);
         state = new_state;
 
-        tx_result = merge_results(tx_result, async_result.clone());
-        tx_result = merge_results(tx_result, callback_result.clone());
+        let gas_remaining =
+            async_gas_remaining(&callback.endpoint, &async_result, &callback_result);
+        state.refund_tx_gas(&from, gas_remaining, gas_price);
+
+        tx_result = merge_promise_result(tx_result, async_result);
+        tx_result = merge_promise_result(tx_result, callback_result);
+        tx_result.result_gas_used = tx_result.result_gas_used.saturating_sub(gas_remaining);
     }
 
     (tx_result, state)
 }
 
+/// Promises are independent of each other and of the original transaction:
+/// a failed promise (or promise callback) only has its own changes reverted.
+fn merge_promise_result(original: TxResult, new: TxResult) -> TxResult {
+    if new.result_status == 0 {
+        merge_results(original, new)
+    } else {
+        original
+    }
+}
+
 pub fn execute_promise_call_and_callback(
     address: &Address,
     promise: &Promise,
     state: BlockchainMock,
 ) -> (TxResult, TxResult, BlockchainMock) {
     if state.accounts.contains_key(&promise.endpoint.to) {
-        let async_input = async_call_tx_input(&promise.endpoint);
-        let (async_result, state) = sc_call_with_async_and_callback(async_input, state);
-
-        let callback_input = async_promise_tx_input(address, promise, &async_result);
-        let (callback_result, state) = execute_sc_call(callback_input, state);
-        assert!(
-            callback_result.result_calls.promises.is_empty(),
-            "successive promises currently not supported"
-        );
+        let (async_result, state) = execute_async_call(&promise.endpoint, state);
+        let (callback_result, state) =
+            execute_promise_callback(address, promise, &async_result, state);
         (async_result, callback_result, state)
     } else {
-        let state_rc = Rc::new(state);
-        let tx_cache = TxCache::new(state_rc.clone());
-        tx_cache.subtract_egld_balance(address, &promise.endpoint.call_value);
-        tx_cache.insert_account(AccountData {
-            address: promise.endpoint.to.clone(),
-            nonce: 0,
-            egld_balance: promise.endpoint.call_value.clone(),
-            esdt: AccountEsdt::default(),
-            username: Vec::new(),
-            storage: HashMap::new(),
-            contract_path: None,
-            contract_owner: None,
-            developer_rewards: BigUint::zero(),
-        });
-        let blockchain_updates = tx_cache.into_blockchain_updates();
-        let mut state = Rc::try_unwrap(state_rc).unwrap();
-        state.commit_updates(blockchain_updates);
-
+        let state = transfer_to_new_account(&promise.endpoint, state);
         (TxResult::empty(), TxResult::empty(), state)
     }
 }
+
+/// Promise callbacks can register further promises, these get executed right away.
+pub fn execute_promise_callback(
+    address: &Address,
+    promise: &Promise,
+    async_result: &TxResult,
+    state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
+    let callback_input = async_promise_tx_input(address, promise, async_result);
+    sc_call_with_async_and_callback(callback_input, state)
+}
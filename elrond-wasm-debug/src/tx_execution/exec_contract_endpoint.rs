@@ -28,6 +28,11 @@ fn execute_tx_context_rc(tx_context_rc: Rc<TxContext>) -> (Rc<TxContext>, TxResu
     let func_name = tx_context_ref.tx_input_box.func_name.as_slice();
     let contract_identifier = get_contract_identifier(&tx_context_ref);
     let contract_map = &tx_context_rc.blockchain_ref().contract_map;
+    if let Some(coverage) = &tx_context_rc.blockchain_ref().coverage {
+        coverage
+            .borrow_mut()
+            .record_endpoint_call(contract_identifier.as_slice(), func_name);
+    }
 
     // Not redundant at all, func_name is borrowed from it...
     #[allow(clippy::redundant_clone)]
@@ -35,9 +40,10 @@ fn execute_tx_context_rc(tx_context_rc: Rc<TxContext>) -> (Rc<TxContext>, TxResu
         contract_map.new_contract_instance(contract_identifier.as_slice(), tx_context_ref.clone());
 
     TxContextStack::static_push(tx_context_rc.clone());
-    let tx_result = execute_contract_instance_endpoint(contract_instance, func_name);
+    let mut tx_result = execute_contract_instance_endpoint(contract_instance, func_name);
 
     let tx_context_rc = TxContextStack::static_pop();
+    tx_result.result_gas_used = gas_used_after_execution(&tx_context_rc, &tx_result);
     (tx_context_rc, tx_result)
 }
 
@@ -54,6 +60,15 @@ fn get_contract_identifier(tx_context: &TxContext) -> Vec<u8> {
         })
 }
 
+/// Failed executions consume the entire gas limit, just like on the real blockchain.
+pub fn gas_used_after_execution(tx_context: &TxContext, tx_result: &TxResult) -> u64 {
+    if tx_result.result_status == 0 {
+        tx_context.gas_used()
+    } else {
+        tx_context.input_ref().gas_limit
+    }
+}
+
 /// The actual execution and the extraction/wrapping of results.
 fn execute_contract_instance_endpoint(
     contract_instance: Box<dyn CallableContract>,
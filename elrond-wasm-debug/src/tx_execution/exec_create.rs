@@ -21,11 +21,19 @@ pub fn sc_create(
 
     let state_rc = Rc::new(state);
     let tx_cache = TxCache::new(state_rc.clone());
+    let from = tx_input.from.clone();
+    let gas_limit = tx_input.gas_limit;
+    let gas_price = tx_input.gas_price;
     let (tx_result, new_address, blockchain_updates) =
         deploy_contract(tx_input, contract_path.to_vec(), tx_cache);
     let mut state = Rc::try_unwrap(state_rc).unwrap();
 
     blockchain_updates.apply(&mut state);
+    state.refund_tx_gas(
+        &from,
+        gas_limit.saturating_sub(tx_result.result_gas_used),
+        gas_price,
+    );
 
     (tx_result, new_address, state)
 }
@@ -0,0 +1,70 @@
+use crate::{
+    tx_mock::{async_gas_remaining, CrossShardCall, CrossShardCallStep, TxResult},
+    world_mock::BlockchainMock,
+};
+
+use super::{
+    execute_async_call, execute_async_callback, execute_promise_callback, transfer_to_new_account,
+};
+
+/// Executes one step of a cross-shard call.
+///
+/// Async calls and promises queue their callbacks, since these need to travel back to the caller shard.
+/// Once a callback runs, the remaining gas is refunded to the sender of the original transaction.
+pub fn execute_cross_shard_call(
+    call: CrossShardCall,
+    mut state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
+    state.return_in_flight_egld(&call);
+    let CrossShardCall {
+        tx_from,
+        tx_gas_price,
+        step,
+    } = call;
+
+    match step {
+        CrossShardCallStep::AsyncCall(async_data) => {
+            if !state.account_exists(&async_data.to) {
+                let state = transfer_to_new_account(&async_data, state);
+                return (TxResult::empty(), state);
+            }
+
+            let (async_result, mut state) = execute_async_call(&async_data, state);
+            state.queue_cross_shard_call(CrossShardCall::new(
+                tx_from,
+                tx_gas_price,
+                CrossShardCallStep::AsyncCallback(async_data, async_result.clone()),
+            ));
+            (async_result, state)
+        },
+        CrossShardCallStep::AsyncCallback(async_data, async_result) => {
+            let (callback_result, mut state) =
+                execute_async_callback(&async_data, &async_result, state);
+            let gas_remaining = async_gas_remaining(&async_data, &async_result, &callback_result);
+            state.refund_tx_gas(&tx_from, gas_remaining, tx_gas_price);
+            (callback_result, state)
+        },
+        CrossShardCallStep::Promise(promise) => {
+            if !state.account_exists(&promise.endpoint.to) {
+                let state = transfer_to_new_account(&promise.endpoint, state);
+                return (TxResult::empty(), state);
+            }
+
+            let (async_result, mut state) = execute_async_call(&promise.endpoint, state);
+            state.queue_cross_shard_call(CrossShardCall::new(
+                tx_from,
+                tx_gas_price,
+                CrossShardCallStep::PromiseCallback(promise, async_result.clone()),
+            ));
+            (async_result, state)
+        },
+        CrossShardCallStep::PromiseCallback(promise, async_result) => {
+            let (callback_result, mut state) =
+                execute_promise_callback(&promise.endpoint.from, &promise, &async_result, state);
+            let gas_remaining =
+                async_gas_remaining(&promise.endpoint, &async_result, &callback_result);
+            state.refund_tx_gas(&tx_from, gas_remaining, tx_gas_price);
+            (callback_result, state)
+        },
+    }
+}
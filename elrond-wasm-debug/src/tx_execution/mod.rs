@@ -2,10 +2,12 @@ mod builtin_function_mocks;
 mod exec_call;
 mod exec_contract_endpoint;
 mod exec_create;
+mod exec_cross_shard;
 mod exec_general_tx;
 
 pub use builtin_function_mocks::*;
 pub use exec_call::*;
 pub use exec_contract_endpoint::*;
 pub use exec_create::*;
+pub use exec_cross_shard::*;
 pub use exec_general_tx::*;
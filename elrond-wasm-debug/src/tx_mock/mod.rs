@@ -9,6 +9,7 @@ mod tx_callback_selector;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
+mod tx_cross_shard_call;
 mod tx_input;
 mod tx_input_util;
 mod tx_log;
@@ -25,6 +26,7 @@ pub use tx_callback_selector::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
+pub use tx_cross_shard_call::*;
 pub use tx_input::*;
 pub use tx_input_util::*;
 pub use tx_log::*;
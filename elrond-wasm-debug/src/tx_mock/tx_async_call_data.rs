@@ -18,6 +18,8 @@ pub struct AsyncCallTxData {
     pub endpoint_name: Vec<u8>,
     pub arguments: Vec<Vec<u8>>,
     pub tx_hash: H256,
+    pub gas_limit: u64,
+    pub extra_gas_for_callback: u64,
 }
 
 pub fn async_call_tx_input(async_data: &AsyncCallTxData) -> TxInput {
@@ -28,12 +30,31 @@ pub fn async_call_tx_input(async_data: &AsyncCallTxData) -> TxInput {
         esdt_values: Vec::new(),
         func_name: async_data.endpoint_name.clone(),
         args: async_data.arguments.clone(),
-        gas_limit: 1000,
+        gas_limit: async_data.gas_limit,
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
+        promise_callback_closure_data: Vec::new(),
     }
 }
 
+/// The callback receives the gas reserved for it, plus whatever the async call did not use.
+fn callback_gas_limit(async_data: &AsyncCallTxData, async_result: &TxResult) -> u64 {
+    async_data
+        .gas_limit
+        .saturating_sub(async_result.result_gas_used)
+        .saturating_add(async_data.extra_gas_for_callback)
+}
+
+/// The gas left after both the async call and its callback have finished.
+/// It goes back to the original caller.
+pub fn async_gas_remaining(
+    async_data: &AsyncCallTxData,
+    async_result: &TxResult,
+    callback_result: &TxResult,
+) -> u64 {
+    callback_gas_limit(async_data, async_result).saturating_sub(callback_result.result_gas_used)
+}
+
 fn result_status_bytes(result_status: u64) -> Vec<u8> {
     if result_status == 0 {
         vec![0x00]
@@ -56,9 +77,10 @@ pub fn async_callback_tx_input(async_data: &AsyncCallTxData, async_result: &TxRe
         esdt_values: Vec::new(),
         func_name: b"callBack".to_vec(),
         args,
-        gas_limit: 1000,
+        gas_limit: callback_gas_limit(async_data, async_result),
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
+        promise_callback_closure_data: Vec::new(),
     }
 }
 
@@ -85,9 +107,10 @@ pub fn async_promise_tx_input(
         esdt_values: Vec::new(),
         func_name: callback,
         args,
-        gas_limit: 1000,
+        gas_limit: callback_gas_limit(&promise.endpoint, async_result),
         gas_price: 0,
         tx_hash: promise.endpoint.tx_hash.clone(),
+        promise_callback_closure_data: promise.callback_closure.clone(),
     }
 }
 
@@ -22,7 +22,9 @@ impl TxCacheSource for TxCache {
 
 impl TxCacheSource for BlockchainMock {
     fn load_account(&self, address: &Address) -> Option<AccountData> {
-        self.accounts.get(address).map(AccountData::clone)
+        self.accounts
+            .get(address)
+            .map(|account| AccountData::clone(account))
     }
 
     fn blockchain_ref(&self) -> &BlockchainMock {
@@ -5,4 +5,5 @@ pub struct Promise {
     pub endpoint: AsyncCallTxData,
     pub success_callback: &'static [u8],
     pub error_callback: &'static [u8],
+    pub callback_closure: Vec<u8>,
 }
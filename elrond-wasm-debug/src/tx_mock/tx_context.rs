@@ -1,9 +1,9 @@
 use crate::{
     num_bigint::BigUint,
-    world_mock::{AccountData, AccountEsdt, BlockchainMock},
+    world_mock::{AccountData, AccountEsdt, BlockchainMock, CoverageRecorder, GasSchedule},
 };
 use alloc::vec::Vec;
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use elrond_wasm::types::{heap::Address, LockableStaticBuffer};
 use num_traits::Zero;
 use std::{
@@ -13,7 +13,8 @@ use std::{
 };
 
 use super::{
-    BlockchainRng, BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxResult, TxStaticVars,
+    BlockchainRng, BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxPanic, TxResult,
+    TxStaticVars,
 };
 
 #[derive(Debug)]
@@ -26,6 +27,8 @@ pub struct TxContext {
     pub tx_result_cell: RefCell<TxResult>,
     pub b_rng: RefCell<BlockchainRng>,
     pub printed_messages: RefCell<Vec<String>>,
+    pub gas_used_cell: Cell<u64>,
+    pub direct_endpoint_call_recorded: Cell<bool>,
 }
 
 impl TxContext {
@@ -40,6 +43,8 @@ impl TxContext {
             tx_result_cell: RefCell::new(TxResult::empty()),
             b_rng,
             printed_messages: RefCell::new(Vec::new()),
+            gas_used_cell: Cell::new(0),
+            direct_endpoint_call_recorded: Cell::new(false),
         }
     }
 
@@ -68,6 +73,7 @@ impl TxContext {
             gas_limit: 0,
             gas_price: 0,
             tx_hash: b"dummy...........................".into(),
+            promise_callback_closure_data: Vec::new(),
         };
 
         let b_rng = RefCell::new(BlockchainRng::new(&tx_input, &tx_cache));
@@ -80,6 +86,8 @@ impl TxContext {
             tx_result_cell: RefCell::new(TxResult::empty()),
             b_rng,
             printed_messages: RefCell::new(Vec::new()),
+            gas_used_cell: Cell::new(0),
+            direct_endpoint_call_recorded: Cell::new(false),
         }
     }
 
@@ -147,6 +155,72 @@ impl TxContext {
         self.b_rng.borrow_mut()
     }
 
+    pub fn gas_schedule(&self) -> Option<&GasSchedule> {
+        self.blockchain_ref().gas_schedule.as_ref()
+    }
+
+    pub fn gas_used(&self) -> u64 {
+        self.gas_used_cell.get()
+    }
+
+    pub fn gas_left(&self) -> u64 {
+        self.tx_input_box.gas_limit.saturating_sub(self.gas_used())
+    }
+
+    /// Consumes gas according to the gas schedule.
+    /// Does nothing if gas metering is not enabled.
+    pub fn use_gas<F>(&self, cost_fn: F)
+    where
+        F: FnOnce(&GasSchedule) -> u64,
+    {
+        if let Some(gas_schedule) = self.gas_schedule() {
+            self.use_gas_raw(cost_fn(gas_schedule));
+        }
+    }
+
+    /// Consumes the given amount of gas, and kills the execution if the gas limit is exceeded.
+    /// Does nothing if gas metering is not enabled.
+    pub fn use_gas_raw(&self, gas: u64) {
+        if self.gas_schedule().is_none() {
+            return;
+        }
+
+        let gas_used = self.gas_used().saturating_add(gas);
+        if gas_used > self.tx_input_box.gas_limit {
+            std::panic::panic_any(TxPanic::out_of_gas());
+        }
+        self.gas_used_cell.set(gas_used);
+    }
+
+    /// Records coverage data for the contract deployed at the given address.
+    /// Does nothing if coverage is not enabled, or if there is no contract at that address.
+    pub fn record_coverage<F>(&self, address: &Address, f: F)
+    where
+        F: FnOnce(&mut CoverageRecorder, &[u8]),
+    {
+        if let Some(coverage) = &self.blockchain_ref().coverage {
+            self.with_account(address, |account| {
+                if let Some(contract_code) = &account.contract_path {
+                    f(&mut coverage.borrow_mut(), contract_code.as_slice());
+                }
+            });
+        }
+    }
+
+    /// Endpoints called by name are recorded when they get dispatched.
+    /// This only records the endpoints called directly from Rust tests, e.g. in `execute_tx`,
+    /// and only the first one, not the endpoints it calls in turn as plain methods.
+    pub fn record_direct_endpoint_call(&self, endpoint_name: &str) {
+        if !self.input_ref().func_name.is_empty()
+            || self.direct_endpoint_call_recorded.replace(true)
+        {
+            return;
+        }
+        self.record_coverage(&self.input_ref().to, |coverage, contract_code| {
+            coverage.record_endpoint_call(contract_code, endpoint_name.as_bytes())
+        });
+    }
+
     pub fn create_new_contract(
         &self,
         new_address: &Address,
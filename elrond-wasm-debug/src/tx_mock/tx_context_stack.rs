@@ -17,6 +17,14 @@ impl TxContextStack {
         })
     }
 
+    /// Same as `static_peek`, but does not panic when there is no transaction context.
+    pub fn static_try_peek() -> Option<Rc<TxContext>> {
+        API_STACK.with(|cell| {
+            let stack = cell.borrow();
+            stack.0.last().cloned()
+        })
+    }
+
     pub fn static_push(tx_context_rc: Rc<TxContext>) {
         API_STACK.with(|cell| {
             let mut stack = cell.borrow_mut();
@@ -0,0 +1,45 @@
+use elrond_wasm::types::heap::Address;
+
+use crate::num_bigint::BigUint;
+
+use super::{AsyncCallTxData, Promise, TxResult};
+
+/// A call that crosses shards, so it only gets executed in a later (simulated) block.
+#[derive(Clone, Debug)]
+pub struct CrossShardCall {
+    /// The sender of the original transaction. Unused gas is refunded to it, once the callback finishes.
+    pub tx_from: Address,
+    pub tx_gas_price: u64,
+    pub step: CrossShardCallStep,
+}
+
+#[derive(Clone, Debug)]
+pub enum CrossShardCallStep {
+    AsyncCall(AsyncCallTxData),
+    AsyncCallback(AsyncCallTxData, TxResult),
+    Promise(Promise),
+    PromiseCallback(Promise, TxResult),
+}
+
+impl CrossShardCall {
+    pub fn new(tx_from: Address, tx_gas_price: u64, step: CrossShardCallStep) -> Self {
+        CrossShardCall {
+            tx_from,
+            tx_gas_price,
+            step,
+        }
+    }
+
+    /// The EGLD that leaves the sender when the call is queued and only arrives at the destination on delivery.
+    pub fn in_flight_egld(&self) -> Option<(&Address, &BigUint)> {
+        match &self.step {
+            CrossShardCallStep::AsyncCall(async_data) => {
+                Some((&async_data.from, &async_data.call_value))
+            },
+            CrossShardCallStep::Promise(promise) => {
+                Some((&promise.endpoint.from, &promise.endpoint.call_value))
+            },
+            _ => None,
+        }
+    }
+}
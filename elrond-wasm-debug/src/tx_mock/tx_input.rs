@@ -15,6 +15,9 @@ pub struct TxInput {
     pub gas_limit: u64,
     pub gas_price: u64,
     pub tx_hash: H256,
+
+    /// Only set in promise callbacks.
+    pub promise_callback_closure_data: Vec<u8>,
 }
 
 impl fmt::Display for TxInput {
@@ -45,6 +48,7 @@ impl TxInput {
             gas_limit: 0,
             gas_price: 0,
             tx_hash: H256::zero(),
+            promise_callback_closure_data: Vec::new(),
         }
     }
 }
@@ -2,3 +2,12 @@ pub struct TxPanic {
     pub status: u64,
     pub message: String,
 }
+
+impl TxPanic {
+    pub fn out_of_gas() -> Self {
+        TxPanic {
+            status: 5,
+            message: "not enough gas".to_string(),
+        }
+    }
+}
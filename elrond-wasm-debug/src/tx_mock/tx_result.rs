@@ -12,6 +12,7 @@ pub struct TxResult {
     pub result_values: Vec<Vec<u8>>,
     pub result_logs: Vec<TxLog>,
     pub result_calls: TxResultCalls,
+    pub result_gas_used: u64,
 }
 
 impl TxResult {
@@ -22,6 +23,7 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            result_gas_used: 0,
         }
     }
 
@@ -36,6 +38,7 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            result_gas_used: 0,
         }
     }
 
@@ -47,6 +50,7 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            result_gas_used: 0,
         }
     }
 
@@ -61,6 +65,7 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            result_gas_used: 0,
         }
     }
 
@@ -120,8 +125,8 @@ impl fmt::Display for TxResult {
             .collect();
         write!(
             f,
-            "TxResult {{\n\tresult_status: {},\n\tresult_values:{:?}\n}}",
-            self.result_status, results_hex
+            "TxResult {{\n\tresult_status: {},\n\tresult_values:{:?},\n\tresult_gas_used: {}\n}}",
+            self.result_status, results_hex, self.result_gas_used
         )
     }
 }
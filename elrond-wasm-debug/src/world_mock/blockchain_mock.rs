@@ -1,18 +1,23 @@
 use crate::{
-    mandos_system::model::Scenario, num_bigint::BigUint, tx_mock::BlockchainUpdate, ContractMap,
+    mandos_system::model::Scenario,
+    num_bigint::BigUint,
+    tx_mock::{BlockchainUpdate, CrossShardCall},
+    ContractMap,
 };
 use elrond_wasm::types::heap::Address;
 use mandos::{interpret_trait::InterpreterContext, value_interpreter::interpret_string};
 use num_traits::Zero;
-use std::{collections::HashMap, path::PathBuf};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
 
-use super::{AccountData, BlockInfo};
+use super::{
+    AccountData, BlockInfo, BlockchainSnapshot, CoverageRecorder, GasSchedule, ShardConfig,
+};
 
 const ELROND_REWARD_KEY: &[u8] = b"ELRONDreward";
 
 #[derive(Debug)]
 pub struct BlockchainMock {
-    pub accounts: HashMap<Address, AccountData>,
+    pub accounts: HashMap<Address, Rc<AccountData>>,
     pub addr_to_mandos_string_map: HashMap<Address, String>,
     pub new_addresses: HashMap<(Address, u64), Address>,
     pub previous_block_info: BlockInfo,
@@ -20,6 +25,11 @@ pub struct BlockchainMock {
     pub contract_map: ContractMap,
     pub current_dir: PathBuf,
     pub mandos_trace: Scenario,
+    pub gas_schedule: Option<GasSchedule>,
+    pub shard_config: Option<ShardConfig>,
+    pub pending_cross_shard_calls: Vec<CrossShardCall>,
+    pub snapshots: Vec<BlockchainSnapshot>,
+    pub coverage: Option<Rc<RefCell<CoverageRecorder>>>,
 }
 
 impl BlockchainMock {
@@ -33,6 +43,11 @@ impl BlockchainMock {
             contract_map: ContractMap::default(),
             current_dir: std::env::current_dir().unwrap(),
             mandos_trace: Scenario::default(),
+            gas_schedule: None,
+            shard_config: None,
+            pending_cross_shard_calls: Vec::new(),
+            snapshots: Vec::new(),
+            coverage: None,
         }
     }
 }
@@ -48,6 +63,26 @@ impl BlockchainMock {
         self.accounts.contains_key(address)
     }
 
+    pub fn account(&self, address: &Address) -> Option<&AccountData> {
+        self.accounts.get(address).map(Rc::as_ref)
+    }
+
+    /// Accounts can be shared with snapshots and forks, they only get copied here, on first write.
+    pub fn account_mut(&mut self, address: &Address) -> Option<&mut AccountData> {
+        self.accounts.get_mut(address).map(Rc::make_mut)
+    }
+
+    /// Enables gas metering, with the given gas schedule.
+    ///
+    /// Without it, API calls are free and the entire gas limit is always consumed.
+    pub fn set_gas_schedule(&mut self, gas_schedule: GasSchedule) {
+        self.gas_schedule = Some(gas_schedule);
+    }
+
+    pub fn is_gas_metering_enabled(&self) -> bool {
+        self.gas_schedule.is_some()
+    }
+
     pub fn contains_contract(&self, contract_path_expr: &str) -> bool {
         let contract_bytes = interpret_string(
             contract_path_expr,
@@ -62,7 +97,7 @@ impl BlockchainMock {
     }
 
     pub fn increase_account_nonce(&mut self, address: &Address) {
-        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
+        let account = self.account_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
                 &std::str::from_utf8(address.as_ref()).unwrap()
@@ -72,7 +107,7 @@ impl BlockchainMock {
     }
 
     pub fn subtract_tx_gas(&mut self, address: &Address, gas_limit: u64, gas_price: u64) {
-        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
+        let account = self.account_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
                 &std::str::from_utf8(address.as_ref()).unwrap()
@@ -86,8 +121,24 @@ impl BlockchainMock {
         account.egld_balance -= &gas_cost;
     }
 
+    /// Credits back the gas that was paid upfront, but not used.
+    /// Only happens when gas metering is enabled.
+    pub fn refund_tx_gas(&mut self, address: &Address, gas_remaining: u64, gas_price: u64) {
+        if !self.is_gas_metering_enabled() {
+            return;
+        }
+
+        let account = self.account_mut(address).unwrap_or_else(|| {
+            panic!(
+                "Account not found: {}",
+                &std::str::from_utf8(address.as_ref()).unwrap()
+            )
+        });
+        account.egld_balance += BigUint::from(gas_remaining) * BigUint::from(gas_price);
+    }
+
     pub fn increase_validator_reward(&mut self, address: &Address, amount: &BigUint) {
-        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
+        let account = self.account_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
                 &std::str::from_utf8(address.as_ref()).unwrap()
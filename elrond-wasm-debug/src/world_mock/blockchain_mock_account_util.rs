@@ -1,11 +1,11 @@
 use alloc::vec::Vec;
 use elrond_wasm::types::heap::Address;
 
-use std::{collections::HashMap, fmt::Write};
+use std::{collections::HashMap, fmt::Write, rc::Rc};
 
 use crate::address_hex;
 
-use super::AccountData;
+use super::{AccountData, StorageDecoder};
 
 const SC_ADDRESS_NUM_LEADING_ZEROS: u8 = 8;
 
@@ -14,7 +14,7 @@ use super::BlockchainMock;
 impl BlockchainMock {
     pub fn add_account(&mut self, acct: AccountData) {
         let address = acct.address.clone();
-        self.accounts.insert(address.clone(), acct);
+        self.accounts.insert(address.clone(), Rc::new(acct));
         self.add_addr_mandos_string(address);
     }
 
@@ -37,7 +37,11 @@ impl BlockchainMock {
             self.add_addr_mandos_string(addr.clone());
         }
 
-        self.accounts.extend(accounts.into_iter());
+        self.accounts.extend(
+            accounts
+                .into_iter()
+                .map(|(address, account)| (address, Rc::new(account))),
+        );
     }
 
     pub fn print_accounts(&self) {
@@ -48,6 +52,17 @@ impl BlockchainMock {
         println!("Accounts: {}", &accounts_buf);
     }
 
+    /// The storage of a contract, decoded using the storage section of its ABI.
+    ///
+    /// Returns `None` if the account is not a contract, or if no ABI was registered for its code.
+    pub fn decoded_storage(&self, address: &Address) -> Option<Vec<String>> {
+        let account = self.accounts.get(address)?;
+        let abi = self
+            .contract_map
+            .contract_abi(account.contract_path.as_ref()?)?;
+        Some(StorageDecoder::new(abi).decode_storage(&account.storage))
+    }
+
     pub fn put_new_address(
         &mut self,
         creator_address: Address,
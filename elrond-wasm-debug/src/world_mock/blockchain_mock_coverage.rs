@@ -0,0 +1,142 @@
+use elrond_wasm::abi::ContractAbi;
+use serde::Serialize;
+use std::{cell::RefCell, fs, io, path::Path, rc::Rc, sync::Mutex};
+
+use super::{BlockchainMock, ContractCoverageReport, CoverageRecorder};
+
+/// Tests running in parallel can write to the same report files.
+static COVERAGE_FILE_LOCK: Mutex<()> = Mutex::new(());
+
+impl BlockchainMock {
+    /// Starts recording the endpoints called and the storage keys touched by each contract.
+    pub fn enable_coverage(&mut self) {
+        if self.coverage.is_none() {
+            self.coverage = Some(Rc::new(RefCell::new(CoverageRecorder::default())));
+        }
+    }
+
+    pub fn is_coverage_enabled(&self) -> bool {
+        self.coverage.is_some()
+    }
+
+    /// One report for each contract that was either called, or had its ABI registered.
+    ///
+    /// The ABI is used to also list the endpoints that were never called.
+    pub fn coverage_reports(&self) -> Vec<ContractCoverageReport> {
+        let coverage = self
+            .coverage
+            .as_ref()
+            .expect("coverage not enabled")
+            .borrow();
+
+        let mut contract_codes: Vec<&Vec<u8>> = coverage
+            .contracts
+            .keys()
+            .chain(self.contract_map.contract_abis().keys())
+            .collect();
+        contract_codes.sort();
+        contract_codes.dedup();
+
+        let mut reports: Vec<ContractCoverageReport> = contract_codes
+            .into_iter()
+            .map(|contract_code| {
+                let opt_abi = self.contract_map.contract_abi(contract_code);
+                ContractCoverageReport::new(
+                    self.contract_name_for_coverage(contract_code, opt_abi),
+                    &coverage
+                        .contracts
+                        .get(contract_code)
+                        .cloned()
+                        .unwrap_or_default(),
+                    opt_abi,
+                )
+            })
+            .collect();
+        reports.sort_by(|a, b| a.contract_name.cmp(&b.contract_name));
+        reports
+    }
+
+    /// Writes a `<contract>.coverage.json` and a human readable `<contract>.coverage.txt` file
+    /// for each contract in the given directory.
+    ///
+    /// Reports already in the directory get merged with the new ones,
+    /// so all tests in a run can contribute to the same report.
+    /// Clear the directory to start over.
+    pub fn write_coverage_reports<P: AsRef<Path>>(&self, dir_path: P) {
+        let dir_path = self.current_dir.join(dir_path);
+        self.try_write_coverage_reports(&dir_path)
+            .unwrap_or_else(|err| {
+                panic!("cannot write coverage reports to {:?}: {}", dir_path, err)
+            });
+    }
+
+    fn try_write_coverage_reports(&self, dir_path: &Path) -> io::Result<()> {
+        let _lock = COVERAGE_FILE_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        fs::create_dir_all(dir_path)?;
+
+        for mut report in self.coverage_reports() {
+            let file_stem = file_stem_for_contract_name(&report.contract_name);
+            let json_path = dir_path.join(format!("{}.coverage.json", file_stem));
+            if let Ok(existing_json) = fs::read_to_string(&json_path) {
+                let existing_report: ContractCoverageReport = serde_json::from_str(&existing_json)
+                    .map_err(|err| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid coverage file {:?}: {}", json_path, err),
+                        )
+                    })?;
+                report.merge(existing_report);
+            }
+
+            fs::write(&json_path, report_to_json(&report))?;
+            fs::write(
+                dir_path.join(format!("{}.coverage.txt", file_stem)),
+                report.to_string(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn contract_name_for_coverage(
+        &self,
+        contract_code: &[u8],
+        opt_abi: Option<&ContractAbi>,
+    ) -> String {
+        if let Some(abi) = opt_abi {
+            return abi.name.to_string();
+        }
+        if let Some(file_stem) = self
+            .contract_map
+            .contract_path(contract_code)
+            .and_then(Path::file_stem)
+        {
+            return file_stem.to_string_lossy().into_owned();
+        }
+        let prefix_len = contract_code.len().min(8);
+        format!("0x{}", hex::encode(&contract_code[..prefix_len]))
+    }
+}
+
+fn report_to_json(report: &ContractCoverageReport) -> String {
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut ser = serde_json::Serializer::with_formatter(Vec::new(), formatter);
+    report.serialize(&mut ser).unwrap();
+    let mut json = String::from_utf8(ser.into_inner()).unwrap();
+    json.push('\n');
+    json
+}
+
+fn file_stem_for_contract_name(contract_name: &str) -> String {
+    contract_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
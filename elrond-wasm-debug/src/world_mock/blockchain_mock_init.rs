@@ -1,12 +1,17 @@
 use std::path::{Path, PathBuf};
 
-use elrond_wasm::contract_base::{CallableContract, CallableContractBuilder};
+use elrond_wasm::{
+    abi::ContractAbi,
+    contract_base::{CallableContract, CallableContractBuilder},
+};
 use mandos::{interpret_trait::InterpreterContext, value_interpreter::interpret_string};
 
 use crate::DebugApi;
 
 use super::BlockchainMock;
 
+pub(crate) const FILE_PREFIX: &str = "file:";
+
 fn is_target(path_buf: &Path) -> bool {
     path_buf.file_name().unwrap() == "target"
 }
@@ -43,6 +48,10 @@ impl BlockchainMock {
     ) {
         let contract_bytes = interpret_string(expression, &self.interpreter_context());
         // panic!("{}", String::from_utf8(contract_bytes).unwrap());
+        if let Some(file_path) = expression.strip_prefix(FILE_PREFIX) {
+            self.contract_map
+                .register_contract_path(contract_bytes.clone(), self.current_dir.join(file_path));
+        }
         self.contract_map
             .register_contract(contract_bytes, new_contract_obj);
     }
@@ -65,4 +74,17 @@ impl BlockchainMock {
             contract_builder.new_contract_obj::<elrond_wasm::api::ExternalViewApi<DebugApi>>(),
         )
     }
+
+    /// Provides the ABI of a registered contract.
+    ///
+    /// It is used to list the endpoints that were never called in coverage reports,
+    /// and to decode the contract storage when dumping the state.
+    pub fn register_contract_abi(&mut self, contract_path_expr: &str, abi: ContractAbi) {
+        let contract_bytes = interpret_string(contract_path_expr, &self.interpreter_context());
+        self.contract_map.register_contract_abi(contract_bytes, abi);
+    }
+
+    pub fn register_contract_abi_raw(&mut self, contract_bytes: Vec<u8>, abi: ContractAbi) {
+        self.contract_map.register_contract_abi(contract_bytes, abi);
+    }
 }
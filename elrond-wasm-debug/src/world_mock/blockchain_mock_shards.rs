@@ -0,0 +1,176 @@
+use elrond_wasm::types::heap::Address;
+use std::collections::HashMap;
+
+use crate::{
+    num_bigint::BigUint,
+    tx_execution::execute_cross_shard_call,
+    tx_mock::{CrossShardCall, TxResult},
+};
+
+use super::{is_smart_contract_address, BlockchainMock};
+
+/// The shard id of the metachain, where the system smart contracts are.
+pub const METACHAIN_SHARD_ID: u32 = u32::MAX;
+
+/// System smart contract addresses have these bytes, after the VM type, set to zero.
+const METACHAIN_SC_ZERO_BYTES: core::ops::Range<usize> = 10..15;
+
+/// System smart contract addresses end in this byte.
+const METACHAIN_SC_LAST_BYTE: u8 = 255;
+
+/// Splits the accounts of the mock into several shards.
+///
+/// Calls between accounts in different shards are no longer executed right away,
+/// they are only delivered when the next block gets simulated.
+#[derive(Clone, Debug, Default)]
+pub struct ShardConfig {
+    pub num_shards: u32,
+
+    /// Explicit shard assignments. All other accounts are placed in shards the same way the real protocol does it,
+    /// based on the last byte of the address.
+    pub account_shards: HashMap<Address, u32>,
+}
+
+impl ShardConfig {
+    pub fn new(num_shards: u32) -> Self {
+        assert!(num_shards > 0, "the number of shards must be positive");
+        ShardConfig {
+            num_shards,
+            account_shards: HashMap::new(),
+        }
+    }
+
+    pub fn shard_of_address(&self, address: &Address) -> u32 {
+        if let Some(shard) = self.account_shards.get(address) {
+            return *shard;
+        }
+
+        compute_shard_of_address(address, self.num_shards)
+    }
+}
+
+/// Same algorithm as the one used by the protocol's multi-shard coordinator.
+///
+/// System smart contracts, like the ESDT system smart contract, are on the metachain, whatever the number of shards.
+pub fn compute_shard_of_address(address: &Address, num_shards: u32) -> u32 {
+    if is_metachain_smart_contract_address(address) {
+        return METACHAIN_SHARD_ID;
+    }
+    if num_shards <= 1 {
+        return 0;
+    }
+
+    let n = u32::BITS - (num_shards - 1).leading_zeros();
+    let mask_high = (1u32 << n) - 1;
+    let mask_low = (1u32 << (n - 1)) - 1;
+    let last_byte = *address.as_array().last().unwrap() as u32;
+    let shard = last_byte & mask_high;
+    if shard < num_shards {
+        shard
+    } else {
+        last_byte & mask_low
+    }
+}
+
+fn is_metachain_smart_contract_address(address: &Address) -> bool {
+    let bytes = address.as_bytes();
+    is_smart_contract_address(address)
+        && bytes[METACHAIN_SC_ZERO_BYTES].iter().all(|byte| *byte == 0)
+        && bytes[bytes.len() - 1] == METACHAIN_SC_LAST_BYTE
+}
+
+impl BlockchainMock {
+    /// Splits the accounts into the given number of shards.
+    ///
+    /// From now on, async calls and promises between accounts in different shards
+    /// only get executed when calling `deliver_cross_shard_calls`.
+    pub fn enable_sharding(&mut self, num_shards: u32) {
+        self.shard_config = Some(ShardConfig::new(num_shards));
+    }
+
+    pub fn is_sharding_enabled(&self) -> bool {
+        self.shard_config.is_some()
+    }
+
+    /// Overrides the shard of an account. `METACHAIN_SHARD_ID` is also accepted.
+    pub fn set_account_shard(&mut self, address: &Address, shard: u32) {
+        let shard_config = self
+            .shard_config
+            .as_mut()
+            .expect("sharding not enabled, call enable_sharding first");
+        assert!(
+            shard < shard_config.num_shards || shard == METACHAIN_SHARD_ID,
+            "shard {} out of range, only {} shards configured",
+            shard,
+            shard_config.num_shards
+        );
+        shard_config.account_shards.insert(address.clone(), shard);
+    }
+
+    /// Without sharding, all accounts are in shard 0.
+    pub fn get_shard_of_address(&self, address: &Address) -> u32 {
+        if let Some(shard_config) = &self.shard_config {
+            shard_config.shard_of_address(address)
+        } else {
+            0
+        }
+    }
+
+    pub fn is_cross_shard_call(&self, from: &Address, to: &Address) -> bool {
+        self.is_sharding_enabled()
+            && self.get_shard_of_address(from) != self.get_shard_of_address(to)
+    }
+
+    pub fn has_pending_cross_shard_calls(&self) -> bool {
+        !self.pending_cross_shard_calls.is_empty()
+    }
+
+    /// Holds a call until the next block.
+    ///
+    /// The EGLD value of the call leaves the sender right away. It is only credited to the receiver on delivery,
+    /// or returned to the sender if the call fails. ESDT transfers only happen on delivery.
+    pub fn queue_cross_shard_call(&mut self, call: CrossShardCall) {
+        if let Some((from, call_value)) = call.in_flight_egld() {
+            self.change_egld_balance_in_flight(from, call_value, false);
+        }
+        self.pending_cross_shard_calls.push(call);
+    }
+
+    pub(crate) fn return_in_flight_egld(&mut self, call: &CrossShardCall) {
+        if let Some((from, call_value)) = call.in_flight_egld() {
+            self.change_egld_balance_in_flight(from, call_value, true);
+        }
+    }
+
+    fn change_egld_balance_in_flight(&mut self, address: &Address, value: &BigUint, credit: bool) {
+        let account = self.account_mut(address).unwrap_or_else(|| {
+            panic!(
+                "Account not found: {}",
+                &std::str::from_utf8(address.as_ref()).unwrap()
+            )
+        });
+        if credit {
+            account.egld_balance += value;
+        } else {
+            assert!(
+                &account.egld_balance >= value,
+                "Not enough balance to send cross-shard call value"
+            );
+            account.egld_balance -= value;
+        }
+    }
+
+    /// Simulates a new block: executes all the cross-shard calls queued so far.
+    ///
+    /// Async calls executed now have their callbacks queued in turn, so they only run on the following call.
+    /// Returns the results of all executed calls and callbacks, in order.
+    pub fn deliver_cross_shard_calls(&mut self) -> Vec<TxResult> {
+        let pending = std::mem::take(&mut self.pending_cross_shard_calls);
+        let mut results = Vec::with_capacity(pending.len());
+        for call in pending {
+            let result = self.with_borrowed(|state| execute_cross_shard_call(call, state));
+            results.push(result);
+        }
+        results
+    }
+}
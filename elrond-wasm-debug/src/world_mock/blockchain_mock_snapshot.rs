@@ -0,0 +1,82 @@
+use elrond_wasm::types::heap::Address;
+use std::{collections::HashMap, rc::Rc};
+
+use crate::{mandos_system::model::Scenario, tx_mock::CrossShardCall};
+
+use super::{AccountData, BlockInfo, BlockchainMock, ShardConfig};
+
+/// Identifies a snapshot taken with `BlockchainMock::snapshot`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SnapshotId(usize);
+
+/// A saved copy of the world state.
+///
+/// Accounts are shared with the live state, an account is only copied when it is next modified.
+/// The registered contracts, the gas schedule and the mandos trace are not part of the snapshot.
+#[derive(Clone, Debug)]
+pub struct BlockchainSnapshot {
+    accounts: HashMap<Address, Rc<AccountData>>,
+    addr_to_mandos_string_map: HashMap<Address, String>,
+    new_addresses: HashMap<(Address, u64), Address>,
+    previous_block_info: BlockInfo,
+    current_block_info: BlockInfo,
+    shard_config: Option<ShardConfig>,
+    pending_cross_shard_calls: Vec<CrossShardCall>,
+}
+
+impl BlockchainMock {
+    /// Saves the current world state, so it can be restored any number of times later.
+    pub fn snapshot(&mut self) -> SnapshotId {
+        let snapshot = BlockchainSnapshot {
+            accounts: self.accounts.clone(),
+            addr_to_mandos_string_map: self.addr_to_mandos_string_map.clone(),
+            new_addresses: self.new_addresses.clone(),
+            previous_block_info: self.previous_block_info.clone(),
+            current_block_info: self.current_block_info.clone(),
+            shard_config: self.shard_config.clone(),
+            pending_cross_shard_calls: self.pending_cross_shard_calls.clone(),
+        };
+        self.snapshots.push(snapshot);
+        SnapshotId(self.snapshots.len() - 1)
+    }
+
+    /// Brings the world state back to what it was when the snapshot was taken.
+    ///
+    /// The snapshot is kept, so it can be restored again.
+    pub fn restore(&mut self, snapshot_id: SnapshotId) {
+        let snapshot = self
+            .snapshots
+            .get(snapshot_id.0)
+            .unwrap_or_else(|| panic!("unknown snapshot: {:?}", snapshot_id))
+            .clone();
+        self.accounts = snapshot.accounts;
+        self.addr_to_mandos_string_map = snapshot.addr_to_mandos_string_map;
+        self.new_addresses = snapshot.new_addresses;
+        self.previous_block_info = snapshot.previous_block_info;
+        self.current_block_info = snapshot.current_block_info;
+        self.shard_config = snapshot.shard_config;
+        self.pending_cross_shard_calls = snapshot.pending_cross_shard_calls;
+    }
+
+    /// Creates an independent copy of the blockchain, including registered contracts and snapshots.
+    ///
+    /// Accounts are shared between the two until modified, so forking is cheap even for large states.
+    /// The fork starts with an empty mandos trace, coverage data is collected together with the original.
+    pub fn fork(&self) -> BlockchainMock {
+        BlockchainMock {
+            accounts: self.accounts.clone(),
+            addr_to_mandos_string_map: self.addr_to_mandos_string_map.clone(),
+            new_addresses: self.new_addresses.clone(),
+            previous_block_info: self.previous_block_info.clone(),
+            current_block_info: self.current_block_info.clone(),
+            contract_map: self.contract_map.clone(),
+            current_dir: self.current_dir.clone(),
+            mandos_trace: Scenario::default(),
+            gas_schedule: self.gas_schedule.clone(),
+            shard_config: self.shard_config.clone(),
+            pending_cross_shard_calls: self.pending_cross_shard_calls.clone(),
+            snapshots: self.snapshots.clone(),
+            coverage: self.coverage.clone(),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use elrond_wasm::types::heap::Address;
+use mandos::{
+    serde_raw::{AccountRaw, NewAddressRaw, ScenarioRaw, StepRaw, ValueSubTree},
+    value_interpreter::interpret_string,
+};
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{
+    mandos_system::{model::Step, parse_scenario},
+    testing_framework::raw_converter::{
+        account_as_raw, block_info_as_raw, bytes_to_hex, u64_as_raw,
+    },
+};
+
+use super::{
+    address_as_mandos_string, blockchain_mock_init::FILE_PREFIX, AccountData, BlockchainMock,
+};
+
+impl BlockchainMock {
+    /// Saves the entire world state as a mandos scenario, consisting of a single `setState` step.
+    ///
+    /// Contract code is referenced by path, relative to the exported file,
+    /// so the file can be loaded by any mandos runner, including the Go VM one.
+    /// The path is relative to the current directory of the mock.
+    pub fn export_state<P: AsRef<Path>>(&self, path: P) {
+        let file_path = normalize_path(self.current_dir.join(path));
+        let file_dir = file_path.parent().unwrap();
+
+        let mut accounts_raw = BTreeMap::new();
+        for (address, account) in self.accounts.iter() {
+            accounts_raw.insert(
+                self.address_as_export_string(address),
+                self.account_as_export_raw(account, file_dir),
+            );
+        }
+
+        let mut new_addresses: Vec<_> = self.new_addresses.iter().collect();
+        new_addresses.sort_by_key(|((creator_address, creator_nonce), _)| {
+            (creator_address.as_array(), *creator_nonce)
+        });
+        let new_addresses_raw = new_addresses
+            .into_iter()
+            .map(
+                |((creator_address, creator_nonce), new_address)| NewAddressRaw {
+                    creator_address: ValueSubTree::Str(
+                        self.address_as_export_string(creator_address),
+                    ),
+                    creator_nonce: u64_as_raw(*creator_nonce),
+                    new_address: ValueSubTree::Str(self.address_as_export_string(new_address)),
+                },
+            )
+            .collect();
+
+        let scenario_raw = ScenarioRaw {
+            check_gas: None,
+            comment: None,
+            gas_schedule: None,
+            name: None,
+            steps: vec![StepRaw::SetState {
+                accounts: accounts_raw,
+                block_hashes: Vec::new(),
+                new_addresses: new_addresses_raw,
+                comment: None,
+                tags: Vec::new(),
+                current_block_info: Some(block_info_as_raw(&self.current_block_info)),
+                previous_block_info: Some(block_info_as_raw(&self.previous_block_info)),
+            }],
+        };
+        scenario_raw.save_to_file(file_path);
+    }
+
+    /// Loads a world state saved with `export_state`, or any other scenario consisting only of `setState` steps.
+    ///
+    /// Accounts that are not in the file are left unchanged.
+    /// The path is relative to the current directory of the mock.
+    pub fn import_state<P: AsRef<Path>>(&mut self, path: P) {
+        let file_path = self.current_dir.join(path);
+        let scenario = parse_scenario(&file_path);
+        for step in scenario.steps {
+            if let Step::SetState(set_state_step) = step {
+                self.mandos_set_state(set_state_step);
+            } else {
+                panic!(
+                    "state file {:?} can only contain setState steps",
+                    file_path.as_path()
+                );
+            }
+        }
+    }
+
+    /// Uses the readable form of the address only if it interprets back to the same address.
+    fn address_as_export_string(&self, address: &Address) -> String {
+        let readable = self
+            .addr_to_mandos_string_map
+            .get(address)
+            .cloned()
+            .unwrap_or_else(|| address_as_mandos_string(address));
+        if interpret_string(&readable, &self.interpreter_context()) == address.as_bytes() {
+            readable
+        } else {
+            bytes_to_hex(address.as_bytes())
+        }
+    }
+
+    fn account_as_export_raw(&self, account: &AccountData, file_dir: &Path) -> AccountRaw {
+        let mut account_raw = account_as_raw(&AccountData {
+            contract_path: None,
+            ..account.clone()
+        });
+        account_raw.owner = account
+            .contract_owner
+            .as_ref()
+            .map(|owner| ValueSubTree::Str(self.address_as_export_string(owner)));
+        account_raw.code = account.contract_path.as_ref().map(|contract_bytes| {
+            if let Some(contract_path) = self.contract_map.contract_path(contract_bytes) {
+                let relative_path =
+                    relative_path(&normalize_path(contract_path.to_path_buf()), file_dir);
+                ValueSubTree::Str(format!("{}{}", FILE_PREFIX, relative_path.display()))
+            } else {
+                ValueSubTree::Str(bytes_to_hex(contract_bytes))
+            }
+        });
+        account_raw
+    }
+}
+
+/// Resolves `..` components, without touching the file system.
+fn normalize_path(path: PathBuf) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        if component == Component::ParentDir && normalized.pop() {
+            continue;
+        }
+        normalized.push(component);
+    }
+    normalized
+}
+
+fn relative_path(target: &Path, base_dir: &Path) -> PathBuf {
+    let target_components: Vec<_> = target.components().collect();
+    let base_components: Vec<_> = base_dir.components().collect();
+    let common_len = target_components
+        .iter()
+        .zip(base_components.iter())
+        .take_while(|(t, b)| t == b)
+        .count();
+
+    let mut result = PathBuf::new();
+    for _ in common_len..base_components.len() {
+        result.push(Component::ParentDir);
+    }
+    for component in &target_components[common_len..] {
+        result.push(component);
+    }
+    result
+}
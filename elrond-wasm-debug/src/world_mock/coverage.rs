@@ -0,0 +1,185 @@
+use elrond_wasm::abi::ContractAbi;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+};
+
+const CALLBACK_ENDPOINT_NAME: &str = "callBack";
+
+/// What a contract touched during the transactions executed while coverage was enabled.
+#[derive(Clone, Debug, Default)]
+pub struct ContractCoverage {
+    pub endpoint_calls: BTreeMap<String, u64>,
+    pub storage_reads: BTreeSet<Vec<u8>>,
+    pub storage_writes: BTreeSet<Vec<u8>>,
+}
+
+/// Collects coverage data for all contracts, indexed by contract code.
+#[derive(Debug, Default)]
+pub struct CoverageRecorder {
+    pub contracts: HashMap<Vec<u8>, ContractCoverage>,
+}
+
+impl CoverageRecorder {
+    pub fn record_endpoint_call(&mut self, contract_code: &[u8], endpoint_name: &[u8]) {
+        let endpoint_name = String::from_utf8_lossy(endpoint_name).into_owned();
+        *self
+            .contract_mut(contract_code)
+            .endpoint_calls
+            .entry(endpoint_name)
+            .or_default() += 1;
+    }
+
+    pub fn record_storage_read(&mut self, contract_code: &[u8], key: &[u8]) {
+        insert_key(&mut self.contract_mut(contract_code).storage_reads, key);
+    }
+
+    pub fn record_storage_write(&mut self, contract_code: &[u8], key: &[u8]) {
+        insert_key(&mut self.contract_mut(contract_code).storage_writes, key);
+    }
+
+    /// Called on every storage access, so it only copies the contract code the first time.
+    fn contract_mut(&mut self, contract_code: &[u8]) -> &mut ContractCoverage {
+        if !self.contracts.contains_key(contract_code) {
+            self.contracts
+                .insert(contract_code.to_vec(), ContractCoverage::default());
+        }
+        self.contracts.get_mut(contract_code).unwrap()
+    }
+}
+
+fn insert_key(keys: &mut BTreeSet<Vec<u8>>, key: &[u8]) {
+    if !keys.contains(key) {
+        keys.insert(key.to_vec());
+    }
+}
+
+/// Serializable coverage report for a single contract.
+///
+/// Reports of the same contract coming from different test runs can be merged.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractCoverageReport {
+    pub contract_name: String,
+
+    /// All endpoints in the ABI, as well as any other endpoint that was called, with their call count.
+    pub endpoints: BTreeMap<String, u64>,
+
+    /// Endpoints in the ABI that were never called.
+    pub untested_endpoints: Vec<String>,
+
+    /// Storage keys are grouped by their readable prefix, which is usually the storage mapper key.
+    pub storage_keys_read: BTreeSet<String>,
+
+    pub storage_keys_written: BTreeSet<String>,
+}
+
+impl ContractCoverageReport {
+    pub fn new(
+        contract_name: String,
+        coverage: &ContractCoverage,
+        opt_abi: Option<&ContractAbi>,
+    ) -> Self {
+        let mut endpoints = coverage.endpoint_calls.clone();
+        if let Some(abi) = opt_abi {
+            for endpoint_name in abi_endpoint_names(abi) {
+                endpoints.entry(endpoint_name).or_default();
+            }
+        }
+
+        let mut report = ContractCoverageReport {
+            contract_name,
+            endpoints,
+            untested_endpoints: Vec::new(),
+            storage_keys_read: group_storage_keys(&coverage.storage_reads),
+            storage_keys_written: group_storage_keys(&coverage.storage_writes),
+        };
+        report.update_untested_endpoints();
+        report
+    }
+
+    pub fn merge(&mut self, other: ContractCoverageReport) {
+        for (endpoint_name, num_calls) in other.endpoints {
+            *self.endpoints.entry(endpoint_name).or_default() += num_calls;
+        }
+        self.storage_keys_read.extend(other.storage_keys_read);
+        self.storage_keys_written.extend(other.storage_keys_written);
+        self.update_untested_endpoints();
+    }
+
+    fn update_untested_endpoints(&mut self) {
+        self.untested_endpoints = self
+            .endpoints
+            .iter()
+            .filter(|(_, num_calls)| **num_calls == 0)
+            .map(|(endpoint_name, _)| endpoint_name.clone())
+            .collect();
+    }
+}
+
+impl fmt::Display for ContractCoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let num_tested = self.endpoints.len() - self.untested_endpoints.len();
+        writeln!(
+            f,
+            "Contract {}: {} out of {} endpoints called",
+            self.contract_name,
+            num_tested,
+            self.endpoints.len()
+        )?;
+
+        writeln!(f, "\nUntested endpoints:")?;
+        for endpoint_name in &self.untested_endpoints {
+            writeln!(f, "\t{}", endpoint_name)?;
+        }
+
+        writeln!(f, "\nEndpoint calls:")?;
+        for (endpoint_name, num_calls) in &self.endpoints {
+            writeln!(f, "\t{}: {}", endpoint_name, num_calls)?;
+        }
+
+        writeln!(f, "\nStorage keys read:")?;
+        for key in &self.storage_keys_read {
+            writeln!(f, "\t{}", key)?;
+        }
+
+        writeln!(f, "\nStorage keys written:")?;
+        for key in &self.storage_keys_written {
+            writeln!(f, "\t{}", key)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn abi_endpoint_names(abi: &ContractAbi) -> Vec<String> {
+    let mut names: Vec<String> = abi
+        .constructors
+        .iter()
+        .chain(abi.endpoints.iter())
+        .map(|endpoint_abi| endpoint_abi.name.to_string())
+        .collect();
+    if abi.has_callback {
+        names.push(CALLBACK_ENDPOINT_NAME.to_string());
+    }
+    names
+}
+
+fn group_storage_keys(keys: &BTreeSet<Vec<u8>>) -> BTreeSet<String> {
+    keys.iter().map(|key| storage_key_prefix(key)).collect()
+}
+
+/// The storage mapper keys are readable strings, followed by the binary encoding of the mapper arguments.
+/// Keys that do not start with a readable string are shown in full, as hex.
+fn storage_key_prefix(key: &[u8]) -> String {
+    let prefix_len = key
+        .iter()
+        .take_while(|b| b.is_ascii_graphic() || **b == b' ')
+        .count();
+    if prefix_len == 0 {
+        format!("0x{}", hex::encode(key))
+    } else {
+        format!("str:{}", String::from_utf8_lossy(&key[..prefix_len]))
+    }
+}
@@ -0,0 +1,94 @@
+/// Approximate gas costs, used by the debugger when gas metering is enabled.
+///
+/// The default values are loosely based on the gas schedule of the real VM,
+/// but no attempt is made to match it exactly.
+/// They should only be relied upon to catch gas regressions, not to estimate real gas costs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasSchedule {
+    /// Charged for every storage read, including reads from other accounts.
+    pub storage_load: u64,
+
+    /// Charged for every byte loaded from storage.
+    pub storage_load_per_byte: u64,
+
+    /// Charged for every storage write, including clears.
+    pub storage_store: u64,
+
+    /// Charged for every byte written to storage.
+    pub storage_store_per_byte: u64,
+
+    /// Charged for cheap big int operations: add, sub, comparisons, bitwise operations, etc.
+    pub big_int_op: u64,
+
+    /// Charged for expensive big int operations: mul, div, mod, pow, sqrt.
+    pub big_int_mul_div: u64,
+
+    /// Charged for every managed buffer operation: creating, reading, slicing, appending, etc.
+    pub managed_buffer_op: u64,
+
+    /// Charged for every SHA-256, Keccak-256 or RIPEMD-160 hash.
+    pub crypto_hash: u64,
+
+    /// Charged for every ed25519 or secp256k1 signature verification.
+    pub crypto_verify_signature: u64,
+
+    /// Charged for any EGLD or ESDT transfer initiated by a contract.
+    pub transfer_value: u64,
+
+    /// Charged for synchronous calls and contract deploys initiated by a contract.
+    pub execute_on_dest_context: u64,
+
+    /// Charged for launching an async call or registering a promise.
+    pub async_call: u64,
+
+    /// Gas reserved for the callback of a legacy async call.
+    pub async_callback_gas_lock: u64,
+
+    /// Charged for calling any builtin function (ESDT transfers, local mint/burn, NFT create, etc.).
+    pub builtin_function: u64,
+}
+
+impl GasSchedule {
+    /// A gas schedule where everything is free.
+    ///
+    /// Gas metering is still active with it, so gas forwarding and refunds still apply.
+    pub fn zero() -> Self {
+        GasSchedule {
+            storage_load: 0,
+            storage_load_per_byte: 0,
+            storage_store: 0,
+            storage_store_per_byte: 0,
+            big_int_op: 0,
+            big_int_mul_div: 0,
+            managed_buffer_op: 0,
+            crypto_hash: 0,
+            crypto_verify_signature: 0,
+            transfer_value: 0,
+            execute_on_dest_context: 0,
+            async_call: 0,
+            async_callback_gas_lock: 0,
+            builtin_function: 0,
+        }
+    }
+}
+
+impl Default for GasSchedule {
+    fn default() -> Self {
+        GasSchedule {
+            storage_load: 50_000,
+            storage_load_per_byte: 50,
+            storage_store: 75_000,
+            storage_store_per_byte: 10_000,
+            big_int_op: 2_000,
+            big_int_mul_div: 6_000,
+            managed_buffer_op: 2_000,
+            crypto_hash: 1_000_000,
+            crypto_verify_signature: 2_000_000,
+            transfer_value: 100_000,
+            execute_on_dest_context: 100_000,
+            async_call: 100_000,
+            async_callback_gas_lock: 4_000_000,
+            builtin_function: 200_000,
+        }
+    }
+}
@@ -2,23 +2,35 @@ mod account_data;
 mod block_info;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
+mod blockchain_mock_coverage;
 mod blockchain_mock_init;
 mod blockchain_mock_mandos_gen;
+mod blockchain_mock_shards;
+mod blockchain_mock_snapshot;
+mod blockchain_mock_state_file;
 mod blockchain_tx_info;
+mod coverage;
 mod esdt_data;
 mod esdt_instance;
 mod esdt_instance_metadata;
 mod esdt_instances;
 mod esdt_roles;
+mod gas_schedule;
+mod storage_decoder;
 
 pub use account_data::*;
 pub use block_info::*;
 pub use blockchain_mock::*;
 pub use blockchain_mock_account_util::is_smart_contract_address;
 pub use blockchain_mock_mandos_gen::*;
+pub use blockchain_mock_shards::{compute_shard_of_address, ShardConfig, METACHAIN_SHARD_ID};
+pub use blockchain_mock_snapshot::{BlockchainSnapshot, SnapshotId};
 pub use blockchain_tx_info::*;
+pub use coverage::*;
 pub use esdt_data::*;
 pub use esdt_instance::*;
 pub use esdt_instance_metadata::*;
 pub use esdt_instances::*;
 pub use esdt_roles::*;
+pub use gas_schedule::*;
+pub use storage_decoder::StorageDecoder;
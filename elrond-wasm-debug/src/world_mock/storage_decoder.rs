@@ -0,0 +1,556 @@
+use elrond_wasm::abi::{
+    ContractAbi, StorageAbi, TypeContents, TypeDescriptionContainerImpl, SINGLE_VALUE_MAPPER_NAME,
+};
+use num_bigint::{BigInt, BigUint};
+use std::{cmp::Reverse, collections::HashMap};
+
+const U32_TYPE_NAME: &str = "u32";
+const MERKLE_HASH_TYPE_NAME: &str = "array32<u8>";
+const QUEUE_INFO_FIELDS: &[&str] = &["len", "front", "back", "new"];
+const QUEUE_NODE_FIELDS: &[&str] = &["previous", "next"];
+const LINKED_LIST_NODE_ID_FIELDS: &[&str] = &["node_id", "next_id", "prev_id"];
+const SORTED_SET_INFO_FIELDS: &[&str] = &["len", "root", "new"];
+const SORTED_SET_NODE_FIELDS: &[&str] = &["parent", "left", "right", "height"];
+
+/// Decodes the storage of a contract into readable entries,
+/// based on the storage section of its ABI.
+///
+/// Each entry is shown as `identifier(arg1, arg2) = value`.
+/// The internal keys of the known storage mappers are shown with their suffix,
+/// e.g. `list.item(1) = 5` or `list.len = 1` for a `VecMapper`.
+/// Whatever cannot be decoded is shown as hex.
+pub struct StorageDecoder<'a> {
+    storage_abis: Vec<&'a StorageAbi>,
+    type_decoder: TypeDecoder<'a>,
+}
+
+impl<'a> StorageDecoder<'a> {
+    pub fn new(abi: &'a ContractAbi) -> Self {
+        let mut storage_abis: Vec<&StorageAbi> = abi.storage.iter().collect();
+        // longer identifiers first, in case one identifier is a prefix of another
+        storage_abis.sort_by_key(|storage_abi| Reverse(storage_abi.identifier.len()));
+        StorageDecoder {
+            storage_abis,
+            type_decoder: TypeDecoder {
+                type_descriptions: &abi.type_descriptions,
+            },
+        }
+    }
+
+    /// All entries, sorted by key.
+    pub fn decode_storage(&self, storage: &HashMap<Vec<u8>, Vec<u8>>) -> Vec<String> {
+        let mut entries: Vec<(&Vec<u8>, &Vec<u8>)> = storage.iter().collect();
+        entries.sort();
+        entries
+            .into_iter()
+            .map(|(key, value)| self.decode_entry(key, value))
+            .collect()
+    }
+
+    pub fn decode_entry(&self, key: &[u8], value: &[u8]) -> String {
+        for storage_abi in &self.storage_abis {
+            if let Some(entry) = self.decode_mapper_entry(storage_abi, key, value) {
+                return entry;
+            }
+        }
+
+        // unknown mappers, or key arguments that could not be decoded
+        for storage_abi in &self.storage_abis {
+            if let Some(rest) = key.strip_prefix(storage_abi.identifier.as_bytes()) {
+                return format!(
+                    "{} 0x{} = {}",
+                    storage_abi.identifier,
+                    hex::encode(rest),
+                    hex_literal(value)
+                );
+            }
+        }
+
+        format!("{} = {}", bytes_literal(key), hex_literal(value))
+    }
+
+    fn decode_mapper_entry(
+        &self,
+        storage_abi: &StorageAbi,
+        key: &[u8],
+        value: &[u8],
+    ) -> Option<String> {
+        let mut input = key.strip_prefix(storage_abi.identifier.as_bytes())?;
+        let mut key_name = storage_abi.identifier.to_string();
+        if !storage_abi.key_args.is_empty() {
+            let mut args = Vec::new();
+            for key_arg in &storage_abi.key_args {
+                args.push(
+                    self.type_decoder
+                        .decode_nested(&key_arg.type_name, &mut input)?,
+                );
+            }
+            key_name.push_str(&format!("({})", args.join(", ")));
+        }
+
+        for layout in mapper_key_layouts(storage_abi)? {
+            if let Some(entry) = self.decode_with_layout(&layout, &key_name, input, value) {
+                return Some(entry);
+            }
+        }
+        None
+    }
+
+    fn decode_with_layout(
+        &self,
+        layout: &MapperKeyLayout,
+        key_name: &str,
+        key_suffix: &[u8],
+        value: &[u8],
+    ) -> Option<String> {
+        let mut input = key_suffix.strip_prefix(layout.suffix.as_bytes())?;
+        let mut full_key_name = format!("{}{}", key_name, layout.suffix);
+        if let Some(suffix_arg_type) = &layout.suffix_arg_type {
+            let suffix_arg = self
+                .type_decoder
+                .decode_nested(suffix_arg_type, &mut input)?;
+            full_key_name.push_str(&format!("({})", suffix_arg));
+        }
+        if !input.is_empty() {
+            return None;
+        }
+
+        let value_str = match &layout.value {
+            ValueLayout::Type(type_name) => self.type_decoder.decode_top(type_name, value),
+            ValueLayout::Fields(fields) => self.type_decoder.decode_top_fields(fields, value),
+        };
+        Some(format!(
+            "{} = {}",
+            full_key_name,
+            value_str.unwrap_or_else(|| hex_literal(value))
+        ))
+    }
+}
+
+/// One of the keys used by a storage mapper, relative to the mapper base key.
+struct MapperKeyLayout {
+    suffix: &'static str,
+    suffix_arg_type: Option<String>,
+    value: ValueLayout,
+}
+
+enum ValueLayout {
+    Type(String),
+    /// Used for the mapper internal structures, which are not described in the ABI.
+    Fields(Vec<(String, String)>),
+}
+
+impl MapperKeyLayout {
+    fn new(suffix: &'static str, suffix_arg_type: Option<&str>, value: ValueLayout) -> Self {
+        MapperKeyLayout {
+            suffix,
+            suffix_arg_type: suffix_arg_type.map(str::to_string),
+            value,
+        }
+    }
+}
+
+fn value_type(type_name: &str) -> ValueLayout {
+    ValueLayout::Type(type_name.to_string())
+}
+
+fn u32_fields(field_names: &[&str]) -> ValueLayout {
+    ValueLayout::Fields(
+        field_names
+            .iter()
+            .map(|field_name| (field_name.to_string(), U32_TYPE_NAME.to_string()))
+            .collect(),
+    )
+}
+
+/// Mirrors the storage layout of the framework storage mappers. Other mappers are not decoded.
+fn mapper_key_layouts(storage_abi: &StorageAbi) -> Option<Vec<MapperKeyLayout>> {
+    let value_types = &storage_abi.value_types;
+    let layouts = match storage_abi.mapper {
+        SINGLE_VALUE_MAPPER_NAME => vec![MapperKeyLayout::new(
+            "",
+            None,
+            value_type(value_types.first()?),
+        )],
+        "VecMapper" => vec_layouts(value_types.first()?),
+        "UnorderedSetMapper" => {
+            let item_type = value_types.first()?;
+            let mut layouts = vec_layouts(item_type);
+            layouts.push(MapperKeyLayout::new(
+                ".index",
+                Some(item_type),
+                value_type(U32_TYPE_NAME),
+            ));
+            layouts
+        },
+        "QueueMapper" => queue_layouts(value_types.first()?),
+        "SetMapper" => set_layouts(value_types.first()?),
+        "MapMapper" => {
+            let key_type = value_types.first()?;
+            let mut layouts = set_layouts(key_type);
+            layouts.push(MapperKeyLayout::new(
+                ".mapped",
+                Some(key_type),
+                value_type(value_types.get(1)?),
+            ));
+            layouts
+        },
+        "MerkleTreeMapper" => vec![
+            MapperKeyLayout::new(".leaf_count", None, value_type(U32_TYPE_NAME)),
+            MapperKeyLayout::new(
+                ".branch",
+                Some(U32_TYPE_NAME),
+                value_type(MERKLE_HASH_TYPE_NAME),
+            ),
+        ],
+        "PriorityQueueMapper" => vec![
+            MapperKeyLayout::new(".len", None, value_type(U32_TYPE_NAME)),
+            MapperKeyLayout::new(
+                ".item",
+                Some(U32_TYPE_NAME),
+                ValueLayout::Fields(vec![
+                    ("priority".to_string(), value_types.first()?.clone()),
+                    ("item".to_string(), value_types.get(1)?.clone()),
+                ]),
+            ),
+        ],
+        "SortedSetMapper" => sorted_set_layouts(value_types.first()?),
+        "SortedMapMapper" => {
+            let key_type = value_types.first()?;
+            let mut layouts = sorted_set_layouts(key_type);
+            layouts.push(MapperKeyLayout::new(
+                ".mapped",
+                Some(key_type),
+                value_type(value_types.get(1)?),
+            ));
+            layouts
+        },
+        "LinkedListMapper" => {
+            let mut node_fields = vec![("value".to_string(), value_types.first()?.clone())];
+            for field_name in LINKED_LIST_NODE_ID_FIELDS {
+                node_fields.push((field_name.to_string(), U32_TYPE_NAME.to_string()));
+            }
+            vec![
+                MapperKeyLayout::new(".info", None, u32_fields(QUEUE_INFO_FIELDS)),
+                MapperKeyLayout::new(
+                    ".node",
+                    Some(U32_TYPE_NAME),
+                    ValueLayout::Fields(node_fields),
+                ),
+            ]
+        },
+        _ => return None,
+    };
+    Some(layouts)
+}
+
+fn vec_layouts(item_type: &str) -> Vec<MapperKeyLayout> {
+    vec![
+        MapperKeyLayout::new(".len", None, value_type(U32_TYPE_NAME)),
+        MapperKeyLayout::new(".item", Some(U32_TYPE_NAME), value_type(item_type)),
+    ]
+}
+
+fn queue_layouts(item_type: &str) -> Vec<MapperKeyLayout> {
+    vec![
+        MapperKeyLayout::new(".info", None, u32_fields(QUEUE_INFO_FIELDS)),
+        MapperKeyLayout::new(
+            ".node_links",
+            Some(U32_TYPE_NAME),
+            u32_fields(QUEUE_NODE_FIELDS),
+        ),
+        MapperKeyLayout::new(".value", Some(U32_TYPE_NAME), value_type(item_type)),
+    ]
+}
+
+fn set_layouts(item_type: &str) -> Vec<MapperKeyLayout> {
+    let mut layouts = queue_layouts(item_type);
+    layouts.push(MapperKeyLayout::new(
+        ".node_id",
+        Some(item_type),
+        value_type(U32_TYPE_NAME),
+    ));
+    layouts
+}
+
+fn sorted_set_layouts(item_type: &str) -> Vec<MapperKeyLayout> {
+    vec![
+        MapperKeyLayout::new(".info", None, u32_fields(SORTED_SET_INFO_FIELDS)),
+        MapperKeyLayout::new(
+            ".node_links",
+            Some(U32_TYPE_NAME),
+            u32_fields(SORTED_SET_NODE_FIELDS),
+        ),
+        MapperKeyLayout::new(".value", Some(U32_TYPE_NAME), value_type(item_type)),
+        MapperKeyLayout::new(".node_id", Some(item_type), value_type(U32_TYPE_NAME)),
+    ]
+}
+
+/// Decodes values based on their ABI type name.
+/// Structs and enums are decoded if they are described in the ABI.
+struct TypeDecoder<'a> {
+    type_descriptions: &'a TypeDescriptionContainerImpl,
+}
+
+impl<'a> TypeDecoder<'a> {
+    fn decode_top(&self, type_name: &str, bytes: &[u8]) -> Option<String> {
+        let (base_name, type_args) = split_type_name(type_name);
+        if let Some(num_bytes) = int_size(base_name) {
+            if bytes.len() > num_bytes {
+                return None;
+            }
+            return Some(int_to_string(base_name, bytes));
+        }
+        match (base_name, type_args.as_slice()) {
+            ("bool", _) => match bytes {
+                [] => Some("false".to_string()),
+                [1] => Some("true".to_string()),
+                _ => None,
+            },
+            ("BigUint", _) => Some(BigUint::from_bytes_be(bytes).to_string()),
+            ("BigInt", _) => Some(BigInt::from_signed_bytes_be(bytes).to_string()),
+            (name, _) if is_bytes_type(name) => Some(bytes_literal(bytes)),
+            ("Option", [_]) if bytes.is_empty() => Some("None".to_string()),
+            ("List", [item_type]) => {
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.decode_nested(item_type, &mut input)?);
+                }
+                Some(format!("[{}]", items.join(", ")))
+            },
+            _ => {
+                if bytes.is_empty() {
+                    if let Some(variant_name) = self.first_enum_variant_name(type_name) {
+                        return Some(variant_name);
+                    }
+                }
+                let mut input = bytes;
+                let result = self.decode_nested(type_name, &mut input)?;
+                if input.is_empty() {
+                    Some(result)
+                } else {
+                    None
+                }
+            },
+        }
+    }
+
+    fn decode_top_fields(&self, fields: &[(String, String)], bytes: &[u8]) -> Option<String> {
+        let mut input = bytes;
+        let result = self.decode_nested_fields(fields, &mut input)?;
+        if input.is_empty() {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    fn decode_nested(&self, type_name: &str, input: &mut &[u8]) -> Option<String> {
+        let (base_name, type_args) = split_type_name(type_name);
+        if let Some(num_bytes) = int_size(base_name) {
+            let bytes = take_bytes(input, num_bytes)?;
+            return Some(int_to_string(base_name, bytes));
+        }
+        match (base_name, type_args.as_slice()) {
+            ("bool", _) => match take_bytes(input, 1)? {
+                [0] => Some("false".to_string()),
+                [1] => Some("true".to_string()),
+                _ => None,
+            },
+            ("BigUint", _) => {
+                let bytes = take_length_prefixed(input)?;
+                Some(BigUint::from_bytes_be(bytes).to_string())
+            },
+            ("BigInt", _) => {
+                let bytes = take_length_prefixed(input)?;
+                Some(BigInt::from_signed_bytes_be(bytes).to_string())
+            },
+            (name, _) if is_bytes_type(name) => Some(bytes_literal(take_length_prefixed(input)?)),
+            ("Address" | "H256", _) => Some(hex_literal(take_bytes(input, 32)?)),
+            ("Option", [item_type]) => match take_bytes(input, 1)? {
+                [0] => Some("None".to_string()),
+                [1] => Some(format!("Some({})", self.decode_nested(item_type, input)?)),
+                _ => None,
+            },
+            ("List", [item_type]) => {
+                let len = take_u32(input)?;
+                let mut items = Vec::new();
+                for _ in 0..len {
+                    items.push(self.decode_nested(item_type, input)?);
+                }
+                Some(format!("[{}]", items.join(", ")))
+            },
+            ("tuple", item_types) => {
+                let mut items = Vec::new();
+                for item_type in item_types {
+                    items.push(self.decode_nested(item_type, input)?);
+                }
+                Some(format!("({})", items.join(", ")))
+            },
+            (name, [item_type]) if name.starts_with("array") => {
+                let len: usize = name["array".len()..].parse().ok()?;
+                let mut items = Vec::new();
+                for _ in 0..len {
+                    items.push(self.decode_nested(item_type, input)?);
+                }
+                Some(format!("[{}]", items.join(", ")))
+            },
+            _ => self.decode_nested_described(type_name, input),
+        }
+    }
+
+    fn decode_nested_described(&self, type_name: &str, input: &mut &[u8]) -> Option<String> {
+        let type_description = self.type_descriptions.descriptions.get(type_name)?;
+        match &type_description.contents {
+            TypeContents::Struct(fields) => {
+                // older versions have fewer fields, only the current layout is described
+                if let Some(version) = type_description.version {
+                    if take_bytes(input, 1)?[0] != version {
+                        return None;
+                    }
+                }
+                let fields: Vec<(String, String)> = fields
+                    .iter()
+                    .map(|field| (field.name.to_string(), field.field_type.clone()))
+                    .collect();
+                Some(format!(
+                    "{} {}",
+                    type_name,
+                    self.decode_nested_fields(&fields, input)?
+                ))
+            },
+            TypeContents::Enum(variants) => {
+                let discriminant = take_bytes(input, 1)?[0] as usize;
+                let variant = variants
+                    .iter()
+                    .find(|variant| variant.discriminant == discriminant)?;
+                if variant.fields.is_empty() {
+                    return Some(variant.name.to_string());
+                }
+                let fields: Vec<(String, String)> = variant
+                    .fields
+                    .iter()
+                    .map(|field| (field.name.to_string(), field.field_type.clone()))
+                    .collect();
+                Some(format!(
+                    "{} {}",
+                    variant.name,
+                    self.decode_nested_fields(&fields, input)?
+                ))
+            },
+            TypeContents::NotSpecified => None,
+        }
+    }
+
+    fn decode_nested_fields(
+        &self,
+        fields: &[(String, String)],
+        input: &mut &[u8],
+    ) -> Option<String> {
+        let mut field_strs = Vec::new();
+        for (field_name, field_type) in fields {
+            let field_value = self.decode_nested(field_type, input)?;
+            field_strs.push(format!("{}: {}", field_name, field_value));
+        }
+        Some(format!("{{ {} }}", field_strs.join(", ")))
+    }
+
+    /// Enums top-encode their first variant as empty bytes.
+    fn first_enum_variant_name(&self, type_name: &str) -> Option<String> {
+        if let TypeContents::Enum(variants) =
+            &self.type_descriptions.descriptions.get(type_name)?.contents
+        {
+            let variant = variants.iter().find(|variant| variant.discriminant == 0)?;
+            if variant.fields.is_empty() {
+                return Some(variant.name.to_string());
+            }
+        }
+        None
+    }
+}
+
+/// Splits `MapMapper<u32,List<u8>>` into `MapMapper` and `["u32", "List<u8>"]`.
+fn split_type_name(type_name: &str) -> (&str, Vec<&str>) {
+    let (base_name, args_str) = match type_name.find('<') {
+        Some(index) if type_name.ends_with('>') => (
+            &type_name[..index],
+            &type_name[index + 1..type_name.len() - 1],
+        ),
+        _ => return (type_name, Vec::new()),
+    };
+
+    let mut type_args = Vec::new();
+    let mut depth = 0;
+    let mut arg_start = 0;
+    for (index, c) in args_str.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                type_args.push(args_str[arg_start..index].trim());
+                arg_start = index + 1;
+            },
+            _ => {},
+        }
+    }
+    type_args.push(args_str[arg_start..].trim());
+    (base_name, type_args)
+}
+
+fn int_size(type_name: &str) -> Option<usize> {
+    match type_name {
+        "u8" | "i8" => Some(1),
+        "u16" | "i16" => Some(2),
+        "u32" | "i32" | "usize" | "isize" => Some(4),
+        "u64" | "i64" => Some(8),
+        _ => None,
+    }
+}
+
+fn int_to_string(type_name: &str, bytes: &[u8]) -> String {
+    if type_name.starts_with('i') {
+        BigInt::from_signed_bytes_be(bytes).to_string()
+    } else {
+        BigUint::from_bytes_be(bytes).to_string()
+    }
+}
+
+fn is_bytes_type(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier"
+    )
+}
+
+fn take_bytes<'b>(input: &mut &'b [u8], num_bytes: usize) -> Option<&'b [u8]> {
+    if input.len() < num_bytes {
+        return None;
+    }
+    let (bytes, rest) = input.split_at(num_bytes);
+    *input = rest;
+    Some(bytes)
+}
+
+fn take_u32(input: &mut &[u8]) -> Option<usize> {
+    let bytes = take_bytes(input, 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+}
+
+fn take_length_prefixed<'b>(input: &mut &'b [u8]) -> Option<&'b [u8]> {
+    let len = take_u32(input)?;
+    take_bytes(input, len)
+}
+
+fn hex_literal(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+/// Readable text is shown between quotes, anything else as hex.
+fn bytes_literal(bytes: &[u8]) -> String {
+    if bytes.iter().all(|b| b.is_ascii_graphic() || *b == b' ') {
+        format!("\"{}\"", String::from_utf8_lossy(bytes))
+    } else {
+        hex_literal(bytes)
+    }
+}
@@ -0,0 +1,74 @@
+use elrond_wasm::types::Address;
+use elrond_wasm_debug::{
+    num_bigint::BigUint,
+    world_mock::{AccountData, AccountEsdt},
+    BlockchainMock,
+};
+use std::collections::HashMap;
+
+fn address(last_byte: u8) -> Address {
+    let mut bytes = [0u8; 32];
+    bytes[31] = last_byte;
+    Address::from(bytes)
+}
+
+fn user_account(address: Address, balance: u32) -> AccountData {
+    AccountData {
+        address,
+        nonce: 0,
+        egld_balance: BigUint::from(balance),
+        esdt: AccountEsdt::default(),
+        storage: HashMap::new(),
+        username: Vec::new(),
+        contract_path: None,
+        contract_owner: None,
+        developer_rewards: BigUint::from(0u32),
+    }
+}
+
+fn world() -> BlockchainMock {
+    let mut world = BlockchainMock::new();
+    world.add_account(user_account(address(1), 100));
+    world.add_account(user_account(address(2), 200));
+    world
+}
+
+fn balance(world: &BlockchainMock, address: &Address) -> BigUint {
+    world.account(address).unwrap().egld_balance.clone()
+}
+
+#[test]
+fn test_snapshot_restore() {
+    let mut world = world();
+    let snapshot_id = world.snapshot();
+
+    world.increase_account_nonce(&address(1));
+    world.account_mut(&address(1)).unwrap().egld_balance = BigUint::from(50u32);
+    world.add_account(user_account(address(3), 300));
+
+    world.restore(snapshot_id);
+    assert_eq!(balance(&world, &address(1)), BigUint::from(100u32));
+    assert_eq!(world.account(&address(1)).unwrap().nonce, 0);
+    assert!(!world.account_exists(&address(3)));
+
+    // the same snapshot can be restored again
+    world.account_mut(&address(2)).unwrap().egld_balance = BigUint::from(0u32);
+    world.restore(snapshot_id);
+    assert_eq!(balance(&world, &address(2)), BigUint::from(200u32));
+}
+
+#[test]
+fn test_fork() {
+    let world = world();
+    let mut fork = world.fork();
+
+    fork.account_mut(&address(1)).unwrap().egld_balance = BigUint::from(1u32);
+    assert_eq!(balance(&fork, &address(1)), BigUint::from(1u32));
+    assert_eq!(balance(&world, &address(1)), BigUint::from(100u32));
+
+    // untouched accounts are still shared
+    assert!(std::rc::Rc::ptr_eq(
+        world.accounts.get(&address(2)).unwrap(),
+        fork.accounts.get(&address(2)).unwrap()
+    ));
+}
@@ -0,0 +1,9 @@
+/// The ABI derive keeps a copy of the codec derive attribute parser, they must not drift apart.
+#[test]
+fn codec_attr_copies_in_sync_test() {
+    let codec_derive_copy =
+        std::fs::read_to_string("../elrond-codec-derive/src/codec_attr.rs").unwrap();
+    let wasm_derive_copy =
+        std::fs::read_to_string("../elrond-wasm-derive/src/codec_attr.rs").unwrap();
+    assert_eq!(codec_derive_copy, wasm_derive_copy);
+}
@@ -0,0 +1,162 @@
+//! Known answers for the curve arithmetic, generated with OpenSSL.
+//!
+//! For each curve, `scalar` is an arbitrary private key `k`, and the points are `G`, `2G`, `3G`, `kG` and `2kG`.
+
+use elrond_wasm_debug::{api::EllipticCurveParams, num_bigint::BigUint};
+
+struct CurveVectors {
+    name: &'static [u8],
+    scalar: &'static str,
+    g: &'static str,
+    g2: &'static str,
+    g2_compressed: &'static str,
+    g3: &'static str,
+    g3_compressed: &'static str,
+    kg: &'static str,
+    kg_compressed: &'static str,
+    k2g: &'static str,
+}
+
+const P224: CurveVectors = CurveVectors {
+    name: b"p224",
+    scalar: "2352545c97455686d2a20a06d160383a9cb4fac65fdad5ffacf2b77f",
+    g: "04b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+    g2: "04706a46dc76dcb76798e60e6d89474788d16dc18032d268fd1a704fa61c2b76a7bc25e7702a704fa986892849fca629487acf3709d2e4e8bb",
+    g2_compressed: "03706a46dc76dcb76798e60e6d89474788d16dc18032d268fd1a704fa6",
+    g3: "04df1b1d66a551d0d31eff822558b9d2cc75c2180279fe0d08fd896d04a3f7f03cadd0be444c0aa56830130ddf77d317344e1af3591981a925",
+    g3_compressed: "03df1b1d66a551d0d31eff822558b9d2cc75c2180279fe0d08fd896d04",
+    kg: "040e33ebbb295d50cfabfc11161bdbec14babe58147414a85567c26dff55eed8aa7d8f24e36c5d02443eaeeecaf829fd7808fe5ff05e4ec8c4",
+    kg_compressed: "020e33ebbb295d50cfabfc11161bdbec14babe58147414a85567c26dff",
+    k2g: "04f42938a06e8aa531194d40c96171979fb0cef879ebcf5fed0f00e1a6c07fa08555b6f8f97bc4d520a129a15be12ebf208eae6ae0f2e8dac7",
+};
+
+const P256: CurveVectors = CurveVectors {
+    name: b"p256",
+    scalar: "ff8eb8555284daa950887425d1855f0c8ed40c92f98d349f285b722ad32ba0e8",
+    g: "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+    g2: "047cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc4766997807775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+    g2_compressed: "037cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+    g3: "045ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032",
+    g3_compressed: "025ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c",
+    kg: "043f26634d53975dd0ef7c52ae05765aa148243870b245eb435a10fafe4f3e58c8a88b5087568fb6aefed192e22203d6bc75a20613cd9593e1ec5b26589329a1d1",
+    kg_compressed: "033f26634d53975dd0ef7c52ae05765aa148243870b245eb435a10fafe4f3e58c8",
+    k2g: "04cde316c6f3b2bdd67636149b9690ed13cc700078ef426d6b65214e3ebd6c21a2e346f19bfdf552e1cbd7e264f52bb2bf6f683fc5c345661bcf5ab1a91ec51a7c",
+};
+
+const P384: CurveVectors = CurveVectors {
+    name: b"p384",
+    scalar: "2722a45477f3f4335ac451e1363e47b7c4f11df942ff830f88e70ebc9aaf449779c7b48584b30f9ca0e449c91b54d1ae",
+    g: "04aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab73617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+    g2: "0408d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df618e80f1fa5b1b3cedb7bfe8dffd6dba74b275d875bc6cc43e904e505f256ab4255ffd43e94d39e22d61501e700a940e80",
+    g2_compressed: "0208d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df61",
+    g3: "04077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06bea4f240d3566da6b408bbae5026580d02d7e5c70500c831c995f7ca0b0c42837d0bbe9602a9fc998520b41c85115aa5f7684c0edc111eacc24abd6be4b5d298b65f28600a2f1df1",
+    g3_compressed: "03077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06bea4f240d3566da6b408bbae5026580d02d7e5c70500c831",
+    kg: "04f91215f87db5992d8dd7e714944576395700ea43847e3b6af07725ee0aaedb063357c419b9cfab3fda1e88aefd70ab7b7402ac9651cb82c6d706e33aeb72e09bfe217d6e122c6adcceb747ba66e43d83b86ac17ad09c93256704ef02f269de4b",
+    kg_compressed: "03f91215f87db5992d8dd7e714944576395700ea43847e3b6af07725ee0aaedb063357c419b9cfab3fda1e88aefd70ab7b",
+    k2g: "049c94a60a9ebca32be95520c8fbed05bb237e33094b2141d5c0436cf90dde23c62b3703e22bdf6510c4d40f893527b43db37b6a0e93fd20680b4074b054e61b17d5afc2466277679d08ae96db6cd639547e9b0bd01ec7b72e55b659ac6a174ac2",
+};
+
+const P521: CurveVectors = CurveVectors {
+    name: b"p521",
+    scalar: "0113cc1822c9f332feed278d3cb1c2b8fd4e325155e7a05b05a387fa526171335baefaa1a965635f3d95783cecb59831dda4c9695f27aa660dec1ade26c2400dc7f0",
+    g: "0400c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+    g2: "0400433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d00f4bb8cc7f86db26700a7f3eceeeed3f0b5c6b5107c4da97740ab21a29906c42dbbb3e377de9f251f6b93937fa99a3248f4eafcbe95edc0f4f71be356d661f41b02",
+    g2_compressed: "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d",
+    g3: "0401a73d352443de29195dd91d6a64b5959479b52a6e5b123d9ab9e5ad7a112d7a8dd1ad3f164a3a4832051da6bd16b59fe21baeb490862c32ea05a5919d2ede37ad7d013e9b03b97dfa62ddd9979f86c6cab814f2f1557fa82a9d0317d2f8ab1fa355ceec2e2dd4cf8dc575b02d5aced1dec3c70cf105c9bc93a590425f588ca1ee86c0e5",
+    g3_compressed: "0301a73d352443de29195dd91d6a64b5959479b52a6e5b123d9ab9e5ad7a112d7a8dd1ad3f164a3a4832051da6bd16b59fe21baeb490862c32ea05a5919d2ede37ad7d",
+    kg: "04008b3dfb1c14d9d72fcd2380cf8d5ad74d9b5da4a4d77863722dd7cc6dc55ec4eefa4d0a35ef47345de199fa56b004903b9007e29bf4d957eeca470024ee6995b0600006bab8d57aeb88252ba716ceda0120ffd0e3e7d073b6d6997bc1bf7101e50b7b38420984bea555295a9c1a5dcceb0e65ebfe9efb4c902243df6853c2bf763cfe6c",
+    kg_compressed: "02008b3dfb1c14d9d72fcd2380cf8d5ad74d9b5da4a4d77863722dd7cc6dc55ec4eefa4d0a35ef47345de199fa56b004903b9007e29bf4d957eeca470024ee6995b060",
+    k2g: "0401ef030250d03bc4b4855113d3d842eef38ba9358d1fdbc7c4bc3dded8e0f72ee06484a5d212521aab170c610152c31cb5b5e7264f8af2e1c0d9f004969607327138001f247494f3a6cb4c56bd079ab3c406cada8346149ba0ac6684d8f6ce73eee6e6a172d626097ea8bac5eea46c03ed86a65343e9cf9db6fc76cbb048368b9a669c0f",
+};
+
+fn point(curve: &EllipticCurveParams, hex_str: &str) -> (BigUint, BigUint) {
+    curve.unmarshal(&hex::decode(hex_str).unwrap()).unwrap()
+}
+
+fn check_curve(vectors: &CurveVectors) {
+    let curve = EllipticCurveParams::from_name(vectors.name).unwrap();
+    let scalar = hex::decode(vectors.scalar).unwrap();
+    let g = point(&curve, vectors.g);
+    let g2 = point(&curve, vectors.g2);
+    let g3 = point(&curve, vectors.g3);
+    let kg = point(&curve, vectors.kg);
+    let k2g = point(&curve, vectors.k2g);
+    assert_eq!(g, (curve.x_base_point.clone(), curve.y_base_point.clone()));
+
+    // add & double
+    assert_eq!(curve.double(&g.0, &g.1), g2);
+    assert_eq!(curve.add(&g.0, &g.1, &g.0, &g.1), g2);
+    assert_eq!(curve.add(&g.0, &g.1, &g2.0, &g2.1), g3);
+    assert_eq!(curve.add(&g2.0, &g2.1, &g.0, &g.1), g3);
+
+    // scalar mult
+    assert_eq!(curve.scalar_base_mult(&scalar), kg);
+    assert_eq!(curve.scalar_mult(&g.0, &g.1, &scalar), kg);
+    assert_eq!(curve.scalar_mult(&g2.0, &g2.1, &scalar), k2g);
+    assert_eq!(curve.scalar_base_mult(&[3]), g3);
+
+    // marshal
+    assert_eq!(hex::encode(curve.marshal(&kg.0, &kg.1)), vectors.kg);
+    assert_eq!(hex::encode(curve.marshal(&k2g.0, &k2g.1)), vectors.k2g);
+    assert_eq!(
+        hex::encode(curve.marshal_compressed(&g2.0, &g2.1)),
+        vectors.g2_compressed
+    );
+    assert_eq!(
+        hex::encode(curve.marshal_compressed(&g3.0, &g3.1)),
+        vectors.g3_compressed
+    );
+    assert_eq!(
+        hex::encode(curve.marshal_compressed(&kg.0, &kg.1)),
+        vectors.kg_compressed
+    );
+
+    // unmarshal compressed, the vectors cover both parities of y
+    for (compressed, expected) in [
+        (vectors.g2_compressed, &g2),
+        (vectors.g3_compressed, &g3),
+        (vectors.kg_compressed, &kg),
+    ] {
+        let unmarshalled = curve
+            .unmarshal_compressed(&hex::decode(compressed).unwrap())
+            .unwrap();
+        assert_eq!(&unmarshalled, expected);
+    }
+
+    // points not on the curve are rejected
+    let mut not_on_curve = hex::decode(vectors.kg).unwrap();
+    *not_on_curve.last_mut().unwrap() ^= 1;
+    assert!(curve.unmarshal(&not_on_curve).is_none());
+    assert!(!curve.is_on_curve(&kg.0, &g.1));
+}
+
+#[test]
+fn elliptic_curve_p224_test() {
+    check_curve(&P224);
+}
+
+#[test]
+fn elliptic_curve_p256_test() {
+    check_curve(&P256);
+}
+
+#[test]
+fn elliptic_curve_p384_test() {
+    check_curve(&P384);
+}
+
+#[test]
+fn elliptic_curve_p521_test() {
+    check_curve(&P521);
+}
+
+#[test]
+fn elliptic_curve_infinity_test() {
+    let curve = EllipticCurveParams::from_name(b"p256").unwrap();
+    let g = (curve.x_base_point.clone(), curve.y_base_point.clone());
+    let minus_g_y = &curve.field_order - &g.1;
+    let infinity = curve.add(&g.0, &g.1, &g.0, &minus_g_y);
+    assert_eq!(infinity, (BigUint::from(0u32), BigUint::from(0u32)));
+    assert_eq!(curve.add(&infinity.0, &infinity.1, &g.0, &g.1), g);
+    assert_eq!(curve.scalar_base_mult(&[0]), infinity);
+}
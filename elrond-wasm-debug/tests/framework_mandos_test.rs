@@ -245,3 +245,72 @@ fn transfer_esdt_rs() {
 fn validator_reward_rs() {
     elrond_wasm_debug::mandos_rs("tests/mandos-self/validatorReward.scen.json", world());
 }
+
+#[test]
+#[should_panic]
+fn step_filter_unfiltered_rs() {
+    elrond_wasm_debug::mandos_rs(
+        "tests/mandos-self/step_filter/step_filter.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn step_filter_tags_rs() {
+    elrond_wasm_debug::mandos_rs_filtered(
+        "tests/mandos-self/step_filter/step_filter.scen.json",
+        world(),
+        &MandosStepFilter::new()
+            .with_tag("setup")
+            .with_tag("tx1")
+            .with_tag("check-tx1"),
+    );
+}
+
+#[test]
+fn step_filter_tags_set_state_always_runs_rs() {
+    elrond_wasm_debug::mandos_rs_filtered(
+        "tests/mandos-self/step_filter/step_filter.scen.json",
+        world(),
+        &MandosStepFilter::new()
+            .with_tag("tx1")
+            .with_tag("check-tx1"),
+    );
+}
+
+#[test]
+#[should_panic]
+fn step_filter_tags_skipped_tx_rs() {
+    elrond_wasm_debug::mandos_rs_filtered(
+        "tests/mandos-self/step_filter/step_filter.scen.json",
+        world(),
+        &MandosStepFilter::new()
+            .with_tag("setup")
+            .with_tag("tx1")
+            .with_tag("check-tx2"),
+    );
+}
+
+#[test]
+fn step_filter_stop_after_rs() {
+    elrond_wasm_debug::mandos_rs_filtered(
+        "tests/mandos-self/step_filter/step_filter.scen.json",
+        world(),
+        &MandosStepFilter::new().stop_after("2"),
+    );
+}
+
+#[test]
+fn step_filter_external_steps_rs() {
+    elrond_wasm_debug::mandos_rs_filtered(
+        "tests/mandos-self/step_filter/step_filter_external.scen.json",
+        world(),
+        &MandosStepFilter::new().stop_after("2"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "no .scen.json files found")]
+fn dir_without_scenarios_rs() {
+    elrond_wasm_debug::mandos_rs_dir("src/mandos_system", world);
+}
@@ -0,0 +1,142 @@
+use elrond_wasm::{
+    api::ManagedTypeApi,
+    derive::LazyDecode,
+    elrond_codec,
+    elrond_codec::{
+        elrond_codec_derive::{EncodedSize, NestedDecode, NestedEncode, TopDecode, TopEncode},
+        test_util::top_encode_to_vec_u8_or_panic,
+        TopDecode,
+    },
+    types::{BigUint, ManagedAddress, ManagedBuffer, ManagedVec},
+};
+use elrond_wasm_debug::DebugApi;
+
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, EncodedSize, LazyDecode, PartialEq, Debug,
+)]
+pub struct Order<M: ManagedTypeApi> {
+    pub id: u64,
+    pub owner: ManagedAddress<M>,
+    pub price: BigUint<M>,
+    pub tags: ManagedVec<M, ManagedBuffer<M>>,
+    pub amount: u32,
+    #[codec(skip)]
+    pub cached: u8,
+    #[codec(default)]
+    pub expiry: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, EncodedSize, LazyDecode)]
+pub struct Plain {
+    pub flag: bool,
+    pub value: u16,
+}
+
+/// A struct with several `#[codec(default)]` fields, all of them missing from old data.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, EncodedSize, LazyDecode)]
+pub struct Upgraded<M: ManagedTypeApi> {
+    pub a: u32,
+    #[codec(default)]
+    pub b: ManagedBuffer<M>,
+    #[codec(default)]
+    pub c: u64,
+}
+
+fn order() -> Order<DebugApi> {
+    let mut tags = ManagedVec::new();
+    tags.push(ManagedBuffer::from(&b"nft"[..]));
+    tags.push(ManagedBuffer::from(&b"sale"[..]));
+    Order {
+        id: 7,
+        owner: ManagedAddress::from(&[3u8; 32]),
+        price: BigUint::from(1_000_000u64),
+        tags,
+        amount: 42,
+        cached: 0,
+        expiry: 1_234_567,
+    }
+}
+
+fn lazy_order(order: &Order<DebugApi>) -> OrderLazy<DebugApi> {
+    let buffer = ManagedBuffer::new_from_bytes(top_encode_to_vec_u8_or_panic(order).as_slice());
+    OrderLazy::new(buffer)
+}
+
+#[test]
+fn lazy_decode_fields_test() {
+    let _ = DebugApi::dummy();
+    let order = order();
+    let lazy = lazy_order(&order);
+
+    assert_eq!(lazy.id(), order.id);
+    assert_eq!(lazy.owner(), order.owner);
+    assert_eq!(lazy.price(), order.price);
+    assert_eq!(lazy.tags(), order.tags);
+    assert_eq!(lazy.amount(), order.amount);
+    assert_eq!(lazy.expiry(), order.expiry);
+    assert_eq!(lazy.decode_all(), order);
+}
+
+#[test]
+fn lazy_decode_default_field_test() {
+    let _ = DebugApi::dummy();
+    let order = order();
+    let mut encoded = top_encode_to_vec_u8_or_panic(&order);
+    encoded.truncate(encoded.len() - 8);
+    let lazy = OrderLazy::<DebugApi>::new(ManagedBuffer::new_from_bytes(encoded.as_slice()));
+
+    assert_eq!(lazy.amount(), order.amount);
+    assert_eq!(lazy.expiry(), 0);
+}
+
+#[test]
+fn lazy_decode_codec_test() {
+    let _ = DebugApi::dummy();
+    let order = order();
+    let lazy = lazy_order(&order);
+    let encoded = top_encode_to_vec_u8_or_panic(&lazy);
+    assert_eq!(encoded, top_encode_to_vec_u8_or_panic(&order));
+
+    let decoded = OrderLazy::<DebugApi>::top_decode(encoded.as_slice()).unwrap();
+    assert_eq!(decoded.price(), order.price);
+    assert_eq!(decoded.as_managed_buffer(), lazy.as_managed_buffer());
+}
+
+#[test]
+fn lazy_decode_without_api_param_test() {
+    let _ = DebugApi::dummy();
+    let encoded = top_encode_to_vec_u8_or_panic(&Plain {
+        flag: true,
+        value: 500,
+    });
+    let lazy = PlainLazy::<DebugApi>::new(ManagedBuffer::new_from_bytes(encoded.as_slice()));
+    assert!(lazy.flag());
+    assert_eq!(lazy.value(), 500);
+}
+
+#[test]
+#[should_panic]
+fn lazy_decode_input_too_short_test() {
+    let _ = DebugApi::dummy();
+    let lazy = PlainLazy::<DebugApi>::new(ManagedBuffer::new_from_bytes(&[1, 2]));
+    let _ = lazy.value();
+}
+
+#[test]
+fn lazy_decode_missing_default_fields_test() {
+    let _ = DebugApi::dummy();
+    // only `a`, nested-encoded
+    let lazy = UpgradedLazy::<DebugApi>::new(ManagedBuffer::new_from_bytes(&[0, 0, 0, 5]));
+    assert_eq!(lazy.a(), 5);
+    assert_eq!(lazy.b(), ManagedBuffer::new());
+    assert_eq!(lazy.c(), 0);
+
+    let encoded = top_encode_to_vec_u8_or_panic(&Upgraded::<DebugApi> {
+        a: 5,
+        b: ManagedBuffer::from(&b"abc"[..]),
+        c: 7,
+    });
+    let lazy = UpgradedLazy::<DebugApi>::new(ManagedBuffer::new_from_bytes(encoded.as_slice()));
+    assert_eq!(lazy.b(), ManagedBuffer::from(&b"abc"[..]));
+    assert_eq!(lazy.c(), 7);
+}
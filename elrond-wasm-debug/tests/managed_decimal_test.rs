@@ -0,0 +1,146 @@
+use elrond_wasm::{
+    formatter::FormatBuffer,
+    types::{
+        BigUint, ConstDecimals, ManagedBuffer, ManagedBufferCachedBuilder, ManagedDecimal,
+        NumDecimals, RoundingMode,
+    },
+};
+use elrond_wasm_debug::{check_managed_top_encode_decode, DebugApi};
+
+fn const_decimal<const DECIMALS: NumDecimals>(
+    raw_units: u64,
+) -> ManagedDecimal<DebugApi, ConstDecimals<DECIMALS>> {
+    ManagedDecimal::from_raw_units(BigUint::from(raw_units), ConstDecimals)
+}
+
+fn num_decimal(raw_units: u64, num_decimals: NumDecimals) -> ManagedDecimal<DebugApi, NumDecimals> {
+    ManagedDecimal::from_raw_units(BigUint::from(raw_units), num_decimals)
+}
+
+fn format_decimal<D: elrond_wasm::types::Decimals>(
+    decimal: &ManagedDecimal<DebugApi, D>,
+) -> ManagedBuffer<DebugApi> {
+    let mut builder = ManagedBufferCachedBuilder::<DebugApi>::default();
+    builder.append_display(decimal);
+    builder.into_managed_buffer()
+}
+
+#[test]
+fn test_managed_decimal_add_sub() {
+    let _ = DebugApi::dummy();
+
+    let sum = const_decimal::<2>(150) + const_decimal::<2>(275);
+    assert_eq!(sum.raw_units(), &BigUint::from(425u32));
+    assert_eq!(sum.trunc(), BigUint::from(4u32));
+
+    let difference = const_decimal::<2>(425) - const_decimal::<2>(25);
+    assert_eq!(difference, const_decimal::<2>(400));
+
+    assert!(const_decimal::<2>(1)
+        .checked_sub(&const_decimal(2))
+        .is_none());
+    assert!(num_decimal(1, 2).checked_add(&num_decimal(1, 3)).is_none());
+    assert_eq!(
+        num_decimal(1, 2).checked_add(&num_decimal(2, 2)),
+        Some(num_decimal(3, 2))
+    );
+}
+
+#[test]
+fn test_managed_decimal_mul_div() {
+    let _ = DebugApi::dummy();
+
+    // 1.5 * 2.5 = 3.75
+    let product =
+        const_decimal::<1>(15).mul_with_precision(&const_decimal::<1>(25), RoundingMode::Down);
+    assert_eq!(product, const_decimal::<1>(37));
+    let product =
+        const_decimal::<1>(15).mul_with_precision(&const_decimal::<1>(25), RoundingMode::HalfUp);
+    assert_eq!(product, const_decimal::<1>(38));
+
+    // 1 / 3 = 0.333...
+    let quotient = const_decimal::<2>(100).checked_div(&const_decimal::<0>(3), RoundingMode::Down);
+    assert_eq!(quotient, Some(const_decimal::<2>(33)));
+    let quotient = const_decimal::<2>(100).checked_div(&const_decimal::<0>(3), RoundingMode::Up);
+    assert_eq!(quotient, Some(const_decimal::<2>(34)));
+
+    assert!(const_decimal::<2>(100)
+        .checked_div(&const_decimal::<2>(0), RoundingMode::Down)
+        .is_none());
+
+    // operators keep the decimals of the left operand and round down
+    assert_eq!(
+        const_decimal::<18>(2_000_000_000_000_000_000) / num_decimal(4, 0),
+        const_decimal::<18>(500_000_000_000_000_000)
+    );
+}
+
+#[test]
+fn test_managed_decimal_rescale() {
+    let _ = DebugApi::dummy();
+
+    let value = const_decimal::<3>(1_245);
+    assert_eq!(
+        value.rescale(5usize, RoundingMode::Down),
+        num_decimal(124_500, 5)
+    );
+    assert_eq!(
+        value.rescale(ConstDecimals::<2>, RoundingMode::Down),
+        const_decimal::<2>(124)
+    );
+    assert_eq!(
+        value.rescale(ConstDecimals::<2>, RoundingMode::HalfUp),
+        const_decimal::<2>(125)
+    );
+    assert_eq!(
+        value.rescale(ConstDecimals::<2>, RoundingMode::HalfEven),
+        const_decimal::<2>(124)
+    );
+    assert_eq!(
+        const_decimal::<3>(1_255).rescale(ConstDecimals::<2>, RoundingMode::HalfEven),
+        const_decimal::<2>(126)
+    );
+
+    // equality compares values, regardless of the number of decimals
+    assert_eq!(num_decimal(15, 1), num_decimal(150, 2));
+    assert!(num_decimal(15, 1) < num_decimal(151, 2));
+}
+
+#[test]
+fn test_managed_decimal_codec() {
+    let api = DebugApi::dummy();
+
+    check_managed_top_encode_decode(api.clone(), const_decimal::<18>(0x0102), &[1, 2]);
+    check_managed_top_encode_decode(
+        api.clone(),
+        vec![const_decimal::<18>(0x0102)],
+        &[0, 0, 0, 2, 1, 2],
+    );
+    check_managed_top_encode_decode(
+        api,
+        num_decimal(0x0102, 18),
+        &[0, 0, 0, 2, 1, 2, 0, 0, 0, 18],
+    );
+}
+
+#[test]
+fn test_managed_decimal_format() {
+    let _ = DebugApi::dummy();
+
+    assert_eq!(
+        format_decimal(&const_decimal::<3>(1_500)),
+        ManagedBuffer::from(&b"1.500"[..])
+    );
+    assert_eq!(
+        format_decimal(&num_decimal(5, 3)),
+        ManagedBuffer::from(&b"0.005"[..])
+    );
+    assert_eq!(
+        format_decimal(&num_decimal(0, 2)),
+        ManagedBuffer::from(&b"0.00"[..])
+    );
+    assert_eq!(
+        format_decimal(&num_decimal(42, 0)),
+        ManagedBuffer::from(&b"42"[..])
+    );
+}
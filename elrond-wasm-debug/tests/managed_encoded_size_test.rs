@@ -0,0 +1,65 @@
+use elrond_wasm::{
+    api::ManagedTypeApi,
+    elrond_codec,
+    elrond_codec::{
+        elrond_codec_derive::{EncodedSize, NestedEncode},
+        test_util::check_dep_encode,
+        EncodedSize,
+    },
+    types::{
+        BigUint, CodeMetadata, EsdtLocalRole, EsdtTokenPayment, ManagedAddress, ManagedByteArray,
+        ManagedOption, ManagedVec, TokenIdentifier,
+    },
+};
+use elrond_wasm_debug::DebugApi;
+
+#[derive(NestedEncode, EncodedSize)]
+pub struct FixedManagedStruct<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub eth_address: ManagedByteArray<M, 20>,
+    pub role: EsdtLocalRole,
+    pub metadata: CodeMetadata,
+    pub nonce: u64,
+}
+
+#[derive(NestedEncode, EncodedSize)]
+pub struct UnboundedManagedStruct<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub payments: ManagedVec<M, EsdtTokenPayment<M>>,
+}
+
+#[test]
+fn test_managed_encoded_sizes() {
+    assert_eq!(ManagedAddress::<DebugApi>::ENCODED_SIZE, Some(32));
+    assert_eq!(ManagedByteArray::<DebugApi, 20>::ENCODED_SIZE, Some(20));
+    assert_eq!(
+        ManagedOption::<DebugApi, ManagedAddress<DebugApi>>::MAX_ENCODED_LEN,
+        Some(33)
+    );
+    assert_eq!(BigUint::<DebugApi>::MAX_ENCODED_LEN, None);
+    assert_eq!(TokenIdentifier::<DebugApi>::MAX_ENCODED_LEN, None);
+    assert_eq!(EsdtTokenPayment::<DebugApi>::MAX_ENCODED_LEN, None);
+}
+
+#[test]
+fn test_derived_managed_encoded_size() {
+    let _ = DebugApi::dummy();
+    let fixed = FixedManagedStruct::<DebugApi> {
+        owner: ManagedAddress::zero(),
+        eth_address: ManagedByteArray::new_from_bytes(&[1u8; 20]),
+        role: EsdtLocalRole::Mint,
+        metadata: CodeMetadata::UPGRADEABLE,
+        nonce: 5,
+    };
+    assert_eq!(
+        FixedManagedStruct::<DebugApi>::ENCODED_SIZE,
+        Some(32 + 20 + 1 + 2 + 8)
+    );
+    assert_eq!(
+        check_dep_encode(&fixed).len(),
+        FixedManagedStruct::<DebugApi>::ENCODED_SIZE.unwrap()
+    );
+
+    assert_eq!(UnboundedManagedStruct::<DebugApi>::ENCODED_SIZE, None);
+    assert_eq!(UnboundedManagedStruct::<DebugApi>::MAX_ENCODED_LEN, None);
+}
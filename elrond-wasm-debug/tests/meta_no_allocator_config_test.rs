@@ -0,0 +1,33 @@
+use elrond_wasm_debug::meta::{ElrondJsonSerde, MultiContractConfigSerde};
+
+#[test]
+fn elrond_json_no_allocator_test() {
+    let elrond_json: ElrondJsonSerde = serde_json::from_str(
+        r#"{
+            "language": "rust",
+            "noAllocator": true
+        }"#,
+    )
+    .unwrap();
+    assert!(elrond_json.no_allocator);
+
+    let elrond_json: ElrondJsonSerde = serde_json::from_str(r#"{ "language": "rust" }"#).unwrap();
+    assert!(!elrond_json.no_allocator);
+}
+
+#[test]
+fn multi_contract_no_allocator_test() {
+    let config: MultiContractConfigSerde = toml::from_str(
+        r#"
+        [contracts.admin]
+        add-labels = ["admin"]
+        no-allocator = true
+
+        [contracts.reader]
+        add-labels = ["reader"]
+        "#,
+    )
+    .unwrap();
+    assert!(config.contracts["admin"].no_allocator);
+    assert!(!config.contracts["reader"].no_allocator);
+}
@@ -0,0 +1,70 @@
+use elrond_wasm::types::Address;
+use elrond_wasm_debug::{
+    world_mock::{compute_shard_of_address, METACHAIN_SHARD_ID},
+    BlockchainMock,
+};
+
+fn address_ending_in(last_byte: u8) -> Address {
+    let mut bytes = [1u8; 32];
+    bytes[31] = last_byte;
+    Address::from(bytes)
+}
+
+fn sc_address_ending_in(last_byte: u8) -> Address {
+    let mut bytes = [0u8; 32];
+    bytes[31] = last_byte;
+    Address::from(bytes)
+}
+
+#[test]
+fn test_compute_shard_of_address() {
+    assert_eq!(compute_shard_of_address(&address_ending_in(0xff), 1), 0);
+
+    assert_eq!(compute_shard_of_address(&address_ending_in(0x00), 2), 0);
+    assert_eq!(compute_shard_of_address(&address_ending_in(0x01), 2), 1);
+    assert_eq!(compute_shard_of_address(&address_ending_in(0xfe), 2), 0);
+
+    assert_eq!(compute_shard_of_address(&address_ending_in(0x00), 3), 0);
+    assert_eq!(compute_shard_of_address(&address_ending_in(0x01), 3), 1);
+    assert_eq!(compute_shard_of_address(&address_ending_in(0x02), 3), 2);
+    assert_eq!(compute_shard_of_address(&address_ending_in(0x03), 3), 1);
+    assert_eq!(compute_shard_of_address(&address_ending_in(0x07), 3), 1);
+}
+
+#[test]
+fn test_account_shard_override() {
+    let mut b_mock = BlockchainMock::new();
+    let address = address_ending_in(0x01);
+    assert_eq!(b_mock.get_shard_of_address(&address), 0);
+
+    b_mock.enable_sharding(3);
+    assert_eq!(b_mock.get_shard_of_address(&address), 1);
+
+    b_mock.set_account_shard(&address, 2);
+    assert_eq!(b_mock.get_shard_of_address(&address), 2);
+    assert!(!b_mock.is_cross_shard_call(&address, &address_ending_in(0x02)));
+    assert!(b_mock.is_cross_shard_call(&address, &address_ending_in(0x00)));
+}
+
+#[test]
+fn test_metachain_shard_of_address() {
+    let esdt_system_sc_address = Address::from(elrond_wasm::hex_literal::hex!(
+        "000000000000000000010000000000000000000000000000000000000002ffff"
+    ));
+    assert_eq!(
+        compute_shard_of_address(&esdt_system_sc_address, 3),
+        METACHAIN_SHARD_ID
+    );
+    assert_eq!(
+        compute_shard_of_address(&sc_address_ending_in(0xff), 1),
+        METACHAIN_SHARD_ID
+    );
+    assert_eq!(compute_shard_of_address(&sc_address_ending_in(0xfe), 3), 2);
+    assert_eq!(compute_shard_of_address(&address_ending_in(0xff), 3), 1);
+
+    let mut b_mock = BlockchainMock::new();
+    b_mock.enable_sharding(3);
+    assert!(b_mock.is_cross_shard_call(&address_ending_in(0x02), &esdt_system_sc_address));
+    b_mock.set_account_shard(&address_ending_in(0x02), METACHAIN_SHARD_ID);
+    assert!(!b_mock.is_cross_shard_call(&address_ending_in(0x02), &esdt_system_sc_address));
+}
@@ -46,3 +46,45 @@ fn test_verify_ed25519_invalid_args() {
     let success = ctx.verify_ed25519_legacy(&pub_bytes, &msg_bytes, &sig_bytes);
     assert!(!success);
 }
+
+#[test]
+fn test_verify_bls_basic() {
+    let public_key: &[u8] = b"e5eecbb074dd8b779aae69b233f6d55266957c4bd68b60e8a0c5d5a7424b90148e3868b49c547cc62cfcb480594f66090d414ad916ccb73d585e726a998ef8bedd1e2b526045add0fd7a8f94f033fde2259495247d2ad6fa2eec5c7d4fd62f09";
+    let message: &[u8] = b"message to be signed";
+    let signature: &[u8] = b"dfac1f8e4bbc298656f1936179de9613ce1e6045ac740f7f7fa10f3941534c7efea97def14f7869fbf9a61508f257080";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    let ctx = DebugApi::dummy();
+    let success = ctx.verify_bls_legacy(&pub_bytes, message, &sig_bytes);
+    assert!(success);
+}
+
+#[test]
+fn test_verify_bls_bad_message() {
+    let public_key: &[u8] = b"e5eecbb074dd8b779aae69b233f6d55266957c4bd68b60e8a0c5d5a7424b90148e3868b49c547cc62cfcb480594f66090d414ad916ccb73d585e726a998ef8bedd1e2b526045add0fd7a8f94f033fde2259495247d2ad6fa2eec5c7d4fd62f09";
+    let message: &[u8] = b"message to be signed!";
+    let signature: &[u8] = b"dfac1f8e4bbc298656f1936179de9613ce1e6045ac740f7f7fa10f3941534c7efea97def14f7869fbf9a61508f257080";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    let ctx = DebugApi::dummy();
+    let success = ctx.verify_bls_legacy(&pub_bytes, message, &sig_bytes);
+    assert!(!success);
+}
+
+#[test]
+fn test_verify_bls_invalid_args() {
+    let public_key: &[u8] = b"e5eecbb074dd8b779aae69b233f6d55266957c4bd68b60e8a0c5d5a7424b90148e3868b49c547cc62cfcb480594f66090d414ad916ccb73d585e726a998ef8bedd1e2b526045add0fd7a8f94f033fde2259495247d2ad6fa2eec5c7d4fd62f";
+    let message: &[u8] = b"message to be signed";
+    let signature: &[u8] = b"dfac1f8e4bbc298656f1936179de9613ce1e6045ac740f7f7fa10f3941534c7efea97def14f7869fbf9a61508f2570";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    let ctx = DebugApi::dummy();
+    let success = ctx.verify_bls_legacy(&pub_bytes, message, &sig_bytes);
+    assert!(!success);
+}
@@ -0,0 +1,266 @@
+use elrond_wasm::{
+    elrond_codec::{multi_types::MultiValue2, TopDecodeMulti, TopEncodeMulti},
+    storage::{
+        mappers::{
+            LinkedListMapper, MapMapper, PriorityQueueMapper, QueueMapper, SetMapper,
+            SortedMapMapper, SortedSetMapper, StorageMapper, UnorderedSetMapper, VecMapper,
+        },
+        StorageKey,
+    },
+    types::MultiValuePage,
+};
+use elrond_wasm_debug::DebugApi;
+
+fn base_key(name: &[u8]) -> StorageKey<DebugApi> {
+    let _ = DebugApi::dummy();
+    StorageKey::new(name)
+}
+
+fn page_items<T>(page: MultiValuePage<DebugApi, T>) -> Vec<T>
+where
+    T: TopEncodeMulti + TopDecodeMulti,
+{
+    page.into_items().into_iter().collect()
+}
+
+/// Requests pages until the last one, checks that all pages but the last are full.
+fn collect_pages<T, F>(limit: usize, mut load_page: F) -> Vec<T>
+where
+    T: TopEncodeMulti + TopDecodeMulti,
+    F: FnMut(usize) -> MultiValuePage<DebugApi, T>,
+{
+    let mut result = Vec::new();
+    let mut cursor = 0;
+    loop {
+        let page = load_page(cursor);
+        let next_cursor = page.next_cursor();
+        let items = page_items(page);
+        if next_cursor != 0 {
+            assert_eq!(items.len(), limit);
+        } else {
+            assert!(items.len() <= limit);
+        }
+        result.extend(items);
+        if next_cursor == 0 {
+            return result;
+        }
+        cursor = next_cursor;
+    }
+}
+
+fn into_tuples<K, V>(entries: Vec<MultiValue2<K, V>>) -> Vec<(K, V)> {
+    entries.into_iter().map(MultiValue2::into_tuple).collect()
+}
+
+#[test]
+fn test_vec_mapper_pages() {
+    let mut vec: VecMapper<DebugApi, u64> = VecMapper::new(base_key(b"vec"));
+    assert_eq!(
+        collect_pages(3, |cursor| vec.page(cursor, 3)),
+        Vec::<u64>::new()
+    );
+    for item in 1..=10u64 {
+        vec.push(&(item * 10));
+    }
+    let expected: Vec<u64> = vec.iter().collect();
+    for limit in 1..=11 {
+        assert_eq!(
+            collect_pages(limit, |cursor| vec.page(cursor, limit)),
+            expected
+        );
+    }
+
+    let page = vec.page(9, 5);
+    assert_eq!(page.next_cursor(), 0);
+    assert_eq!(page_items(page), vec![90, 100]);
+    let page = vec.page(20, 5);
+    assert!(page.is_last_page());
+    assert!(page.items().is_empty());
+}
+
+#[test]
+fn test_unordered_set_mapper_pages() {
+    let mut set: UnorderedSetMapper<DebugApi, u64> =
+        UnorderedSetMapper::new(base_key(b"unordered_set"));
+    for item in 1..=10u64 {
+        set.insert(item);
+    }
+    set.swap_remove(&3);
+    let expected: Vec<u64> = set.iter().collect();
+    assert_eq!(collect_pages(4, |cursor| set.page(cursor, 4)), expected);
+}
+
+#[test]
+fn test_queue_mapper_pages() {
+    let mut queue: QueueMapper<DebugApi, u64> = QueueMapper::new(base_key(b"queue"));
+    for item in 1..=10u64 {
+        queue.push_back(item);
+    }
+    queue.push_front(0);
+    queue.pop_back();
+    let expected: Vec<u64> = queue.iter().collect();
+    for limit in 1..=11 {
+        assert_eq!(
+            collect_pages(limit, |cursor| queue.page(cursor, limit)),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_queue_mapper_cursor_survives_changes() {
+    let mut queue: QueueMapper<DebugApi, u64> = QueueMapper::new(base_key(b"queue"));
+    for item in 1..=6u64 {
+        queue.push_back(item);
+    }
+    let first_page = queue.page(0, 3);
+    let next_cursor = first_page.next_cursor();
+    assert_eq!(page_items(first_page), vec![1, 2, 3]);
+
+    // changes before the cursor do not affect the following pages
+    queue.pop_front();
+    queue.push_back(7);
+    let second_page = queue.page(next_cursor, 10);
+    assert!(second_page.is_last_page());
+    assert_eq!(page_items(second_page), vec![4, 5, 6, 7]);
+}
+
+#[test]
+#[should_panic]
+fn test_queue_mapper_removed_cursor() {
+    let mut queue: QueueMapper<DebugApi, u64> = QueueMapper::new(base_key(b"queue"));
+    for item in 1..=6u64 {
+        queue.push_back(item);
+    }
+    let first_page = queue.page(0, 3);
+    for _ in 0..4 {
+        queue.pop_front();
+    }
+    let _ = queue.page(first_page.next_cursor(), 3);
+}
+
+#[test]
+fn test_set_and_map_mapper_pages() {
+    let mut set: SetMapper<DebugApi, u64> = SetMapper::new(base_key(b"set"));
+    let mut map: MapMapper<DebugApi, u64, u64> = MapMapper::new(base_key(b"map"));
+    for item in 1..=10u64 {
+        set.insert(item);
+        map.insert(item, item * 100);
+    }
+    set.remove(&5);
+    map.remove(&5);
+
+    let expected_set: Vec<u64> = set.iter().collect();
+    assert_eq!(collect_pages(3, |cursor| set.page(cursor, 3)), expected_set);
+
+    let expected_map: Vec<(u64, u64)> = map.iter().collect();
+    let pages = collect_pages(3, |cursor| map.page(cursor, 3));
+    assert_eq!(into_tuples(pages), expected_map);
+}
+
+#[test]
+fn test_linked_list_mapper_pages() {
+    let mut list: LinkedListMapper<DebugApi, u64> = LinkedListMapper::new(base_key(b"list"));
+    for item in 1..=10u64 {
+        list.push_back(item);
+    }
+    list.pop_front();
+    let expected: Vec<u64> = list.iter().map(|node| node.into_value()).collect();
+    for limit in 1..=10 {
+        assert_eq!(
+            collect_pages(limit, |cursor| list.page(cursor, limit)),
+            expected
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_linked_list_mapper_unknown_cursor() {
+    let mut list: LinkedListMapper<DebugApi, u64> = LinkedListMapper::new(base_key(b"list"));
+    list.push_back(1);
+    let _ = list.page(2, 3);
+}
+
+#[test]
+fn test_sorted_mapper_pages() {
+    let mut set: SortedSetMapper<DebugApi, u64> = SortedSetMapper::new(base_key(b"sorted_set"));
+    let mut map: SortedMapMapper<DebugApi, u64, u64> =
+        SortedMapMapper::new(base_key(b"sorted_map"));
+    for item in [50u64, 20, 80, 10, 30, 70, 90, 60, 40] {
+        set.insert(item);
+        map.insert(item, item + 1);
+    }
+    set.remove(&30);
+    map.remove(&30);
+
+    let expected_set: Vec<u64> = set.iter().collect();
+    for limit in 1..=9 {
+        assert_eq!(
+            collect_pages(limit, |cursor| set.page(cursor, limit)),
+            expected_set
+        );
+    }
+
+    let expected_map: Vec<(u64, u64)> = map.iter().collect();
+    let pages = collect_pages(2, |cursor| map.page(cursor, 2));
+    assert_eq!(into_tuples(pages), expected_map);
+}
+
+#[test]
+fn test_sorted_set_mapper_cursor_after_insert() {
+    let mut set: SortedSetMapper<DebugApi, u64> = SortedSetMapper::new(base_key(b"sorted_set"));
+    for item in [50u64, 20, 80, 10, 30, 70, 90] {
+        set.insert(item);
+    }
+    let first_page = set.page(0, 4);
+    let next_cursor = first_page.next_cursor();
+    assert_eq!(page_items(first_page), vec![10, 20, 30, 50]);
+    for item in [60u64, 65, 75, 100] {
+        set.insert(item);
+    }
+    let second_page = set.page(next_cursor, 10);
+    // 60 and 65 come before the cursor, so the next page skips them
+    assert_eq!(page_items(second_page), vec![70, 75, 80, 90, 100]);
+}
+
+/// Removing 50, which has two children, moves 70 into its node,
+/// so the cursor pointing at 70 is invalid, even though 70 is still in the set.
+#[test]
+#[should_panic]
+fn test_sorted_set_mapper_cursor_after_removal() {
+    let mut set: SortedSetMapper<DebugApi, u64> = SortedSetMapper::new(base_key(b"sorted_set"));
+    for item in [50u64, 20, 80, 10, 30, 70, 90] {
+        set.insert(item);
+    }
+    let first_page = set.page(0, 4);
+    set.remove(&50);
+    let _ = set.page(first_page.next_cursor(), 4);
+}
+
+#[test]
+fn test_priority_queue_mapper_pages() {
+    let mut queue: PriorityQueueMapper<DebugApi, u64, u64> =
+        PriorityQueueMapper::new(base_key(b"priority_queue"));
+    for item in [5u64, 3, 8, 1, 9, 2] {
+        queue.push(item, item * 10);
+    }
+    let expected: Vec<(u64, u64)> = queue.iter().collect();
+    let pages = collect_pages(4, |cursor| queue.page(cursor, 4));
+    assert_eq!(into_tuples(pages), expected);
+}
+
+#[test]
+#[should_panic]
+fn test_priority_queue_mapper_cursor_after_pop() {
+    let mut queue: PriorityQueueMapper<DebugApi, u64, u64> =
+        PriorityQueueMapper::new(base_key(b"priority_queue"));
+    for item in 1..=6u64 {
+        queue.push(item, item);
+    }
+    let first_page = queue.page(0, 5);
+    for _ in 0..3 {
+        queue.pop();
+    }
+    let _ = queue.page(first_page.next_cursor(), 5);
+}
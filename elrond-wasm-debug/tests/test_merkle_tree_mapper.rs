@@ -0,0 +1,152 @@
+use elrond_wasm::{
+    storage::{
+        mappers::{MerkleTreeMapper, StorageClearable, StorageMapper},
+        StorageKey,
+    },
+    types::{verify_merkle_proof, ManagedByteArray, MultiValueEncoded},
+};
+use elrond_wasm_debug::{
+    merkle_tree::{self, MerkleHash, MerkleTree},
+    DebugApi,
+};
+
+fn create_tree() -> MerkleTreeMapper<DebugApi> {
+    let _ = DebugApi::dummy();
+    let base_key = StorageKey::new(&b"my_merkle_tree"[..]);
+    MerkleTreeMapper::new(base_key)
+}
+
+fn leaf(i: u8) -> MerkleHash {
+    [i; 32]
+}
+
+fn managed_hash(hash: &MerkleHash) -> ManagedByteArray<DebugApi, 32> {
+    ManagedByteArray::new_from_bytes(hash)
+}
+
+fn managed_proof(
+    proof: &[MerkleHash],
+) -> MultiValueEncoded<DebugApi, ManagedByteArray<DebugApi, 32>> {
+    proof.iter().map(managed_hash).collect()
+}
+
+#[test]
+fn test_merkle_tree_empty() {
+    let tree = create_tree();
+    assert!(tree.is_empty());
+    assert_eq!(tree.depth(), 0);
+    assert_eq!(tree.root().to_byte_array(), [0u8; 32]);
+    assert_eq!(MerkleTree::new().root(), [0u8; 32]);
+}
+
+#[test]
+fn test_merkle_tree_small() {
+    let mut tree = create_tree();
+    assert_eq!(tree.append(managed_hash(&leaf(1))), 0);
+    assert_eq!(tree.root().to_byte_array(), leaf(1));
+
+    assert_eq!(tree.append(managed_hash(&leaf(2))), 1);
+    let root_2 = merkle_tree::merkle_node_hash(&leaf(1), &leaf(2));
+    assert_eq!(tree.root().to_byte_array(), root_2);
+
+    tree.append(managed_hash(&leaf(3)));
+    let root_3 = merkle_tree::merkle_node_hash(
+        &root_2,
+        &merkle_tree::merkle_node_hash(&leaf(3), &[0u8; 32]),
+    );
+    assert_eq!(tree.depth(), 2);
+    assert_eq!(tree.root().to_byte_array(), root_3);
+}
+
+#[test]
+fn test_merkle_tree_matches_off_chain() {
+    let mut tree = create_tree();
+    let mut off_chain_tree = MerkleTree::new();
+    for i in 0..20u8 {
+        let index = tree.append(managed_hash(&leaf(i)));
+        assert_eq!(index, off_chain_tree.append(leaf(i)));
+        assert_eq!(tree.leaf_count(), off_chain_tree.leaf_count());
+        assert_eq!(tree.depth(), off_chain_tree.depth());
+        let root = off_chain_tree.root();
+        assert_eq!(tree.root().to_byte_array(), root);
+
+        for leaf_index in 0..=index {
+            let proof = off_chain_tree.proof(leaf_index);
+            assert_eq!(proof.len(), off_chain_tree.depth());
+            let leaf = leaf(leaf_index as u8);
+            let depth = off_chain_tree.depth();
+            assert!(merkle_tree::verify_merkle_proof(
+                &root, &leaf, leaf_index, depth, &proof
+            ));
+            assert!(tree.verify_proof(&managed_hash(&leaf), leaf_index, managed_proof(&proof)));
+            assert!(verify_merkle_proof(
+                &managed_hash(&root),
+                &managed_hash(&leaf),
+                leaf_index,
+                depth,
+                managed_proof(&proof)
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_merkle_tree_invalid_proofs() {
+    let mut tree = create_tree();
+    let off_chain_tree = MerkleTree::from_leaves((0..5u8).map(leaf).collect());
+    for leaf in off_chain_tree.leaves() {
+        tree.append(managed_hash(leaf));
+    }
+    let proof = off_chain_tree.proof(2);
+    assert!(tree.verify_proof(&managed_hash(&leaf(2)), 2, managed_proof(&proof)));
+
+    // wrong leaf
+    assert!(!tree.verify_proof(&managed_hash(&leaf(3)), 2, managed_proof(&proof)));
+    // wrong index
+    assert!(!tree.verify_proof(&managed_hash(&leaf(2)), 3, managed_proof(&proof)));
+    assert!(!tree.verify_proof(&managed_hash(&leaf(2)), 2 + 8, managed_proof(&proof)));
+    // tampered proof
+    let mut tampered_proof = proof.clone();
+    tampered_proof[1][0] ^= 1;
+    assert!(!tree.verify_proof(&managed_hash(&leaf(2)), 2, managed_proof(&tampered_proof)));
+    // an inner node posing as a leaf, with a shorter proof
+    let inner_node = merkle_tree::merkle_node_hash(&leaf(2), &leaf(3));
+    let short_proof = &proof[1..];
+    let depth = off_chain_tree.depth();
+    assert!(!merkle_tree::verify_merkle_proof(
+        &off_chain_tree.root(),
+        &inner_node,
+        1,
+        depth,
+        short_proof
+    ));
+    assert!(!verify_merkle_proof(
+        &managed_hash(&off_chain_tree.root()),
+        &managed_hash(&inner_node),
+        1,
+        depth,
+        managed_proof(short_proof)
+    ));
+    assert!(!tree.verify_proof(&managed_hash(&inner_node), 1, managed_proof(short_proof)));
+    // so the depth has to come from a trusted source, not from the length of the proof
+    assert!(merkle_tree::verify_merkle_proof(
+        &off_chain_tree.root(),
+        &inner_node,
+        1,
+        depth - 1,
+        short_proof
+    ));
+}
+
+#[test]
+fn test_merkle_tree_clear() {
+    let mut tree = create_tree();
+    for i in 0..7u8 {
+        tree.append(managed_hash(&leaf(i)));
+    }
+    tree.clear();
+    assert!(tree.is_empty());
+    assert_eq!(tree.root().to_byte_array(), [0u8; 32]);
+    tree.append(managed_hash(&leaf(9)));
+    assert_eq!(tree.root().to_byte_array(), leaf(9));
+}
@@ -0,0 +1,105 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use elrond_wasm::storage::{
+    mappers::{PriorityQueueMapper, StorageClearable, StorageMapper},
+    StorageKey,
+};
+use elrond_wasm_debug::DebugApi;
+
+fn create_queue() -> PriorityQueueMapper<DebugApi, u64, u64> {
+    let _ = DebugApi::dummy();
+    let base_key = StorageKey::new(&b"my_priority_queue"[..]);
+    PriorityQueueMapper::new(base_key)
+}
+
+/// Simple deterministic pseudo-random sequence, so failures can be reproduced.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % bound
+    }
+}
+
+#[test]
+fn test_priority_queue_simple() {
+    let mut queue = create_queue();
+    assert!(queue.is_empty());
+    assert_eq!(queue.peek(), None);
+    assert_eq!(queue.pop(), None);
+
+    queue.push(30, 300);
+    queue.push(10, 100);
+    queue.push(20, 200);
+    assert_eq!(queue.len(), 3);
+    assert!(queue.check_internal_consistency());
+    assert_eq!(queue.peek(), Some((10, 100)));
+    assert_eq!(queue.peek_priority(), Some(10));
+    assert_eq!(queue.iter().next(), Some((10, 100)));
+
+    assert_eq!(queue.pop(), Some((10, 100)));
+    assert_eq!(queue.pop(), Some((20, 200)));
+    assert_eq!(queue.pop(), Some((30, 300)));
+    assert_eq!(queue.pop(), None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_priority_queue_pop_if_priority_at_most() {
+    let mut queue = create_queue();
+    queue.push(5, 1);
+    queue.push(15, 2);
+    assert_eq!(queue.pop_if_priority_at_most(&4), None);
+    assert_eq!(queue.pop_if_priority_at_most(&10), Some((5, 1)));
+    assert_eq!(queue.pop_if_priority_at_most(&10), None);
+    assert_eq!(queue.pop_if_priority_at_most(&15), Some((15, 2)));
+    assert_eq!(queue.pop_if_priority_at_most(&100), None);
+}
+
+#[test]
+fn test_priority_queue_random_operations() {
+    let mut queue = create_queue();
+    let mut expected = BinaryHeap::new();
+    let mut rng = Lcg(3);
+    for _ in 0..1000 {
+        if rng.next(3) == 0 {
+            let popped = queue.pop().map(|(priority, _)| priority);
+            let expected_popped = expected.pop().map(|Reverse(priority)| priority);
+            assert_eq!(popped, expected_popped);
+        } else {
+            let priority = rng.next(100);
+            queue.push(priority, priority * 10);
+            expected.push(Reverse(priority));
+        }
+        assert_eq!(queue.len(), expected.len());
+        assert_eq!(
+            queue.peek_priority(),
+            expected.peek().map(|Reverse(priority)| *priority)
+        );
+    }
+    assert!(queue.check_internal_consistency());
+
+    let mut previous_priority = 0;
+    while let Some((priority, item)) = queue.pop() {
+        assert!(priority >= previous_priority);
+        assert_eq!(item, priority * 10);
+        previous_priority = priority;
+    }
+}
+
+#[test]
+fn test_priority_queue_clear() {
+    let mut queue = create_queue();
+    for priority in 0..10 {
+        queue.push(priority, priority);
+    }
+    queue.clear();
+    assert!(queue.is_empty());
+    assert_eq!(queue.iter().count(), 0);
+    queue.push(7, 8);
+    assert_eq!(queue.peek(), Some((7, 8)));
+}
@@ -0,0 +1,219 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Bound,
+};
+
+use elrond_wasm::storage::{
+    mappers::{SortedMapMapper, SortedSetMapper, StorageClearable, StorageMapper},
+    StorageKey,
+};
+use elrond_wasm_debug::DebugApi;
+
+fn create_set() -> SortedSetMapper<DebugApi, u64> {
+    let _ = DebugApi::dummy();
+    let base_key = StorageKey::new(&b"my_sorted_set"[..]);
+    SortedSetMapper::new(base_key)
+}
+
+fn create_map() -> SortedMapMapper<DebugApi, u64, u64> {
+    let _ = DebugApi::dummy();
+    let base_key = StorageKey::new(&b"my_sorted_map"[..]);
+    SortedMapMapper::new(base_key)
+}
+
+fn check_set(set: &SortedSetMapper<DebugApi, u64>, expected: Vec<u64>) {
+    assert_eq!(set.len(), expected.len());
+    assert!(set.check_internal_consistency());
+    let actual: Vec<u64> = set.iter().collect();
+    assert_eq!(actual, expected);
+    let reversed: Vec<u64> = set.iter().rev().collect();
+    assert_eq!(
+        reversed,
+        expected.iter().rev().cloned().collect::<Vec<u64>>()
+    );
+    assert_eq!(set.first(), expected.first().cloned());
+    assert_eq!(set.last(), expected.last().cloned());
+}
+
+/// Simple deterministic pseudo-random sequence, so failures can be reproduced.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % bound
+    }
+}
+
+#[test]
+fn test_sorted_set_simple() {
+    let mut set = create_set();
+    check_set(&set, vec![]);
+    assert!(set.insert(42));
+    check_set(&set, vec![42]);
+    assert!(!set.insert(42));
+    check_set(&set, vec![42]);
+    set.insert(44);
+    set.insert(43);
+    check_set(&set, vec![42, 43, 44]);
+    assert!(set.contains(&43));
+    assert!(!set.contains(&50));
+}
+
+#[test]
+fn test_sorted_set_removal() {
+    let mut set = create_set();
+    for item in [50, 30, 70, 20, 40, 60, 80] {
+        set.insert(item);
+    }
+    check_set(&set, vec![20, 30, 40, 50, 60, 70, 80]);
+    assert!(!set.remove(&45));
+    assert!(set.remove(&50));
+    check_set(&set, vec![20, 30, 40, 60, 70, 80]);
+    assert!(set.remove(&20));
+    check_set(&set, vec![30, 40, 60, 70, 80]);
+    assert_eq!(set.pop_first(), Some(30));
+    assert_eq!(set.pop_last(), Some(80));
+    check_set(&set, vec![40, 60, 70]);
+    assert!(!set.contains(&50));
+}
+
+#[test]
+fn test_sorted_set_ascending_inserts_stay_balanced() {
+    let mut set = create_set();
+    for item in 0..100 {
+        set.insert(item);
+    }
+    check_set(&set, (0..100).collect());
+    for item in (0..100).step_by(2) {
+        set.remove(&item);
+    }
+    check_set(&set, (1..100).step_by(2).collect());
+}
+
+#[test]
+fn test_sorted_set_range() {
+    let mut set = create_set();
+    for item in (10..=100).step_by(10) {
+        set.insert(item);
+    }
+    assert_eq!(set.range(30..60).collect::<Vec<u64>>(), vec![30, 40, 50]);
+    assert_eq!(
+        set.range(25..=60).collect::<Vec<u64>>(),
+        vec![30, 40, 50, 60]
+    );
+    assert_eq!(set.range(..25).collect::<Vec<u64>>(), vec![10, 20]);
+    assert_eq!(set.range(95..).collect::<Vec<u64>>(), vec![100]);
+    assert_eq!(
+        set.range(30..60).rev().collect::<Vec<u64>>(),
+        vec![50, 40, 30]
+    );
+    assert_eq!(set.range(31..39).count(), 0);
+    assert_eq!(set.range(200..).count(), 0);
+    assert_eq!(
+        set.range((Bound::Included(60), Bound::Excluded(30)))
+            .count(),
+        0
+    );
+
+    let mut iter = set.range(20..=50);
+    assert_eq!(iter.next(), Some(20));
+    assert_eq!(iter.next_back(), Some(50));
+    assert_eq!(iter.next(), Some(30));
+    assert_eq!(iter.next_back(), Some(40));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_sorted_set_random_operations() {
+    let mut set = create_set();
+    let mut expected = BTreeSet::new();
+    let mut rng = Lcg(7);
+    for _ in 0..1000 {
+        let item = rng.next(200);
+        if rng.next(3) == 0 {
+            assert_eq!(set.remove(&item), expected.remove(&item));
+        } else {
+            assert_eq!(set.insert(item), expected.insert(item));
+        }
+    }
+    check_set(&set, expected.iter().cloned().collect());
+
+    for _ in 0..20 {
+        let start = rng.next(200);
+        let end = start + rng.next(50);
+        assert_eq!(
+            set.range(start..end).collect::<Vec<u64>>(),
+            expected.range(start..end).cloned().collect::<Vec<u64>>()
+        );
+        assert_eq!(
+            set.range(start..=end).rev().collect::<Vec<u64>>(),
+            expected
+                .range(start..=end)
+                .rev()
+                .cloned()
+                .collect::<Vec<u64>>()
+        );
+    }
+}
+
+#[test]
+fn test_sorted_set_clear() {
+    let mut set = create_set();
+    for item in 0..10 {
+        set.insert(item);
+    }
+    set.clear();
+    check_set(&set, vec![]);
+    assert!(!set.contains(&5));
+    set.insert(5);
+    check_set(&set, vec![5]);
+}
+
+#[test]
+fn test_sorted_map() {
+    let mut map = create_map();
+    let mut expected = BTreeMap::new();
+    let mut rng = Lcg(11);
+    for _ in 0..300 {
+        let key = rng.next(100);
+        if rng.next(3) == 0 {
+            assert_eq!(map.remove(&key), expected.remove(&key));
+        } else {
+            let value = rng.next(1000);
+            assert_eq!(map.insert(key, value), expected.insert(key, value));
+        }
+    }
+    assert_eq!(map.len(), expected.len());
+    assert_eq!(
+        map.iter().collect::<Vec<(u64, u64)>>(),
+        expected
+            .iter()
+            .map(|(k, v)| (*k, *v))
+            .collect::<Vec<(u64, u64)>>()
+    );
+    assert_eq!(
+        map.values().rev().collect::<Vec<u64>>(),
+        expected.values().rev().cloned().collect::<Vec<u64>>()
+    );
+    assert_eq!(
+        map.range(20..40).collect::<Vec<(u64, u64)>>(),
+        expected
+            .range(20..40)
+            .map(|(k, v)| (*k, *v))
+            .collect::<Vec<(u64, u64)>>()
+    );
+    assert_eq!(map.first(), expected.iter().next().map(|(k, v)| (*k, *v)));
+    assert_eq!(
+        map.last(),
+        expected.iter().next_back().map(|(k, v)| (*k, *v))
+    );
+
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(map.get(&expected.keys().next().cloned().unwrap()), None);
+}
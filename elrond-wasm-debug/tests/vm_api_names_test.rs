@@ -0,0 +1,86 @@
+use std::{fs, path::Path};
+
+use elrond_wasm_debug::meta::VM_API_FUNCTION_NAMES;
+
+const DERIVE_RESERVED_PATH: &str = "../elrond-wasm-derive/src/validate/reserved.rs";
+const NODE_SRC_PATH: &str = "../elrond-wasm-node/src";
+
+/// The names in the `RESERVED` list of the derive crate, which cannot depend on this one.
+fn derive_reserved_names(source: &str) -> Vec<&str> {
+    let list_start = source
+        .find("const RESERVED: &[&str] = &[")
+        .expect("RESERVED list not found");
+    let list_end = list_start + source[list_start..].find("];").unwrap();
+    source[list_start..list_end]
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .collect()
+}
+
+/// The functions declared in the `extern "C"` blocks of a source file.
+fn extern_function_names(source: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    for block in source.split("extern \"C\" {").skip(1) {
+        let block_end = block.find("\n}").expect("unterminated extern block");
+        for declaration in block[..block_end].split("fn ").skip(1) {
+            let name_end = declaration.find('(').unwrap();
+            names.push(declaration[..name_end].trim());
+        }
+    }
+    names
+}
+
+fn collect_sources(dir: &Path, sources: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_sources(&path, sources);
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some("rs") {
+            sources.push(fs::read_to_string(&path).unwrap());
+        }
+    }
+}
+
+#[test]
+fn vm_api_names_include_node_imports_test() {
+    let mut sources = Vec::new();
+    collect_sources(Path::new(NODE_SRC_PATH), &mut sources);
+    let imported_names: Vec<&str> = sources
+        .iter()
+        .flat_map(|source| extern_function_names(source))
+        .collect();
+    assert!(imported_names.contains(&"mBufferNew"));
+    for name in &imported_names {
+        assert!(
+            VM_API_FUNCTION_NAMES.contains(name),
+            "`{}` is imported by elrond-wasm-node, but missing from VM_API_FUNCTION_NAMES",
+            name
+        );
+    }
+
+    // the other names are VM functions that are no longer imported, but still reserved
+    let source = fs::read_to_string(DERIVE_RESERVED_PATH).unwrap();
+    let reserved_names = derive_reserved_names(source.as_str());
+    for name in VM_API_FUNCTION_NAMES {
+        assert!(
+            imported_names.contains(name) || reserved_names.contains(name),
+            "`{}` is in VM_API_FUNCTION_NAMES, but neither imported by elrond-wasm-node, nor reserved in the derive crate",
+            name
+        );
+    }
+}
+
+#[test]
+fn vm_api_names_include_derive_reserved_test() {
+    let source = fs::read_to_string(DERIVE_RESERVED_PATH).unwrap();
+    let reserved_names = derive_reserved_names(source.as_str());
+    assert!(reserved_names.contains(&"getSCAddress"));
+    for name in reserved_names {
+        assert!(
+            VM_API_FUNCTION_NAMES.contains(&name),
+            "`{}` is reserved in the derive crate, but missing from VM_API_FUNCTION_NAMES",
+            name
+        );
+    }
+}
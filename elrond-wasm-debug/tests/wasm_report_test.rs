@@ -0,0 +1,165 @@
+use elrond_wasm_debug::meta::{FunctionSize, WasmReport};
+
+fn report_from_file(path: &str) -> WasmReport {
+    let wasm_bytes = std::fs::read(path).unwrap();
+    WasmReport::parse(wasm_bytes.as_slice()).unwrap()
+}
+
+#[test]
+fn wasm_report_factorial_test() {
+    let report = report_from_file("../contracts/examples/multisig/test-contracts/factorial.wasm");
+    assert_eq!(
+        report.imports,
+        vec![
+            "bigIntNew",
+            "signalError",
+            "bigIntGetCallValue",
+            "bigIntCmp",
+            "getNumArguments",
+            "bigIntGetUnsignedArgument",
+            "bigIntMul",
+            "bigIntAdd",
+            "bigIntFinishUnsigned",
+        ]
+    );
+    assert!(report.unknown_imports().is_empty());
+    assert!(!report.has_allocator());
+
+    // stripped, so no names
+    assert!(!report.has_function_names);
+    assert_eq!(report.functions.len(), 10);
+    assert!(report.functions[0].name.starts_with("func["));
+    assert!(report
+        .functions
+        .windows(2)
+        .all(|pair| pair[0].size >= pair[1].size));
+    assert!(report.functions.iter().map(|f| f.size).sum::<usize>() < report.size);
+}
+
+#[test]
+fn wasm_report_allocator_test() {
+    let report = report_from_file("../contracts/examples/multisig/test-contracts/adder.wasm");
+    assert!(report.unknown_imports().is_empty());
+    assert!(report.has_allocator());
+    assert_eq!(report.memory_grow_functions, vec!["func[49]"]);
+    assert_eq!(report.allocator_functions(), vec!["func[49]"]);
+}
+
+/// Hand-written, with an unknown import and a name section:
+/// ```wat
+/// (module
+///   (import "env" "foo" (func))
+///   (memory 1)
+///   (func $bar
+///     (drop (memory.grow (i32.const 1)))))
+/// ```
+#[test]
+fn wasm_report_names_test() {
+    let header: &[u8] = &[0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    let type_section: &[u8] = &[0x01, 0x04, 0x01, 0x60, 0x00, 0x00];
+    let import_section: &[u8] = &[
+        0x02, 0x0b, 0x01, 0x03, b'e', b'n', b'v', 0x03, b'f', b'o', b'o', 0x00, 0x00,
+    ];
+    let function_section: &[u8] = &[0x03, 0x02, 0x01, 0x00];
+    let memory_section: &[u8] = &[0x05, 0x03, 0x01, 0x00, 0x01];
+    let code_section: &[u8] = &[
+        0x0a, 0x09, 0x01, 0x07, 0x00, 0x41, 0x01, 0x40, 0x00, 0x1a, 0x0b,
+    ];
+    let name_section: &[u8] = &[
+        0x00, 0x0d, 0x04, b'n', b'a', b'm', b'e', 0x01, 0x06, 0x01, 0x01, 0x03, b'b', b'a', b'r',
+    ];
+    let wasm_bytes = [
+        header,
+        type_section,
+        import_section,
+        function_section,
+        memory_section,
+        code_section,
+        name_section,
+    ]
+    .concat();
+
+    let report = WasmReport::parse(wasm_bytes.as_slice()).unwrap();
+    assert_eq!(report.size, wasm_bytes.len());
+    assert_eq!(report.unknown_imports(), vec!["foo"]);
+    assert!(report.has_function_names);
+    assert_eq!(
+        report.functions,
+        vec![FunctionSize {
+            name: "bar".to_string(),
+            size: 7,
+        }]
+    );
+    assert_eq!(report.memory_grow_functions, vec!["bar"]);
+}
+
+#[test]
+fn wasm_report_invalid_test() {
+    assert!(WasmReport::parse(b"not a wasm file").is_err());
+}
+
+/// Hand-written, an empty function that is only recognizable as an allocator by its name:
+/// ```wat
+/// (module
+///   (func $__rust_alloc)
+///   (export "__rust_alloc" (func $__rust_alloc)))
+/// ```
+#[test]
+fn wasm_report_allocator_names_test() {
+    let header: &[u8] = &[0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    let type_section: &[u8] = &[0x01, 0x04, 0x01, 0x60, 0x00, 0x00];
+    let function_section: &[u8] = &[0x03, 0x02, 0x01, 0x00];
+    let export_section: &[u8] = &[
+        &[0x07, 0x10, 0x01, 0x0c][..],
+        b"__rust_alloc",
+        &[0x00, 0x00],
+    ]
+    .concat();
+    let code_section: &[u8] = &[0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b];
+    let name_section: &[u8] = &[
+        &[0x00, 0x16, 0x04][..],
+        b"name",
+        &[0x01, 0x0f, 0x01, 0x00, 0x0c],
+        b"__rust_alloc",
+    ]
+    .concat();
+
+    let only_exported = [
+        header,
+        type_section,
+        function_section,
+        export_section,
+        code_section,
+    ]
+    .concat();
+    let report = WasmReport::parse(only_exported.as_slice()).unwrap();
+    assert!(!report.has_function_names);
+    assert!(report.memory_grow_functions.is_empty());
+    assert!(report.has_allocator());
+    assert_eq!(report.allocator_functions(), vec!["__rust_alloc"]);
+
+    let only_named = [
+        header,
+        type_section,
+        function_section,
+        code_section,
+        name_section,
+    ]
+    .concat();
+    let report = WasmReport::parse(only_named.as_slice()).unwrap();
+    assert!(report.has_function_names);
+    assert!(report.has_allocator());
+    assert_eq!(report.allocator_functions(), vec!["__rust_alloc"]);
+
+    let exported_and_named = [
+        header,
+        type_section,
+        function_section,
+        export_section,
+        code_section,
+        name_section,
+    ]
+    .concat();
+    let report = WasmReport::parse(exported_and_named.as_slice()).unwrap();
+    assert_eq!(report.named_allocator_functions, vec!["__rust_alloc"]);
+}
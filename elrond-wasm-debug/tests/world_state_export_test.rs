@@ -0,0 +1,105 @@
+use elrond_wasm::types::Address;
+use elrond_wasm_debug::{
+    num_bigint::BigUint,
+    world_mock::{AccountData, AccountEsdt, EsdtInstanceMetadata},
+    BlockchainMock,
+};
+use std::collections::HashMap;
+
+const TOKEN_ID: &[u8] = b"TOKEN-123456";
+const NFT_ID: &[u8] = b"NFT-123456";
+
+fn address(byte: u8) -> Address {
+    Address::from([byte; 32])
+}
+
+fn sc_address(last_byte: u8) -> Address {
+    let mut bytes = [0u8; 32];
+    bytes[31] = last_byte;
+    Address::from(bytes)
+}
+
+fn world() -> BlockchainMock {
+    let mut esdt = AccountEsdt::default();
+    esdt.set_esdt_balance(
+        TOKEN_ID.to_vec(),
+        0,
+        &BigUint::from(1000u32),
+        EsdtInstanceMetadata::default(),
+    );
+    esdt.set_esdt_balance(
+        NFT_ID.to_vec(),
+        3,
+        &BigUint::from(1u32),
+        EsdtInstanceMetadata {
+            attributes: b"some attributes".to_vec(),
+            ..Default::default()
+        },
+    );
+    esdt.set_roles(TOKEN_ID.to_vec(), vec![b"ESDTRoleLocalMint".to_vec()]);
+
+    let mut storage = HashMap::new();
+    storage.insert(b"key".to_vec(), b"value".to_vec());
+    storage.insert(vec![0xff, 0x00], vec![1, 2, 3]);
+
+    let mut world = BlockchainMock::new();
+    world.add_account(AccountData {
+        address: address(1),
+        nonce: 5,
+        egld_balance: BigUint::from(100u32),
+        esdt,
+        storage,
+        username: b"user.elrond".to_vec(),
+        contract_path: None,
+        contract_owner: None,
+        developer_rewards: BigUint::from(0u32),
+    });
+    world.put_new_address(address(1), 5, sc_address(2));
+    world.current_block_info.block_nonce = 10;
+    world.previous_block_info.block_timestamp = 1234;
+    world
+}
+
+#[test]
+fn test_export_import_state() {
+    let file_path = std::env::temp_dir().join("elrond_wasm_debug_world_state_export.scen.json");
+    world().export_state(&file_path);
+
+    let mut imported = BlockchainMock::new();
+    imported.import_state(&file_path);
+    std::fs::remove_file(&file_path).unwrap();
+
+    let account = imported.account(&address(1)).unwrap();
+    assert_eq!(account.nonce, 5);
+    assert_eq!(account.egld_balance, BigUint::from(100u32));
+    assert_eq!(account.username, b"user.elrond".to_vec());
+    assert_eq!(
+        account.storage.get(&b"key".to_vec()),
+        Some(&b"value".to_vec())
+    );
+    assert_eq!(account.storage.get(&vec![0xff, 0x00]), Some(&vec![1, 2, 3]));
+    assert_eq!(
+        account.esdt.get_esdt_balance(TOKEN_ID, 0),
+        BigUint::from(1000u32)
+    );
+    assert_eq!(
+        account.esdt.get_roles(TOKEN_ID),
+        vec![b"ESDTRoleLocalMint".to_vec()]
+    );
+    let nft = account
+        .esdt
+        .get_by_identifier(NFT_ID)
+        .unwrap()
+        .instances
+        .get_by_nonce(3)
+        .unwrap();
+    assert_eq!(nft.balance, BigUint::from(1u32));
+    assert_eq!(nft.metadata.attributes, b"some attributes".to_vec());
+
+    assert_eq!(
+        imported.new_addresses.get(&(address(1), 5)),
+        Some(&sc_address(2))
+    );
+    assert_eq!(imported.current_block_info.block_nonce, 10);
+    assert_eq!(imported.previous_block_info.block_timestamp, 1234);
+}
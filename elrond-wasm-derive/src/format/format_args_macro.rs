@@ -36,7 +36,7 @@ pub fn format_receiver_args_macro(input: proc_macro::TokenStream) -> proc_macro:
     format_str_parts.into_iter().map(|part| {
         match part {
             FormatPartType::StaticAscii(ascii_string) => {
-                let str_as_bytes = byte_str_literal(ascii_string.as_bytes());
+                let str_as_bytes = proc_macro::TokenStream::from(byte_str_literal(ascii_string.as_bytes()));
                 quote! (
                     elrond_wasm::formatter::FormatBuffer::append_ascii(&mut $accumulator_expr, $str_as_bytes);
                 )
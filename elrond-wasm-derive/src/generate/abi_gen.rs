@@ -1,10 +1,11 @@
 use super::util::*;
 use crate::model::{
     AutoImpl, ContractTrait, EndpointLocationMetadata, EndpointMutabilityMetadata, Method,
-    MethodImpl, PublicRole,
+    MethodArgument, MethodImpl, PublicRole,
 };
 
 fn generate_endpoint_snippet(
+    contract: &ContractTrait,
     m: &Method,
     endpoint_name: &str,
     only_owner: bool,
@@ -14,6 +15,8 @@ fn generate_endpoint_snippet(
 ) -> proc_macro2::TokenStream {
     let endpoint_docs = &m.docs;
     let rust_method_name = m.name.to_string();
+    let module_name = contract.trait_name.to_string();
+    let labels = &m.labels;
     let payable_in_tokens = m.payable_metadata().abi_strings();
 
     let input_snippets: Vec<proc_macro2::TokenStream> = m
@@ -59,6 +62,8 @@ fn generate_endpoint_snippet(
             only_admin: #only_admin,
             mutability: #mutability_tokens,
             location: #location_tokens,
+            labels: &[ #(#labels),* ],
+            module: #module_name,
             payable_in_tokens: &[ #(#payable_in_tokens),* ],
             inputs: elrond_wasm::types::heap::Vec::new(),
             outputs: elrond_wasm::types::heap::Vec::new(),
@@ -75,6 +80,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
         .filter_map(|m| match &m.public_role {
             PublicRole::Init(_) => {
                 let endpoint_def = generate_endpoint_snippet(
+                    contract,
                     m,
                     "init",
                     false,
@@ -90,6 +96,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
             PublicRole::Endpoint(endpoint_metadata) => {
                 let endpoint_name_str = endpoint_metadata.public_name.to_string();
                 let endpoint_def = generate_endpoint_snippet(
+                    contract,
                     m,
                     &endpoint_name_str,
                     endpoint_metadata.only_owner,
@@ -107,8 +114,13 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
         .collect()
 }
 
-fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStream {
+fn generate_event_snippet(
+    contract: &ContractTrait,
+    m: &Method,
+    event_name: &str,
+) -> proc_macro2::TokenStream {
     let event_docs = &m.docs;
+    let module_name = contract.trait_name.to_string();
     let input_snippets: Vec<proc_macro2::TokenStream> = m
         .method_args
         .iter()
@@ -130,6 +142,7 @@ fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStr
             docs: &[ #(#event_docs),* ],
             identifier: #event_name,
             inputs: elrond_wasm::types::heap::Vec::new(),
+            module: #module_name,
         };
         #(#input_snippets)*
     }
@@ -141,7 +154,7 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .iter()
         .filter_map(|m| {
             if let MethodImpl::Generated(AutoImpl::Event { identifier }) = &m.implementation {
-                let event_def = generate_event_snippet(m, identifier);
+                let event_def = generate_event_snippet(contract, m, identifier);
                 Some(quote! {
                     #event_def
                     contract_abi.events.push(event_abi);
@@ -153,6 +166,193 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+/// Named via `TypeAbi` when the type implements it, with the source code name as fallback.
+fn storage_type_snippet(ty: &syn::Type) -> proc_macro2::TokenStream {
+    let mut storage_type = ty.clone();
+    clear_all_type_lifetimes(&mut storage_type);
+    let source_name = storage_type_name(ty);
+    quote! {
+        let storage_type = &elrond_wasm::abi::StorageTypeAbi::<#storage_type>::new();
+        storage_type.provide_storage_type_descriptions(&mut contract_abi);
+        let storage_type_name = storage_type.storage_type_name(#source_name);
+    }
+}
+
+fn generate_storage_snippet(
+    m: &Method,
+    identifier: &str,
+    mapper: proc_macro2::TokenStream,
+    key_args: &[MethodArgument],
+    value_types: Vec<&syn::Type>,
+) -> proc_macro2::TokenStream {
+    let storage_docs = &m.docs;
+    let rust_method_name = m.name.to_string();
+    let key_arg_snippets: Vec<proc_macro2::TokenStream> = key_args
+        .iter()
+        .map(|arg| {
+            let arg_name = &arg.pat;
+            let arg_name_str = quote! { #arg_name }.to_string();
+            let type_snippet = storage_type_snippet(&arg.ty);
+            quote! {
+                #type_snippet
+                storage_abi.add_key_arg(#arg_name_str, storage_type_name.as_str());
+            }
+        })
+        .collect();
+    let value_type_snippets: Vec<proc_macro2::TokenStream> = value_types
+        .into_iter()
+        .map(|value_type| {
+            let type_snippet = storage_type_snippet(value_type);
+            quote! {
+                #type_snippet
+                storage_abi.add_value_type(storage_type_name.as_str());
+            }
+        })
+        .collect();
+
+    quote! {
+        let mut storage_abi = elrond_wasm::abi::StorageAbi{
+            docs: &[ #(#storage_docs),* ],
+            identifier: #identifier,
+            rust_method_name: #rust_method_name,
+            mapper: #mapper,
+            key_args: elrond_wasm::types::heap::Vec::new(),
+            value_types: elrond_wasm::types::heap::Vec::new(),
+        };
+        {
+            use elrond_wasm::abi::{StorageTypeAbiFallback, StorageTypeAbiProvider};
+            #(#key_arg_snippets)*
+            #(#value_type_snippets)*
+        }
+        contract_abi.add_storage(storage_abi);
+    }
+}
+
+fn generate_storage_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .methods
+        .iter()
+        .filter_map(|m| match (&m.implementation, &m.return_type) {
+            (
+                MethodImpl::Generated(AutoImpl::StorageGetter { identifier }),
+                syn::ReturnType::Type(_, ty),
+            ) => Some(generate_storage_snippet(
+                m,
+                identifier,
+                quote! { elrond_wasm::abi::SINGLE_VALUE_MAPPER_NAME },
+                m.method_args.as_slice(),
+                vec![ty.as_ref()],
+            )),
+            (MethodImpl::Generated(AutoImpl::StorageSetter { identifier }), _) => {
+                let (value_arg, key_args) = m.method_args.split_last()?;
+                Some(generate_storage_snippet(
+                    m,
+                    identifier,
+                    quote! { elrond_wasm::abi::SINGLE_VALUE_MAPPER_NAME },
+                    key_args,
+                    vec![&value_arg.ty],
+                ))
+            },
+            (
+                MethodImpl::Generated(AutoImpl::StorageMapper { identifier }),
+                syn::ReturnType::Type(_, ty),
+            ) => {
+                let (mapper, value_types) = mapper_name_and_value_types(ty);
+                Some(generate_storage_snippet(
+                    m,
+                    identifier,
+                    quote! { #mapper },
+                    m.method_args.as_slice(),
+                    value_types,
+                ))
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Splits a mapper type such as `MapMapper<Self::Api, K, V>` into its name and its generic type arguments.
+fn mapper_name_and_value_types(ty: &syn::Type) -> (String, Vec<&syn::Type>) {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(last_segment) = type_path.path.segments.last() {
+            return (
+                last_segment.ident.to_string(),
+                generic_type_args(&last_segment.arguments),
+            );
+        }
+    }
+    (quote! { #ty }.to_string(), Vec::new())
+}
+
+/// Skips the API type argument.
+fn generic_type_args(arguments: &syn::PathArguments) -> Vec<&syn::Type> {
+    if let syn::PathArguments::AngleBracketed(angle_bracketed) = arguments {
+        angle_bracketed
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(arg_ty) if !is_api_type(arg_ty) => Some(arg_ty),
+                _ => None,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// The storage types are not required to implement `TypeAbi`,
+/// so the names are derived from the source code, following the `TypeAbi` naming conventions
+/// for the most common types.
+fn storage_type_name(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Reference(type_reference) => storage_type_name(&type_reference.elem),
+        syn::Type::Paren(type_paren) => storage_type_name(&type_paren.elem),
+        syn::Type::Tuple(type_tuple) => {
+            let elem_names: Vec<String> = type_tuple.elems.iter().map(storage_type_name).collect();
+            format!("tuple<{}>", elem_names.join(","))
+        },
+        syn::Type::Array(type_array) => {
+            let len = &type_array.len;
+            format!(
+                "array{}<{}>",
+                quote! { #len },
+                storage_type_name(&type_array.elem)
+            )
+        },
+        syn::Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap();
+            let name = last_segment.ident.to_string();
+            let arg_names = generic_type_arg_names(&last_segment.arguments);
+            match (name.as_str(), arg_names.as_slice()) {
+                ("ManagedBuffer" | "BoxedBytes", _) => "bytes".to_string(),
+                ("ManagedAddress", _) => "Address".to_string(),
+                ("Vec" | "ManagedVec", [item_name]) if item_name == "u8" => "bytes".to_string(),
+                ("Vec" | "ManagedVec", [item_name]) => format!("List<{}>", item_name),
+                ("Box", [item_name]) => item_name.clone(),
+                (_, []) => name,
+                (_, _) => format!("{}<{}>", name, arg_names.join(",")),
+            }
+        },
+        _ => quote! { #ty }.to_string().replace(' ', ""),
+    }
+}
+
+fn generic_type_arg_names(arguments: &syn::PathArguments) -> Vec<String> {
+    generic_type_args(arguments)
+        .into_iter()
+        .map(storage_type_name)
+        .collect()
+}
+
+fn is_api_type(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        let segments = &type_path.path.segments;
+        segments.len() == 2 && segments[0].ident == "Self" && segments[1].ident == "Api"
+    } else {
+        false
+    }
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -183,6 +383,7 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_snippets = generate_storage_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -205,11 +406,13 @@ fn generate_abi_method_body(
             constructors: elrond_wasm::types::heap::Vec::new(),
             endpoints: elrond_wasm::types::heap::Vec::new(),
             events: elrond_wasm::types::heap::Vec::new(),
+            storage: elrond_wasm::types::heap::Vec::new(),
             has_callback: #has_callbacks,
             type_descriptions: <elrond_wasm::abi::TypeDescriptionContainerImpl as elrond_wasm::abi::TypeDescriptionContainer>::new(),
         };
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_snippets)*
         #(#supertrait_snippets)*
         contract_abi
     }
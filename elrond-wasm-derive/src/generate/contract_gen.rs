@@ -1,5 +1,5 @@
 use super::{method_call_gen::generate_call_method, method_gen};
-use crate::model::{ContractTrait, MethodImpl, PublicRole};
+use crate::model::{ContractTrait, Method, MethodImpl, PublicRole};
 
 pub fn extract_method_impls(contract_trait: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
     contract_trait
@@ -8,10 +8,19 @@ pub fn extract_method_impls(contract_trait: &ContractTrait) -> Vec<proc_macro2::
         .filter_map(|m| {
             if let MethodImpl::Explicit(body) = &m.implementation {
                 let msig = method_gen::generate_sig_with_attributes(m);
-                Some(quote! {
-                    #msig
-                    #body
-                })
+                match endpoint_name(m) {
+                    Some(endpoint_name) => Some(quote! {
+                        #msig
+                        {
+                            <Self::Api as elrond_wasm::api::PrintApi>::trace_endpoint_call(#endpoint_name);
+                            #body
+                        }
+                    }),
+                    None => Some(quote! {
+                        #msig
+                        #body
+                    }),
+                }
             } else {
                 None
             }
@@ -19,6 +28,14 @@ pub fn extract_method_impls(contract_trait: &ContractTrait) -> Vec<proc_macro2::
         .collect()
 }
 
+fn endpoint_name(m: &Method) -> Option<String> {
+    match &m.public_role {
+        PublicRole::Init(_) => Some("init".to_string()),
+        PublicRole::Endpoint(endpoint_metadata) => Some(endpoint_metadata.public_name.to_string()),
+        _ => None,
+    }
+}
+
 pub fn generate_call_methods(contract_trait: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
     contract_trait
         .methods
@@ -0,0 +1,163 @@
+use super::codec_attr::{check_fields_attributes, container_version, field_attributes};
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+
+pub fn lazy_decode_derive(ast: &syn::DeriveInput) -> TokenStream {
+    match &ast.data {
+        syn::Data::Struct(data_struct) => struct_derive(data_struct, ast),
+        syn::Data::Enum(_) => panic!("LazyDecode only supports structs"),
+        syn::Data::Union(_) => panic!("Union not supported!"),
+    }
+}
+
+fn is_managed_type_api_bound(bound: &syn::TypeParamBound) -> bool {
+    if let syn::TypeParamBound::Trait(trait_bound) = bound {
+        if let Some(last_segment) = trait_bound.path.segments.last() {
+            return last_segment.ident == "ManagedTypeApi";
+        }
+    }
+    false
+}
+
+/// The generics of the view: the ones of the struct,
+/// plus a managed type API parameter, if the struct does not already have one.
+fn view_generics(generics: &syn::Generics) -> (syn::Generics, syn::Ident) {
+    for type_param in generics.type_params() {
+        if type_param.bounds.iter().any(is_managed_type_api_bound) {
+            return (generics.clone(), type_param.ident.clone());
+        }
+    }
+
+    let api_ident = format_ident!("M");
+    assert!(
+        generics
+            .type_params()
+            .all(|type_param| type_param.ident != api_ident),
+        "LazyDecode requires the type parameter M to be bound by ManagedTypeApi"
+    );
+    let mut view_generics = generics.clone();
+    view_generics.params.push(syn::GenericParam::Type(
+        syn::parse_quote!(#api_ident: elrond_wasm::api::ManagedTypeApi),
+    ));
+    (view_generics, api_ident)
+}
+
+/// Each accessor skips the encoded fields before its own, then decodes only its own field.
+fn generate_accessor_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
+    let fields_named = match fields {
+        syn::Fields::Named(fields_named) => fields_named,
+        syn::Fields::Unit => return Vec::new(),
+        syn::Fields::Unnamed(_) => panic!("LazyDecode only supports named fields"),
+    };
+
+    let mut preceding_types = Vec::<&syn::Type>::new();
+    let mut snippets = Vec::new();
+    for field in fields_named.named.iter() {
+        let attributes = field_attributes(field);
+        if attributes.skip {
+            continue;
+        }
+
+        let field_ident = &field.ident;
+        let field_ty = &field.ty;
+        let doc_attrs = field.attrs.iter().filter(|attr| attr.path.is_ident("doc"));
+        let mut_token = if preceding_types.is_empty() {
+            quote! {}
+        } else {
+            quote! { mut }
+        };
+        let decode_method = if attributes.default {
+            quote! { decode_or_default }
+        } else {
+            quote! { decode }
+        };
+        snippets.push(quote! {
+            #(#doc_attrs)*
+            pub fn #field_ident(&self) -> #field_ty {
+                let #mut_token input = elrond_wasm::types::ManagedBufferLazyDecodeInput::new(&self.buffer);
+                #(input.skip::<#preceding_types>();)*
+                input.#decode_method::<#field_ty>()
+            }
+        });
+        preceding_types.push(field_ty);
+    }
+    snippets
+}
+
+fn struct_derive(data_struct: &syn::DataStruct, ast: &syn::DeriveInput) -> TokenStream {
+    assert!(
+        container_version(ast).is_none(),
+        "LazyDecode does not support versioned structs"
+    );
+    check_fields_attributes(&data_struct.fields, None);
+
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let view_name = format_ident!("{}Lazy", name);
+    let view_doc = format!(
+        "Lazily decoded view of an encoded [`{}`]: only the fields that are accessed get decoded.",
+        name
+    );
+    let (_, struct_ty_generics, _) = ast.generics.split_for_impl();
+    let (view_generics, api_ident) = view_generics(&ast.generics);
+    let (impl_generics, ty_generics, where_clause) = view_generics.split_for_impl();
+    let accessor_snippets = generate_accessor_snippets(&data_struct.fields);
+
+    let gen = quote! {
+        #[doc = #view_doc]
+        #vis struct #view_name #impl_generics #where_clause {
+            buffer: elrond_wasm::types::ManagedBuffer<#api_ident>,
+            _phantom: core::marker::PhantomData<#name #struct_ty_generics>,
+        }
+
+        impl #impl_generics #view_name #ty_generics #where_clause {
+            pub fn new(buffer: elrond_wasm::types::ManagedBuffer<#api_ident>) -> Self {
+                #view_name {
+                    buffer,
+                    _phantom: core::marker::PhantomData,
+                }
+            }
+
+            pub fn as_managed_buffer(&self) -> &elrond_wasm::types::ManagedBuffer<#api_ident> {
+                &self.buffer
+            }
+
+            pub fn into_managed_buffer(self) -> elrond_wasm::types::ManagedBuffer<#api_ident> {
+                self.buffer
+            }
+
+            /// Decodes all fields at once.
+            pub fn decode_all(&self) -> #name #struct_ty_generics
+            where
+                #name #struct_ty_generics: elrond_wasm::elrond_codec::TopDecode,
+            {
+                elrond_wasm::contract_base::ManagedSerializer::<#api_ident>::new()
+                    .top_decode_from_managed_buffer(&self.buffer)
+            }
+
+            #(#accessor_snippets)*
+        }
+
+        impl #impl_generics elrond_wasm::elrond_codec::TopEncode for #view_name #ty_generics #where_clause {
+            fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> core::result::Result<(), H::HandledErr>
+            where
+                O: elrond_wasm::elrond_codec::TopEncodeOutput,
+                H: elrond_wasm::elrond_codec::EncodeErrorHandler,
+            {
+                elrond_wasm::elrond_codec::TopEncode::top_encode_or_handle_err(&self.buffer, output, h)
+            }
+        }
+
+        impl #impl_generics elrond_wasm::elrond_codec::TopDecode for #view_name #ty_generics #where_clause {
+            fn top_decode_or_handle_err<I, H>(input: I, h: H) -> core::result::Result<Self, H::HandledErr>
+            where
+                I: elrond_wasm::elrond_codec::TopDecodeInput,
+                H: elrond_wasm::elrond_codec::DecodeErrorHandler,
+            {
+                let buffer = <elrond_wasm::types::ManagedBuffer<#api_ident> as elrond_wasm::elrond_codec::TopDecode>::top_decode_or_handle_err(input, h)?;
+                core::result::Result::Ok(#view_name::new(buffer))
+            }
+        }
+    };
+    gen.into()
+}
@@ -9,9 +9,12 @@ extern crate syn;
 #[macro_use]
 extern crate quote;
 
+/// A copy of the one in `elrond-codec-derive`, so that the ABI describes exactly what the codec derives encode.
+mod codec_attr;
 mod contract_impl;
 mod format;
 mod generate;
+mod lazy_decode_derive;
 mod macro_contract;
 mod macro_module;
 mod macro_proxy;
@@ -60,6 +63,13 @@ pub fn managed_vec_item_derive(input: proc_macro::TokenStream) -> proc_macro::To
     managed_vec_item_derive::managed_vec_item_derive(&ast)
 }
 
+#[proc_macro_derive(LazyDecode, attributes(codec))]
+pub fn lazy_decode_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+
+    lazy_decode_derive::lazy_decode_derive(&ast)
+}
+
 #[proc_macro]
 pub fn format_receiver_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     format::format_receiver_args_macro(input)
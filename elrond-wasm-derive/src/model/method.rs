@@ -8,6 +8,7 @@ pub enum AutoImpl {
     StorageGetter { identifier: String },
     StorageSetter { identifier: String },
     StorageMapper { identifier: String },
+    StorageMapperFromAddress { identifier: String },
     StorageIsEmpty { identifier: String },
     StorageClear { identifier: String },
     ProxyGetter,
@@ -41,6 +42,7 @@ pub struct Method {
     pub unprocessed_attributes: Vec<syn::Attribute>,
     pub method_args: Vec<MethodArgument>,
     pub output_names: Vec<String>,
+    pub labels: Vec<String>,
     pub return_type: syn::ReturnType,
     pub implementation: MethodImpl,
 }
@@ -3,6 +3,7 @@ pub(super) static ATTR_ONLY_OWNER: &str = "only_owner";
 pub(super) static ATTR_ONLY_ADMIN: &str = "only_admin";
 pub(super) static ATTR_ONLY_USER_ACCOUNT: &str = "only_user_account";
 pub(super) static ATTR_OUTPUT_NAME: &str = "output_name";
+pub(super) static ATTR_LABEL: &str = "label";
 pub(super) static ATTR_PAYMENT: &str = "payment"; // synonymous with `payment_amount`
 pub(super) static ATTR_PAYMENT_AMOUNT: &str = "payment_amount";
 pub(super) static ATTR_PAYMENT_TOKEN: &str = "payment_token";
@@ -21,6 +22,7 @@ pub(super) static ATTR_CALLBACK_CALL_RESULT: &str = "call_result";
 pub(super) static ATTR_STORAGE_GET: &str = "storage_get";
 pub(super) static ATTR_STORAGE_SET: &str = "storage_set";
 pub(super) static ATTR_STORAGE_MAPPER: &str = "storage_mapper";
+pub(super) static ATTR_STORAGE_MAPPER_FROM_ADDRESS: &str = "storage_mapper_from_address";
 pub(super) static ATTR_STORAGE_IS_EMPTY: &str = "storage_is_empty";
 pub(super) static ATTR_STORAGE_CLEAR: &str = "storage_clear";
 pub(super) static ATTR_PROXY: &str = "proxy";
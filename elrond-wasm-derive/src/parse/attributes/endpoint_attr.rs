@@ -99,3 +99,14 @@ impl CallbackAttribute {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct LabelAttribute {
+    pub label: String,
+}
+
+impl LabelAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<LabelAttribute> {
+        is_attr_one_string_arg(attr, ATTR_LABEL).map(|label| LabelAttribute { label })
+    }
+}
@@ -6,8 +6,8 @@ use crate::model::{
 use super::{
     attributes::{
         is_callback_raw, is_init, is_only_admin, is_only_owner, is_only_user_account,
-        CallbackAttribute, EndpointAttribute, ExternalViewAttribute, OutputNameAttribute,
-        ViewAttribute,
+        CallbackAttribute, EndpointAttribute, ExternalViewAttribute, LabelAttribute,
+        OutputNameAttribute, ViewAttribute,
     },
     MethodAttributesPass1,
 };
@@ -174,3 +174,11 @@ pub fn process_output_names_attribute(attr: &syn::Attribute, method: &mut Method
         })
         .is_some()
 }
+
+pub fn process_label_attribute(attr: &syn::Attribute, method: &mut Method) -> bool {
+    LabelAttribute::parse(attr)
+        .map(|label_attr| {
+            method.labels.push(label_attr.label);
+        })
+        .is_some()
+}
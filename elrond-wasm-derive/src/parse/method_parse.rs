@@ -6,11 +6,11 @@ use super::{
         process_event_attribute, process_legacy_event_attribute, process_proxy_attribute,
         process_storage_clear_attribute, process_storage_get_attribute,
         process_storage_is_empty_attribute, process_storage_mapper_attribute,
-        process_storage_set_attribute,
+        process_storage_mapper_from_address_attribute, process_storage_set_attribute,
     },
     extract_method_args, process_callback_attribute, process_callback_raw_attribute,
     process_endpoint_attribute, process_external_view_attribute, process_init_attribute,
-    process_only_admin_attribute, process_only_owner_attribute,
+    process_label_attribute, process_only_admin_attribute, process_only_owner_attribute,
     process_only_user_account_attribute, process_output_names_attribute, process_payable_attribute,
     process_view_attribute,
 };
@@ -54,6 +54,7 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         unprocessed_attributes: Vec::new(),
         method_args,
         output_names: Vec::new(),
+        labels: Vec::new(),
         return_type: m.sig.output.clone(),
         implementation,
     };
@@ -120,7 +121,9 @@ fn process_attribute_second_pass(
         || process_storage_get_attribute(attr, method)
         || process_storage_set_attribute(attr, method)
         || process_storage_mapper_attribute(attr, method)
+        || process_storage_mapper_from_address_attribute(attr, method)
         || process_storage_is_empty_attribute(attr, method)
         || process_storage_clear_attribute(attr, method)
         || process_output_names_attribute(attr, method)
+        || process_label_attribute(attr, method)
 }
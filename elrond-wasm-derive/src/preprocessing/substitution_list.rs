@@ -63,6 +63,7 @@ fn add_managed_types(substitutions: &mut SubstitutionsMap) {
     add_managed_type_with_generics(substitutions, &quote!(ManagedCountedVarArgs));
     add_managed_type_with_generics(substitutions, &quote!(ManagedCountedMultiResultVec));
     add_managed_type_with_generics(substitutions, &quote!(MultiValueManagedVec));
+    add_managed_type_with_generics(substitutions, &quote!(MultiValuePage));
 
     // types::managed::wrapped
     add_managed_type(substitutions, &quote!(EgldOrEsdtTokenIdentifier));
@@ -92,6 +93,9 @@ fn add_storage_mapper_single_generic_arg(
         quote!(#mapper_name<Self::Api>),
     );
     substitutions.add_substitution(quote!(#mapper_name), quote!(#mapper_name<Self::Api>));
+
+    // the storage address, for mappers reading from other contracts
+    add_managed_type_with_generics(substitutions, mapper_name);
 }
 
 fn add_storage_mapper(
@@ -104,13 +108,17 @@ fn add_storage_mapper(
 fn add_storage_mappers(substitutions: &mut SubstitutionsMap) {
     add_storage_mapper_single_generic_arg(substitutions, &quote!(TokenAttributesMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(UserMapper));
+    add_storage_mapper_single_generic_arg(substitutions, &quote!(MerkleTreeMapper));
 
     add_storage_mapper(substitutions, &quote!(LinkedListMapper));
     add_storage_mapper(substitutions, &quote!(MapMapper));
     add_storage_mapper(substitutions, &quote!(MapStorageMapper));
+    add_storage_mapper(substitutions, &quote!(PriorityQueueMapper));
     add_storage_mapper(substitutions, &quote!(SetMapper));
     add_storage_mapper(substitutions, &quote!(UnorderedSetMapper));
     add_storage_mapper(substitutions, &quote!(SingleValueMapper));
+    add_storage_mapper(substitutions, &quote!(SortedMapMapper));
+    add_storage_mapper(substitutions, &quote!(SortedSetMapper));
     add_storage_mapper(substitutions, &quote!(VecMapper));
     add_storage_mapper(substitutions, &quote!(QueueMapper));
 }
@@ -1,4 +1,9 @@
-use super::parse::attributes::extract_doc;
+use super::{
+    codec_attr::{
+        check_fields_attributes, container_version, field_attributes, variant_discriminants,
+    },
+    parse::attributes::extract_doc,
+};
 use proc_macro::TokenStream;
 use quote::quote;
 
@@ -10,11 +15,19 @@ fn field_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
         index.to_string()
     };
     let field_ty = &field.ty;
+    let attributes = field_attributes(field);
+    let since_snippet = match attributes.since {
+        Some(since) => quote! { core::option::Option::Some(#since) },
+        None => quote! { core::option::Option::None },
+    };
+    let default = attributes.default;
     quote! {
         field_descriptions.push(elrond_wasm::abi::StructFieldDescription {
             docs: &[ #(#field_docs),* ],
             name: #field_name_str,
             field_type: <#field_ty>::type_name(),
+            since: #since_snippet,
+            default: #default,
         });
         <#field_ty>::provide_type_descriptions(accumulator);
     }
@@ -26,12 +39,14 @@ fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
             .named
             .iter()
             .enumerate()
+            .filter(|(_, field)| !field_attributes(field).skip)
             .map(|(index, field)| field_snippet(index, field))
             .collect(),
         syn::Fields::Unnamed(fields_unnamed) => fields_unnamed
             .unnamed
             .iter()
             .enumerate()
+            .filter(|(_, field)| !field_attributes(field).skip)
             .map(|(index, field)| field_snippet(index, field))
             .collect(),
         syn::Fields::Unit => Vec::new(),
@@ -40,12 +55,18 @@ fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
 
 pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
     let type_docs = extract_doc(ast.attrs.as_slice());
+    let version_snippet = match container_version(ast) {
+        Some(version) => quote! { core::option::Option::Some(#version) },
+        None => quote! { core::option::Option::None },
+    };
     let type_description_impl = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            check_fields_attributes(&data_struct.fields, container_version(ast));
             let struct_field_snippets = fields_snippets(&data_struct.fields);
             quote! {
                 fn provide_type_descriptions<TDC: elrond_wasm::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
                     let type_name = Self::type_name();
+                    accumulator.register_rust_type(&type_name, core::any::type_name::<Self>());
                     if !accumulator.contains_type(&type_name) {
                         accumulator.reserve_type_name(type_name.clone());
                         let mut field_descriptions = elrond_wasm::types::heap::Vec::new();
@@ -55,6 +76,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                             elrond_wasm::abi::TypeDescription {
                                 docs: &[ #(#type_docs),* ],
                                 name: type_name,
+                                version: #version_snippet,
                                 contents: elrond_wasm::abi::TypeContents::Struct(field_descriptions),
                             },
                         );
@@ -66,8 +88,10 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
             let enum_variant_snippets: Vec<proc_macro2::TokenStream> = data_enum
                 .variants
                 .iter()
-                .enumerate()
-                .map(|(variant_index, variant)| {
+                .zip(variant_discriminants(data_enum))
+                .map(|(variant, discriminant)| {
+                    let discriminant = discriminant as usize;
+                    check_fields_attributes(&variant.fields, None);
                     let variant_docs = extract_doc(variant.attrs.as_slice());
                     let variant_name_str = variant.ident.to_string();
                     let variant_field_snippets = fields_snippets(&variant.fields);
@@ -76,7 +100,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                         #(#variant_field_snippets)*
                         variant_descriptions.push(elrond_wasm::abi::EnumVariantDescription {
                             docs: &[ #(#variant_docs),* ],
-                            discriminant: #variant_index,
+                            discriminant: #discriminant,
                             name: #variant_name_str,
                             fields: field_descriptions,
                         });
@@ -86,6 +110,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
             quote! {
                 fn provide_type_descriptions<TDC: elrond_wasm::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
                     let type_name = Self::type_name();
+                    accumulator.register_rust_type(&type_name, core::any::type_name::<Self>());
                     if !accumulator.contains_type(&type_name) {
                         accumulator.reserve_type_name(type_name.clone());
                         let mut variant_descriptions = elrond_wasm::types::heap::Vec::new();
@@ -95,6 +120,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                             elrond_wasm::abi::TypeDescription {
                                 docs: &[ #(#type_docs),* ],
                                 name: type_name,
+                                version: #version_snippet,
                                 contents: elrond_wasm::abi::TypeContents::Enum(variant_descriptions),
                             },
                         );
@@ -135,6 +135,8 @@ extern "C" {
     fn managedIsESDTPaused(tokenIDHandle: i32) -> i32;
     fn managedIsESDTLimitedTransfer(tokenIDHandle: i32) -> i32;
 
+    fn managedGetCallbackClosure(callbackClosureHandle: i32);
+
     fn getESDTLocalRoles(tokenhandle: i32) -> i64;
 }
 
@@ -276,6 +278,13 @@ impl BlockchainApiImpl for VmApiImpl {
         unsafe { getGasLeft() as u64 }
     }
 
+    #[inline]
+    fn load_callback_closure(&self, dest: Self::ManagedBufferHandle) {
+        unsafe {
+            managedGetCallbackClosure(dest);
+        }
+    }
+
     #[inline]
     fn get_block_timestamp(&self) -> u64 {
         unsafe { getBlockTimestamp() as u64 }
@@ -251,6 +251,7 @@ extern "C" {
         errorLength: i32,
         gas: i64,
         extraGasForCallback: i64,
+        callbackClosureHandle: i32,
     ) -> i32;
 
     fn getNumReturnData() -> i32;
@@ -562,6 +563,7 @@ impl SendApiImpl for VmApiImpl {
         gas: u64,
         extra_gas_for_callback: u64,
         arg_buffer: &ManagedArgBuffer<M>,
+        callback_closure: &ManagedBuffer<M>,
     ) {
         unsafe {
             let _ = managedCreateAsyncCall(
@@ -575,6 +577,7 @@ impl SendApiImpl for VmApiImpl {
                 error.len() as i32,
                 gas as i64,
                 extra_gas_for_callback as i64,
+                callback_closure.get_raw_handle(),
             );
         }
     }
@@ -9,6 +9,7 @@ pub struct ContractAbi {
     pub constructors: Vec<EndpointAbi>,
     pub endpoints: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+    pub storage: Vec<StorageAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
 }
@@ -19,6 +20,9 @@ impl ContractAbi {
             .extend_from_slice(other.constructors.as_slice());
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
         self.events.extend_from_slice(other.events.as_slice());
+        for storage_abi in other.storage {
+            self.add_storage(storage_abi);
+        }
         self.has_callback |= other.has_callback;
         self.type_descriptions.insert_all(&other.type_descriptions);
     }
@@ -38,6 +42,7 @@ impl ContractAbi {
                 .cloned()
                 .collect(),
             events: self.events.clone(),
+            storage: self.storage.clone(),
             has_callback: self.has_callback,
             type_descriptions: self.type_descriptions.clone(),
         }
@@ -65,11 +70,50 @@ impl ContractAbi {
                 .cloned()
                 .collect(),
             events: self.events.clone(),
+            storage: self.storage.clone(),
             has_callback: false,
             type_descriptions: self.type_descriptions.clone(),
         }
     }
 
+    /// Only the endpoints selected by the filter, out of all endpoints, external views included.
+    /// If only external views are selected, it is an external view contract, otherwise it is like the main contract.
+    /// Used for the output contracts configured in `multicontract.toml`.
+    #[must_use]
+    pub fn filtered_contract<F>(&self, filter: F) -> ContractAbi
+    where
+        F: Fn(&EndpointAbi) -> bool,
+    {
+        let endpoints: Vec<EndpointAbi> = self
+            .endpoints
+            .iter()
+            .filter(|endpoint| filter(endpoint))
+            .cloned()
+            .collect();
+        let only_external_views = !endpoints.is_empty()
+            && endpoints
+                .iter()
+                .all(|endpoint| endpoint.location == EndpointLocationAbi::ViewContract);
+        let mut contract_abi = if only_external_views {
+            self.secondary_contract(EndpointLocationAbi::ViewContract)
+        } else {
+            self.main_contract()
+        };
+        contract_abi.endpoints = endpoints;
+        contract_abi
+    }
+
+    /// Getters, setters and mappers of the same storage entry only get added once.
+    pub fn add_storage(&mut self, storage_abi: StorageAbi) {
+        if !self
+            .storage
+            .iter()
+            .any(|existing| existing.identifier == storage_abi.identifier)
+        {
+            self.storage.push(storage_abi);
+        }
+    }
+
     /// A type can provide more than 1 type descripions.
     /// For instance, a struct can also provide the descriptions of its fields.
     pub fn add_type_descriptions<T: TypeAbi>(&mut self) {
@@ -40,6 +40,10 @@ pub struct EndpointAbi {
     pub only_admin: bool,
     pub mutability: EndpointMutabilityAbi,
     pub location: EndpointLocationAbi,
+    /// Set with `#[label(...)]`, used to select the endpoints of each output contract.
+    pub labels: &'static [&'static str],
+    /// The name of the contract or module trait where the endpoint is declared.
+    pub module: &'static str,
     pub payable_in_tokens: &'static [&'static str],
     pub inputs: Vec<InputAbi>,
     pub outputs: OutputAbis,
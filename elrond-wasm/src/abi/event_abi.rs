@@ -13,6 +13,8 @@ pub struct EventAbi {
     pub docs: &'static [&'static str],
     pub identifier: &'static str,
     pub inputs: Vec<EventInputAbi>,
+    /// The name of the contract or module trait where the event is declared.
+    pub module: &'static str,
 }
 
 impl EventAbi {
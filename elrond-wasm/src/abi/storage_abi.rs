@@ -0,0 +1,89 @@
+use super::*;
+use alloc::{string::ToString, vec::Vec};
+use core::marker::PhantomData;
+
+/// Plain storage getters and setters use the same storage layout as this mapper.
+pub const SINGLE_VALUE_MAPPER_NAME: &str = "SingleValueMapper";
+
+#[derive(Clone, Debug)]
+pub struct StorageKeyArgAbi {
+    pub arg_name: &'static str,
+    pub type_name: TypeName,
+}
+
+/// Describes a storage entry, declared via `#[storage_mapper]`, `#[storage_get]` or `#[storage_set]`.
+///
+/// Storage types are not required to implement `TypeAbi`.
+/// The ones that do are named and described like any other ABI type,
+/// for the others the type names are derived from the contract source code,
+/// following the ABI naming conventions for the common framework types.
+#[derive(Clone, Debug)]
+pub struct StorageAbi {
+    pub docs: &'static [&'static str],
+
+    /// All storage keys of the entry start with it.
+    pub identifier: &'static str,
+    pub rust_method_name: &'static str,
+
+    /// The mapper type name, e.g. `VecMapper`.
+    pub mapper: &'static str,
+
+    /// The arguments of the storage method, they are appended to the identifier, nested-encoded.
+    pub key_args: Vec<StorageKeyArgAbi>,
+
+    /// The generic arguments of the mapper, or the stored type, for plain getters and setters.
+    pub value_types: Vec<TypeName>,
+}
+
+impl StorageAbi {
+    pub fn add_key_arg(&mut self, arg_name: &'static str, type_name: &str) {
+        self.key_args.push(StorageKeyArgAbi {
+            arg_name,
+            type_name: type_name.into(),
+        });
+    }
+
+    pub fn add_value_type(&mut self, type_name: &str) {
+        self.value_types.push(type_name.into());
+    }
+}
+
+/// Picks the ABI description of a storage type, depending on whether it implements `TypeAbi`.
+///
+/// Method resolution prefers `StorageTypeAbiProvider` on `&StorageTypeAbi<T>` receivers,
+/// and only falls back to `StorageTypeAbiFallback` (via auto-ref) if `T` is not `TypeAbi`.
+/// Both traits need to be in scope at the call site.
+pub struct StorageTypeAbi<T>(PhantomData<T>);
+
+impl<T> StorageTypeAbi<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        StorageTypeAbi(PhantomData)
+    }
+}
+
+pub trait StorageTypeAbiProvider {
+    fn storage_type_name(&self, source_name: &str) -> TypeName;
+
+    fn provide_storage_type_descriptions(&self, contract_abi: &mut ContractAbi);
+}
+
+impl<T: TypeAbi> StorageTypeAbiProvider for StorageTypeAbi<T> {
+    fn storage_type_name(&self, _source_name: &str) -> TypeName {
+        T::type_name()
+    }
+
+    fn provide_storage_type_descriptions(&self, contract_abi: &mut ContractAbi) {
+        contract_abi.add_type_descriptions::<T>();
+    }
+}
+
+pub trait StorageTypeAbiFallback {
+    fn storage_type_name(&self, source_name: &str) -> TypeName {
+        source_name.to_string()
+    }
+
+    fn provide_storage_type_descriptions(&self, _contract_abi: &mut ContractAbi) {}
+}
+
+impl<T> StorageTypeAbiFallback for &StorageTypeAbi<T> {}
@@ -17,6 +17,7 @@ pub trait TypeAbi {
             TypeDescription {
                 docs: &[],
                 name: Self::type_name(),
+                version: None,
                 contents: TypeContents::NotSpecified,
             },
         );
@@ -1,5 +1,8 @@
 use super::*;
-use crate::arrayvec::ArrayVec;
+use crate::{
+    arrayvec::ArrayVec,
+    elrond_codec::{Compact, CompactVec},
+};
 use alloc::{
     boxed::Box,
     string::{String, ToString},
@@ -139,6 +142,34 @@ impl<T: TypeAbi> TypeAbi for Option<T> {
     }
 }
 
+/// Compact numbers are nested-encoded as unsigned LEB128, see `elrond_codec::Compact`.
+impl<T: TypeAbi> TypeAbi for Compact<T> {
+    fn type_name() -> TypeName {
+        let mut repr = TypeName::from("Compact<");
+        repr.push_str(T::type_name().as_str());
+        repr.push('>');
+        repr
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
+
+/// Same as `List<T>`, but the nested length prefix is a `Compact<u32>`.
+impl<T: TypeAbi> TypeAbi for CompactVec<T> {
+    fn type_name() -> TypeName {
+        let mut repr = TypeName::from("CompactList<");
+        repr.push_str(T::type_name().as_str());
+        repr.push('>');
+        repr
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
+
 impl<T: TypeAbi, E> TypeAbi for Result<T, E> {
     fn type_name() -> TypeName {
         T::type_name()
@@ -4,6 +4,8 @@ use alloc::{string::String, vec::Vec};
 pub struct TypeDescription {
     pub docs: &'static [&'static str],
     pub name: String,
+    /// The version byte that the encoding starts with, for structs with `#[codec(version = N)]`.
+    pub version: Option<u8>,
     pub contents: TypeContents,
 }
 
@@ -15,6 +17,7 @@ impl TypeDescription {
     pub const PLACEHOLDER: TypeDescription = TypeDescription {
         docs: &[],
         name: String::new(),
+        version: None,
         contents: TypeContents::NotSpecified,
     };
 }
@@ -45,4 +48,8 @@ pub struct StructFieldDescription {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub field_type: String,
+    /// From `#[codec(since = N)]`: data encoded with an older version does not have this field.
+    pub since: Option<u8>,
+    /// From `#[codec(default)]`: top-encoded data can end before this field.
+    pub default: bool,
 }
@@ -1,4 +1,5 @@
 use super::*;
+use alloc::vec::Vec;
 use hashbrown::HashMap;
 
 pub trait TypeDescriptionContainer {
@@ -15,27 +16,56 @@ pub trait TypeDescriptionContainer {
     fn insert(&mut self, type_name: TypeName, type_description: TypeDescription);
 
     fn insert_all(&mut self, other: &Self);
+
+    /// Records the Rust type that describes itself under a type name.
+    /// Only the first description of a name gets kept, so this is how distinct types
+    /// that end up with the same name in the ABI can be spotted.
+    ///
+    /// Does nothing by default, containers that do not check for name collisions can ignore it.
+    fn register_rust_type(&mut self, _type_name: &str, _rust_type_path: &'static str) {}
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct TypeDescriptionContainerImpl(pub HashMap<TypeName, TypeDescription>);
+pub struct TypeDescriptionContainerImpl {
+    pub descriptions: HashMap<TypeName, TypeDescription>,
+    rust_type_paths: HashMap<TypeName, Vec<&'static str>>,
+}
+
+impl TypeDescriptionContainerImpl {
+    /// The Rust types registered under each type name, as given by `core::any::type_name`.
+    pub fn rust_type_paths(&self) -> &HashMap<TypeName, Vec<&'static str>> {
+        &self.rust_type_paths
+    }
+}
 
 impl TypeDescriptionContainer for TypeDescriptionContainerImpl {
     fn new() -> Self {
-        TypeDescriptionContainerImpl(HashMap::new())
+        TypeDescriptionContainerImpl::default()
     }
 
     fn contains_type(&self, type_name: &str) -> bool {
-        self.0.contains_key(type_name)
+        self.descriptions.contains_key(type_name)
     }
 
     fn insert(&mut self, type_name: TypeName, type_description: TypeDescription) {
-        self.0.insert(type_name, type_description);
+        self.descriptions.insert(type_name, type_description);
     }
 
     fn insert_all(&mut self, other: &Self) {
-        for (key, value) in other.0.iter() {
-            self.0.insert(key.clone(), value.clone());
+        for (key, value) in other.descriptions.iter() {
+            self.descriptions.insert(key.clone(), value.clone());
+        }
+        for (type_name, rust_type_paths) in other.rust_type_paths.iter() {
+            for rust_type_path in rust_type_paths {
+                self.register_rust_type(type_name, rust_type_path);
+            }
+        }
+    }
+
+    fn register_rust_type(&mut self, type_name: &str, rust_type_path: &'static str) {
+        let rust_type_paths = self.rust_type_paths.entry(type_name.into()).or_default();
+        if !rust_type_paths.contains(&rust_type_path) {
+            rust_type_paths.push(rust_type_path);
         }
     }
 }
@@ -79,6 +79,10 @@ pub trait BlockchainApiImpl: ManagedTypeApiImpl {
 
     fn get_gas_left(&self) -> u64;
 
+    /// Loads the callback closure registered together with the current promise.
+    /// Only available in promise callbacks.
+    fn load_callback_closure(&self, dest: Self::ManagedBufferHandle);
+
     fn get_block_timestamp(&self) -> u64;
 
     fn get_block_nonce(&self) -> u64;
@@ -6,6 +6,11 @@ pub trait PrintApi: ManagedTypeApi {
     type PrintApiImpl: PrintApiImpl;
 
     fn print_api_impl() -> Self::PrintApiImpl;
+
+    /// Called at the start of every endpoint, whether it is dispatched or called as a method.
+    /// Only the Rust debugger uses it, to include endpoints called directly from Rust tests in the coverage.
+    #[inline]
+    fn trace_endpoint_call(_endpoint_name: &'static str) {}
 }
 
 pub trait PrintApiImpl {
@@ -133,6 +133,7 @@ pub trait SendApiImpl {
         gas: u64,
         extra_gas_for_callback: u64,
         arg_buffer: &ManagedArgBuffer<M>,
+        callback_closure: &ManagedBuffer<M>,
     );
 
     /// Deploys a new contract in the same shard.
@@ -55,6 +55,10 @@ impl BlockchainApiImpl for UncallableApi {
         unreachable!()
     }
 
+    fn load_callback_closure(&self, _dest: Self::ManagedBufferHandle) {
+        unreachable!()
+    }
+
     fn get_block_timestamp(&self) -> u64 {
         unreachable!()
     }
@@ -149,6 +149,7 @@ impl SendApiImpl for UncallableApi {
         _gas: u64,
         _extra_gas_for_callback: u64,
         _arg_buffer: &ManagedArgBuffer<M>,
+        _callback_closure: &ManagedBuffer<M>,
     ) {
         unreachable!()
     }
@@ -168,6 +168,25 @@ where
         A::blockchain_api_impl().get_gas_left()
     }
 
+    /// Decodes the callback closure arguments, as added by `ContractCall::with_callback_closure_arg`.
+    ///
+    /// Only meaningful in the success or error callback of a promise.
+    #[cfg(feature = "promises")]
+    pub fn get_promise_callback_closure_args<T: elrond_codec::TopDecodeMulti>(&self) -> T {
+        let handle: A::ManagedBufferHandle = A::static_var_api_impl().next_handle();
+        A::blockchain_api_impl().load_callback_closure(handle.clone());
+        let closure_buffer = crate::types::ManagedBuffer::<A>::from_handle(handle);
+        let closure_args: crate::types::ManagedArgBuffer<A> =
+            crate::contract_base::ManagedSerializer::<A>::new()
+                .top_decode_from_managed_buffer(&closure_buffer);
+
+        let mut loader = crate::io::ManagedResultArgLoader::new(closure_args.data);
+        let arg_id = crate::io::ArgId::from(&b"callback closure"[..]);
+        let h = crate::io::ArgErrorHandler::<A>::from(arg_id);
+        let Ok(result) = T::multi_decode_or_handle_err(&mut loader, h);
+        result
+    }
+
     #[inline]
     pub fn get_block_timestamp(&self) -> u64 {
         A::blockchain_api_impl().get_block_timestamp()
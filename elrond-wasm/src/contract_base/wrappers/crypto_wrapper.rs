@@ -2,10 +2,13 @@ use core::marker::PhantomData;
 
 use crate::{
     api::{
-        CryptoApi, CryptoApiImpl, StaticVarApiImpl, ED25519_KEY_BYTE_LEN,
+        CryptoApi, CryptoApiImpl, ErrorApi, StaticVarApiImpl, ED25519_KEY_BYTE_LEN,
         ED25519_SIGNATURE_BYTE_LEN, KECCAK256_RESULT_LEN, SHA256_RESULT_LEN,
     },
-    types::{ManagedBuffer, ManagedByteArray, ManagedType, MessageHashType},
+    types::{
+        verify_merkle_proof, ManagedBuffer, ManagedByteArray, ManagedType, MessageHashType,
+        MultiValueEncoded, MERKLE_HASH_LEN,
+    },
 };
 
 #[derive(Default)]
@@ -219,3 +222,24 @@ where
         ManagedBuffer::from_handle(new_handle)
     }
 }
+
+impl<A> CryptoWrapper<A>
+where
+    A: CryptoApi + ErrorApi,
+{
+    /// Checks that a leaf is at the given index in the Merkle tree with the given root and depth.
+    ///
+    /// The proof lists the sibling hashes, from the leaf level up to just below the root,
+    /// so it must have exactly `depth` hashes.
+    /// Nodes are hashed with SHA-256, the same way as in the `MerkleTreeMapper`.
+    pub fn verify_merkle_proof(
+        &self,
+        root: &ManagedByteArray<A, MERKLE_HASH_LEN>,
+        leaf: &ManagedByteArray<A, MERKLE_HASH_LEN>,
+        leaf_index: usize,
+        depth: usize,
+        proof: MultiValueEncoded<A, ManagedByteArray<A, MERKLE_HASH_LEN>>,
+    ) -> bool {
+        verify_merkle_proof(root, leaf, leaf_index, depth, proof)
+    }
+}
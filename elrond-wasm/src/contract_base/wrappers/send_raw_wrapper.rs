@@ -243,6 +243,7 @@ where
         gas: u64,
         extra_gas_for_callback: u64,
         arg_buffer: &ManagedArgBuffer<A>,
+        callback_closure: &ManagedBuffer<A>,
     ) {
         A::send_api_impl().create_async_call_raw(
             to,
@@ -253,6 +254,7 @@ where
             gas,
             extra_gas_for_callback,
             arg_buffer,
+            callback_closure,
         )
     }
 
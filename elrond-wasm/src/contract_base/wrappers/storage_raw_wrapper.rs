@@ -3,15 +3,9 @@ use core::marker::PhantomData;
 use elrond_codec::{TopDecode, TopEncode};
 
 use crate::{
-    api::{
-        const_handles::MBUF_TEMPORARY_1, use_raw_handle, ErrorApi, ManagedTypeApi, StorageReadApi,
-        StorageReadApiImpl, StorageWriteApi,
-    },
-    storage::StorageKey,
-    storage_get,
-    storage_get::StorageGetErrorHandler,
-    storage_set,
-    types::{ManagedAddress, ManagedBuffer, ManagedType},
+    api::{ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
+    storage::{storage_get, storage_get_from_address, storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType},
 };
 
 #[derive(Default)]
@@ -56,16 +50,7 @@ where
         V: TopDecode,
     {
         let key: StorageKey<A> = storage_key.into();
-        let result_buffer = ManagedBuffer::<A>::from_handle(use_raw_handle(MBUF_TEMPORARY_1));
-        A::storage_read_api_impl().storage_load_from_address(
-            address.get_handle(),
-            key.get_handle(),
-            result_buffer.get_handle(),
-        );
-
-        let Ok(value) =
-            V::top_decode_or_handle_err(result_buffer, StorageGetErrorHandler::<A>::default());
-        value
+        storage_get_from_address(address.as_ref(), key.as_ref())
     }
 
     /// Write a serializable value to storage under the given key
@@ -24,9 +24,11 @@ pub const STORAGE_DECODE_ERROR: &[u8] = b"storage decode error: ";
 pub const STORAGE_ENCODE_ERROR: &[u8] = b"storage encode error: ";
 pub const STORAGE_KEY_ENCODE_ERROR: &[u8] = b"storage key encode error: ";
 pub const STORAGE_VALUE_EXCEEDS_BUFFER: &[u8] = b"storage value exceeds buffer";
+pub const STORAGE_MAPPER_INVALID_CURSOR: &[u8] = b"invalid pagination cursor";
 pub const FINISH_ENCODE_ERROR: &[u8] = b"endpoint result encode error: ";
 pub const SERIALIZER_DECODE_ERROR: &[u8] = b"serializer decode error: ";
 pub const SERIALIZER_ENCODE_ERROR: &[u8] = b"serializer encode error: ";
+pub const LAZY_DECODE_ERROR: &[u8] = b"lazy decode error: ";
 pub const FORMATTER_ENCODE_ERROR: &[u8] = b"formatter encode error: ";
 pub const LOG_TOPIC_ENCODE_ERROR: &[u8] = b"log topic encode error: ";
 pub const LOG_DATA_ENCODE_ERROR: &[u8] = b"log data encode error: ";
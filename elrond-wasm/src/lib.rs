@@ -5,7 +5,7 @@
 #![feature(control_flow_enum)]
 #![allow(clippy::type_complexity)]
 #![allow(deprecated)]
-#![feature(maybe_uninit_uninit_array)]
+#![feature(try_trait_v2_residual)]
 #![feature(maybe_uninit_array_assume_init)]
 #![feature(negative_impls)]
 
@@ -40,10 +40,10 @@ macro_rules! imports {
 macro_rules! derive_imports {
     () => {
         use elrond_wasm::{
-            derive::{ManagedVecItem, TypeAbi},
+            derive::{LazyDecode, ManagedVecItem, TypeAbi},
             elrond_codec,
             elrond_codec::elrond_codec_derive::{
-                NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode,
+                EncodedSize, NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode,
                 TopEncodeOrDefault,
             },
         };
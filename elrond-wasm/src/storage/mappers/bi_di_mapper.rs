@@ -11,7 +11,7 @@ use crate::{
     api::StorageMapperApi,
     storage::{storage_get, storage_set, StorageKey},
     storage_clear,
-    types::{ManagedType, MultiValueEncoded},
+    types::{ManagedType, MultiValueEncoded, MultiValuePage},
 };
 
 const VALUE_SUFIX: &[u8] = b"_value";
@@ -171,6 +171,17 @@ where
         Iter::new(self)
     }
 
+    /// Loads at most `limit` id-value pairs, starting from `cursor` (`0` for the first page).
+    ///
+    /// Cursors work as for the `UnorderedSetMapper` of the ids,
+    /// so the pages are only consistent while the mapper does not change.
+    pub fn page(&self, cursor: usize, limit: usize) -> MultiValuePage<SA, MultiValue2<K, V>> {
+        self.id_set_mapper.page_map(cursor, limit, |id| {
+            let value = self.get_value(&id);
+            MultiValue2::from((id, value))
+        })
+    }
+
     pub fn is_empty(&self) -> bool {
         self.value_set_mapper.is_empty()
     }
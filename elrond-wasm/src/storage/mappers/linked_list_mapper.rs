@@ -3,9 +3,10 @@ use core::marker::PhantomData;
 use super::{StorageClearable, StorageMapper};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
-    api::StorageMapperApi,
+    api::{ErrorApiImpl, StorageMapperApi},
+    err_msg,
     storage::{storage_get, storage_set, StorageKey},
-    types::{heap::BoxedBytes, ManagedType, MultiValueEncoded},
+    types::{heap::BoxedBytes, ManagedType, MultiValueEncoded, MultiValuePage},
 };
 use alloc::vec::Vec;
 use elrond_codec::{
@@ -438,6 +439,30 @@ where
         Iter::new_from_node_id(self, node_id)
     }
 
+    /// Loads the values of at most `limit` nodes, from front to back,
+    /// starting from the node with id `cursor` (`0` for the first page).
+    ///
+    /// The cursors are node ids, so they stay valid while other nodes are added or removed.
+    /// A cursor whose node was removed in the meantime is invalid and signals an error.
+    /// The next cursor is `0` if there are no more nodes.
+    pub fn page(&self, cursor: usize, limit: usize) -> MultiValuePage<SA, T> {
+        let mut iter = if cursor == 0 {
+            self.iter()
+        } else {
+            if cursor > u32::MAX as usize || self.is_empty_node(cursor as u32) {
+                SA::error_api_impl().signal_error(err_msg::STORAGE_MAPPER_INVALID_CURSOR);
+            }
+            self.iter_from_node_id(cursor as u32)
+        };
+        let items = iter
+            .by_ref()
+            .take(limit)
+            .map(LinkedListNode::into_value)
+            .collect();
+        let next_cursor = iter.node_opt.map_or(NULL_ENTRY, |node| node.node_id);
+        MultiValuePage::new(next_cursor as usize, items)
+    }
+
     pub fn check_internal_consistency(&self) -> bool {
         let info = self.get_info();
         let mut front = info.front;
@@ -1,11 +1,14 @@
 use core::marker::PhantomData;
 
-use super::{set_mapper, SetMapper, StorageClearable, StorageMapper};
+use super::{
+    set_mapper, CurrentStorage, SetMapper, StorageAddress, StorageClearable, StorageMapper,
+    StorageMapperFromAddress,
+};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
-    storage::{storage_clear, storage_get, storage_set, StorageKey},
-    types::{ManagedType, MultiValueEncoded},
+    storage::{storage_clear, storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, MultiValueEncoded, MultiValuePage},
 };
 use elrond_codec::{
     multi_encode_iter_or_handle_err, multi_types::MultiValue2, CodecFrom, EncodeErrorHandler,
@@ -13,17 +16,19 @@ use elrond_codec::{
 };
 
 const MAPPED_VALUE_IDENTIFIER: &[u8] = b".mapped";
-type Keys<'a, SA, T> = set_mapper::Iter<'a, SA, T>;
+type Keys<'a, SA, A, T> = set_mapper::Iter<'a, SA, T, A>;
 
-pub struct MapMapper<SA, K, V>
+pub struct MapMapper<SA, K, V, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
     _phantom_api: PhantomData<SA>,
+    address: A,
     base_key: StorageKey<SA>,
-    keys_set: SetMapper<SA, K>,
+    keys_set: SetMapper<SA, K, A>,
     _phantom_value: PhantomData<V>,
 }
 
@@ -36,6 +41,7 @@ where
     fn new(base_key: StorageKey<SA>) -> Self {
         MapMapper {
             _phantom_api: PhantomData,
+            address: CurrentStorage,
             base_key: base_key.clone(),
             keys_set: SetMapper::<SA, K>::new(base_key),
             _phantom_value: PhantomData,
@@ -43,6 +49,23 @@ where
     }
 }
 
+impl<SA, K, V> StorageMapperFromAddress<SA> for MapMapper<SA, K, V, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode,
+    V: TopEncode + TopDecode,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        MapMapper {
+            _phantom_api: PhantomData,
+            address: address.clone(),
+            base_key: base_key.clone(),
+            keys_set: SetMapper::new_from_address(address, base_key),
+            _phantom_value: PhantomData,
+        }
+    }
+}
+
 impl<SA, K, V> StorageClearable for MapMapper<SA, K, V>
 where
     SA: StorageMapperApi,
@@ -57,9 +80,10 @@ where
     }
 }
 
-impl<SA, K, V> MapMapper<SA, K, V>
+impl<SA, K, V, A> MapMapper<SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode,
     V: TopEncode + TopDecode,
 {
@@ -71,18 +95,8 @@ where
     }
 
     fn get_mapped_value(&self, key: &K) -> V {
-        storage_get(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref())
-    }
-
-    fn set_mapped_value(&self, key: &K, value: &V) {
-        storage_set(
-            self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref(),
-            &value,
-        );
-    }
-
-    fn clear_mapped_value(&self, key: &K) {
-        storage_clear(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref());
+        self.address
+            .address_storage_get(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref())
     }
 
     /// Returns `true` if the map contains no elements.
@@ -100,6 +114,60 @@ where
         self.keys_set.contains(k)
     }
 
+    /// Gets a reference to the value in the entry.
+    pub fn get(&self, k: &K) -> Option<V> {
+        if self.keys_set.contains(k) {
+            return Some(self.get_mapped_value(k));
+        }
+        None
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    /// The iterator element type is `&'a K`.
+    pub fn keys(&self) -> Keys<SA, A, K> {
+        self.keys_set.iter()
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    /// The iterator element type is `&'a V`.
+    pub fn values(&self) -> Values<SA, A, K, V> {
+        Values::new(self)
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    /// The iterator element type is `(&'a K, &'a V)`.
+    pub fn iter(&self) -> Iter<SA, A, K, V> {
+        Iter::new(self)
+    }
+
+    /// Loads at most `limit` key-value pairs, starting from `cursor` (`0` for the first page).
+    ///
+    /// Cursors work as for the `SetMapper` of the keys: they stay valid while other entries are inserted or removed.
+    pub fn page(&self, cursor: usize, limit: usize) -> MultiValuePage<SA, MultiValue2<K, V>> {
+        self.keys_set.page_map(cursor, limit, |key| {
+            let value = self.get_mapped_value(&key);
+            MultiValue2::from((key, value))
+        })
+    }
+}
+
+impl<SA, K, V> MapMapper<SA, K, V, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode,
+    V: TopEncode + TopDecode,
+{
+    fn set_mapped_value(&self, key: &K, value: &V) {
+        storage_set(
+            self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref(),
+            &value,
+        );
+    }
+
+    fn clear_mapped_value(&self, key: &K) {
+        storage_clear(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref());
+    }
+
     /// Gets the given key's corresponding entry in the map for in-place manipulation.
     pub fn entry(&mut self, key: K) -> Entry<'_, SA, K, V> {
         if self.contains_key(&key) {
@@ -117,14 +185,6 @@ where
         }
     }
 
-    /// Gets a reference to the value in the entry.
-    pub fn get(&self, k: &K) -> Option<V> {
-        if self.keys_set.contains(k) {
-            return Some(self.get_mapped_value(k));
-        }
-        None
-    }
-
     /// Sets the value of the entry, and returns the entry's old value.
     pub fn insert(&mut self, k: K, v: V) -> Option<V> {
         let old_value = self.get(&k);
@@ -142,43 +202,27 @@ where
         }
         None
     }
-
-    /// An iterator visiting all keys in arbitrary order.
-    /// The iterator element type is `&'a K`.
-    pub fn keys(&self) -> Keys<SA, K> {
-        self.keys_set.iter()
-    }
-
-    /// An iterator visiting all values in arbitrary order.
-    /// The iterator element type is `&'a V`.
-    pub fn values(&self) -> Values<SA, K, V> {
-        Values::new(self)
-    }
-
-    /// An iterator visiting all key-value pairs in arbitrary order.
-    /// The iterator element type is `(&'a K, &'a V)`.
-    pub fn iter(&self) -> Iter<SA, K, V> {
-        Iter::new(self)
-    }
 }
 
-pub struct Iter<'a, SA, K, V>
+pub struct Iter<'a, SA, A, K, V>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
-    key_iter: Keys<'a, SA, K>,
-    hash_map: &'a MapMapper<SA, K, V>,
+    key_iter: Keys<'a, SA, A, K>,
+    hash_map: &'a MapMapper<SA, K, V, A>,
 }
 
-impl<'a, SA, K, V> Iter<'a, SA, K, V>
+impl<'a, SA, A, K, V> Iter<'a, SA, A, K, V>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
-    fn new(hash_map: &'a MapMapper<SA, K, V>) -> Iter<'a, SA, K, V> {
+    fn new(hash_map: &'a MapMapper<SA, K, V, A>) -> Iter<'a, SA, A, K, V> {
         Iter {
             key_iter: hash_map.keys(),
             hash_map,
@@ -186,9 +230,10 @@ where
     }
 }
 
-impl<'a, SA, K, V> Iterator for Iter<'a, SA, K, V>
+impl<'a, SA, A, K, V> Iterator for Iter<'a, SA, A, K, V>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
@@ -204,23 +249,25 @@ where
     }
 }
 
-pub struct Values<'a, SA, K, V>
+pub struct Values<'a, SA, A, K, V>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
-    key_iter: Keys<'a, SA, K>,
-    hash_map: &'a MapMapper<SA, K, V>,
+    key_iter: Keys<'a, SA, A, K>,
+    hash_map: &'a MapMapper<SA, K, V, A>,
 }
 
-impl<'a, SA, K, V> Values<'a, SA, K, V>
+impl<'a, SA, A, K, V> Values<'a, SA, A, K, V>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
-    fn new(hash_map: &'a MapMapper<SA, K, V>) -> Values<'a, SA, K, V> {
+    fn new(hash_map: &'a MapMapper<SA, K, V, A>) -> Values<'a, SA, A, K, V> {
         Values {
             key_iter: hash_map.keys(),
             hash_map,
@@ -228,9 +275,10 @@ where
     }
 }
 
-impl<'a, SA, K, V> Iterator for Values<'a, SA, K, V>
+impl<'a, SA, A, K, V> Iterator for Values<'a, SA, A, K, V>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
@@ -438,9 +486,10 @@ where
 }
 
 /// Behaves like a MultiResultVec<MultiValue2<K, V>> when an endpoint result.
-impl<SA, K, V> TopEncodeMulti for MapMapper<SA, K, V>
+impl<SA, K, V, A> TopEncodeMulti for MapMapper<SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
@@ -454,18 +503,20 @@ where
     }
 }
 
-impl<SA, K, V> CodecFrom<MapMapper<SA, K, V>> for MultiValueEncoded<SA, MultiValue2<K, V>>
+impl<SA, K, V, A> CodecFrom<MapMapper<SA, K, V, A>> for MultiValueEncoded<SA, MultiValue2<K, V>>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
 }
 
 /// Behaves like a MultiResultVec<MultiValue<K, V>> when an endpoint result.
-impl<SA, K, V> TypeAbi for MapMapper<SA, K, V>
+impl<SA, K, V, A> TypeAbi for MapMapper<SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi + 'static,
     V: TopEncode + TopDecode + TypeAbi + 'static,
 {
@@ -0,0 +1,154 @@
+use core::marker::PhantomData;
+
+use super::{StorageClearable, StorageMapper};
+use crate::{
+    api::{CryptoApi, StorageMapperApi},
+    storage::{storage_clear, storage_get, storage_set, StorageKey},
+    types::{
+        merkle_node_hash, verify_merkle_proof, ManagedByteArray, ManagedType, MultiValueEncoded,
+        MERKLE_HASH_LEN,
+    },
+};
+
+const LEAF_COUNT_SUFFIX: &[u8] = b".leaf_count";
+const BRANCH_SUFFIX: &[u8] = b".branch";
+
+/// An append-only Merkle tree, with SHA-256 node hashes.
+///
+/// The leaves themselves are not saved, only the hashes of the complete subtrees on the
+/// right edge of the tree, one per level (the "branch"). This is enough to append leaves
+/// and to compute the root, with a number of storage reads and hashes logarithmic in the leaf count.
+///
+/// The depth of the tree is the smallest one that fits all the leaves.
+/// The missing leaves on the right are considered to be 32 zero bytes.
+/// The root of a tree with a single leaf is the leaf itself, the root of an empty tree is 32 zero bytes.
+///
+/// Proofs can be built off-chain from the appended leaves,
+/// e.g. with the `MerkleTree` of the Rust debugger.
+pub struct MerkleTreeMapper<SA>
+where
+    SA: StorageMapperApi + CryptoApi,
+{
+    _phantom_api: PhantomData<SA>,
+    base_key: StorageKey<SA>,
+}
+
+impl<SA> StorageMapper<SA> for MerkleTreeMapper<SA>
+where
+    SA: StorageMapperApi + CryptoApi,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        MerkleTreeMapper {
+            _phantom_api: PhantomData,
+            base_key,
+        }
+    }
+}
+
+impl<SA> StorageClearable for MerkleTreeMapper<SA>
+where
+    SA: StorageMapperApi + CryptoApi,
+{
+    fn clear(&mut self) {
+        let mut leaf_count = self.leaf_count();
+        let mut level = 0;
+        while leaf_count > 0 {
+            storage_clear(self.branch_key(level).as_ref());
+            leaf_count >>= 1;
+            level += 1;
+        }
+        storage_clear(self.leaf_count_key().as_ref());
+    }
+}
+
+impl<SA> MerkleTreeMapper<SA>
+where
+    SA: StorageMapperApi + CryptoApi,
+{
+    fn leaf_count_key(&self) -> StorageKey<SA> {
+        let mut leaf_count_key = self.base_key.clone();
+        leaf_count_key.append_bytes(LEAF_COUNT_SUFFIX);
+        leaf_count_key
+    }
+
+    fn branch_key(&self, level: usize) -> StorageKey<SA> {
+        let mut branch_key = self.base_key.clone();
+        branch_key.append_bytes(BRANCH_SUFFIX);
+        branch_key.append_item(&level);
+        branch_key
+    }
+
+    fn get_branch(&self, level: usize) -> ManagedByteArray<SA, MERKLE_HASH_LEN> {
+        storage_get(self.branch_key(level).as_ref())
+    }
+
+    /// Number of leaves appended so far.
+    pub fn leaf_count(&self) -> usize {
+        storage_get(self.leaf_count_key().as_ref())
+    }
+
+    /// True if no leaf was appended yet.
+    pub fn is_empty(&self) -> bool {
+        self.leaf_count() == 0
+    }
+
+    /// The number of levels below the root, which is also the length of the proofs.
+    pub fn depth(&self) -> usize {
+        self.leaf_count().next_power_of_two().trailing_zeros() as usize
+    }
+
+    /// Adds a leaf to the tree, returns its index.
+    pub fn append(&mut self, leaf: ManagedByteArray<SA, MERKLE_HASH_LEN>) -> usize {
+        let leaf_index = self.leaf_count();
+        let mut node = leaf;
+        let mut size = leaf_index + 1;
+        let mut level = 0;
+        // complete subtrees on the right edge get merged with their left neighbours
+        while size & 1 == 0 {
+            node = merkle_node_hash(&self.get_branch(level), &node);
+            size >>= 1;
+            level += 1;
+        }
+        storage_set(self.branch_key(level).as_ref(), &node);
+        storage_set(self.leaf_count_key().as_ref(), &(leaf_index + 1));
+        leaf_index
+    }
+
+    /// The current root of the tree.
+    pub fn root(&self) -> ManagedByteArray<SA, MERKLE_HASH_LEN> {
+        let leaf_count = self.leaf_count();
+        let depth = self.depth();
+        if leaf_count.is_power_of_two() {
+            // the tree is complete, its root was saved on the last append
+            return self.get_branch(depth);
+        }
+
+        let mut zero_subtree_root = ManagedByteArray::new_from_bytes(&[0u8; MERKLE_HASH_LEN]);
+        let mut node = zero_subtree_root.clone();
+        let mut size = leaf_count;
+        for level in 0..depth {
+            node = if size & 1 == 1 {
+                merkle_node_hash(&self.get_branch(level), &node)
+            } else {
+                merkle_node_hash(&node, &zero_subtree_root)
+            };
+            zero_subtree_root = merkle_node_hash(&zero_subtree_root, &zero_subtree_root);
+            size >>= 1;
+        }
+        node
+    }
+
+    /// Checks that a leaf was appended at the given index.
+    ///
+    /// The proof lists the sibling hashes, from the leaf level up to just below the root.
+    /// Its length must be the current depth of the tree.
+    pub fn verify_proof(
+        &self,
+        leaf: &ManagedByteArray<SA, MERKLE_HASH_LEN>,
+        leaf_index: usize,
+        proof: MultiValueEncoded<SA, ManagedByteArray<SA, MERKLE_HASH_LEN>>,
+    ) -> bool {
+        leaf_index < self.leaf_count()
+            && verify_merkle_proof(&self.root(), leaf, leaf_index, self.depth(), proof)
+    }
+}
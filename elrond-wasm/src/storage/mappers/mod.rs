@@ -4,10 +4,15 @@ mod linked_list_mapper;
 mod map_mapper;
 mod map_storage_mapper;
 mod mapper;
+mod merkle_tree_mapper;
 mod non_fungible_token_mapper;
+mod priority_queue_mapper;
 mod queue_mapper;
 mod set_mapper;
 mod single_value_mapper;
+mod sorted_map_mapper;
+mod sorted_set_mapper;
+mod storage_address;
 mod token_attributes_mapper;
 mod token_mapper;
 mod unique_id_mapper;
@@ -21,11 +26,16 @@ pub use fungible_token_mapper::FungibleTokenMapper;
 pub use linked_list_mapper::{LinkedListMapper, LinkedListNode};
 pub use map_mapper::MapMapper;
 pub use map_storage_mapper::MapStorageMapper;
-pub use mapper::{StorageClearable, StorageMapper};
+pub use mapper::{StorageClearable, StorageMapper, StorageMapperFromAddress};
+pub use merkle_tree_mapper::MerkleTreeMapper;
 pub use non_fungible_token_mapper::NonFungibleTokenMapper;
+pub use priority_queue_mapper::PriorityQueueMapper;
 pub use queue_mapper::QueueMapper;
 pub use set_mapper::SetMapper;
 pub use single_value_mapper::{SingleValue, SingleValueMapper};
+pub use sorted_map_mapper::SortedMapMapper;
+pub use sorted_set_mapper::SortedSetMapper;
+pub use storage_address::{CurrentStorage, StorageAddress};
 pub use token_attributes_mapper::TokenAttributesMapper;
 pub use token_mapper::StorageTokenWrapper;
 pub use unique_id_mapper::{UniqueId, UniqueIdMapper};
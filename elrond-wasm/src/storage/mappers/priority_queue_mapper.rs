@@ -0,0 +1,328 @@
+use super::{StorageClearable, StorageMapper};
+use crate::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{ErrorApiImpl, StorageMapperApi},
+    err_msg,
+    storage::{storage_clear, storage_get, storage_set, StorageKey},
+    types::{ManagedType, MultiValueEncoded, MultiValuePage},
+};
+use core::marker::PhantomData;
+use elrond_codec::{
+    multi_encode_iter_or_handle_err, multi_types::MultiValue2, CodecFrom, EncodeErrorHandler,
+    NestedDecode, NestedEncode, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
+};
+
+const ITEM_SUFFIX: &[u8] = b".item";
+const LEN_SUFFIX: &[u8] = b".len";
+
+/// A priority queue, kept in storage as a binary min-heap.
+///
+/// Each item is pushed together with its priority, which can be any type with an ordering
+/// (e.g. a deadline timestamp). `pop` always returns the item with the smallest priority.
+/// Items with equal priorities are popped in no particular order.
+///
+/// The heap is saved like a `VecMapper`: each priority-item pair under a separate key,
+/// at indexes starting from 1, with the length saved separately.
+/// `peek` reads a single entry, `push` and `pop` only read and write a logarithmic number of entries.
+pub struct PriorityQueueMapper<SA, P, T>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    base_key: StorageKey<SA>,
+    len_key: StorageKey<SA>,
+    _phantom_item: PhantomData<(P, T)>,
+}
+
+impl<SA, P, T> StorageMapper<SA> for PriorityQueueMapper<SA, P, T>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        let mut len_key = base_key.clone();
+        len_key.append_bytes(LEN_SUFFIX);
+
+        PriorityQueueMapper {
+            _phantom_api: PhantomData,
+            base_key,
+            len_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, P, T> StorageClearable for PriorityQueueMapper<SA, P, T>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    fn clear(&mut self) {
+        let len = self.len();
+        for index in 1..=len {
+            storage_clear(self.item_key(index).as_ref());
+        }
+        storage_clear(self.len_key.as_ref());
+    }
+}
+
+impl<SA, P, T> PriorityQueueMapper<SA, P, T>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    fn item_key(&self, index: usize) -> StorageKey<SA> {
+        let mut item_key = self.base_key.clone();
+        item_key.append_bytes(ITEM_SUFFIX);
+        item_key.append_item(&index);
+        item_key
+    }
+
+    fn get_entry(&self, index: usize) -> (P, T) {
+        storage_get(self.item_key(index).as_ref())
+    }
+
+    fn set_entry(&self, index: usize, entry: &(P, T)) {
+        storage_set(self.item_key(index).as_ref(), entry);
+    }
+
+    fn save_len(&self, new_len: usize) {
+        storage_set(self.len_key.as_ref(), &new_len);
+    }
+
+    /// Number of items in the queue.
+    pub fn len(&self) -> usize {
+        storage_get(self.len_key.as_ref())
+    }
+
+    /// True if the queue contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds an item with the given priority.
+    pub fn push(&mut self, priority: P, item: T) {
+        let new_len = self.len() + 1;
+        // the new entry is only written once, at its final position
+        let mut index = new_len;
+        while index > 1 {
+            let parent_index = index / 2;
+            let parent_entry = self.get_entry(parent_index);
+            if parent_entry.0 <= priority {
+                break;
+            }
+            self.set_entry(index, &parent_entry);
+            index = parent_index;
+        }
+        self.set_entry(index, &(priority, item));
+        self.save_len(new_len);
+    }
+
+    /// The item with the smallest priority, together with its priority,
+    /// or `None` if the queue is empty.
+    pub fn peek(&self) -> Option<(P, T)> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.get_entry(1))
+    }
+
+    /// The smallest priority in the queue, or `None` if the queue is empty.
+    pub fn peek_priority(&self) -> Option<P> {
+        self.peek().map(|(priority, _)| priority)
+    }
+
+    /// Removes the item with the smallest priority and returns it, together with its priority,
+    /// or `None` if the queue is empty.
+    pub fn pop(&mut self) -> Option<(P, T)> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let top_entry = self.get_entry(1);
+        let last_entry = self.get_entry(len);
+        storage_clear(self.item_key(len).as_ref());
+        let new_len = len - 1;
+        self.save_len(new_len);
+        if new_len > 0 {
+            self.sift_down_from_root(last_entry, new_len);
+        }
+        Some(top_entry)
+    }
+
+    /// Pops the item with the smallest priority, but only if its priority
+    /// is at most the given bound (e.g. a deadline that has passed).
+    pub fn pop_if_priority_at_most(&mut self, max_priority: &P) -> Option<(P, T)> {
+        match self.peek_priority() {
+            Some(priority) if priority <= *max_priority => self.pop(),
+            _ => None,
+        }
+    }
+
+    /// Places the entry at the root, then moves it down to its position.
+    fn sift_down_from_root(&self, entry: (P, T), len: usize) {
+        let mut index = 1;
+        loop {
+            let left_index = index * 2;
+            if left_index > len {
+                break;
+            }
+            let mut child_index = left_index;
+            let mut child_entry = self.get_entry(left_index);
+            let right_index = left_index + 1;
+            if right_index <= len {
+                let right_entry = self.get_entry(right_index);
+                if right_entry.0 < child_entry.0 {
+                    child_index = right_index;
+                    child_entry = right_entry;
+                }
+            }
+            if entry.0 <= child_entry.0 {
+                break;
+            }
+            self.set_entry(index, &child_entry);
+            index = child_index;
+        }
+        self.set_entry(index, &entry);
+    }
+
+    /// Iterates over all entries, in storage (heap) order, which is not sorted.
+    /// The first entry is always the one with the smallest priority.
+    pub fn iter(&self) -> Iter<SA, P, T> {
+        Iter::new(self)
+    }
+
+    /// Loads at most `limit` entries, in storage (heap) order, starting from index `cursor` (`0` for the first page).
+    ///
+    /// The next cursor is the index after the last entry loaded, or `0` if there are no more entries.
+    /// Pushing and popping moves entries around, so the pages are only consistent while the queue does not change.
+    /// Signals an error if the cursor is past the last entry, e.g. after popping.
+    pub fn page(&self, cursor: usize, limit: usize) -> MultiValuePage<SA, MultiValue2<P, T>> {
+        let len = self.len();
+        if cursor > len {
+            SA::error_api_impl().signal_error(err_msg::STORAGE_MAPPER_INVALID_CURSOR);
+        }
+        let start = if cursor == 0 { 1 } else { cursor };
+        let end = core::cmp::min(start.saturating_add(limit), len + 1);
+        let items = (start..end)
+            .map(|index| MultiValue2::from(self.get_entry(index)))
+            .collect();
+        let next_cursor = if end <= len { end } else { 0 };
+        MultiValuePage::new(next_cursor, items)
+    }
+
+    /// Checks that each entry has a priority no smaller than that of its parent.
+    /// Used for unit tests.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let len = self.len();
+        for index in 2..=len {
+            if self.get_entry(index).0 < self.get_entry(index / 2).0 {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// An iterator over the entries of a `PriorityQueueMapper`, in storage (heap) order.
+///
+/// This `struct` is created by [`PriorityQueueMapper::iter()`]. See its
+/// documentation for more.
+pub struct Iter<'a, SA, P, T>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+{
+    index: usize,
+    len: usize,
+    queue: &'a PriorityQueueMapper<SA, P, T>,
+}
+
+impl<'a, SA, P, T> Iter<'a, SA, P, T>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+{
+    fn new(queue: &'a PriorityQueueMapper<SA, P, T>) -> Iter<'a, SA, P, T> {
+        Iter {
+            index: 1,
+            len: queue.len(),
+            queue,
+        }
+    }
+}
+
+impl<'a, SA, P, T> Iterator for Iter<'a, SA, P, T>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+{
+    type Item = (P, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<(P, T)> {
+        let current_index = self.index;
+        if current_index > self.len {
+            return None;
+        }
+        self.index += 1;
+        Some(self.queue.get_entry(current_index))
+    }
+}
+
+/// Behaves like a MultiResultVec<MultiValue2<P, T>> when an endpoint result,
+/// with the entries in storage (heap) order.
+impl<SA, P, T> TopEncodeMulti for PriorityQueueMapper<SA, P, T>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        let iter = self.iter().map(MultiValue2::<P, T>::from);
+        multi_encode_iter_or_handle_err(iter, output, h)
+    }
+}
+
+impl<SA, P, T> CodecFrom<PriorityQueueMapper<SA, P, T>> for MultiValueEncoded<SA, MultiValue2<P, T>>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+{
+}
+
+/// Behaves like a MultiResultVec<MultiValue2<P, T>> when an endpoint result.
+impl<SA, P, T> TypeAbi for PriorityQueueMapper<SA, P, T>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + TypeAbi + 'static,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi + 'static,
+{
+    fn type_name() -> TypeName {
+        MultiValueEncoded::<SA, MultiValue2<P, T>>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        P::provide_type_descriptions(accumulator);
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
@@ -1,11 +1,14 @@
 use core::marker::PhantomData;
 
-use super::{StorageClearable, StorageMapper};
+use super::{
+    CurrentStorage, StorageAddress, StorageClearable, StorageMapper, StorageMapperFromAddress,
+};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
-    api::StorageMapperApi,
-    storage::{storage_get, storage_set, StorageKey},
-    types::{ManagedType, MultiValueEncoded},
+    api::{ErrorApiImpl, StorageMapperApi},
+    err_msg,
+    storage::{storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, MultiValueEncoded, MultiValuePage},
 };
 use alloc::vec::Vec;
 use elrond_codec::{
@@ -61,12 +64,16 @@ impl QueueMapperInfo {
 ///
 /// The `QueueMapper` allows pushing and popping elements at either end
 /// in constant time.
-pub struct QueueMapper<SA, T>
+///
+/// When built over another contract address, it can only read the elements.
+pub struct QueueMapper<SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     _phantom_api: PhantomData<SA>,
+    address: A,
     base_key: StorageKey<SA>,
     _phantom_item: PhantomData<T>,
 }
@@ -79,6 +86,22 @@ where
     fn new(base_key: StorageKey<SA>) -> Self {
         QueueMapper {
             _phantom_api: PhantomData,
+            address: CurrentStorage,
+            base_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> StorageMapperFromAddress<SA> for QueueMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        QueueMapper {
+            _phantom_api: PhantomData,
+            address,
             base_key,
             _phantom_item: PhantomData,
         }
@@ -103,9 +126,10 @@ where
     }
 }
 
-impl<SA, T> QueueMapper<SA, T>
+impl<SA, T, A> QueueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
     fn build_node_id_named_key(&self, name: &[u8], node_id: u32) -> StorageKey<SA> {
@@ -122,20 +146,184 @@ where
     }
 
     fn get_info(&self) -> QueueMapperInfo {
-        storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
-    }
-
-    fn set_info(&mut self, value: QueueMapperInfo) {
-        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &value);
+        self.address
+            .address_storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
     }
 
     fn get_node(&self, node_id: u32) -> Node {
-        storage_get(
+        self.address.address_storage_get(
             self.build_node_id_named_key(NODE_IDENTIFIER, node_id)
                 .as_ref(),
         )
     }
 
+    fn get_value(&self, node_id: u32) -> T {
+        self.address.address_storage_get(
+            self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn get_value_option(&self, node_id: u32) -> Option<T> {
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        Some(self.get_value(node_id))
+    }
+
+    /// Returns `true` if the `Queue` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn is_empty(&self) -> bool {
+        self.get_info().len == 0
+    }
+
+    /// Returns the length of the `Queue`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn len(&self) -> usize {
+        self.get_info().len as usize
+    }
+
+    /// Provides a copy to the front element, or `None` if the queue is
+    /// empty.
+    pub fn front(&self) -> Option<T> {
+        self.get_value_option(self.get_info().front)
+    }
+
+    /// Provides a copy to the back element, or `None` if the queue is
+    /// empty.
+    pub fn back(&self) -> Option<T> {
+        self.get_value_option(self.get_info().back)
+    }
+
+    /// Provides a forward iterator.
+    pub fn iter(&self) -> Iter<SA, T, A> {
+        Iter::new(self)
+    }
+
+    /// Provides a forward iterator, starting from the node with the given id,
+    /// or from the front of the queue if the id is `0`.
+    /// Signals an error if there is no node with that id.
+    fn iter_from_cursor(&self, cursor: usize) -> Iter<SA, T, A> {
+        if cursor == 0 {
+            return Iter::new(self);
+        }
+        if cursor > u32::MAX as usize
+            || self.address.address_storage_get_len(
+                self.build_node_id_named_key(NODE_IDENTIFIER, cursor as u32)
+                    .as_ref(),
+            ) == 0
+        {
+            SA::error_api_impl().signal_error(err_msg::STORAGE_MAPPER_INVALID_CURSOR);
+        }
+        Iter {
+            node_id: cursor as u32,
+            queue: self,
+        }
+    }
+
+    /// Loads at most `limit` elements, from front to back, starting from `cursor` (`0` for the first page).
+    ///
+    /// The cursors are node ids, so they stay valid while other elements are pushed or popped.
+    /// A cursor whose element was removed in the meantime is invalid and signals an error.
+    /// The next cursor is `0` if there are no more elements.
+    pub fn page(&self, cursor: usize, limit: usize) -> MultiValuePage<SA, T> {
+        self.page_map(cursor, limit, |value| value)
+    }
+
+    /// Same as `page`, but with each element converted by `f`.
+    pub(crate) fn page_map<U, F>(&self, cursor: usize, limit: usize, f: F) -> MultiValuePage<SA, U>
+    where
+        U: TopEncodeMulti,
+        F: FnMut(T) -> U,
+    {
+        let mut iter = self.iter_from_cursor(cursor);
+        let items = iter.by_ref().take(limit).map(f).collect();
+        MultiValuePage::new(iter.node_id as usize, items)
+    }
+
+    /// Runs several checks in order to verify that both forwards and backwards iteration
+    /// yields the same node entries and that the number of items in the queue is correct.
+    /// Used for unit testing.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let info = self.get_info();
+        let mut front = info.front;
+        let mut back = info.back;
+        if info.len == 0 {
+            // if the queue is empty, both ends should point to null entries
+            if front != NULL_ENTRY {
+                return false;
+            }
+            if back != NULL_ENTRY {
+                return false;
+            }
+            true
+        } else {
+            // if the queue is non-empty, both ends should point to non-null entries
+            if front == NULL_ENTRY {
+                return false;
+            }
+            if back == NULL_ENTRY {
+                return false;
+            }
+
+            // the node before the first and the one after the last should both be null
+            if self.get_node(front).previous != NULL_ENTRY {
+                return false;
+            }
+            if self.get_node(back).next != NULL_ENTRY {
+                return false;
+            }
+
+            // iterate forwards
+            let mut forwards = Vec::new();
+            while front != NULL_ENTRY {
+                forwards.push(front);
+                front = self.get_node(front).next;
+            }
+            if forwards.len() != info.len as usize {
+                return false;
+            }
+
+            // iterate backwards
+            let mut backwards = Vec::new();
+            while back != NULL_ENTRY {
+                backwards.push(back);
+                back = self.get_node(back).previous;
+            }
+            if backwards.len() != info.len as usize {
+                return false;
+            }
+
+            // check that both iterations match element-wise
+            let backwards_reversed: Vec<u32> = backwards.iter().rev().cloned().collect();
+            if forwards != backwards_reversed {
+                return false;
+            }
+
+            // check that the node IDs are unique
+            forwards.sort_unstable();
+            forwards.dedup();
+            if forwards.len() != info.len as usize {
+                return false;
+            }
+            true
+        }
+    }
+}
+
+impl<SA, T> QueueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn set_info(&mut self, value: QueueMapperInfo) {
+        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &value);
+    }
+
     fn set_node(&mut self, node_id: u32, item: Node) {
         storage_set(
             self.build_node_id_named_key(NODE_IDENTIFIER, node_id)
@@ -152,20 +340,6 @@ where
         );
     }
 
-    fn get_value(&self, node_id: u32) -> T {
-        storage_get(
-            self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
-                .as_ref(),
-        )
-    }
-
-    fn get_value_option(&self, node_id: u32) -> Option<T> {
-        if node_id == NULL_ENTRY {
-            return None;
-        }
-        Some(self.get_value(node_id))
-    }
-
     fn set_value(&mut self, node_id: u32, value: &T) {
         storage_set(
             self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
@@ -182,20 +356,6 @@ where
         )
     }
 
-    /// Returns `true` if the `Queue` is empty.
-    ///
-    /// This operation should compute in *O*(1) time.
-    pub fn is_empty(&self) -> bool {
-        self.get_info().len == 0
-    }
-
-    /// Returns the length of the `Queue`.
-    ///
-    /// This operation should compute in *O*(1) time.
-    pub fn len(&self) -> usize {
-        self.get_info().len as usize
-    }
-
     /// Appends an element to the back of a queue
     /// and returns the node id of the newly added node.
     ///
@@ -263,18 +423,6 @@ where
         self.set_info(info);
     }
 
-    /// Provides a copy to the front element, or `None` if the queue is
-    /// empty.
-    pub fn front(&self) -> Option<T> {
-        self.get_value_option(self.get_info().front)
-    }
-
-    /// Provides a copy to the back element, or `None` if the queue is
-    /// empty.
-    pub fn back(&self) -> Option<T> {
-        self.get_value_option(self.get_info().back)
-    }
-
     /// Removes the last element from a queue and returns it, or `None` if
     /// it is empty.
     ///
@@ -326,103 +474,29 @@ where
         self.set_info(info);
         Some(removed_value)
     }
-
-    /// Provides a forward iterator.
-    pub fn iter(&self) -> Iter<SA, T> {
-        Iter::new(self)
-    }
-
-    /// Runs several checks in order to verify that both forwards and backwards iteration
-    /// yields the same node entries and that the number of items in the queue is correct.
-    /// Used for unit testing.
-    ///
-    /// This operation should compute in *O*(n) time.
-    pub fn check_internal_consistency(&self) -> bool {
-        let info = self.get_info();
-        let mut front = info.front;
-        let mut back = info.back;
-        if info.len == 0 {
-            // if the queue is empty, both ends should point to null entries
-            if front != NULL_ENTRY {
-                return false;
-            }
-            if back != NULL_ENTRY {
-                return false;
-            }
-            true
-        } else {
-            // if the queue is non-empty, both ends should point to non-null entries
-            if front == NULL_ENTRY {
-                return false;
-            }
-            if back == NULL_ENTRY {
-                return false;
-            }
-
-            // the node before the first and the one after the last should both be null
-            if self.get_node(front).previous != NULL_ENTRY {
-                return false;
-            }
-            if self.get_node(back).next != NULL_ENTRY {
-                return false;
-            }
-
-            // iterate forwards
-            let mut forwards = Vec::new();
-            while front != NULL_ENTRY {
-                forwards.push(front);
-                front = self.get_node(front).next;
-            }
-            if forwards.len() != info.len as usize {
-                return false;
-            }
-
-            // iterate backwards
-            let mut backwards = Vec::new();
-            while back != NULL_ENTRY {
-                backwards.push(back);
-                back = self.get_node(back).previous;
-            }
-            if backwards.len() != info.len as usize {
-                return false;
-            }
-
-            // check that both iterations match element-wise
-            let backwards_reversed: Vec<u32> = backwards.iter().rev().cloned().collect();
-            if forwards != backwards_reversed {
-                return false;
-            }
-
-            // check that the node IDs are unique
-            forwards.sort_unstable();
-            forwards.dedup();
-            if forwards.len() != info.len as usize {
-                return false;
-            }
-            true
-        }
-    }
 }
 
 /// An iterator over the elements of a `QueueMapper`.
 ///
 /// This `struct` is created by [`QueueMapper::iter()`]. See its
 /// documentation for more.
-pub struct Iter<'a, SA, T>
+pub struct Iter<'a, SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     node_id: u32,
-    queue: &'a QueueMapper<SA, T>,
+    queue: &'a QueueMapper<SA, T, A>,
 }
 
-impl<'a, SA, T> Iter<'a, SA, T>
+impl<'a, SA, T, A> Iter<'a, SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
-    fn new(queue: &'a QueueMapper<SA, T>) -> Iter<'a, SA, T> {
+    fn new(queue: &'a QueueMapper<SA, T, A>) -> Iter<'a, SA, T, A> {
         Iter {
             node_id: queue.get_info().front,
             queue,
@@ -430,9 +504,10 @@ where
     }
 }
 
-impl<'a, SA, T> Iterator for Iter<'a, SA, T>
+impl<'a, SA, T, A> Iterator for Iter<'a, SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     type Item = T;
@@ -449,9 +524,10 @@ where
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TopEncodeMulti for QueueMapper<SA, T>
+impl<SA, T, A> TopEncodeMulti for QueueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
     fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -463,17 +539,19 @@ where
     }
 }
 
-impl<SA, T> CodecFrom<QueueMapper<SA, T>> for MultiValueEncoded<SA, T>
+impl<SA, T, A> CodecFrom<QueueMapper<SA, T, A>> for MultiValueEncoded<SA, T>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TypeAbi for QueueMapper<SA, T>
+impl<SA, T, A> TypeAbi for QueueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + TypeAbi,
 {
     fn type_name() -> TypeName {
@@ -1,11 +1,11 @@
 use core::{borrow::Borrow, marker::PhantomData};
 
-use super::StorageMapper;
+use super::{CurrentStorage, StorageAddress, StorageMapper, StorageMapperFromAddress};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
-    storage::{storage_clear, storage_get, storage_get_len, storage_set, StorageKey},
-    types::ManagedType,
+    storage::{storage_clear, storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType},
 };
 use elrond_codec::{
     multi_types::PlaceholderOutput, CodecFrom, CodecFromSelf, DecodeErrorHandler,
@@ -14,11 +14,15 @@ use elrond_codec::{
 };
 
 /// Manages a single serializable item in storage.
-pub struct SingleValueMapper<SA, T>
+///
+/// When built over another contract address, it can only read the value.
+pub struct SingleValueMapper<SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
+    address: A,
     key: StorageKey<SA>,
     _phantom_api: PhantomData<SA>,
     _phantom_item: PhantomData<T>,
@@ -32,6 +36,7 @@ where
     #[inline]
     fn new(base_key: StorageKey<SA>) -> Self {
         SingleValueMapper {
+            address: CurrentStorage,
             key: base_key,
             _phantom_api: PhantomData,
             _phantom_item: PhantomData,
@@ -39,14 +44,31 @@ where
     }
 }
 
-impl<SA, T> SingleValueMapper<SA, T>
+impl<SA, T> StorageMapperFromAddress<SA> for SingleValueMapper<SA, T, ManagedAddress<SA>>
 where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode,
+{
+    #[inline]
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        SingleValueMapper {
+            address,
+            key: base_key,
+            _phantom_api: PhantomData,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T, A> SingleValueMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode,
 {
     /// Retrieves current value from storage.
     pub fn get(&self) -> T {
-        storage_get(self.key.as_ref())
+        self.address.address_storage_get(self.key.as_ref())
     }
 
     /// Returns whether the storage managed by this mapper is empty.
@@ -54,6 +76,16 @@ where
         self.raw_byte_length() == 0
     }
 
+    pub fn raw_byte_length(&self) -> usize {
+        self.address.address_storage_get_len(self.key.as_ref())
+    }
+}
+
+impl<SA, T> SingleValueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
     /// Saves argument to storage.
     ///
     /// Accepts owned item of type `T`, or any borrowed form of it, such as `&T`.
@@ -90,15 +122,12 @@ where
         self.set(value);
         result
     }
-
-    pub fn raw_byte_length(&self) -> usize {
-        storage_get_len(self.key.as_ref())
-    }
 }
 
-impl<SA, T> TopEncodeMulti for SingleValueMapper<SA, T>
+impl<SA, T, A> TopEncodeMulti for SingleValueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
     fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -148,31 +177,35 @@ impl<T: TopDecode> SingleValue<T> {
     }
 }
 
-impl<SA, T> !CodecFromSelf for SingleValueMapper<SA, T>
+impl<SA, T, A> !CodecFromSelf for SingleValueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
 }
 
-impl<SA, T, R> CodecFrom<SingleValueMapper<SA, T>> for SingleValue<R>
+impl<SA, T, R, A> CodecFrom<SingleValueMapper<SA, T, A>> for SingleValue<R>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
     R: TopDecode + CodecFrom<T>,
 {
 }
 
-impl<SA, T> CodecFrom<SingleValueMapper<SA, T>> for PlaceholderOutput
+impl<SA, T, A> CodecFrom<SingleValueMapper<SA, T, A>> for PlaceholderOutput
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
 }
 
-impl<SA, T> TypeAbi for SingleValueMapper<SA, T>
+impl<SA, T, A> TypeAbi for SingleValueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + TypeAbi,
 {
     fn type_name() -> TypeName {
@@ -0,0 +1,328 @@
+use core::{marker::PhantomData, ops::RangeBounds};
+
+use super::{sorted_set_mapper, SortedSetMapper, StorageClearable, StorageMapper};
+use crate::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    api::StorageMapperApi,
+    storage::{storage_clear, storage_get, storage_set, StorageKey},
+    types::{ManagedType, MultiValueEncoded, MultiValuePage},
+};
+use elrond_codec::{
+    multi_encode_iter_or_handle_err, multi_types::MultiValue2, CodecFrom, EncodeErrorHandler,
+    NestedDecode, NestedEncode, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
+};
+
+const MAPPED_VALUE_IDENTIFIER: &[u8] = b".mapped";
+type Keys<'a, SA, T> = sorted_set_mapper::Iter<'a, SA, T>;
+
+/// A map that keeps its entries sorted by key.
+///
+/// The keys are kept in a `SortedSetMapper`, so looking up the first, the last,
+/// or the start of a range of keys only reads a logarithmic number of storage entries.
+pub struct SortedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    base_key: StorageKey<SA>,
+    keys_set: SortedSetMapper<SA, K>,
+    _phantom_value: PhantomData<V>,
+}
+
+impl<SA, K, V> StorageMapper<SA> for SortedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        SortedMapMapper {
+            _phantom_api: PhantomData,
+            base_key: base_key.clone(),
+            keys_set: SortedSetMapper::<SA, K>::new(base_key),
+            _phantom_value: PhantomData,
+        }
+    }
+}
+
+impl<SA, K, V> StorageClearable for SortedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn clear(&mut self) {
+        for key in self.keys_set.iter() {
+            self.clear_mapped_value(&key);
+        }
+        self.keys_set.clear();
+    }
+}
+
+impl<SA, K, V> SortedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn build_named_key(&self, name: &[u8], key: &K) -> StorageKey<SA> {
+        let mut named_key = self.base_key.clone();
+        named_key.append_bytes(name);
+        named_key.append_item(key);
+        named_key
+    }
+
+    fn get_mapped_value(&self, key: &K) -> V {
+        storage_get(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref())
+    }
+
+    fn set_mapped_value(&self, key: &K, value: &V) {
+        storage_set(
+            self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref(),
+            &value,
+        );
+    }
+
+    fn clear_mapped_value(&self, key: &K) {
+        storage_clear(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref());
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.keys_set.is_empty()
+    }
+
+    /// Returns the number of elements in the map.
+    pub fn len(&self) -> usize {
+        self.keys_set.len()
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.keys_set.contains(k)
+    }
+
+    /// Gets a reference to the value in the entry.
+    pub fn get(&self, k: &K) -> Option<V> {
+        if self.keys_set.contains(k) {
+            return Some(self.get_mapped_value(k));
+        }
+        None
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        let old_value = self.get(&k);
+        self.set_mapped_value(&k, &v);
+        self.keys_set.insert(k);
+        old_value
+    }
+
+    /// Takes the value out of the entry, and returns it.
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        if self.keys_set.remove(k) {
+            let value = self.get_mapped_value(k);
+            self.clear_mapped_value(k);
+            return Some(value);
+        }
+        None
+    }
+
+    /// The entry with the smallest key, or `None` if the map is empty.
+    pub fn first(&self) -> Option<(K, V)> {
+        let key = self.keys_set.first()?;
+        let value = self.get_mapped_value(&key);
+        Some((key, value))
+    }
+
+    /// The entry with the largest key, or `None` if the map is empty.
+    pub fn last(&self) -> Option<(K, V)> {
+        let key = self.keys_set.last()?;
+        let value = self.get_mapped_value(&key);
+        Some((key, value))
+    }
+
+    /// An iterator visiting all keys in ascending order.
+    pub fn keys(&self) -> Keys<SA, K> {
+        self.keys_set.iter()
+    }
+
+    /// An iterator visiting all values, in the ascending order of their keys.
+    pub fn values(&self) -> Values<SA, K, V> {
+        Values::new(self.keys_set.iter(), self)
+    }
+
+    /// An iterator visiting all key-value pairs in ascending key order.
+    pub fn iter(&self) -> Iter<SA, K, V> {
+        Iter::new(self.keys_set.iter(), self)
+    }
+
+    /// An iterator visiting the key-value pairs with the keys in the given range,
+    /// in ascending key order.
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Iter<SA, K, V> {
+        Iter::new(self.keys_set.range(range), self)
+    }
+
+    /// Loads at most `limit` key-value pairs, in ascending key order, starting from `cursor` (`0` for the first page).
+    ///
+    /// Cursors work as for the `SortedSetMapper` of the keys: inserts keep them valid, any removal can invalidate them.
+    pub fn page(&self, cursor: usize, limit: usize) -> MultiValuePage<SA, MultiValue2<K, V>> {
+        self.keys_set.page_map(cursor, limit, |key| {
+            let value = self.get_mapped_value(&key);
+            MultiValue2::from((key, value))
+        })
+    }
+}
+
+/// An iterator over the key-value pairs of a `SortedMapMapper`, or over a range of them.
+///
+/// It can be iterated from both ends.
+pub struct Iter<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    key_iter: Keys<'a, SA, K>,
+    hash_map: &'a SortedMapMapper<SA, K, V>,
+}
+
+impl<'a, SA, K, V> Iter<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn new(key_iter: Keys<'a, SA, K>, hash_map: &'a SortedMapMapper<SA, K, V>) -> Self {
+        Iter { key_iter, hash_map }
+    }
+}
+
+impl<'a, SA, K, V> Iterator for Iter<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        let key = self.key_iter.next()?;
+        let value = self.hash_map.get_mapped_value(&key);
+        Some((key, value))
+    }
+}
+
+impl<'a, SA, K, V> DoubleEndedIterator for Iter<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<(K, V)> {
+        let key = self.key_iter.next_back()?;
+        let value = self.hash_map.get_mapped_value(&key);
+        Some((key, value))
+    }
+}
+
+/// An iterator over the values of a `SortedMapMapper`, in the ascending order of their keys.
+pub struct Values<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    key_iter: Keys<'a, SA, K>,
+    hash_map: &'a SortedMapMapper<SA, K, V>,
+}
+
+impl<'a, SA, K, V> Values<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn new(key_iter: Keys<'a, SA, K>, hash_map: &'a SortedMapMapper<SA, K, V>) -> Self {
+        Values { key_iter, hash_map }
+    }
+}
+
+impl<'a, SA, K, V> Iterator for Values<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<V> {
+        let key = self.key_iter.next()?;
+        Some(self.hash_map.get_mapped_value(&key))
+    }
+}
+
+impl<'a, SA, K, V> DoubleEndedIterator for Values<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<V> {
+        let key = self.key_iter.next_back()?;
+        Some(self.hash_map.get_mapped_value(&key))
+    }
+}
+
+/// Behaves like a MultiResultVec<MultiValue2<K, V>> when an endpoint result,
+/// with the entries in ascending key order.
+impl<SA, K, V> TopEncodeMulti for SortedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        let iter = self.iter().map(MultiValue2::<K, V>::from);
+        multi_encode_iter_or_handle_err(iter, output, h)
+    }
+}
+
+impl<SA, K, V> CodecFrom<SortedMapMapper<SA, K, V>> for MultiValueEncoded<SA, MultiValue2<K, V>>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+}
+
+/// Behaves like a MultiResultVec<MultiValue2<K, V>> when an endpoint result.
+impl<SA, K, V> TypeAbi for SortedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + TypeAbi + 'static,
+    V: TopEncode + TopDecode + TypeAbi + 'static,
+{
+    fn type_name() -> TypeName {
+        MultiValueEncoded::<SA, MultiValue2<K, V>>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
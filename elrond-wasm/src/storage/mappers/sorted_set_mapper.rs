@@ -0,0 +1,842 @@
+use core::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use super::{StorageClearable, StorageMapper};
+use crate::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{ErrorApiImpl, StorageMapperApi},
+    err_msg,
+    storage::{storage_clear, storage_get, storage_get_len, storage_set, StorageKey},
+    types::{ManagedType, MultiValueEncoded, MultiValuePage},
+};
+use elrond_codec::{
+    elrond_codec_derive::{TopDecode, TopDecodeOrDefault, TopEncode, TopEncodeOrDefault},
+    multi_encode_iter_or_handle_err, CodecFrom, DecodeDefault, EncodeDefault, EncodeErrorHandler,
+    NestedDecode, NestedEncode, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
+};
+
+const NULL_ENTRY: u32 = 0;
+const INFO_IDENTIFIER: &[u8] = b".info";
+const NODE_LINKS_IDENTIFIER: &[u8] = b".node_links";
+const VALUE_IDENTIFIER: &[u8] = b".value";
+const NODE_ID_IDENTIFIER: &[u8] = b".node_id";
+
+/// Position of a node in the tree. The height of a leaf is 1.
+#[derive(TopEncode, TopDecode, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SortedNodeLinks {
+    pub parent: u32,
+    pub left: u32,
+    pub right: u32,
+    pub height: u32,
+}
+
+#[derive(TopEncodeOrDefault, TopDecodeOrDefault, PartialEq, Eq, Clone, Copy)]
+pub struct SortedSetInfo {
+    pub len: u32,
+    pub root: u32,
+    pub new: u32,
+}
+
+impl EncodeDefault for SortedSetInfo {
+    fn is_default(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl DecodeDefault for SortedSetInfo {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            root: 0,
+            new: 0,
+        }
+    }
+}
+
+impl SortedSetInfo {
+    pub fn generate_new_node_id(&mut self) -> u32 {
+        self.new += 1;
+        self.new
+    }
+}
+
+/// A set that keeps its elements sorted.
+///
+/// The elements are kept in a balanced (AVL) tree, one node per storage entry.
+/// `insert`, `remove`, `first`, `last` and finding the start of a range
+/// only read a logarithmic number of storage entries. `contains` reads a single one.
+///
+/// Iteration goes in ascending order, `rev()` can be used to iterate in descending order.
+pub struct SortedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    base_key: StorageKey<SA>,
+    _phantom_item: PhantomData<T>,
+}
+
+impl<SA, T> StorageMapper<SA> for SortedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        SortedSetMapper {
+            _phantom_api: PhantomData,
+            base_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> StorageClearable for SortedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+{
+    fn clear(&mut self) {
+        // post-order traversal, so that the parent links are still there when going up
+        let mut node_id = self.deepest_first_leaf(self.get_info().root);
+        while node_id != NULL_ENTRY {
+            let parent_id = self.get_links(node_id).parent;
+            self.clear_node_id(&self.get_value(node_id));
+            self.clear_node(node_id);
+            if parent_id == NULL_ENTRY {
+                break;
+            }
+            let parent_links = self.get_links(parent_id);
+            node_id = if parent_links.left == node_id && parent_links.right != NULL_ENTRY {
+                self.deepest_first_leaf(parent_links.right)
+            } else {
+                parent_id
+            };
+        }
+        self.set_info(SortedSetInfo::default());
+    }
+}
+
+impl<SA, T> SortedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+{
+    fn build_node_id_named_key(&self, name: &[u8], node_id: u32) -> StorageKey<SA> {
+        let mut named_key = self.base_key.clone();
+        named_key.append_bytes(name);
+        named_key.append_item(&node_id);
+        named_key
+    }
+
+    fn build_named_value_key(&self, name: &[u8], value: &T) -> StorageKey<SA> {
+        let mut named_key = self.base_key.clone();
+        named_key.append_bytes(name);
+        named_key.append_item(value);
+        named_key
+    }
+
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut name_key = self.base_key.clone();
+        name_key.append_bytes(name);
+        name_key
+    }
+
+    fn get_info(&self) -> SortedSetInfo {
+        storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
+    }
+
+    fn set_info(&self, info: SortedSetInfo) {
+        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &info);
+    }
+
+    fn get_links(&self, node_id: u32) -> SortedNodeLinks {
+        if node_id == NULL_ENTRY {
+            return SortedNodeLinks::default();
+        }
+        storage_get(
+            self.build_node_id_named_key(NODE_LINKS_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn set_links(&self, node_id: u32, links: &SortedNodeLinks) {
+        storage_set(
+            self.build_node_id_named_key(NODE_LINKS_IDENTIFIER, node_id)
+                .as_ref(),
+            links,
+        );
+    }
+
+    fn get_value(&self, node_id: u32) -> T {
+        storage_get(
+            self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn set_value(&self, node_id: u32, value: &T) {
+        storage_set(
+            self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+            value,
+        );
+    }
+
+    fn clear_node(&self, node_id: u32) {
+        storage_clear(
+            self.build_node_id_named_key(NODE_LINKS_IDENTIFIER, node_id)
+                .as_ref(),
+        );
+        storage_clear(
+            self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+        );
+    }
+
+    fn get_node_id(&self, value: &T) -> u32 {
+        storage_get(
+            self.build_named_value_key(NODE_ID_IDENTIFIER, value)
+                .as_ref(),
+        )
+    }
+
+    fn set_node_id(&self, value: &T, node_id: u32) {
+        storage_set(
+            self.build_named_value_key(NODE_ID_IDENTIFIER, value)
+                .as_ref(),
+            &node_id,
+        );
+    }
+
+    fn clear_node_id(&self, value: &T) {
+        storage_clear(
+            self.build_named_value_key(NODE_ID_IDENTIFIER, value)
+                .as_ref(),
+        );
+    }
+
+    fn height(&self, node_id: u32) -> u32 {
+        self.get_links(node_id).height
+    }
+
+    /// Returns `true` if the set contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.get_info().len == 0
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.get_info().len as usize
+    }
+
+    /// Returns `true` if the set contains a value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.get_node_id(value) != NULL_ENTRY
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// If the set did not have this value present, `true` is returned.
+    ///
+    /// If the set did have this value present, `false` is returned.
+    pub fn insert(&mut self, value: T) -> bool {
+        if self.contains(&value) {
+            return false;
+        }
+
+        let mut info = self.get_info();
+        let new_node_id = info.generate_new_node_id();
+        let mut parent_id = NULL_ENTRY;
+        let mut goes_left = false;
+        let mut node_id = info.root;
+        while node_id != NULL_ENTRY {
+            parent_id = node_id;
+            let links = self.get_links(node_id);
+            goes_left = value < self.get_value(node_id);
+            node_id = if goes_left { links.left } else { links.right };
+        }
+
+        if parent_id == NULL_ENTRY {
+            info.root = new_node_id;
+        } else {
+            let mut parent_links = self.get_links(parent_id);
+            if goes_left {
+                parent_links.left = new_node_id;
+            } else {
+                parent_links.right = new_node_id;
+            }
+            self.set_links(parent_id, &parent_links);
+        }
+        self.set_links(
+            new_node_id,
+            &SortedNodeLinks {
+                parent: parent_id,
+                left: NULL_ENTRY,
+                right: NULL_ENTRY,
+                height: 1,
+            },
+        );
+        self.set_value(new_node_id, &value);
+        self.set_node_id(&value, new_node_id);
+
+        self.rebalance_up_to_root(parent_id, &mut info);
+        info.len += 1;
+        self.set_info(info);
+        true
+    }
+
+    /// Removes a value from the set. Returns whether the value was
+    /// present in the set.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let node_id = self.get_node_id(value);
+        if node_id == NULL_ENTRY {
+            return false;
+        }
+        self.clear_node_id(value);
+
+        let mut info = self.get_info();
+        let links = self.get_links(node_id);
+        let removed_node_id = if links.left != NULL_ENTRY && links.right != NULL_ENTRY {
+            // the in-order successor has no left child,
+            // so its value moves into the current node, and the successor node is removed instead
+            let successor_id = self.leftmost(links.right);
+            let successor_value = self.get_value(successor_id);
+            self.set_value(node_id, &successor_value);
+            self.set_node_id(&successor_value, node_id);
+            successor_id
+        } else {
+            node_id
+        };
+
+        let removed_links = self.get_links(removed_node_id);
+        let child_id = if removed_links.left != NULL_ENTRY {
+            removed_links.left
+        } else {
+            removed_links.right
+        };
+        if child_id != NULL_ENTRY {
+            let mut child_links = self.get_links(child_id);
+            child_links.parent = removed_links.parent;
+            self.set_links(child_id, &child_links);
+        }
+        self.replace_child(removed_links.parent, removed_node_id, child_id, &mut info);
+        self.clear_node(removed_node_id);
+
+        self.rebalance_up_to_root(removed_links.parent, &mut info);
+        info.len -= 1;
+        self.set_info(info);
+        true
+    }
+
+    /// Removes all the given values from the set.
+    pub fn remove_all<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for item in iter {
+            self.remove(&item);
+        }
+    }
+
+    /// The smallest element in the set, or `None` if it is empty.
+    pub fn first(&self) -> Option<T> {
+        self.value_option(self.first_node_id())
+    }
+
+    /// The largest element in the set, or `None` if it is empty.
+    pub fn last(&self) -> Option<T> {
+        self.value_option(self.last_node_id())
+    }
+
+    /// Removes the smallest element and returns it, or `None` if the set is empty.
+    pub fn pop_first(&mut self) -> Option<T> {
+        let first = self.first()?;
+        self.remove(&first);
+        Some(first)
+    }
+
+    /// Removes the largest element and returns it, or `None` if the set is empty.
+    pub fn pop_last(&mut self) -> Option<T> {
+        let last = self.last()?;
+        self.remove(&last);
+        Some(last)
+    }
+
+    /// Iterates over all elements, in ascending order.
+    pub fn iter(&self) -> Iter<SA, T> {
+        Iter::new(self, self.first_node_id(), self.last_node_id())
+    }
+
+    /// Iterates over the elements in the given range, in ascending order.
+    ///
+    /// Only the ends of the range are searched for, the iteration itself
+    /// reads the elements one by one, so it can be stopped at any point.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Iter<SA, T> {
+        let front_id = self.lower_bound_node_id(range.start_bound());
+        let back_id = self.upper_bound_node_id(range.end_bound());
+        if front_id == NULL_ENTRY
+            || back_id == NULL_ENTRY
+            || self.get_value(front_id) > self.get_value(back_id)
+        {
+            return Iter::new(self, NULL_ENTRY, NULL_ENTRY);
+        }
+        Iter::new(self, front_id, back_id)
+    }
+
+    /// Loads at most `limit` elements, in ascending order, starting from `cursor` (`0` for the first page).
+    ///
+    /// The cursors are node ids. They stay valid while elements are inserted,
+    /// but any removal can invalidate them: removing an element with two children
+    /// moves its successor into its node and frees the successor's node.
+    /// An invalid cursor signals an error.
+    /// The next cursor is `0` if there are no more elements.
+    pub fn page(&self, cursor: usize, limit: usize) -> MultiValuePage<SA, T> {
+        self.page_map(cursor, limit, |value| value)
+    }
+
+    /// Same as `page`, but with each element converted by `f`.
+    pub(crate) fn page_map<U, F>(&self, cursor: usize, limit: usize, f: F) -> MultiValuePage<SA, U>
+    where
+        U: TopEncodeMulti,
+        F: FnMut(T) -> U,
+    {
+        let front_id = if cursor == 0 {
+            self.first_node_id()
+        } else {
+            if cursor > u32::MAX as usize
+                || storage_get_len(
+                    self.build_node_id_named_key(NODE_LINKS_IDENTIFIER, cursor as u32)
+                        .as_ref(),
+                ) == 0
+            {
+                SA::error_api_impl().signal_error(err_msg::STORAGE_MAPPER_INVALID_CURSOR);
+            }
+            cursor as u32
+        };
+        let mut iter = Iter::new(self, front_id, self.last_node_id());
+        let items = iter.by_ref().take(limit).map(f).collect();
+        MultiValuePage::new(iter.front_id as usize, items)
+    }
+
+    fn value_option(&self, node_id: u32) -> Option<T> {
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        Some(self.get_value(node_id))
+    }
+
+    fn first_node_id(&self) -> u32 {
+        self.leftmost(self.get_info().root)
+    }
+
+    fn last_node_id(&self) -> u32 {
+        self.rightmost(self.get_info().root)
+    }
+
+    fn leftmost(&self, mut node_id: u32) -> u32 {
+        if node_id == NULL_ENTRY {
+            return NULL_ENTRY;
+        }
+        loop {
+            let left = self.get_links(node_id).left;
+            if left == NULL_ENTRY {
+                return node_id;
+            }
+            node_id = left;
+        }
+    }
+
+    fn rightmost(&self, mut node_id: u32) -> u32 {
+        if node_id == NULL_ENTRY {
+            return NULL_ENTRY;
+        }
+        loop {
+            let right = self.get_links(node_id).right;
+            if right == NULL_ENTRY {
+                return node_id;
+            }
+            node_id = right;
+        }
+    }
+
+    /// The first leaf reached when always descending to the left child, if available.
+    fn deepest_first_leaf(&self, mut node_id: u32) -> u32 {
+        if node_id == NULL_ENTRY {
+            return NULL_ENTRY;
+        }
+        loop {
+            let links = self.get_links(node_id);
+            if links.left != NULL_ENTRY {
+                node_id = links.left;
+            } else if links.right != NULL_ENTRY {
+                node_id = links.right;
+            } else {
+                return node_id;
+            }
+        }
+    }
+
+    fn successor(&self, node_id: u32) -> u32 {
+        let links = self.get_links(node_id);
+        if links.right != NULL_ENTRY {
+            return self.leftmost(links.right);
+        }
+        let mut child_id = node_id;
+        let mut parent_id = links.parent;
+        while parent_id != NULL_ENTRY {
+            let parent_links = self.get_links(parent_id);
+            if parent_links.left == child_id {
+                return parent_id;
+            }
+            child_id = parent_id;
+            parent_id = parent_links.parent;
+        }
+        NULL_ENTRY
+    }
+
+    fn predecessor(&self, node_id: u32) -> u32 {
+        let links = self.get_links(node_id);
+        if links.left != NULL_ENTRY {
+            return self.rightmost(links.left);
+        }
+        let mut child_id = node_id;
+        let mut parent_id = links.parent;
+        while parent_id != NULL_ENTRY {
+            let parent_links = self.get_links(parent_id);
+            if parent_links.right == child_id {
+                return parent_id;
+            }
+            child_id = parent_id;
+            parent_id = parent_links.parent;
+        }
+        NULL_ENTRY
+    }
+
+    /// The node holding the smallest value within the bound.
+    fn lower_bound_node_id(&self, bound: Bound<&T>) -> u32 {
+        let mut result = NULL_ENTRY;
+        let mut node_id = self.get_info().root;
+        while node_id != NULL_ENTRY {
+            let links = self.get_links(node_id);
+            let within_bound = match bound {
+                Bound::Included(start) => self.get_value(node_id) >= *start,
+                Bound::Excluded(start) => self.get_value(node_id) > *start,
+                Bound::Unbounded => true,
+            };
+            if within_bound {
+                result = node_id;
+                node_id = links.left;
+            } else {
+                node_id = links.right;
+            }
+        }
+        result
+    }
+
+    /// The node holding the largest value within the bound.
+    fn upper_bound_node_id(&self, bound: Bound<&T>) -> u32 {
+        let mut result = NULL_ENTRY;
+        let mut node_id = self.get_info().root;
+        while node_id != NULL_ENTRY {
+            let links = self.get_links(node_id);
+            let within_bound = match bound {
+                Bound::Included(end) => self.get_value(node_id) <= *end,
+                Bound::Excluded(end) => self.get_value(node_id) < *end,
+                Bound::Unbounded => true,
+            };
+            if within_bound {
+                result = node_id;
+                node_id = links.right;
+            } else {
+                node_id = links.left;
+            }
+        }
+        result
+    }
+
+    /// Points the parent (or the root) to the new child.
+    fn replace_child(
+        &self,
+        parent_id: u32,
+        old_child_id: u32,
+        new_child_id: u32,
+        info: &mut SortedSetInfo,
+    ) {
+        if parent_id == NULL_ENTRY {
+            info.root = new_child_id;
+            return;
+        }
+        let mut parent_links = self.get_links(parent_id);
+        if parent_links.left == old_child_id {
+            parent_links.left = new_child_id;
+        } else {
+            parent_links.right = new_child_id;
+        }
+        self.set_links(parent_id, &parent_links);
+    }
+
+    fn rebalance_up_to_root(&self, mut node_id: u32, info: &mut SortedSetInfo) {
+        while node_id != NULL_ENTRY {
+            let subtree_root_id = self.rebalance(node_id, info);
+            node_id = self.get_links(subtree_root_id).parent;
+        }
+    }
+
+    /// Restores the AVL property of the subtree, returns its new root.
+    fn rebalance(&self, node_id: u32, info: &mut SortedSetInfo) -> u32 {
+        let mut links = self.get_links(node_id);
+        let left_height = self.height(links.left);
+        let right_height = self.height(links.right);
+        if left_height > right_height + 1 {
+            let left_links = self.get_links(links.left);
+            if self.height(left_links.left) < self.height(left_links.right) {
+                self.rotate_left(links.left, info);
+            }
+            return self.rotate_right(node_id, info);
+        }
+        if right_height > left_height + 1 {
+            let right_links = self.get_links(links.right);
+            if self.height(right_links.right) < self.height(right_links.left) {
+                self.rotate_right(links.right, info);
+            }
+            return self.rotate_left(node_id, info);
+        }
+
+        let height = 1 + left_height.max(right_height);
+        if links.height != height {
+            links.height = height;
+            self.set_links(node_id, &links);
+        }
+        node_id
+    }
+
+    fn rotate_left(&self, node_id: u32, info: &mut SortedSetInfo) -> u32 {
+        let mut links = self.get_links(node_id);
+        let pivot_id = links.right;
+        let mut pivot_links = self.get_links(pivot_id);
+
+        links.right = pivot_links.left;
+        if pivot_links.left != NULL_ENTRY {
+            let mut moved_links = self.get_links(pivot_links.left);
+            moved_links.parent = node_id;
+            self.set_links(pivot_links.left, &moved_links);
+        }
+        self.replace_child(links.parent, node_id, pivot_id, info);
+        pivot_links.parent = links.parent;
+        pivot_links.left = node_id;
+        links.parent = pivot_id;
+
+        links.height = 1 + self.height(links.left).max(self.height(links.right));
+        pivot_links.height = 1 + links.height.max(self.height(pivot_links.right));
+        self.set_links(node_id, &links);
+        self.set_links(pivot_id, &pivot_links);
+        pivot_id
+    }
+
+    fn rotate_right(&self, node_id: u32, info: &mut SortedSetInfo) -> u32 {
+        let mut links = self.get_links(node_id);
+        let pivot_id = links.left;
+        let mut pivot_links = self.get_links(pivot_id);
+
+        links.left = pivot_links.right;
+        if pivot_links.right != NULL_ENTRY {
+            let mut moved_links = self.get_links(pivot_links.right);
+            moved_links.parent = node_id;
+            self.set_links(pivot_links.right, &moved_links);
+        }
+        self.replace_child(links.parent, node_id, pivot_id, info);
+        pivot_links.parent = links.parent;
+        pivot_links.right = node_id;
+        links.parent = pivot_id;
+
+        links.height = 1 + self.height(links.left).max(self.height(links.right));
+        pivot_links.height = 1 + links.height.max(self.height(pivot_links.left));
+        self.set_links(node_id, &links);
+        self.set_links(pivot_id, &pivot_links);
+        pivot_id
+    }
+
+    /// Checks the internal consistency of the collection: the order of the elements,
+    /// the parent links, the heights and the balance of the tree. Used for unit tests.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let info = self.get_info();
+        if info.root != NULL_ENTRY && self.get_links(info.root).parent != NULL_ENTRY {
+            return false;
+        }
+        match self.check_subtree(info.root) {
+            Some((count, _)) => count == info.len,
+            None => false,
+        }
+    }
+
+    /// Returns the number of nodes and the height of a valid subtree.
+    fn check_subtree(&self, node_id: u32) -> Option<(u32, u32)> {
+        if node_id == NULL_ENTRY {
+            return Some((0, 0));
+        }
+        let links = self.get_links(node_id);
+        let value = self.get_value(node_id);
+        if self.get_node_id(&value) != node_id {
+            return None;
+        }
+        for (child_id, expected_ordering) in [
+            (links.left, Ordering::Less),
+            (links.right, Ordering::Greater),
+        ] {
+            if child_id != NULL_ENTRY
+                && (self.get_links(child_id).parent != node_id
+                    || self.get_value(child_id).cmp(&value) != expected_ordering)
+            {
+                return None;
+            }
+        }
+        let (left_count, left_height) = self.check_subtree(links.left)?;
+        let (right_count, right_height) = self.check_subtree(links.right)?;
+        let height = 1 + left_height.max(right_height);
+        if links.height != height || left_height.abs_diff(right_height) > 1 {
+            return None;
+        }
+        if links.left != NULL_ENTRY && self.get_value(self.rightmost(links.left)) >= value {
+            return None;
+        }
+        if links.right != NULL_ENTRY && self.get_value(self.leftmost(links.right)) <= value {
+            return None;
+        }
+        Some((left_count + right_count + 1, height))
+    }
+}
+
+impl<SA, T> Extend<T> for SortedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+/// An iterator over the elements of a `SortedSetMapper`, or over a range of them.
+///
+/// This `struct` is created by [`SortedSetMapper::iter()`] and [`SortedSetMapper::range()`].
+/// It can be iterated from both ends.
+pub struct Iter<'a, SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+{
+    front_id: u32,
+    back_id: u32,
+    set: &'a SortedSetMapper<SA, T>,
+}
+
+impl<'a, SA, T> Iter<'a, SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+{
+    fn new(set: &'a SortedSetMapper<SA, T>, front_id: u32, back_id: u32) -> Iter<'a, SA, T> {
+        Iter {
+            front_id,
+            back_id,
+            set,
+        }
+    }
+
+    fn finish(&mut self) {
+        self.front_id = NULL_ENTRY;
+        self.back_id = NULL_ENTRY;
+    }
+}
+
+impl<'a, SA, T> Iterator for Iter<'a, SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let current_id = self.front_id;
+        if current_id == NULL_ENTRY {
+            return None;
+        }
+        if current_id == self.back_id {
+            self.finish();
+        } else {
+            self.front_id = self.set.successor(current_id);
+        }
+        Some(self.set.get_value(current_id))
+    }
+}
+
+impl<'a, SA, T> DoubleEndedIterator for Iter<'a, SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+{
+    fn next_back(&mut self) -> Option<T> {
+        let current_id = self.back_id;
+        if current_id == NULL_ENTRY {
+            return None;
+        }
+        if current_id == self.front_id {
+            self.finish();
+        } else {
+            self.back_id = self.set.predecessor(current_id);
+        }
+        Some(self.set.get_value(current_id))
+    }
+}
+
+/// Behaves like a MultiResultVec when an endpoint result, with the elements in ascending order.
+impl<SA, T> TopEncodeMulti for SortedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        multi_encode_iter_or_handle_err(self.iter(), output, h)
+    }
+}
+
+impl<SA, T> CodecFrom<SortedSetMapper<SA, T>> for MultiValueEncoded<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+{
+}
+
+/// Behaves like a MultiResultVec when an endpoint result.
+impl<SA, T> TypeAbi for SortedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + TypeAbi,
+{
+    fn type_name() -> TypeName {
+        crate::abi::type_name_variadic::<T>()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
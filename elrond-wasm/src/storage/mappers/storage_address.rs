@@ -0,0 +1,56 @@
+use elrond_codec::TopDecode;
+
+use crate::{
+    api::StorageMapperApi,
+    storage::{
+        storage_get, storage_get_from_address, storage_get_len, storage_get_len_from_address,
+        StorageKey,
+    },
+    types::{ManagedAddress, ManagedRef, ManagedType},
+};
+
+/// Where the storage mappers read their data from.
+///
+/// Mappers over the current contract storage use `CurrentStorage`, and can also write.
+/// Mappers over a `ManagedAddress` read the storage of another contract, from the same shard, and are read-only.
+pub trait StorageAddress<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn address_storage_get<T: TopDecode>(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> T;
+
+    fn address_storage_get_len(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> usize;
+}
+
+/// Marks storage mappers that work with the storage of the current contract.
+pub struct CurrentStorage;
+
+impl<SA> StorageAddress<SA> for CurrentStorage
+where
+    SA: StorageMapperApi,
+{
+    #[inline]
+    fn address_storage_get<T: TopDecode>(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> T {
+        storage_get(key)
+    }
+
+    #[inline]
+    fn address_storage_get_len(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> usize {
+        storage_get_len(key)
+    }
+}
+
+impl<SA> StorageAddress<SA> for ManagedAddress<SA>
+where
+    SA: StorageMapperApi,
+{
+    #[inline]
+    fn address_storage_get<T: TopDecode>(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> T {
+        storage_get_from_address(ManagedType::as_ref(self), key)
+    }
+
+    #[inline]
+    fn address_storage_get_len(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> usize {
+        storage_get_len_from_address(ManagedType::as_ref(self), key)
+    }
+}
@@ -1,13 +1,16 @@
 use core::marker::PhantomData;
 
 pub use super::vec_mapper::Iter;
-use super::{StorageClearable, StorageMapper, VecMapper};
+use super::{
+    CurrentStorage, StorageAddress, StorageClearable, StorageMapper, StorageMapperFromAddress,
+    VecMapper,
+};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     storage::StorageKey,
-    storage_clear, storage_get, storage_set,
-    types::{ManagedType, MultiValueEncoded},
+    storage_clear, storage_set,
+    types::{ManagedAddress, ManagedType, MultiValueEncoded, MultiValuePage},
 };
 use elrond_codec::{
     multi_encode_iter_or_handle_err, CodecFrom, EncodeErrorHandler, NestedDecode, NestedEncode,
@@ -17,14 +20,16 @@ use elrond_codec::{
 const ITEM_INDEX: &[u8] = b".index";
 const NULL_ENTRY: usize = 0;
 
-pub struct UnorderedSetMapper<SA, T>
+pub struct UnorderedSetMapper<SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
 {
     _phantom_api: PhantomData<SA>,
+    address: A,
     base_key: StorageKey<SA>,
-    vec_mapper: VecMapper<SA, T>,
+    vec_mapper: VecMapper<SA, T, A>,
 }
 
 impl<SA, T> StorageMapper<SA> for UnorderedSetMapper<SA, T>
@@ -35,12 +40,28 @@ where
     fn new(base_key: StorageKey<SA>) -> Self {
         UnorderedSetMapper {
             _phantom_api: PhantomData,
+            address: CurrentStorage,
             base_key: base_key.clone(),
             vec_mapper: VecMapper::<SA, T>::new(base_key),
         }
     }
 }
 
+impl<SA, T> StorageMapperFromAddress<SA> for UnorderedSetMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        UnorderedSetMapper {
+            _phantom_api: PhantomData,
+            address: address.clone(),
+            base_key: base_key.clone(),
+            vec_mapper: VecMapper::new_from_address(address, base_key),
+        }
+    }
+}
+
 impl<SA, T> StorageClearable for UnorderedSetMapper<SA, T>
 where
     SA: StorageMapperApi,
@@ -54,9 +75,10 @@ where
     }
 }
 
-impl<SA, T> UnorderedSetMapper<SA, T>
+impl<SA, T, A> UnorderedSetMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode,
 {
     fn item_index_key(&self, value: &T) -> StorageKey<SA> {
@@ -67,7 +89,8 @@ where
     }
 
     pub fn get_index(&self, value: &T) -> usize {
-        storage_get(self.item_index_key(value).as_ref())
+        self.address
+            .address_storage_get(self.item_index_key(value).as_ref())
     }
 
     /// Get item at index from storage.
@@ -76,14 +99,6 @@ where
         self.vec_mapper.get(index)
     }
 
-    fn set_index(&self, value: &T, index: usize) {
-        storage_set(self.item_index_key(value).as_ref(), &index);
-    }
-
-    fn clear_index(&self, value: &T) {
-        storage_clear(self.item_index_key(value).as_ref());
-    }
-
     /// Returns `true` if the set contains no elements.
     pub fn is_empty(&self) -> bool {
         self.vec_mapper.is_empty()
@@ -99,6 +114,42 @@ where
         self.get_index(value) != NULL_ENTRY
     }
 
+    /// An iterator visiting all elements in arbitrary order.
+    /// The iterator element type is `&'a T`.
+    pub fn iter(&self) -> Iter<SA, T, A> {
+        self.vec_mapper.iter()
+    }
+
+    /// Loads at most `limit` elements, in arbitrary order, starting from index `cursor` (`0` for the first page).
+    ///
+    /// Cursors work as for the `VecMapper`. Removing elements moves the last element to another index,
+    /// so the pages are only consistent while the set does not change.
+    pub fn page(&self, cursor: usize, limit: usize) -> MultiValuePage<SA, T> {
+        self.vec_mapper.page(cursor, limit)
+    }
+
+    pub(crate) fn page_map<U, F>(&self, cursor: usize, limit: usize, f: F) -> MultiValuePage<SA, U>
+    where
+        U: TopEncodeMulti,
+        F: FnMut(T) -> U,
+    {
+        self.vec_mapper.page_map(cursor, limit, f)
+    }
+}
+
+impl<SA, T> UnorderedSetMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    fn set_index(&self, value: &T, index: usize) {
+        storage_set(self.item_index_key(value).as_ref(), &index);
+    }
+
+    fn clear_index(&self, value: &T) {
+        storage_clear(self.item_index_key(value).as_ref());
+    }
+
     /// Adds a value to the set.
     ///
     /// If the set did not have this value present, `true` is returned.
@@ -126,18 +177,13 @@ where
         self.clear_index(value);
         true
     }
-
-    /// An iterator visiting all elements in arbitrary order.
-    /// The iterator element type is `&'a T`.
-    pub fn iter(&self) -> Iter<SA, T> {
-        self.vec_mapper.iter()
-    }
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TopEncodeMulti for UnorderedSetMapper<SA, T>
+impl<SA, T, A> TopEncodeMulti for UnorderedSetMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
 {
     fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -149,17 +195,19 @@ where
     }
 }
 
-impl<SA, T> CodecFrom<UnorderedSetMapper<SA, T>> for MultiValueEncoded<SA, T>
+impl<SA, T, A> CodecFrom<UnorderedSetMapper<SA, T, A>> for MultiValueEncoded<SA, T>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
 {
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TypeAbi for UnorderedSetMapper<SA, T>
+impl<SA, T, A> TypeAbi for UnorderedSetMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi,
 {
     fn type_name() -> TypeName {
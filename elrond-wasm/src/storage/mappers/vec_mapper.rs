@@ -1,9 +1,11 @@
-use super::{StorageClearable, StorageMapper};
+use super::{
+    CurrentStorage, StorageAddress, StorageClearable, StorageMapper, StorageMapperFromAddress,
+};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::{ErrorApiImpl, StorageMapperApi},
-    storage::{storage_clear, storage_get, storage_get_len, storage_set, StorageKey},
-    types::{ManagedType, MultiValueEncoded},
+    storage::{storage_clear, storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, MultiValueEncoded, MultiValuePage},
 };
 use core::{marker::PhantomData, usize};
 use elrond_codec::{
@@ -20,12 +22,16 @@ const LEN_SUFFIX: &[u8] = b".len";
 /// Indexes start from 1, instead of 0. (We avoid 0-value indexes to prevent confusion between an uninitialized variable and zero.)
 /// It also stores the count separately, at what would be index 0.
 /// The count is always kept in sync automatically.
-pub struct VecMapper<SA, T>
+///
+/// When built over another contract address, it can only read the items.
+pub struct VecMapper<SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     _phantom_api: PhantomData<SA>,
+    address: A,
     base_key: StorageKey<SA>,
     len_key: StorageKey<SA>,
     _phantom_item: PhantomData<T>,
@@ -42,6 +48,26 @@ where
 
         VecMapper {
             _phantom_api: PhantomData,
+            address: CurrentStorage,
+            base_key,
+            len_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> StorageMapperFromAddress<SA> for VecMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        let mut len_key = base_key.clone();
+        len_key.append_bytes(LEN_SUFFIX);
+
+        VecMapper {
+            _phantom_api: PhantomData,
+            address,
             base_key,
             len_key,
             _phantom_item: PhantomData,
@@ -59,9 +85,10 @@ where
     }
 }
 
-impl<SA, T> VecMapper<SA, T>
+impl<SA, T, A> VecMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
     fn item_key(&self, index: usize) -> StorageKey<SA> {
@@ -71,13 +98,9 @@ where
         item_key
     }
 
-    fn save_count(&self, new_len: usize) {
-        storage_set(self.len_key.as_ref(), &new_len);
-    }
-
     /// Number of items managed by the mapper.
     pub fn len(&self) -> usize {
-        storage_get(self.len_key.as_ref())
+        self.address.address_storage_get(self.len_key.as_ref())
     }
 
     /// True if no items present in the mapper.
@@ -85,29 +108,6 @@ where
         self.len() == 0
     }
 
-    /// Add one item at the end of the list.
-    /// Returns the index of the newly inserted item, which is also equal to the new number of elements.
-    pub fn push(&mut self, item: &T) -> usize {
-        let mut len = self.len();
-        len += 1;
-        storage_set(self.item_key(len).as_ref(), item);
-        self.save_count(len);
-        len
-    }
-
-    /// Adds multiple items at the end of the list.
-    /// Cheaper than multiple `push`-es because the count only gets updated once at the end.
-    /// Returns the index of the last inserted item, which is also equal to the new number of elements.
-    pub fn extend_from_slice(&mut self, items: &[T]) -> usize {
-        let mut len = self.len();
-        for item in items {
-            len += 1;
-            storage_set(self.item_key(len).as_ref(), item);
-        }
-        self.save_count(len);
-        len
-    }
-
     /// Get item at index from storage.
     /// Index must be valid (1 <= index <= count).
     pub fn get(&self, index: usize) -> T {
@@ -121,7 +121,8 @@ where
     /// There are no restrictions on the index,
     /// calling for an invalid index will simply return the zero-value.
     pub fn get_unchecked(&self, index: usize) -> T {
-        storage_get(self.item_key(index).as_ref())
+        self.address
+            .address_storage_get(self.item_key(index).as_ref())
     }
 
     /// Get item at index from storage.
@@ -140,7 +141,9 @@ where
     /// There are no restrictions on the index,
     /// calling for an invalid index will simply return `true`.
     pub fn item_is_empty_unchecked(&self, index: usize) -> bool {
-        storage_get_len(self.item_key(index).as_ref()) == 0
+        self.address
+            .address_storage_get_len(self.item_key(index).as_ref())
+            == 0
     }
 
     /// Checks whether or not there is anything ins storage at index.
@@ -152,6 +155,76 @@ where
         self.item_is_empty_unchecked(index)
     }
 
+    /// Loads all items from storage and places them in a Vec.
+    /// Can easily consume a lot of gas.
+    #[cfg(feature = "alloc")]
+    pub fn load_as_vec(&self) -> alloc::vec::Vec<T> {
+        self.iter().collect()
+    }
+
+    /// Provides a forward iterator.
+    pub fn iter(&self) -> Iter<SA, T, A> {
+        Iter::new(self)
+    }
+
+    /// Loads at most `limit` items, starting from index `cursor` (`0` for the first page).
+    ///
+    /// The next cursor is the index after the last item loaded, or `0` if there are no more items.
+    /// Cursors beyond the end of the list yield an empty last page.
+    pub fn page(&self, cursor: usize, limit: usize) -> MultiValuePage<SA, T> {
+        self.page_map(cursor, limit, |item| item)
+    }
+
+    /// Same as `page`, but with each item converted by `f`.
+    pub(crate) fn page_map<U, F>(&self, cursor: usize, limit: usize, f: F) -> MultiValuePage<SA, U>
+    where
+        U: TopEncodeMulti,
+        F: FnMut(T) -> U,
+    {
+        let len = self.len();
+        let start = if cursor == 0 { 1 } else { cursor };
+        let end = core::cmp::min(start.saturating_add(limit), len + 1);
+        let items = (start..end)
+            .map(|index| self.get_unchecked(index))
+            .map(f)
+            .collect();
+        let next_cursor = if end <= len { end } else { 0 };
+        MultiValuePage::new(next_cursor, items)
+    }
+}
+
+impl<SA, T> VecMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn save_count(&self, new_len: usize) {
+        storage_set(self.len_key.as_ref(), &new_len);
+    }
+
+    /// Add one item at the end of the list.
+    /// Returns the index of the newly inserted item, which is also equal to the new number of elements.
+    pub fn push(&mut self, item: &T) -> usize {
+        let mut len = self.len();
+        len += 1;
+        storage_set(self.item_key(len).as_ref(), item);
+        self.save_count(len);
+        len
+    }
+
+    /// Adds multiple items at the end of the list.
+    /// Cheaper than multiple `push`-es because the count only gets updated once at the end.
+    /// Returns the index of the last inserted item, which is also equal to the new number of elements.
+    pub fn extend_from_slice(&mut self, items: &[T]) -> usize {
+        let mut len = self.len();
+        for item in items {
+            len += 1;
+            storage_set(self.item_key(len).as_ref(), item);
+        }
+        self.save_count(len);
+        len
+    }
+
     /// Get item at index from storage.
     /// Index must be valid (1 <= index <= count).
     pub fn set(&self, index: usize, item: &T) {
@@ -206,13 +279,6 @@ where
         last_item_as_option
     }
 
-    /// Loads all items from storage and places them in a Vec.
-    /// Can easily consume a lot of gas.
-    #[cfg(feature = "alloc")]
-    pub fn load_as_vec(&self) -> alloc::vec::Vec<T> {
-        self.iter().collect()
-    }
-
     /// Deletes all contents form storage and sets count to 0.
     /// Can easily consume a lot of gas.
     pub fn clear(&mut self) {
@@ -222,33 +288,30 @@ where
         }
         self.save_count(0);
     }
-
-    /// Provides a forward iterator.
-    pub fn iter(&self) -> Iter<SA, T> {
-        Iter::new(self)
-    }
 }
 
 /// An iterator over the elements of a `VecMapper`.
 ///
 /// This `struct` is created by [`VecMapper::iter()`]. See its
 /// documentation for more.
-pub struct Iter<'a, SA, T>
+pub struct Iter<'a, SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     index: usize,
     len: usize,
-    vec: &'a VecMapper<SA, T>,
+    vec: &'a VecMapper<SA, T, A>,
 }
 
-impl<'a, SA, T> Iter<'a, SA, T>
+impl<'a, SA, T, A> Iter<'a, SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
-    fn new(vec: &'a VecMapper<SA, T>) -> Iter<'a, SA, T> {
+    fn new(vec: &'a VecMapper<SA, T, A>) -> Iter<'a, SA, T, A> {
         Iter {
             index: 1,
             len: vec.len(),
@@ -257,9 +320,10 @@ where
     }
 }
 
-impl<'a, SA, T> Iterator for Iter<'a, SA, T>
+impl<'a, SA, T, A> Iterator for Iter<'a, SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     type Item = T;
@@ -276,9 +340,10 @@ where
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TopEncodeMulti for VecMapper<SA, T>
+impl<SA, T, A> TopEncodeMulti for VecMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
     fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -290,17 +355,19 @@ where
     }
 }
 
-impl<SA, T> CodecFrom<VecMapper<SA, T>> for MultiValueEncoded<SA, T>
+impl<SA, T, A> CodecFrom<VecMapper<SA, T, A>> for MultiValueEncoded<SA, T>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TypeAbi for VecMapper<SA, T>
+impl<SA, T, A> TypeAbi for VecMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + TypeAbi,
 {
     fn type_name() -> TypeName {
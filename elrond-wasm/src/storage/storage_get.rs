@@ -7,7 +7,8 @@ use crate::{
     },
     err_msg,
     types::{
-        BigInt, BigUint, ManagedBuffer, ManagedBufferNestedDecodeInput, ManagedRef, ManagedType,
+        BigInt, BigUint, ManagedAddress, ManagedBuffer, ManagedBufferNestedDecodeInput, ManagedRef,
+        ManagedType,
     },
 };
 use alloc::boxed::Box;
@@ -132,6 +133,49 @@ where
     A::managed_type_impl().mb_len(value_handle)
 }
 
+/// Reads a value from the storage of another contract, from the same shard.
+///
+/// Useful for storage mappers.
+pub fn storage_get_from_address<A, T>(
+    address: ManagedRef<'_, A, ManagedAddress<A>>,
+    key: ManagedRef<'_, A, StorageKey<A>>,
+) -> T
+where
+    T: TopDecode,
+    A: StorageReadApi + ManagedTypeApi + ErrorApi,
+{
+    let result_buffer =
+        ManagedBuffer::<A>::from_handle(use_raw_handle(const_handles::MBUF_TEMPORARY_1));
+    A::storage_read_api_impl().storage_load_from_address(
+        address.get_handle(),
+        key.get_handle(),
+        result_buffer.get_handle(),
+    );
+
+    let Ok(value) =
+        T::top_decode_or_handle_err(result_buffer, StorageGetErrorHandler::<A>::default());
+    value
+}
+
+/// Length of the value stored under a key of another contract, from the same shard.
+///
+/// Useful for storage mappers.
+pub fn storage_get_len_from_address<A>(
+    address: ManagedRef<'_, A, ManagedAddress<A>>,
+    key: ManagedRef<'_, A, StorageKey<A>>,
+) -> usize
+where
+    A: StorageReadApi + ManagedTypeApi + ErrorApi,
+{
+    let value_handle: A::ManagedBufferHandle = use_raw_handle(const_handles::MBUF_TEMPORARY_1);
+    A::storage_read_api_impl().storage_load_from_address(
+        address.get_handle(),
+        key.get_handle(),
+        value_handle.clone(),
+    );
+    A::managed_type_impl().mb_len(value_handle)
+}
+
 /// Will immediately end the execution when encountering the first decode error, via `signal_error`.
 /// Because its handled error type is the never type, when compiled,
 /// the codec will return the value directly, without wrapping it in a Result.
@@ -0,0 +1,59 @@
+use crate::{
+    api::{CryptoApi, SHA256_RESULT_LEN},
+    contract_base::CryptoWrapper,
+    types::{ManagedBuffer, ManagedByteArray},
+};
+
+/// Length of all the hashes in a Merkle tree: leaves, nodes and root.
+pub const MERKLE_HASH_LEN: usize = SHA256_RESULT_LEN;
+
+/// The hash of a node in a Merkle tree: the SHA-256 of the concatenated hashes of its two children.
+///
+/// Leaves are not hashed again, it is up to the caller to provide 32-byte hashes as leaves.
+pub fn merkle_node_hash<A: CryptoApi>(
+    left: &ManagedByteArray<A, MERKLE_HASH_LEN>,
+    right: &ManagedByteArray<A, MERKLE_HASH_LEN>,
+) -> ManagedByteArray<A, MERKLE_HASH_LEN> {
+    let mut data = ManagedBuffer::new();
+    data.append(left.as_managed_buffer());
+    data.append(right.as_managed_buffer());
+    CryptoWrapper::<A>::new().sha256(&data)
+}
+
+/// Checks that a leaf is at the given index in the Merkle tree with the given root and depth.
+///
+/// The proof consists of the sibling hashes, from the leaf level up to just below the root,
+/// so its length must be the depth of the tree.
+/// The bits of the leaf index, lowest first, tell on which side the sibling is on each level.
+///
+/// Leaves and inner nodes are hashed the same way, so the depth is required:
+/// without it an inner node could pass for a leaf, with a shorter proof.
+pub fn verify_merkle_proof<A, I>(
+    root: &ManagedByteArray<A, MERKLE_HASH_LEN>,
+    leaf: &ManagedByteArray<A, MERKLE_HASH_LEN>,
+    leaf_index: usize,
+    depth: usize,
+    proof: I,
+) -> bool
+where
+    A: CryptoApi,
+    I: IntoIterator<Item = ManagedByteArray<A, MERKLE_HASH_LEN>>,
+{
+    let mut node = leaf.clone();
+    let mut index = leaf_index;
+    let mut proof_len = 0;
+    for sibling in proof {
+        if proof_len == depth {
+            return false;
+        }
+        node = if index & 1 == 0 {
+            merkle_node_hash(&node, &sibling)
+        } else {
+            merkle_node_hash(&sibling, &node)
+        };
+        index >>= 1;
+        proof_len += 1;
+    }
+    // the index should not point beyond the tree covered by the proof
+    proof_len == depth && index == 0 && node == *root
+}
@@ -1,3 +1,5 @@
+mod merkle_proof;
 mod message_hash_type;
 
+pub use merkle_proof::{merkle_node_hash, verify_merkle_proof, MERKLE_HASH_LEN};
 pub use message_hash_type::MessageHashType;
@@ -59,6 +59,10 @@ impl From<u16> for CodeMetadata {
     }
 }
 
+impl EncodedSize for CodeMetadata {
+    const ENCODED_SIZE: Option<usize> = u16::ENCODED_SIZE;
+}
+
 impl NestedEncode for CodeMetadata {
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
     where
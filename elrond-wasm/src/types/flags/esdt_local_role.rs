@@ -1,4 +1,6 @@
-use elrond_codec::elrond_codec_derive::{NestedDecode, NestedEncode, TopDecode, TopEncode};
+use elrond_codec::elrond_codec_derive::{
+    EncodedSize, NestedDecode, NestedEncode, TopDecode, TopEncode,
+};
 
 use super::EsdtLocalRoleFlags;
 use crate as elrond_wasm;
@@ -15,7 +17,17 @@ static ESDT_ROLE_NFT_UPDATE_ATTRIBUTES: &[u8] = b"ESDTRoleNFTUpdateAttributes";
 static ESDT_ROLE_TRANSFER: &[u8] = b"ESDTTransferRole";
 
 #[derive(
-    TopDecode, TopEncode, NestedDecode, NestedEncode, TypeAbi, Clone, PartialEq, Eq, Debug, Copy,
+    TopDecode,
+    TopEncode,
+    NestedDecode,
+    NestedEncode,
+    EncodedSize,
+    TypeAbi,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    Copy,
 )]
 pub enum EsdtLocalRole {
     None,
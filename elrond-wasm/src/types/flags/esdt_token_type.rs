@@ -79,6 +79,10 @@ impl<'a> From<&'a [u8]> for EsdtTokenType {
     }
 }
 
+impl EncodedSize for EsdtTokenType {
+    const ENCODED_SIZE: Option<usize> = u8::ENCODED_SIZE;
+}
+
 impl NestedEncode for EsdtTokenType {
     #[inline]
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
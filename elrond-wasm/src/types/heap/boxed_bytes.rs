@@ -188,6 +188,10 @@ impl TopEncodeOutput for &mut BoxedBytes {
     }
 }
 
+impl EncodedSize for BoxedBytes {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
 impl NestedEncode for BoxedBytes {
     #[inline]
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -159,6 +159,10 @@ impl H256 {
 
 use elrond_codec::*;
 
+impl EncodedSize for H256 {
+    const ENCODED_SIZE: Option<usize> = Some(32);
+}
+
 impl NestedEncode for H256 {
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, _h: H) -> Result<(), H::HandledErr>
     where
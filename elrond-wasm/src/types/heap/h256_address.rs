@@ -153,6 +153,10 @@ impl Address {
 
 use elrond_codec::*;
 
+impl EncodedSize for Address {
+    const ENCODED_SIZE: Option<usize> = Some(32);
+}
+
 impl NestedEncode for Address {
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
     where
@@ -40,6 +40,7 @@ where
     pub arg_buffer: ManagedArgBuffer<SA>,
     pub success_callback: &'static [u8],
     pub error_callback: &'static [u8],
+    pub callback_closure_args: ManagedArgBuffer<SA>,
     _return_type: PhantomData<OriginalResult>,
 }
 
@@ -88,6 +89,7 @@ where
             _return_type: PhantomData,
             success_callback,
             error_callback,
+            callback_closure_args: ManagedArgBuffer::new(),
         }
     }
 
@@ -148,6 +150,17 @@ where
         self
     }
 
+    /// Adds an argument to the callback closure of a promise.
+    ///
+    /// The callback can retrieve these arguments using `get_promise_callback_closure_args`,
+    /// so each promise can pass its own data to a shared callback.
+    #[cfg(feature = "promises")]
+    pub fn with_callback_closure_arg<T: TopEncodeMulti>(mut self, closure_arg: &T) -> Self {
+        let h = ExitCodecErrorHandler::<SA>::from(err_msg::CONTRACT_CALL_ENCODE_ERROR);
+        let Ok(()) = closure_arg.multi_encode_or_handle_err(&mut self.callback_closure_args, h);
+        self
+    }
+
     #[cfg(feature = "promises")]
     #[inline]
     pub fn with_extra_gas_for_callback(mut self, gas_limit: u64) -> Self {
@@ -225,6 +238,7 @@ where
                     _return_type: PhantomData,
                     success_callback: self.success_callback,
                     error_callback: self.error_callback,
+                    callback_closure_args: self.callback_closure_args,
                 }
             } else {
                 let payments = self.no_payments();
@@ -261,6 +275,7 @@ where
                     _return_type: PhantomData,
                     success_callback: self.success_callback,
                     error_callback: self.error_callback,
+                    callback_closure_args: self.callback_closure_args,
                 }
             }
         } else {
@@ -301,6 +316,7 @@ where
             _return_type: PhantomData,
             success_callback: self.success_callback,
             error_callback: self.error_callback,
+            callback_closure_args: self.callback_closure_args,
         }
     }
 
@@ -324,18 +340,31 @@ where
         }
     }
 
+    /// Registers a promise. Any number of promises can be registered in the same transaction,
+    /// each with its own gas, callbacks and callback closure.
+    ///
+    /// If unspecified, the gas limit is all the gas left and no extra gas is reserved for the callback.
     #[cfg(feature = "promises")]
     pub fn register_promise(mut self) {
         self = self.convert_to_esdt_transfer_call();
+        let gas_limit = self.resolve_gas_limit();
+        let extra_gas_for_callback = if self.extra_gas_for_callback == UNSPECIFIED_GAS_LIMIT {
+            0
+        } else {
+            self.extra_gas_for_callback
+        };
+        let callback_closure = crate::contract_base::ManagedSerializer::<SA>::new()
+            .top_encode_to_managed_buffer(&self.callback_closure_args);
         SendRawWrapper::<SA>::new().create_async_call_raw(
             &self.to,
             &self.egld_payment,
             &self.endpoint_name,
             self.success_callback,
             self.error_callback,
-            self.explicit_gas_limit,
-            self.extra_gas_for_callback,
+            gas_limit,
+            extra_gas_for_callback,
             &self.arg_buffer,
+            &callback_closure,
         )
     }
 }
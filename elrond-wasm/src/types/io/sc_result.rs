@@ -77,12 +77,12 @@ impl<T, E> SCResult<T, E> {
 /// <https://github.com/scottmcm/rfcs/blob/do-or-do-not/text/0000-try-trait-v2.md#the-try-trait>
 impl<T, E> Try for SCResult<T, E> {
     type Output = T;
-    type Residual = E;
+    type Residual = SCResult<convert::Infallible, E>;
 
     fn branch(self) -> ControlFlow<Self::Residual, T> {
         match self {
             SCResult::Ok(t) => ControlFlow::Continue(t),
-            SCResult::Err(e) => ControlFlow::Break(e),
+            SCResult::Err(e) => ControlFlow::Break(SCResult::Err(e)),
         }
     }
     fn from_output(v: T) -> Self {
@@ -90,9 +90,13 @@ impl<T, E> Try for SCResult<T, E> {
     }
 }
 
-impl<T, E> FromResidual for SCResult<T, E> {
-    fn from_residual(r: E) -> Self {
-        SCResult::Err(r)
+impl<T, E> core::ops::Residual<T> for SCResult<convert::Infallible, E> {
+    type TryType = SCResult<T, E>;
+}
+impl<T, E> FromResidual<SCResult<convert::Infallible, E>> for SCResult<T, E> {
+    fn from_residual(r: SCResult<convert::Infallible, E>) -> Self {
+        let SCResult::Err(e) = r;
+        SCResult::Err(e)
     }
 }
 
@@ -10,7 +10,7 @@ use crate::{
     types::{heap::BoxedBytes, BigUint, ManagedBuffer, ManagedOption, ManagedType, Sign},
 };
 use elrond_codec::{
-    CodecFrom, CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
+    CodecFrom, CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, EncodedSize, NestedDecode,
     NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
     TopEncodeOutput, TryStaticCast,
 };
@@ -257,6 +257,10 @@ impl<M: ManagedTypeApi> TopEncode for BigInt<M> {
     }
 }
 
+impl<M: ManagedTypeApi> EncodedSize for BigInt<M> {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> NestedEncode for BigInt<M> {
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
     where
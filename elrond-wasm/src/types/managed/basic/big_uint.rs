@@ -10,7 +10,7 @@ use crate::{
     types::{heap::BoxedBytes, ManagedBuffer, ManagedType},
 };
 use elrond_codec::{
-    CodecFrom, CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
+    CodecFrom, CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, EncodedSize, NestedDecode,
     NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
     TopEncodeOutput, TryStaticCast,
 };
@@ -241,6 +241,10 @@ impl<M: ManagedTypeApi> TopEncode for BigUint<M> {
     }
 }
 
+impl<M: ManagedTypeApi> EncodedSize for BigUint<M> {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> NestedEncode for BigUint<M> {
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
     where
@@ -10,9 +10,9 @@ use crate::{
     types::{heap::BoxedBytes, ManagedType},
 };
 use elrond_codec::{
-    CodecFrom, CodecFromSelf, DecodeErrorHandler, Empty, EncodeErrorHandler, NestedDecode,
-    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
-    TopEncodeOutput, TryStaticCast,
+    CodecFrom, CodecFromSelf, DecodeErrorHandler, Empty, EncodeErrorHandler, EncodedSize,
+    NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput,
+    TopEncode, TopEncodeOutput, TryStaticCast,
 };
 
 #[cfg(feature = "ei-1-2")]
@@ -305,6 +305,10 @@ impl<M: ManagedTypeApi> PartialEq<[u8]> for ManagedBuffer<M> {
 
 impl<M: ManagedTypeApi> TryStaticCast for ManagedBuffer<M> {}
 
+impl<M: ManagedTypeApi> EncodedSize for ManagedBuffer<M> {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> NestedEncode for ManagedBuffer<M> {
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
     where
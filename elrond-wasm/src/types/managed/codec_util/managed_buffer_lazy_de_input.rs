@@ -0,0 +1,177 @@
+use elrond_codec::{
+    try_execute_then_cast, DecodeError, DecodeErrorHandler, EncodedSize, NestedDecode,
+    NestedDecodeInput, TryStaticCast,
+};
+
+use crate::{
+    api::ManagedTypeApi,
+    contract_base::ExitCodecErrorHandler,
+    err_msg,
+    types::{managed::ManagedBufferSizeContext, BigInt, BigUint, ManagedBuffer},
+};
+
+/// Decodes the fields of a nested-encoded struct one at a time, straight from a borrowed managed buffer,
+/// only loading the bytes of the fields that are actually needed.
+///
+/// Fixed-size fields are skipped by only moving the offset, without loading anything.
+/// Variable-size fields that need to be skipped are decoded from the buffer, starting at the offset.
+///
+/// Used by the views generated by `#[derive(LazyDecode)]`.
+pub struct ManagedBufferLazyDecodeInput<'a, M>
+where
+    M: ManagedTypeApi,
+{
+    buffer: &'a ManagedBuffer<M>,
+    buffer_len: usize,
+    offset: usize,
+}
+
+impl<'a, M> ManagedBufferLazyDecodeInput<'a, M>
+where
+    M: ManagedTypeApi,
+{
+    pub fn new(buffer: &'a ManagedBuffer<M>) -> Self {
+        ManagedBufferLazyDecodeInput {
+            buffer,
+            buffer_len: buffer.len(),
+            offset: 0,
+        }
+    }
+
+    fn error_handler() -> ExitCodecErrorHandler<M> {
+        ExitCodecErrorHandler::<M>::from(err_msg::LAZY_DECODE_ERROR)
+    }
+
+    /// Moves past the next field.
+    ///
+    /// Does nothing if the encoded data ends before the field,
+    /// so that the `#[codec(default)]` fields that are missing can be skipped too.
+    pub fn skip<T>(&mut self)
+    where
+        T: NestedDecode + EncodedSize,
+    {
+        if self.is_depleted() {
+            return;
+        }
+
+        let h = Self::error_handler();
+        if let Some(size) = T::ENCODED_SIZE {
+            if size > self.remaining_len() {
+                h.handle_error(DecodeError::INPUT_TOO_SHORT);
+            }
+            self.offset += size;
+        } else {
+            let Ok(_) = T::dep_decode_or_handle_err(self, h);
+        }
+    }
+
+    /// Decodes the next field.
+    pub fn decode<T>(mut self) -> T
+    where
+        T: NestedDecode + EncodedSize,
+    {
+        let Ok(value) = T::dep_decode_or_handle_err(&mut self, Self::error_handler());
+        value
+    }
+
+    /// Decodes the next field, or returns the default value if the encoded data ends before it.
+    /// Used for the trailing fields marked `#[codec(default)]`.
+    pub fn decode_or_default<T>(self) -> T
+    where
+        T: NestedDecode + EncodedSize + Default,
+    {
+        if self.is_depleted() {
+            T::default()
+        } else {
+            self.decode()
+        }
+    }
+
+    fn read_managed_buffer_of_size<H>(
+        &mut self,
+        size: usize,
+        h: H,
+    ) -> Result<ManagedBuffer<M>, H::HandledErr>
+    where
+        H: DecodeErrorHandler,
+    {
+        if let Some(managed_buffer) = self.buffer.copy_slice(self.offset, size) {
+            self.offset += size;
+            Ok(managed_buffer)
+        } else {
+            Err(h.handle_error(DecodeError::INPUT_TOO_SHORT))
+        }
+    }
+
+    fn read_managed_buffer<H>(&mut self, h: H) -> Result<ManagedBuffer<M>, H::HandledErr>
+    where
+        H: DecodeErrorHandler,
+    {
+        let size = usize::dep_decode_or_handle_err(self, h)?;
+        self.read_managed_buffer_of_size(size, h)
+    }
+
+    fn read_big_uint<H: DecodeErrorHandler>(&mut self, h: H) -> Result<BigUint<M>, H::HandledErr> {
+        Ok(BigUint::from_bytes_be_buffer(&self.read_managed_buffer(h)?))
+    }
+
+    fn read_big_int<H: DecodeErrorHandler>(&mut self, h: H) -> Result<BigInt<M>, H::HandledErr> {
+        Ok(BigInt::from_signed_bytes_be_buffer(
+            &self.read_managed_buffer(h)?,
+        ))
+    }
+}
+
+impl<'a, M> NestedDecodeInput for ManagedBufferLazyDecodeInput<'a, M>
+where
+    M: ManagedTypeApi,
+{
+    fn remaining_len(&self) -> usize {
+        self.buffer_len - self.offset
+    }
+
+    fn peek_into<H>(&mut self, into: &mut [u8], h: H) -> Result<(), H::HandledErr>
+    where
+        H: DecodeErrorHandler,
+    {
+        self.buffer
+            .load_slice(self.offset, into)
+            .map_err(|_| h.handle_error(DecodeError::INPUT_TOO_SHORT))
+    }
+
+    fn read_into<H>(&mut self, into: &mut [u8], h: H) -> Result<(), H::HandledErr>
+    where
+        H: DecodeErrorHandler,
+    {
+        self.peek_into(into, h)?;
+        self.offset += into.len();
+        Ok(())
+    }
+
+    fn supports_specialized_type<T: TryStaticCast>() -> bool {
+        T::type_eq::<ManagedBuffer<M>>() || T::type_eq::<BigUint<M>>() || T::type_eq::<BigInt<M>>()
+    }
+
+    fn read_specialized<T, C, H>(&mut self, context: C, h: H) -> Result<T, H::HandledErr>
+    where
+        T: TryStaticCast,
+        C: TryStaticCast,
+        H: DecodeErrorHandler,
+    {
+        if let Some(result) = try_execute_then_cast(|| {
+            if let Some(mb_context) = context.try_cast_ref::<ManagedBufferSizeContext>() {
+                self.read_managed_buffer_of_size(mb_context.0, h)
+            } else {
+                self.read_managed_buffer(h)
+            }
+        }) {
+            result
+        } else if let Some(result) = try_execute_then_cast(|| self.read_big_uint(h)) {
+            result
+        } else if let Some(result) = try_execute_then_cast(|| self.read_big_int(h)) {
+            result
+        } else {
+            Err(h.handle_error(DecodeError::UNSUPPORTED_OPERATION))
+        }
+    }
+}
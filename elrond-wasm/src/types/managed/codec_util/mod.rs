@@ -1,6 +1,8 @@
+mod managed_buffer_lazy_de_input;
 mod managed_buffer_nested_de_input;
 mod managed_buffer_nested_en_output;
 mod managed_buffer_top_de_input;
 mod managed_buffer_top_en_output;
 
+pub use managed_buffer_lazy_de_input::ManagedBufferLazyDecodeInput;
 pub use managed_buffer_nested_de_input::ManagedBufferNestedDecodeInput;
@@ -4,6 +4,7 @@ mod multi_value_encoded;
 mod multi_value_encoded_iter;
 mod multi_value_managed_vec;
 mod multi_value_managed_vec_counted;
+mod multi_value_page;
 
 pub use async_call_result_managed::{ManagedAsyncCallError, ManagedAsyncCallResult};
 pub use esdt_token_payment_multi_arg::{EsdtTokenPaymentMultiArg, EsdtTokenPaymentMultiValue};
@@ -15,3 +16,4 @@ pub use multi_value_managed_vec::{
 pub use multi_value_managed_vec_counted::{
     ManagedCountedMultiResultVec, ManagedCountedVarArgs, MultiValueManagedVecCounted,
 };
+pub use multi_value_page::MultiValuePage;
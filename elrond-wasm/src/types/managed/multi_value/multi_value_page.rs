@@ -0,0 +1,139 @@
+use crate::{
+    abi::{OutputAbi, OutputAbis, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{ErrorApi, ManagedTypeApi},
+    types::MultiValueEncoded,
+};
+use alloc::vec;
+use elrond_codec::{
+    CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, TopDecodeMulti, TopDecodeMultiInput,
+    TopEncodeMulti, TopEncodeMultiOutput,
+};
+
+const DEFAULT_NEXT_CURSOR_OUTPUT_NAME: &str = "next_cursor";
+const DEFAULT_ITEMS_OUTPUT_NAME: &str = "items";
+
+/// One page of a collection, as returned by the `page` method of the collection storage mappers.
+///
+/// It is encoded as the cursor of the next page, followed by the items themselves.
+///
+/// Cursor `0` stands for the first page when requesting a page,
+/// and for "no more pages" when returned as next cursor.
+/// To get the entire collection, start from `0` and request pages until `0` is returned again.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct MultiValuePage<M, T>
+where
+    M: ManagedTypeApi,
+{
+    next_cursor: usize,
+    items: MultiValueEncoded<M, T>,
+}
+
+impl<M, T> MultiValuePage<M, T>
+where
+    M: ManagedTypeApi,
+{
+    #[inline]
+    pub fn new(next_cursor: usize, items: MultiValueEncoded<M, T>) -> Self {
+        MultiValuePage { next_cursor, items }
+    }
+
+    /// The cursor to request the next page with, `0` if this is the last page.
+    #[inline]
+    pub fn next_cursor(&self) -> usize {
+        self.next_cursor
+    }
+
+    #[inline]
+    pub fn is_last_page(&self) -> bool {
+        self.next_cursor == 0
+    }
+
+    #[inline]
+    pub fn items(&self) -> &MultiValueEncoded<M, T> {
+        &self.items
+    }
+
+    #[inline]
+    pub fn into_items(self) -> MultiValueEncoded<M, T> {
+        self.items
+    }
+}
+
+impl<M, T> TopEncodeMulti for MultiValuePage<M, T>
+where
+    M: ManagedTypeApi + ErrorApi,
+    T: TopEncodeMulti,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        self.next_cursor.multi_encode_or_handle_err(output, h)?;
+        self.items.multi_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M, T> TopDecodeMulti for MultiValuePage<M, T>
+where
+    M: ManagedTypeApi + ErrorApi,
+    T: TopDecodeMulti,
+{
+    fn multi_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeMultiInput,
+        H: DecodeErrorHandler,
+    {
+        let next_cursor = usize::multi_decode_or_handle_err(input, h)?;
+        let items = MultiValueEncoded::multi_decode_or_handle_err(input, h)?;
+        Ok(MultiValuePage { next_cursor, items })
+    }
+}
+
+impl<M, T> TypeAbi for MultiValuePage<M, T>
+where
+    M: ManagedTypeApi,
+    T: TypeAbi,
+{
+    fn type_name() -> TypeName {
+        let mut repr = TypeName::from("multi<");
+        repr.push_str(usize::type_name().as_str());
+        repr.push(',');
+        repr.push_str(MultiValueEncoded::<M, T>::type_name().as_str());
+        repr.push('>');
+        repr
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+
+    /// Shows up as 2 results, the next cursor and the variadic items.
+    /// They are named, unless the endpoint provides other names.
+    fn output_abis(output_names: &[&'static str]) -> OutputAbis {
+        vec![
+            OutputAbi {
+                output_name: output_names
+                    .first()
+                    .cloned()
+                    .unwrap_or(DEFAULT_NEXT_CURSOR_OUTPUT_NAME),
+                type_name: usize::type_name(),
+                multi_result: false,
+            },
+            OutputAbi {
+                output_name: output_names
+                    .get(1)
+                    .cloned()
+                    .unwrap_or(DEFAULT_ITEMS_OUTPUT_NAME),
+                type_name: MultiValueEncoded::<M, T>::type_name(),
+                multi_result: true,
+            },
+        ]
+    }
+}
+
+impl<M, T> CodecFromSelf for MultiValuePage<M, T> where M: ManagedTypeApi {}
@@ -148,6 +148,10 @@ impl<M: ManagedTypeApi> PartialEq<TokenIdentifier<M>> for EgldOrEsdtTokenIdentif
     }
 }
 
+impl<M: ManagedTypeApi> EncodedSize for EgldOrEsdtTokenIdentifier<M> {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> NestedEncode for EgldOrEsdtTokenIdentifier<M> {
     #[inline]
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -4,7 +4,7 @@ use crate::{
 };
 
 use elrond_codec::{
-    elrond_codec_derive::{NestedDecode, NestedEncode, TopDecode, TopEncode},
+    elrond_codec_derive::{EncodedSize, NestedDecode, NestedEncode, TopDecode, TopEncode},
     CodecFrom, CodecFromSelf,
 };
 
@@ -14,7 +14,16 @@ use crate::derive::TypeAbi;
 use super::EsdtTokenPayment;
 
 #[derive(
-    TopDecode, TopEncode, NestedDecode, NestedEncode, TypeAbi, Clone, PartialEq, Eq, Debug,
+    TopDecode,
+    TopEncode,
+    NestedDecode,
+    NestedEncode,
+    EncodedSize,
+    TypeAbi,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
 )]
 pub struct EgldOrEsdtTokenPayment<M: ManagedTypeApi> {
     pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
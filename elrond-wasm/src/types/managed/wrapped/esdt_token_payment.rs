@@ -6,11 +6,11 @@ use crate::{
 use crate as elrond_wasm; // needed by the codec and TypeAbi generated code
 use crate::derive::TypeAbi;
 use elrond_codec::{
-    elrond_codec_derive::{NestedEncode, TopEncode},
+    elrond_codec_derive::{EncodedSize, NestedEncode, TopEncode},
     NestedDecode, TopDecode,
 };
 
-#[derive(TopEncode, NestedEncode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+#[derive(TopEncode, NestedEncode, EncodedSize, TypeAbi, Clone, PartialEq, Eq, Debug)]
 pub struct EsdtTokenPayment<M: ManagedTypeApi> {
     pub token_identifier: TokenIdentifier<M>,
     pub token_nonce: u64,
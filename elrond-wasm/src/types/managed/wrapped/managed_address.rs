@@ -7,9 +7,9 @@ use crate::{
     types::{heap::Address, ManagedBuffer, ManagedByteArray, ManagedType},
 };
 use elrond_codec::{
-    CodecFrom, CodecFromSelf, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
-    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
-    TopEncodeOutput, TryStaticCast,
+    CodecFrom, CodecFromSelf, DecodeError, DecodeErrorHandler, EncodeErrorHandler, EncodedSize,
+    NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput,
+    TopEncode, TopEncodeOutput, TryStaticCast,
 };
 
 #[repr(transparent)]
@@ -205,6 +205,13 @@ pub(crate) struct ManagedBufferSizeContext(pub usize);
 
 impl TryStaticCast for ManagedBufferSizeContext {}
 
+impl<M> EncodedSize for ManagedAddress<M>
+where
+    M: ManagedTypeApi,
+{
+    const ENCODED_SIZE: Option<usize> = Some(32);
+}
+
 impl<M> NestedEncode for ManagedAddress<M>
 where
     M: ManagedTypeApi,
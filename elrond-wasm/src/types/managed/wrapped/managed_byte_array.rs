@@ -7,9 +7,9 @@ use crate::{
     types::{ManagedBuffer, ManagedType},
 };
 use elrond_codec::{
-    DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
-    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
-    TryStaticCast,
+    DecodeError, DecodeErrorHandler, EncodeErrorHandler, EncodedSize, NestedDecode,
+    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput, TryStaticCast,
 };
 
 const DECODE_ERROR_BAD_LENGTH: &str = "bad array length";
@@ -165,6 +165,13 @@ pub(crate) struct ManagedBufferSizeContext(pub usize);
 
 impl TryStaticCast for ManagedBufferSizeContext {}
 
+impl<M, const N: usize> EncodedSize for ManagedByteArray<M, N>
+where
+    M: ManagedTypeApi,
+{
+    const ENCODED_SIZE: Option<usize> = Some(N);
+}
+
 impl<M, const N: usize> NestedEncode for ManagedByteArray<M, N>
 where
     M: ManagedTypeApi,
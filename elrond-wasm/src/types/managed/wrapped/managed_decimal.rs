@@ -0,0 +1,478 @@
+use core::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::{
+    abi::{TypeAbi, TypeName},
+    api::{ErrorApiImpl, ManagedTypeApi},
+    formatter::{FormatByteReceiver, SCDisplay},
+    types::BigUint,
+};
+use alloc::string::ToString;
+use elrond_codec::{
+    CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, EncodedSize, NestedDecode,
+    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput,
+};
+
+const DECIMALS_MISMATCH_ERR_MSG: &[u8] = b"managed decimals have different number of decimals";
+const SUB_UNDERFLOW_ERR_MSG: &[u8] = b"managed decimal subtraction underflow";
+const DIVISION_BY_ZERO_ERR_MSG: &[u8] = b"managed decimal division by zero";
+
+/// The number of decimals, known only at runtime.
+pub type NumDecimals = usize;
+
+/// Provides the number of decimals of a `ManagedDecimal`.
+///
+/// Either known at compile time (`ConstDecimals`), or at runtime (`NumDecimals`).
+pub trait Decimals: Clone {
+    fn num_decimals(&self) -> NumDecimals;
+
+    /// 10 to the power of the number of decimals.
+    fn scaling_factor<M: ManagedTypeApi>(&self) -> BigUint<M> {
+        pow10(self.num_decimals())
+    }
+}
+
+impl Decimals for NumDecimals {
+    fn num_decimals(&self) -> NumDecimals {
+        *self
+    }
+}
+
+/// The number of decimals, known at compile time.
+/// It takes no space at runtime and does not get serialized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConstDecimals<const DECIMALS: NumDecimals>;
+
+impl<const DECIMALS: NumDecimals> Decimals for ConstDecimals<DECIMALS> {
+    fn num_decimals(&self) -> NumDecimals {
+        DECIMALS
+    }
+}
+
+/// Decides what happens with the digits that do not fit in the result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Truncates the extra digits.
+    Down,
+
+    /// Rounds up whenever the extra digits are not all zero.
+    Up,
+
+    /// Rounds to the nearest value, halfway values are rounded up.
+    HalfUp,
+
+    /// Rounds to the nearest value, halfway values are rounded towards the even neighbour.
+    HalfEven,
+}
+
+/// Fixed-point decimal number, stored as a `BigUint` amount of the smallest units.
+///
+/// E.g. 1.5 with 18 decimals is stored as 1_500_000_000_000_000_000.
+///
+/// Addition and subtraction require both operands to have the same number of decimals.
+/// Multiplication and division keep the number of decimals of the left operand,
+/// rounding the result as specified.
+/// The operators round down and signal an error where the `checked_*` methods return `None`.
+#[derive(Clone)]
+pub struct ManagedDecimal<M: ManagedTypeApi, D: Decimals> {
+    data: BigUint<M>,
+    decimals: D,
+}
+
+impl<M: ManagedTypeApi, D: Decimals> ManagedDecimal<M, D> {
+    /// The data is the amount of the smallest units, i.e. the value already multiplied by the scaling factor.
+    pub fn from_raw_units(data: BigUint<M>, decimals: D) -> Self {
+        ManagedDecimal { data, decimals }
+    }
+
+    /// The integer value gets multiplied by the scaling factor.
+    pub fn from_integer(integer: BigUint<M>, decimals: D) -> Self {
+        let data = integer * decimals.scaling_factor();
+        ManagedDecimal { data, decimals }
+    }
+
+    pub fn raw_units(&self) -> &BigUint<M> {
+        &self.data
+    }
+
+    pub fn into_raw_units(self) -> BigUint<M> {
+        self.data
+    }
+
+    pub fn decimals(&self) -> &D {
+        &self.decimals
+    }
+
+    pub fn num_decimals(&self) -> NumDecimals {
+        self.decimals.num_decimals()
+    }
+
+    pub fn scaling_factor(&self) -> BigUint<M> {
+        self.decimals.scaling_factor()
+    }
+
+    /// The integer part, the fractional part is discarded.
+    pub fn trunc(&self) -> BigUint<M> {
+        &self.data / &self.scaling_factor()
+    }
+
+    /// Converts to another number of decimals, rounding if precision gets lost.
+    pub fn rescale<T: Decimals>(
+        &self,
+        scale_to: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, T> {
+        let from_num_decimals = self.num_decimals();
+        let to_num_decimals = scale_to.num_decimals();
+        let data = match from_num_decimals.cmp(&to_num_decimals) {
+            Ordering::Equal => self.data.clone(),
+            Ordering::Less => &self.data * &pow10(to_num_decimals - from_num_decimals),
+            Ordering::Greater => div_rounded(
+                &self.data,
+                &pow10(from_num_decimals - to_num_decimals),
+                rounding,
+            ),
+        };
+        ManagedDecimal::from_raw_units(data, scale_to)
+    }
+
+    /// Returns `None` if the numbers of decimals differ.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        if !self.same_decimals(other) {
+            return None;
+        }
+        Some(ManagedDecimal::from_raw_units(
+            &self.data + &other.data,
+            self.decimals.clone(),
+        ))
+    }
+
+    /// Returns `None` if the result would be negative, or if the numbers of decimals differ.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if !self.same_decimals(other) || self.data < other.data {
+            return None;
+        }
+        Some(ManagedDecimal::from_raw_units(
+            &self.data - &other.data,
+            self.decimals.clone(),
+        ))
+    }
+
+    /// The result has the number of decimals of `self`, the extra precision is rounded away.
+    pub fn mul_with_precision<D2: Decimals>(
+        &self,
+        other: &ManagedDecimal<M, D2>,
+        rounding: RoundingMode,
+    ) -> Self {
+        ManagedDecimal::from_raw_units(
+            div_rounded(
+                &(&self.data * &other.data),
+                &other.scaling_factor(),
+                rounding,
+            ),
+            self.decimals.clone(),
+        )
+    }
+
+    /// The result has the number of decimals of `self`.
+    ///
+    /// Returns `None` when dividing by zero.
+    pub fn checked_div<D2: Decimals>(
+        &self,
+        other: &ManagedDecimal<M, D2>,
+        rounding: RoundingMode,
+    ) -> Option<Self> {
+        if other.data == 0u32 {
+            return None;
+        }
+        Some(ManagedDecimal::from_raw_units(
+            div_rounded(
+                &(&self.data * &other.scaling_factor()),
+                &other.data,
+                rounding,
+            ),
+            self.decimals.clone(),
+        ))
+    }
+
+    fn same_decimals(&self, other: &Self) -> bool {
+        self.num_decimals() == other.num_decimals()
+    }
+
+    /// Both values brought to the larger number of decimals, no rounding required.
+    fn common_scale_data(&self, other: &Self) -> (BigUint<M>, BigUint<M>) {
+        let self_num_decimals = self.num_decimals();
+        let other_num_decimals = other.num_decimals();
+        match self_num_decimals.cmp(&other_num_decimals) {
+            Ordering::Equal => (self.data.clone(), other.data.clone()),
+            Ordering::Less => (
+                &self.data * &pow10(other_num_decimals - self_num_decimals),
+                other.data.clone(),
+            ),
+            Ordering::Greater => (
+                self.data.clone(),
+                &other.data * &pow10(self_num_decimals - other_num_decimals),
+            ),
+        }
+    }
+}
+
+fn pow10<M: ManagedTypeApi>(exp: NumDecimals) -> BigUint<M> {
+    BigUint::from(10u32).pow(exp as u32)
+}
+
+fn div_rounded<M: ManagedTypeApi>(
+    numerator: &BigUint<M>,
+    denominator: &BigUint<M>,
+    rounding: RoundingMode,
+) -> BigUint<M> {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder == 0u32 {
+        return quotient;
+    }
+
+    let round_up = match rounding {
+        RoundingMode::Down => false,
+        RoundingMode::Up => true,
+        RoundingMode::HalfUp => &remainder * 2u32 >= *denominator,
+        RoundingMode::HalfEven => match (&remainder * 2u32).cmp(denominator) {
+            Ordering::Less => false,
+            Ordering::Equal => &quotient % 2u32 == 1u32,
+            Ordering::Greater => true,
+        },
+    };
+    if round_up {
+        quotient + 1u32
+    } else {
+        quotient
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> Add for ManagedDecimal<M, D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(&other)
+            .unwrap_or_else(|| M::error_api_impl().signal_error(DECIMALS_MISMATCH_ERR_MSG))
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> Sub for ManagedDecimal<M, D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        if !self.same_decimals(&other) {
+            M::error_api_impl().signal_error(DECIMALS_MISMATCH_ERR_MSG);
+        }
+        self.checked_sub(&other)
+            .unwrap_or_else(|| M::error_api_impl().signal_error(SUB_UNDERFLOW_ERR_MSG))
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals, D2: Decimals> Mul<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D>
+{
+    type Output = Self;
+
+    fn mul(self, other: ManagedDecimal<M, D2>) -> Self {
+        self.mul_with_precision(&other, RoundingMode::Down)
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals, D2: Decimals> Div<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D>
+{
+    type Output = Self;
+
+    fn div(self, other: ManagedDecimal<M, D2>) -> Self {
+        self.checked_div(&other, RoundingMode::Down)
+            .unwrap_or_else(|| M::error_api_impl().signal_error(DIVISION_BY_ZERO_ERR_MSG))
+    }
+}
+
+/// Compares the values, even if the numbers of decimals differ: 1.5 == 1.50.
+impl<M: ManagedTypeApi, D: Decimals> PartialEq for ManagedDecimal<M, D> {
+    fn eq(&self, other: &Self) -> bool {
+        let (self_data, other_data) = self.common_scale_data(other);
+        self_data == other_data
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> PartialOrd for ManagedDecimal<M, D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (self_data, other_data) = self.common_scale_data(other);
+        self_data.partial_cmp(&other_data)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TopEncode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> EncodedSize
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
+impl<M: ManagedTypeApi> EncodedSize for ManagedDecimal<M, NumDecimals> {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> NestedEncode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    #[inline]
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TopDecode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ManagedDecimal::from_raw_units(
+            BigUint::top_decode_or_handle_err(input, h)?,
+            ConstDecimals,
+        ))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> NestedDecode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ManagedDecimal::from_raw_units(
+            BigUint::dep_decode_or_handle_err(input, h)?,
+            ConstDecimals,
+        ))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> CodecFromSelf
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TypeAbi
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn type_name() -> TypeName {
+        let mut repr = TypeName::from("ManagedDecimal<");
+        repr.push_str(DECIMALS.to_string().as_str());
+        repr.push('>');
+        repr
+    }
+}
+
+/// The number of decimals is serialized after the data, as `u32`.
+impl<M: ManagedTypeApi> NestedEncode for ManagedDecimal<M, NumDecimals> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)?;
+        (self.decimals as u32).dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi> TopEncode for ManagedDecimal<M, NumDecimals> {
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        elrond_codec::top_encode_from_nested(self, output, h)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedDecode for ManagedDecimal<M, NumDecimals> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let data = BigUint::dep_decode_or_handle_err(input, h)?;
+        let decimals = u32::dep_decode_or_handle_err(input, h)? as NumDecimals;
+        Ok(ManagedDecimal::from_raw_units(data, decimals))
+    }
+}
+
+impl<M: ManagedTypeApi> TopDecode for ManagedDecimal<M, NumDecimals> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        elrond_codec::top_decode_from_nested_or_handle_err(input, h)
+    }
+}
+
+impl<M: ManagedTypeApi> CodecFromSelf for ManagedDecimal<M, NumDecimals> {}
+
+impl<M: ManagedTypeApi> TypeAbi for ManagedDecimal<M, NumDecimals> {
+    fn type_name() -> TypeName {
+        TypeName::from("ManagedDecimal<usize>")
+    }
+}
+
+/// Formats as `<integer part>.<fractional part>`, with all the decimals, e.g. `1.500`.
+impl<M: ManagedTypeApi, D: Decimals> SCDisplay for ManagedDecimal<M, D> {
+    fn fmt<F: FormatByteReceiver>(&self, f: &mut F) {
+        let num_decimals = self.num_decimals();
+        let scaling_factor = self.scaling_factor();
+        SCDisplay::fmt(&(&self.data / &scaling_factor), f);
+        if num_decimals == 0 {
+            return;
+        }
+
+        f.append_bytes(&b"."[..]);
+        let fractional_part = &self.data % &scaling_factor;
+        let mut bound = scaling_factor;
+        for _ in 1..num_decimals {
+            bound /= 10u32;
+            if fractional_part >= bound {
+                break;
+            }
+            f.append_bytes(&b"0"[..]);
+        }
+        SCDisplay::fmt(&fractional_part, f);
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> core::fmt::Debug for ManagedDecimal<M, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ManagedDecimal")
+            .field("data", &self.data)
+            .field("num_decimals", &self.num_decimals())
+            .finish()
+    }
+}
@@ -1,8 +1,8 @@
 use core::marker::PhantomData;
 
 use elrond_codec::{
-    DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
-    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    DecodeErrorHandler, EncodeErrorHandler, EncodedSize, NestedDecode, NestedDecodeInput,
+    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 
 use crate::{
@@ -218,6 +218,15 @@ where
     }
 }
 
+impl<M, T> EncodedSize for ManagedOption<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedType<M> + EncodedSize,
+{
+    const ENCODED_SIZE: Option<usize> = Option::<T>::ENCODED_SIZE;
+    const MAX_ENCODED_LEN: Option<usize> = Option::<T>::MAX_ENCODED_LEN;
+}
+
 impl<M, T> NestedEncode for ManagedOption<M, T>
 where
     M: ManagedTypeApi,
@@ -10,8 +10,8 @@ use crate::{
 use alloc::vec::Vec;
 use core::{borrow::Borrow, iter::FromIterator, marker::PhantomData};
 use elrond_codec::{
-    DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
-    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeMultiOutput,
+    DecodeErrorHandler, EncodeErrorHandler, EncodedSize, NestedDecode, NestedDecodeInput,
+    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeMultiOutput,
     TopEncodeOutput,
 };
 
@@ -146,7 +146,7 @@ where
             return None;
         }
 
-        let mut result_uninit = core::mem::MaybeUninit::<T::Ref<'_>>::uninit_array();
+        let mut result_uninit = [const { core::mem::MaybeUninit::<T::Ref<'_>>::uninit() }; N];
         for (index, value) in self.iter().enumerate() {
             result_uninit[index].write(value);
         }
@@ -391,6 +391,14 @@ where
     }
 }
 
+impl<M, T> EncodedSize for ManagedVec<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedVecItem,
+{
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
 impl<M, T> NestedEncode for ManagedVec<M, T>
 where
     M: ManagedTypeApi,
@@ -5,6 +5,7 @@ mod esdt_token_payment;
 mod managed_address;
 mod managed_buffer_cached_builder;
 mod managed_byte_array;
+mod managed_decimal;
 mod managed_option;
 mod managed_ref;
 mod managed_vec;
@@ -24,6 +25,7 @@ pub use managed_address::ManagedAddress;
 pub use managed_buffer_cached_builder::ManagedBufferCachedBuilder;
 pub(crate) use managed_byte_array::ManagedBufferSizeContext;
 pub use managed_byte_array::ManagedByteArray;
+pub use managed_decimal::{ConstDecimals, Decimals, ManagedDecimal, NumDecimals, RoundingMode};
 pub use managed_option::ManagedOption;
 pub use managed_ref::ManagedRef;
 pub use managed_vec::{
@@ -106,6 +106,10 @@ impl<M: ManagedTypeApi> PartialEq<EgldOrEsdtTokenIdentifier<M>> for TokenIdentif
     }
 }
 
+impl<M: ManagedTypeApi> EncodedSize for TokenIdentifier<M> {
+    const ENCODED_SIZE: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> NestedEncode for TokenIdentifier<M> {
     #[inline]
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
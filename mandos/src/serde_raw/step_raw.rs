@@ -12,6 +12,10 @@ use crate::serde_raw::{
 #[serde(tag = "step")]
 pub enum StepRaw {
     ExternalSteps {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
@@ -21,6 +25,10 @@ pub enum StepRaw {
 
     #[serde(rename_all = "camelCase")]
     SetState {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
@@ -55,6 +63,10 @@ pub enum StepRaw {
         #[serde(skip_serializing_if = "Option::is_none")]
         tx_id: Option<String>,
 
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
@@ -79,6 +91,10 @@ pub enum StepRaw {
         #[serde(skip_serializing_if = "Option::is_none")]
         tx_id: Option<String>,
 
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
@@ -103,6 +119,10 @@ pub enum StepRaw {
         #[serde(skip_serializing_if = "Option::is_none")]
         tx_id: Option<String>,
 
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
@@ -127,6 +147,10 @@ pub enum StepRaw {
         #[serde(skip_serializing_if = "Option::is_none")]
         tx_id: Option<String>,
 
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
@@ -143,6 +167,10 @@ pub enum StepRaw {
         #[serde(skip_serializing_if = "Option::is_none")]
         tx_id: Option<String>,
 
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
@@ -151,6 +179,10 @@ pub enum StepRaw {
     },
 
     CheckState {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
@@ -159,6 +191,10 @@ pub enum StepRaw {
     },
 
     DumpState {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
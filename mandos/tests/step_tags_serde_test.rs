@@ -0,0 +1,24 @@
+use mandos::serde_raw::ScenarioRaw;
+
+const SCENARIO_JSON: &str = r#"{
+    "steps": [
+        {
+            "step": "setState",
+            "tags": ["setup"],
+            "accounts": {}
+        },
+        {
+            "step": "checkState",
+            "accounts": {}
+        }
+    ]
+}"#;
+
+/// Untagged steps serialize exactly as before tags were introduced.
+#[test]
+fn step_tags_serde_test() {
+    let scenario = ScenarioRaw::from_json_str(SCENARIO_JSON);
+    let json = scenario.to_json_string();
+    assert_eq!(json.matches("\"tags\"").count(), 1);
+    assert!(json.contains("\"setup\""));
+}